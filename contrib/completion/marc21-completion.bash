@@ -34,6 +34,9 @@ _marc21() {
             marc21,concat)
                 cmd="marc21__subcmd__concat"
                 ;;
+            marc21,convert)
+                cmd="marc21__subcmd__convert"
+                ;;
             marc21,count)
                 cmd="marc21__subcmd__count"
                 ;;
@@ -61,9 +64,15 @@ _marc21() {
             marc21,hash)
                 cmd="marc21__subcmd__hash"
                 ;;
+            marc21,index)
+                cmd="marc21__subcmd__index"
+                ;;
             marc21,invalid)
                 cmd="marc21__subcmd__invalid"
                 ;;
+            marc21,lookup)
+                cmd="marc21__subcmd__lookup"
+                ;;
             marc21,partition)
                 cmd="marc21__subcmd__partition"
                 ;;
@@ -89,7 +98,7 @@ _marc21() {
 
     case "${cmd}" in
         marc21)
-            opts="-h -V --help --version check concat cat count cnt dedup describe filter frequency freq glimpse grep hash invalid partition print sample select skosify split build-completion build-man"
+            opts="-h -V --help --version check concat cat convert count cnt dedup describe filter frequency freq glimpse grep hash index invalid lookup partition print sample select skosify split build-completion build-man"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 1 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -147,7 +156,7 @@ _marc21() {
             return 0
             ;;
         marc21__subcmd__check)
-            opts="-R -o -s -l -p -h --rule-set --output --skip-invalid --limit --strsim-threshold --where --filter-normalization --progress --compression --help"
+            opts="-R -o -s -l -p -h --rule-set --output --skip-invalid --limit --strsim-threshold --strsim-algorithm --filter-ignore-case --filter-ignore-diacritics --filter-ignore-normalization --where --filter-normalization --progress --compression --bgzf --marc8 --lenient --control-fields --threads --oversize --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -181,6 +190,10 @@ _marc21() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --strsim-algorithm)
+                    COMPREPLY=($(compgen -W "levenshtein jaro-winkler sorensen-dice token-set" -- "${cur}"))
+                    return 0
+                    ;;
                 --where)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
@@ -193,6 +206,18 @@ _marc21() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --control-fields)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --threads)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --oversize)
+                    COMPREPLY=($(compgen -W "error split truncate keep" -- "${cur}"))
+                    return 0
+                    ;;
                 *)
                     COMPREPLY=()
                     ;;
@@ -201,7 +226,7 @@ _marc21() {
             return 0
             ;;
         marc21__subcmd__concat)
-            opts="-a -o -s -l -p -h --append --tee --output --skip-invalid --limit --strsim-threshold --where --filter-normalization --progress --compression --help"
+            opts="-a -o -s -l -p -h --append --tee --output --skip-invalid --limit --strsim-threshold --strsim-algorithm --filter-ignore-case --filter-ignore-diacritics --filter-ignore-normalization --where --filter-normalization --progress --compression --bgzf --marc8 --lenient --control-fields --threads --oversize --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -231,6 +256,76 @@ _marc21() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --strsim-algorithm)
+                    COMPREPLY=($(compgen -W "levenshtein jaro-winkler sorensen-dice token-set" -- "${cur}"))
+                    return 0
+                    ;;
+                --where)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --filter-normalization)
+                    COMPREPLY=($(compgen -W "nfd nfkd nfc nfkc" -- "${cur}"))
+                    return 0
+                    ;;
+                --compression)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --control-fields)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --threads)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --oversize)
+                    COMPREPLY=($(compgen -W "error split truncate keep" -- "${cur}"))
+                    return 0
+                    ;;
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        marc21__subcmd__convert)
+            opts="-o -s -l -p -h --to --pretty --no-collection --output --skip-invalid --limit --strsim-threshold --strsim-algorithm --filter-ignore-case --filter-ignore-diacritics --filter-ignore-normalization --where --filter-normalization --progress --compression --bgzf --marc8 --lenient --control-fields --threads --oversize --help"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                --to)
+                    COMPREPLY=($(compgen -W "marcxml json jsonl" -- "${cur}"))
+                    return 0
+                    ;;
+                --output)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                -o)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --limit)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                -l)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --strsim-threshold)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --strsim-algorithm)
+                    COMPREPLY=($(compgen -W "levenshtein jaro-winkler sorensen-dice token-set" -- "${cur}"))
+                    return 0
+                    ;;
                 --where)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
@@ -243,6 +338,18 @@ _marc21() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --control-fields)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --threads)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --oversize)
+                    COMPREPLY=($(compgen -W "error split truncate keep" -- "${cur}"))
+                    return 0
+                    ;;
                 *)
                     COMPREPLY=()
                     ;;
@@ -251,7 +358,7 @@ _marc21() {
             return 0
             ;;
         marc21__subcmd__count)
-            opts="-o -s -l -p -h --output --skip-invalid --limit --strsim-threshold --where --filter-normalization --progress --compression --help"
+            opts="-o -s -l -p -h --output --skip-invalid --limit --strsim-threshold --strsim-algorithm --filter-ignore-case --filter-ignore-diacritics --filter-ignore-normalization --where --filter-normalization --progress --compression --bgzf --marc8 --lenient --control-fields --threads --oversize --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -277,6 +384,10 @@ _marc21() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --strsim-algorithm)
+                    COMPREPLY=($(compgen -W "levenshtein jaro-winkler sorensen-dice token-set" -- "${cur}"))
+                    return 0
+                    ;;
                 --where)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
@@ -289,6 +400,18 @@ _marc21() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --control-fields)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --threads)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --oversize)
+                    COMPREPLY=($(compgen -W "error split truncate keep" -- "${cur}"))
+                    return 0
+                    ;;
                 *)
                     COMPREPLY=()
                     ;;
@@ -297,7 +420,7 @@ _marc21() {
             return 0
             ;;
         marc21__subcmd__dedup)
-            opts="-o -s -l -p -h --output --skip-invalid --limit --strsim-threshold --where --filter-normalization --progress --compression --help"
+            opts="-o -s -l -p -h --output --skip-invalid --limit --strsim-threshold --strsim-algorithm --filter-ignore-case --filter-ignore-diacritics --filter-ignore-normalization --where --filter-normalization --progress --compression --bgzf --marc8 --lenient --control-fields --threads --oversize --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -323,6 +446,10 @@ _marc21() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --strsim-algorithm)
+                    COMPREPLY=($(compgen -W "levenshtein jaro-winkler sorensen-dice token-set" -- "${cur}"))
+                    return 0
+                    ;;
                 --where)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
@@ -335,6 +462,18 @@ _marc21() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --control-fields)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --threads)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --oversize)
+                    COMPREPLY=($(compgen -W "error split truncate keep" -- "${cur}"))
+                    return 0
+                    ;;
                 *)
                     COMPREPLY=()
                     ;;
@@ -343,7 +482,7 @@ _marc21() {
             return 0
             ;;
         marc21__subcmd__describe)
-            opts="-o -s -l -p -h --tsv --output --skip-invalid --limit --strsim-threshold --where --filter-normalization --progress --compression --help"
+            opts="-o -s -l -p -h --tsv --output --skip-invalid --limit --strsim-threshold --strsim-algorithm --filter-ignore-case --filter-ignore-diacritics --filter-ignore-normalization --where --filter-normalization --progress --compression --bgzf --marc8 --lenient --control-fields --threads --oversize --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -369,6 +508,10 @@ _marc21() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --strsim-algorithm)
+                    COMPREPLY=($(compgen -W "levenshtein jaro-winkler sorensen-dice token-set" -- "${cur}"))
+                    return 0
+                    ;;
                 --where)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
@@ -381,6 +524,18 @@ _marc21() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --control-fields)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --threads)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --oversize)
+                    COMPREPLY=($(compgen -W "error split truncate keep" -- "${cur}"))
+                    return 0
+                    ;;
                 *)
                     COMPREPLY=()
                     ;;
@@ -389,7 +544,7 @@ _marc21() {
             return 0
             ;;
         marc21__subcmd__filter)
-            opts="-s -v -l -o -p -h --skip-invalid --invert-match --limit --strsim-threshold --filter-normalization --output --progress --compression --help"
+            opts="-s -v -l -o -p -h --skip-invalid --invert-match --limit --strsim-threshold --strsim-algorithm --filter-ignore-case --filter-ignore-diacritics --filter-ignore-normalization --filter-normalization --output --progress --compression --bgzf --marc8 --lenient --control-fields --threads --oversize --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -407,6 +562,10 @@ _marc21() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --strsim-algorithm)
+                    COMPREPLY=($(compgen -W "levenshtein jaro-winkler sorensen-dice token-set" -- "${cur}"))
+                    return 0
+                    ;;
                 --filter-normalization)
                     COMPREPLY=($(compgen -W "nfd nfkd nfc nfkc" -- "${cur}"))
                     return 0
@@ -423,6 +582,18 @@ _marc21() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --control-fields)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --threads)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --oversize)
+                    COMPREPLY=($(compgen -W "error split truncate keep" -- "${cur}"))
+                    return 0
+                    ;;
                 *)
                     COMPREPLY=()
                     ;;
@@ -431,7 +602,7 @@ _marc21() {
             return 0
             ;;
         marc21__subcmd__frequency)
-            opts="-u -r -t -n -H -o -s -l -p -h --unique --reverse --threshold --num --tsv --header --output --skip-invalid --limit --strsim-threshold --where --filter-normalization --progress --compression --help"
+            opts="-u -r -t -n -H -o -s -l -p -h --unique --reverse --threshold --num --tsv --header --output --skip-invalid --limit --strsim-threshold --strsim-algorithm --filter-ignore-case --filter-ignore-diacritics --filter-ignore-normalization --where --filter-normalization --progress --compression --bgzf --marc8 --lenient --control-fields --threads --oversize --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -481,6 +652,10 @@ _marc21() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --strsim-algorithm)
+                    COMPREPLY=($(compgen -W "levenshtein jaro-winkler sorensen-dice token-set" -- "${cur}"))
+                    return 0
+                    ;;
                 --where)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
@@ -493,6 +668,18 @@ _marc21() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --control-fields)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --threads)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --oversize)
+                    COMPREPLY=($(compgen -W "error split truncate keep" -- "${cur}"))
+                    return 0
+                    ;;
                 *)
                     COMPREPLY=()
                     ;;
@@ -501,7 +688,7 @@ _marc21() {
             return 0
             ;;
         marc21__subcmd__glimpse)
-            opts="-n -o -s -l -p -h --max-values --output --skip-invalid --limit --strsim-threshold --where --filter-normalization --progress --compression --help"
+            opts="-n -o -s -l -p -h --max-values --output --skip-invalid --limit --strsim-threshold --strsim-algorithm --filter-ignore-case --filter-ignore-diacritics --filter-ignore-normalization --where --filter-normalization --progress --compression --bgzf --marc8 --lenient --control-fields --threads --oversize --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -535,6 +722,10 @@ _marc21() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --strsim-algorithm)
+                    COMPREPLY=($(compgen -W "levenshtein jaro-winkler sorensen-dice token-set" -- "${cur}"))
+                    return 0
+                    ;;
                 --where)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
@@ -547,6 +738,18 @@ _marc21() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --control-fields)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --threads)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --oversize)
+                    COMPREPLY=($(compgen -W "error split truncate keep" -- "${cur}"))
+                    return 0
+                    ;;
                 *)
                     COMPREPLY=()
                     ;;
@@ -555,7 +758,7 @@ _marc21() {
             return 0
             ;;
         marc21__subcmd__grep)
-            opts="-i -v -o -s -l -p -h --or --ignore-case --invert-match --output --skip-invalid --limit --strsim-threshold --where --filter-normalization --progress --compression --help"
+            opts="-i -v -o -s -l -p -h --or --ignore-case --invert-match --output --skip-invalid --limit --strsim-threshold --strsim-algorithm --filter-ignore-case --filter-ignore-diacritics --filter-ignore-normalization --where --filter-normalization --progress --compression --bgzf --marc8 --lenient --control-fields --threads --oversize --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -585,6 +788,10 @@ _marc21() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --strsim-algorithm)
+                    COMPREPLY=($(compgen -W "levenshtein jaro-winkler sorensen-dice token-set" -- "${cur}"))
+                    return 0
+                    ;;
                 --where)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
@@ -597,6 +804,18 @@ _marc21() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --control-fields)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --threads)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --oversize)
+                    COMPREPLY=($(compgen -W "error split truncate keep" -- "${cur}"))
+                    return 0
+                    ;;
                 *)
                     COMPREPLY=()
                     ;;
@@ -605,7 +824,7 @@ _marc21() {
             return 0
             ;;
         marc21__subcmd__hash)
-            opts="-o -s -l -p -h --tsv --output --skip-invalid --limit --strsim-threshold --where --filter-normalization --progress --compression --help"
+            opts="-o -s -l -p -h --tsv --output --skip-invalid --limit --strsim-threshold --strsim-algorithm --filter-ignore-case --filter-ignore-diacritics --filter-ignore-normalization --where --filter-normalization --progress --compression --bgzf --marc8 --lenient --control-fields --threads --oversize --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -631,6 +850,10 @@ _marc21() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --strsim-algorithm)
+                    COMPREPLY=($(compgen -W "levenshtein jaro-winkler sorensen-dice token-set" -- "${cur}"))
+                    return 0
+                    ;;
                 --where)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
@@ -643,6 +866,88 @@ _marc21() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --control-fields)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --threads)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --oversize)
+                    COMPREPLY=($(compgen -W "error split truncate keep" -- "${cur}"))
+                    return 0
+                    ;;
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        marc21__subcmd__index)
+            opts="-k -o -s -l -p -h --key --output --skip-invalid --limit --strsim-threshold --strsim-algorithm --filter-ignore-case --filter-ignore-diacritics --filter-ignore-normalization --where --filter-normalization --progress --compression --bgzf --marc8 --lenient --control-fields --threads --oversize --help"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                --key)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                -k)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --output)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                -o)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --limit)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                -l)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --strsim-threshold)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --strsim-algorithm)
+                    COMPREPLY=($(compgen -W "levenshtein jaro-winkler sorensen-dice token-set" -- "${cur}"))
+                    return 0
+                    ;;
+                --where)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --filter-normalization)
+                    COMPREPLY=($(compgen -W "nfd nfkd nfc nfkc" -- "${cur}"))
+                    return 0
+                    ;;
+                --compression)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --control-fields)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --threads)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --oversize)
+                    COMPREPLY=($(compgen -W "error split truncate keep" -- "${cur}"))
+                    return 0
+                    ;;
                 *)
                     COMPREPLY=()
                     ;;
@@ -651,7 +956,7 @@ _marc21() {
             return 0
             ;;
         marc21__subcmd__invalid)
-            opts="-o -p -h --output --progress --compression --help"
+            opts="-o -v -p -h --output --verbose --progress --compression --bgzf --marc8 --lenient --control-fields --threads --oversize --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -669,6 +974,56 @@ _marc21() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --control-fields)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --threads)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --oversize)
+                    COMPREPLY=($(compgen -W "error split truncate keep" -- "${cur}"))
+                    return 0
+                    ;;
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        marc21__subcmd__lookup)
+            opts="-o -p -h --output --progress --compression --bgzf --marc8 --lenient --control-fields --threads --oversize --help"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                --output)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                -o)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --compression)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --control-fields)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --threads)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --oversize)
+                    COMPREPLY=($(compgen -W "error split truncate keep" -- "${cur}"))
+                    return 0
+                    ;;
                 *)
                     COMPREPLY=()
                     ;;
@@ -677,7 +1032,7 @@ _marc21() {
             return 0
             ;;
         marc21__subcmd__partition)
-            opts="-t -o -s -l -p -h --template --output --skip-invalid --limit --strsim-threshold --where --filter-normalization --progress --compression --help"
+            opts="-t -o -s -l -p -h --template --output --skip-invalid --limit --strsim-threshold --strsim-algorithm --filter-ignore-case --filter-ignore-diacritics --filter-ignore-normalization --where --filter-normalization --progress --compression --bgzf --marc8 --lenient --control-fields --threads --oversize --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -711,6 +1066,10 @@ _marc21() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --strsim-algorithm)
+                    COMPREPLY=($(compgen -W "levenshtein jaro-winkler sorensen-dice token-set" -- "${cur}"))
+                    return 0
+                    ;;
                 --where)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
@@ -723,6 +1082,18 @@ _marc21() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --control-fields)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --threads)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --oversize)
+                    COMPREPLY=($(compgen -W "error split truncate keep" -- "${cur}"))
+                    return 0
+                    ;;
                 *)
                     COMPREPLY=()
                     ;;
@@ -731,7 +1102,7 @@ _marc21() {
             return 0
             ;;
         marc21__subcmd__print)
-            opts="-o -s -l -p -h --translit --output --skip-invalid --limit --strsim-threshold --where --filter-normalization --progress --compression --help"
+            opts="-o -s -l -p -h --translit --output --skip-invalid --limit --strsim-threshold --strsim-algorithm --filter-ignore-case --filter-ignore-diacritics --filter-ignore-normalization --where --filter-normalization --progress --compression --bgzf --marc8 --lenient --control-fields --threads --oversize --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -761,6 +1132,10 @@ _marc21() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --strsim-algorithm)
+                    COMPREPLY=($(compgen -W "levenshtein jaro-winkler sorensen-dice token-set" -- "${cur}"))
+                    return 0
+                    ;;
                 --where)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
@@ -773,6 +1148,18 @@ _marc21() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --control-fields)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --threads)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --oversize)
+                    COMPREPLY=($(compgen -W "error split truncate keep" -- "${cur}"))
+                    return 0
+                    ;;
                 *)
                     COMPREPLY=()
                     ;;
@@ -781,7 +1168,7 @@ _marc21() {
             return 0
             ;;
         marc21__subcmd__sample)
-            opts="-o -s -l -p -h --seed --output --skip-invalid --limit --strsim-threshold --where --filter-normalization --progress --compression --help"
+            opts="-o -s -l -p -h --seed --output --skip-invalid --limit --strsim-threshold --strsim-algorithm --filter-ignore-case --filter-ignore-diacritics --filter-ignore-normalization --where --filter-normalization --progress --compression --bgzf --marc8 --lenient --control-fields --threads --oversize --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -811,6 +1198,10 @@ _marc21() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --strsim-algorithm)
+                    COMPREPLY=($(compgen -W "levenshtein jaro-winkler sorensen-dice token-set" -- "${cur}"))
+                    return 0
+                    ;;
                 --where)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
@@ -823,6 +1214,18 @@ _marc21() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --control-fields)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --threads)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --oversize)
+                    COMPREPLY=($(compgen -W "error split truncate keep" -- "${cur}"))
+                    return 0
+                    ;;
                 *)
                     COMPREPLY=()
                     ;;
@@ -831,7 +1234,7 @@ _marc21() {
             return 0
             ;;
         marc21__subcmd__select)
-            opts="-H -o -s -l -p -h --tsv --header --output --skip-invalid --limit --strsim-threshold --where --filter-normalization --progress --compression --help"
+            opts="-H -o -s -l -p -h --tsv --header --output --skip-invalid --limit --strsim-threshold --strsim-algorithm --filter-ignore-case --filter-ignore-diacritics --filter-ignore-normalization --where --filter-normalization --progress --compression --bgzf --marc8 --lenient --control-fields --threads --oversize --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -865,6 +1268,10 @@ _marc21() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --strsim-algorithm)
+                    COMPREPLY=($(compgen -W "levenshtein jaro-winkler sorensen-dice token-set" -- "${cur}"))
+                    return 0
+                    ;;
                 --where)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
@@ -877,6 +1284,18 @@ _marc21() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --control-fields)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --threads)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --oversize)
+                    COMPREPLY=($(compgen -W "error split truncate keep" -- "${cur}"))
+                    return 0
+                    ;;
                 *)
                     COMPREPLY=()
                     ;;
@@ -885,7 +1304,7 @@ _marc21() {
             return 0
             ;;
         marc21__subcmd__skosify)
-            opts="-c -o -s -l -p -h --config --format --output --skip-invalid --limit --strsim-threshold --where --filter-normalization --progress --compression --help"
+            opts="-c -o -s -l -p -h --config --format --output --skip-invalid --limit --strsim-threshold --strsim-algorithm --filter-ignore-case --filter-ignore-diacritics --filter-ignore-normalization --where --filter-normalization --progress --compression --bgzf --marc8 --lenient --control-fields --threads --oversize --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -923,6 +1342,10 @@ _marc21() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --strsim-algorithm)
+                    COMPREPLY=($(compgen -W "levenshtein jaro-winkler sorensen-dice token-set" -- "${cur}"))
+                    return 0
+                    ;;
                 --where)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
@@ -935,6 +1358,18 @@ _marc21() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --control-fields)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --threads)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --oversize)
+                    COMPREPLY=($(compgen -W "error split truncate keep" -- "${cur}"))
+                    return 0
+                    ;;
                 *)
                     COMPREPLY=()
                     ;;
@@ -943,7 +1378,7 @@ _marc21() {
             return 0
             ;;
         marc21__subcmd__split)
-            opts="-o -s -l -p -h --filename --outdir --skip-invalid --limit --strsim-threshold --where --filter-normalization --progress --compression --help"
+            opts="-o -s -l -p -h --filename --outdir --skip-invalid --limit --strsim-threshold --strsim-algorithm --filter-ignore-case --filter-ignore-diacritics --filter-ignore-normalization --where --filter-normalization --progress --compression --bgzf --marc8 --lenient --control-fields --threads --oversize --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -973,6 +1408,10 @@ _marc21() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --strsim-algorithm)
+                    COMPREPLY=($(compgen -W "levenshtein jaro-winkler sorensen-dice token-set" -- "${cur}"))
+                    return 0
+                    ;;
                 --where)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
@@ -985,6 +1424,18 @@ _marc21() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --control-fields)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --threads)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --oversize)
+                    COMPREPLY=($(compgen -W "error split truncate keep" -- "${cur}"))
+                    return 0
+                    ;;
                 *)
                     COMPREPLY=()
                     ;;
@@ -29,6 +29,7 @@ complete -c marc21 -n "__fish_marc21_needs_command" -s V -l version -d 'Print ve
 complete -c marc21 -n "__fish_marc21_needs_command" -f -a "check" -d 'Validate records against rule sets'
 complete -c marc21 -n "__fish_marc21_needs_command" -f -a "concat" -d 'Concatenate records from multiple inputs'
 complete -c marc21 -n "__fish_marc21_needs_command" -f -a "cat" -d 'Concatenate records from multiple inputs'
+complete -c marc21 -n "__fish_marc21_needs_command" -f -a "convert" -d 'Convert records into another format'
 complete -c marc21 -n "__fish_marc21_needs_command" -f -a "count" -d 'Prints the number of records in the input data'
 complete -c marc21 -n "__fish_marc21_needs_command" -f -a "cnt" -d 'Prints the number of records in the input data'
 complete -c marc21 -n "__fish_marc21_needs_command" -f -a "dedup" -d 'Remove duplicate records from the input'
@@ -39,7 +40,9 @@ complete -c marc21 -n "__fish_marc21_needs_command" -f -a "freq" -d 'Compute a f
 complete -c marc21 -n "__fish_marc21_needs_command" -f -a "glimpse" -d 'Print a dense preview of a data field'
 complete -c marc21 -n "__fish_marc21_needs_command" -f -a "grep" -d 'Search for records whose values match a pattern'
 complete -c marc21 -n "__fish_marc21_needs_command" -f -a "hash" -d 'Compute SHA-256 checksum of records'
+complete -c marc21 -n "__fish_marc21_needs_command" -f -a "index" -d 'Build an index of record offsets'
 complete -c marc21 -n "__fish_marc21_needs_command" -f -a "invalid" -d 'Output invalid records that cannot be decoded'
+complete -c marc21 -n "__fish_marc21_needs_command" -f -a "lookup" -d 'Read records by key from an index'
 complete -c marc21 -n "__fish_marc21_needs_command" -f -a "partition" -d 'Partition records by values'
 complete -c marc21 -n "__fish_marc21_needs_command" -f -a "print" -d 'Print records in human readable format'
 complete -c marc21 -n "__fish_marc21_needs_command" -f -a "sample" -d 'Select a random permutation of records'
@@ -51,198 +54,507 @@ complete -c marc21 -n "__fish_marc21_needs_command" -f -a "build-man"
 complete -c marc21 -n "__fish_marc21_using_subcommand check" -s R -l rule-set -d 'A set of rules to be checked' -r -F
 complete -c marc21 -n "__fish_marc21_using_subcommand check" -s o -l output -d 'Write output to <filename> instead of stdout' -r -F
 complete -c marc21 -n "__fish_marc21_using_subcommand check" -s l -l limit -d 'Limit the result to first <n> records (a limit value `0` means no limit)' -r
-complete -c marc21 -n "__fish_marc21_using_subcommand check" -l strsim-threshold -d 'The minimum score for string similarity comparisons. The value must be between 0 and 100' -r
-complete -c marc21 -n "__fish_marc21_using_subcommand check" -l where -d 'An expression for filtering records' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand check" -l strsim-threshold -d 'The minimum score for string similarity comparisons. The value must be between 0 and 100. If not set, the default threshold of the algorithm is used (90 for `jaro-winkler`, otherwise 80)' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand check" -l strsim-algorithm -d 'The algorithm for string similarity comparisons, which don\'t specify an algorithm of their own (e.g. `=*(token-set)`)' -r -f -a "levenshtein\t'Normalized Levenshtein distance'
+jaro-winkler\t'Jaro-Winkler similarity'
+sorensen-dice\t'Sørensen-Dice coefficient of character bigrams'
+token-set\t'Similarity of the sets of words (ignores the word order)'"
+complete -c marc21 -n "__fish_marc21_using_subcommand check" -l where -d 'An expression for filtering records. Only if invalid records are skipped, records that can\'t match are rejected before they are decoded' -r
 complete -c marc21 -n "__fish_marc21_using_subcommand check" -l filter-normalization -d 'Transliterate the given filter or query expression into the specified Unicode normal form' -r -f -a "nfd\t''
 nfkd\t''
 nfc\t''
 nfkc\t''"
 complete -c marc21 -n "__fish_marc21_using_subcommand check" -l compression -d 'Specify compression level' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand check" -l control-fields -d 'A comma-separated list of tag matchers, which decides whether a field is a control field (default: `00.`). Local control fields can be added, e.g. `00.,FMT,SYS`' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand check" -l threads -d 'Number of threads used to parse and process records. The value `0` selects the number of available CPUs' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand check" -l oversize -d 'How to write records, which exceed the maximum record length of 99999 bytes. By default, such records are written in the (non-standard) form in which they were read, which is also the default of the library' -r -f -a "error\t'Reject the record with an error'
+split\t'Split the record into records linked by a 773 field'
+truncate\t'Drop the fields, which don\'t fit into the limit'
+keep\t'Write the non-standard oversize form'"
 complete -c marc21 -n "__fish_marc21_using_subcommand check" -s s -l skip-invalid -d 'Skip invalid records that can\'t be decoded'
+complete -c marc21 -n "__fish_marc21_using_subcommand check" -l filter-ignore-case -d 'Compare strings regardless of their case'
+complete -c marc21 -n "__fish_marc21_using_subcommand check" -l filter-ignore-diacritics -d 'Compare strings regardless of their diacritics'
+complete -c marc21 -n "__fish_marc21_using_subcommand check" -l filter-ignore-normalization -d 'Compare strings regardless of their Unicode normal form'
 complete -c marc21 -n "__fish_marc21_using_subcommand check" -s p -l progress -d 'If set, show a progress bar'
-complete -c marc21 -n "__fish_marc21_using_subcommand check" -s h -l help -d 'Print help'
+complete -c marc21 -n "__fish_marc21_using_subcommand check" -l bgzf -d 'If set, gzip compressed output is written as block-gzip (BGZF), which allows `marc21 lookup` to seek directly to a record'
+complete -c marc21 -n "__fish_marc21_using_subcommand check" -l marc8 -d 'If set, MARC-8 encoded records are transcoded into UTF-8'
+complete -c marc21 -n "__fish_marc21_using_subcommand check" -l lenient -d 'If set, malformed records are repaired instead of rejected. Each repair is reported as a warning'
+complete -c marc21 -n "__fish_marc21_using_subcommand check" -s h -l help -d 'Print help (see more with \'--help\')'
 complete -c marc21 -n "__fish_marc21_using_subcommand concat" -l tee -d 'Write to another output file at the same time' -r -F
 complete -c marc21 -n "__fish_marc21_using_subcommand concat" -s o -l output -d 'Write output to <filename> instead of stdout' -r -F
 complete -c marc21 -n "__fish_marc21_using_subcommand concat" -s l -l limit -d 'Limit the result to first <n> records (a limit value `0` means no limit)' -r
-complete -c marc21 -n "__fish_marc21_using_subcommand concat" -l strsim-threshold -d 'The minimum score for string similarity comparisons. The value must be between 0 and 100' -r
-complete -c marc21 -n "__fish_marc21_using_subcommand concat" -l where -d 'An expression for filtering records' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand concat" -l strsim-threshold -d 'The minimum score for string similarity comparisons. The value must be between 0 and 100. If not set, the default threshold of the algorithm is used (90 for `jaro-winkler`, otherwise 80)' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand concat" -l strsim-algorithm -d 'The algorithm for string similarity comparisons, which don\'t specify an algorithm of their own (e.g. `=*(token-set)`)' -r -f -a "levenshtein\t'Normalized Levenshtein distance'
+jaro-winkler\t'Jaro-Winkler similarity'
+sorensen-dice\t'Sørensen-Dice coefficient of character bigrams'
+token-set\t'Similarity of the sets of words (ignores the word order)'"
+complete -c marc21 -n "__fish_marc21_using_subcommand concat" -l where -d 'An expression for filtering records. Only if invalid records are skipped, records that can\'t match are rejected before they are decoded' -r
 complete -c marc21 -n "__fish_marc21_using_subcommand concat" -l filter-normalization -d 'Transliterate the given filter or query expression into the specified Unicode normal form' -r -f -a "nfd\t''
 nfkd\t''
 nfc\t''
 nfkc\t''"
 complete -c marc21 -n "__fish_marc21_using_subcommand concat" -l compression -d 'Specify compression level' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand concat" -l control-fields -d 'A comma-separated list of tag matchers, which decides whether a field is a control field (default: `00.`). Local control fields can be added, e.g. `00.,FMT,SYS`' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand concat" -l threads -d 'Number of threads used to parse and process records. The value `0` selects the number of available CPUs' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand concat" -l oversize -d 'How to write records, which exceed the maximum record length of 99999 bytes. By default, such records are written in the (non-standard) form in which they were read, which is also the default of the library' -r -f -a "error\t'Reject the record with an error'
+split\t'Split the record into records linked by a 773 field'
+truncate\t'Drop the fields, which don\'t fit into the limit'
+keep\t'Write the non-standard oversize form'"
 complete -c marc21 -n "__fish_marc21_using_subcommand concat" -s a -l append -d 'Append to the given file, do not overwrite'
 complete -c marc21 -n "__fish_marc21_using_subcommand concat" -s s -l skip-invalid -d 'Skip invalid records that can\'t be decoded'
+complete -c marc21 -n "__fish_marc21_using_subcommand concat" -l filter-ignore-case -d 'Compare strings regardless of their case'
+complete -c marc21 -n "__fish_marc21_using_subcommand concat" -l filter-ignore-diacritics -d 'Compare strings regardless of their diacritics'
+complete -c marc21 -n "__fish_marc21_using_subcommand concat" -l filter-ignore-normalization -d 'Compare strings regardless of their Unicode normal form'
 complete -c marc21 -n "__fish_marc21_using_subcommand concat" -s p -l progress -d 'If set, show a progress bar'
+complete -c marc21 -n "__fish_marc21_using_subcommand concat" -l bgzf -d 'If set, gzip compressed output is written as block-gzip (BGZF), which allows `marc21 lookup` to seek directly to a record'
+complete -c marc21 -n "__fish_marc21_using_subcommand concat" -l marc8 -d 'If set, MARC-8 encoded records are transcoded into UTF-8'
+complete -c marc21 -n "__fish_marc21_using_subcommand concat" -l lenient -d 'If set, malformed records are repaired instead of rejected. Each repair is reported as a warning'
 complete -c marc21 -n "__fish_marc21_using_subcommand concat" -s h -l help -d 'Print help (see more with \'--help\')'
 complete -c marc21 -n "__fish_marc21_using_subcommand cat" -l tee -d 'Write to another output file at the same time' -r -F
 complete -c marc21 -n "__fish_marc21_using_subcommand cat" -s o -l output -d 'Write output to <filename> instead of stdout' -r -F
 complete -c marc21 -n "__fish_marc21_using_subcommand cat" -s l -l limit -d 'Limit the result to first <n> records (a limit value `0` means no limit)' -r
-complete -c marc21 -n "__fish_marc21_using_subcommand cat" -l strsim-threshold -d 'The minimum score for string similarity comparisons. The value must be between 0 and 100' -r
-complete -c marc21 -n "__fish_marc21_using_subcommand cat" -l where -d 'An expression for filtering records' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand cat" -l strsim-threshold -d 'The minimum score for string similarity comparisons. The value must be between 0 and 100. If not set, the default threshold of the algorithm is used (90 for `jaro-winkler`, otherwise 80)' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand cat" -l strsim-algorithm -d 'The algorithm for string similarity comparisons, which don\'t specify an algorithm of their own (e.g. `=*(token-set)`)' -r -f -a "levenshtein\t'Normalized Levenshtein distance'
+jaro-winkler\t'Jaro-Winkler similarity'
+sorensen-dice\t'Sørensen-Dice coefficient of character bigrams'
+token-set\t'Similarity of the sets of words (ignores the word order)'"
+complete -c marc21 -n "__fish_marc21_using_subcommand cat" -l where -d 'An expression for filtering records. Only if invalid records are skipped, records that can\'t match are rejected before they are decoded' -r
 complete -c marc21 -n "__fish_marc21_using_subcommand cat" -l filter-normalization -d 'Transliterate the given filter or query expression into the specified Unicode normal form' -r -f -a "nfd\t''
 nfkd\t''
 nfc\t''
 nfkc\t''"
 complete -c marc21 -n "__fish_marc21_using_subcommand cat" -l compression -d 'Specify compression level' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand cat" -l control-fields -d 'A comma-separated list of tag matchers, which decides whether a field is a control field (default: `00.`). Local control fields can be added, e.g. `00.,FMT,SYS`' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand cat" -l threads -d 'Number of threads used to parse and process records. The value `0` selects the number of available CPUs' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand cat" -l oversize -d 'How to write records, which exceed the maximum record length of 99999 bytes. By default, such records are written in the (non-standard) form in which they were read, which is also the default of the library' -r -f -a "error\t'Reject the record with an error'
+split\t'Split the record into records linked by a 773 field'
+truncate\t'Drop the fields, which don\'t fit into the limit'
+keep\t'Write the non-standard oversize form'"
 complete -c marc21 -n "__fish_marc21_using_subcommand cat" -s a -l append -d 'Append to the given file, do not overwrite'
 complete -c marc21 -n "__fish_marc21_using_subcommand cat" -s s -l skip-invalid -d 'Skip invalid records that can\'t be decoded'
+complete -c marc21 -n "__fish_marc21_using_subcommand cat" -l filter-ignore-case -d 'Compare strings regardless of their case'
+complete -c marc21 -n "__fish_marc21_using_subcommand cat" -l filter-ignore-diacritics -d 'Compare strings regardless of their diacritics'
+complete -c marc21 -n "__fish_marc21_using_subcommand cat" -l filter-ignore-normalization -d 'Compare strings regardless of their Unicode normal form'
 complete -c marc21 -n "__fish_marc21_using_subcommand cat" -s p -l progress -d 'If set, show a progress bar'
+complete -c marc21 -n "__fish_marc21_using_subcommand cat" -l bgzf -d 'If set, gzip compressed output is written as block-gzip (BGZF), which allows `marc21 lookup` to seek directly to a record'
+complete -c marc21 -n "__fish_marc21_using_subcommand cat" -l marc8 -d 'If set, MARC-8 encoded records are transcoded into UTF-8'
+complete -c marc21 -n "__fish_marc21_using_subcommand cat" -l lenient -d 'If set, malformed records are repaired instead of rejected. Each repair is reported as a warning'
 complete -c marc21 -n "__fish_marc21_using_subcommand cat" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c marc21 -n "__fish_marc21_using_subcommand convert" -l to -d 'The output format' -r -f -a "marcxml\t'MARC 21 slim XML'
+json\t'MARC-in-JSON (a JSON array of records)'
+jsonl\t'MARC-in-JSON (one record per line)'"
+complete -c marc21 -n "__fish_marc21_using_subcommand convert" -s o -l output -d 'Write output to <path> instead of stdout' -r -F
+complete -c marc21 -n "__fish_marc21_using_subcommand convert" -s l -l limit -d 'Limit the result to first <n> records (a limit value `0` means no limit)' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand convert" -l strsim-threshold -d 'The minimum score for string similarity comparisons. The value must be between 0 and 100. If not set, the default threshold of the algorithm is used (90 for `jaro-winkler`, otherwise 80)' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand convert" -l strsim-algorithm -d 'The algorithm for string similarity comparisons, which don\'t specify an algorithm of their own (e.g. `=*(token-set)`)' -r -f -a "levenshtein\t'Normalized Levenshtein distance'
+jaro-winkler\t'Jaro-Winkler similarity'
+sorensen-dice\t'Sørensen-Dice coefficient of character bigrams'
+token-set\t'Similarity of the sets of words (ignores the word order)'"
+complete -c marc21 -n "__fish_marc21_using_subcommand convert" -l where -d 'An expression for filtering records. Only if invalid records are skipped, records that can\'t match are rejected before they are decoded' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand convert" -l filter-normalization -d 'Transliterate the given filter or query expression into the specified Unicode normal form' -r -f -a "nfd\t''
+nfkd\t''
+nfc\t''
+nfkc\t''"
+complete -c marc21 -n "__fish_marc21_using_subcommand convert" -l compression -d 'Specify compression level' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand convert" -l control-fields -d 'A comma-separated list of tag matchers, which decides whether a field is a control field (default: `00.`). Local control fields can be added, e.g. `00.,FMT,SYS`' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand convert" -l threads -d 'Number of threads used to parse and process records. The value `0` selects the number of available CPUs' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand convert" -l oversize -d 'How to write records, which exceed the maximum record length of 99999 bytes. By default, such records are written in the (non-standard) form in which they were read, which is also the default of the library' -r -f -a "error\t'Reject the record with an error'
+split\t'Split the record into records linked by a 773 field'
+truncate\t'Drop the fields, which don\'t fit into the limit'
+keep\t'Write the non-standard oversize form'"
+complete -c marc21 -n "__fish_marc21_using_subcommand convert" -l pretty -d 'If set, the output is indented (MARCXML only)'
+complete -c marc21 -n "__fish_marc21_using_subcommand convert" -l no-collection -d 'If set, the records aren\'t wrapped into a collection element. Instead, each record is written on its own line (MARCXML only)'
+complete -c marc21 -n "__fish_marc21_using_subcommand convert" -s s -l skip-invalid -d 'Skip invalid records that can\'t be decoded'
+complete -c marc21 -n "__fish_marc21_using_subcommand convert" -l filter-ignore-case -d 'Compare strings regardless of their case'
+complete -c marc21 -n "__fish_marc21_using_subcommand convert" -l filter-ignore-diacritics -d 'Compare strings regardless of their diacritics'
+complete -c marc21 -n "__fish_marc21_using_subcommand convert" -l filter-ignore-normalization -d 'Compare strings regardless of their Unicode normal form'
+complete -c marc21 -n "__fish_marc21_using_subcommand convert" -s p -l progress -d 'If set, show a progress bar'
+complete -c marc21 -n "__fish_marc21_using_subcommand convert" -l bgzf -d 'If set, gzip compressed output is written as block-gzip (BGZF), which allows `marc21 lookup` to seek directly to a record'
+complete -c marc21 -n "__fish_marc21_using_subcommand convert" -l marc8 -d 'If set, MARC-8 encoded records are transcoded into UTF-8'
+complete -c marc21 -n "__fish_marc21_using_subcommand convert" -l lenient -d 'If set, malformed records are repaired instead of rejected. Each repair is reported as a warning'
+complete -c marc21 -n "__fish_marc21_using_subcommand convert" -s h -l help -d 'Print help (see more with \'--help\')'
 complete -c marc21 -n "__fish_marc21_using_subcommand count" -s o -l output -d 'Write output to FILENAME instead of stdout' -r -F
 complete -c marc21 -n "__fish_marc21_using_subcommand count" -s l -l limit -d 'Limit the result to first <n> records (a limit value `0` means no limit)' -r
-complete -c marc21 -n "__fish_marc21_using_subcommand count" -l strsim-threshold -d 'The minimum score for string similarity comparisons. The value must be between 0 and 100' -r
-complete -c marc21 -n "__fish_marc21_using_subcommand count" -l where -d 'An expression for filtering records' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand count" -l strsim-threshold -d 'The minimum score for string similarity comparisons. The value must be between 0 and 100. If not set, the default threshold of the algorithm is used (90 for `jaro-winkler`, otherwise 80)' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand count" -l strsim-algorithm -d 'The algorithm for string similarity comparisons, which don\'t specify an algorithm of their own (e.g. `=*(token-set)`)' -r -f -a "levenshtein\t'Normalized Levenshtein distance'
+jaro-winkler\t'Jaro-Winkler similarity'
+sorensen-dice\t'Sørensen-Dice coefficient of character bigrams'
+token-set\t'Similarity of the sets of words (ignores the word order)'"
+complete -c marc21 -n "__fish_marc21_using_subcommand count" -l where -d 'An expression for filtering records. Only if invalid records are skipped, records that can\'t match are rejected before they are decoded' -r
 complete -c marc21 -n "__fish_marc21_using_subcommand count" -l filter-normalization -d 'Transliterate the given filter or query expression into the specified Unicode normal form' -r -f -a "nfd\t''
 nfkd\t''
 nfc\t''
 nfkc\t''"
 complete -c marc21 -n "__fish_marc21_using_subcommand count" -l compression -d 'Specify compression level' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand count" -l control-fields -d 'A comma-separated list of tag matchers, which decides whether a field is a control field (default: `00.`). Local control fields can be added, e.g. `00.,FMT,SYS`' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand count" -l threads -d 'Number of threads used to parse and process records. The value `0` selects the number of available CPUs' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand count" -l oversize -d 'How to write records, which exceed the maximum record length of 99999 bytes. By default, such records are written in the (non-standard) form in which they were read, which is also the default of the library' -r -f -a "error\t'Reject the record with an error'
+split\t'Split the record into records linked by a 773 field'
+truncate\t'Drop the fields, which don\'t fit into the limit'
+keep\t'Write the non-standard oversize form'"
 complete -c marc21 -n "__fish_marc21_using_subcommand count" -s s -l skip-invalid -d 'Skip invalid records that can\'t be decoded'
+complete -c marc21 -n "__fish_marc21_using_subcommand count" -l filter-ignore-case -d 'Compare strings regardless of their case'
+complete -c marc21 -n "__fish_marc21_using_subcommand count" -l filter-ignore-diacritics -d 'Compare strings regardless of their diacritics'
+complete -c marc21 -n "__fish_marc21_using_subcommand count" -l filter-ignore-normalization -d 'Compare strings regardless of their Unicode normal form'
 complete -c marc21 -n "__fish_marc21_using_subcommand count" -s p -l progress -d 'If set, show a progress bar'
-complete -c marc21 -n "__fish_marc21_using_subcommand count" -s h -l help -d 'Print help'
+complete -c marc21 -n "__fish_marc21_using_subcommand count" -l bgzf -d 'If set, gzip compressed output is written as block-gzip (BGZF), which allows `marc21 lookup` to seek directly to a record'
+complete -c marc21 -n "__fish_marc21_using_subcommand count" -l marc8 -d 'If set, MARC-8 encoded records are transcoded into UTF-8'
+complete -c marc21 -n "__fish_marc21_using_subcommand count" -l lenient -d 'If set, malformed records are repaired instead of rejected. Each repair is reported as a warning'
+complete -c marc21 -n "__fish_marc21_using_subcommand count" -s h -l help -d 'Print help (see more with \'--help\')'
 complete -c marc21 -n "__fish_marc21_using_subcommand cnt" -s o -l output -d 'Write output to FILENAME instead of stdout' -r -F
 complete -c marc21 -n "__fish_marc21_using_subcommand cnt" -s l -l limit -d 'Limit the result to first <n> records (a limit value `0` means no limit)' -r
-complete -c marc21 -n "__fish_marc21_using_subcommand cnt" -l strsim-threshold -d 'The minimum score for string similarity comparisons. The value must be between 0 and 100' -r
-complete -c marc21 -n "__fish_marc21_using_subcommand cnt" -l where -d 'An expression for filtering records' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand cnt" -l strsim-threshold -d 'The minimum score for string similarity comparisons. The value must be between 0 and 100. If not set, the default threshold of the algorithm is used (90 for `jaro-winkler`, otherwise 80)' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand cnt" -l strsim-algorithm -d 'The algorithm for string similarity comparisons, which don\'t specify an algorithm of their own (e.g. `=*(token-set)`)' -r -f -a "levenshtein\t'Normalized Levenshtein distance'
+jaro-winkler\t'Jaro-Winkler similarity'
+sorensen-dice\t'Sørensen-Dice coefficient of character bigrams'
+token-set\t'Similarity of the sets of words (ignores the word order)'"
+complete -c marc21 -n "__fish_marc21_using_subcommand cnt" -l where -d 'An expression for filtering records. Only if invalid records are skipped, records that can\'t match are rejected before they are decoded' -r
 complete -c marc21 -n "__fish_marc21_using_subcommand cnt" -l filter-normalization -d 'Transliterate the given filter or query expression into the specified Unicode normal form' -r -f -a "nfd\t''
 nfkd\t''
 nfc\t''
 nfkc\t''"
 complete -c marc21 -n "__fish_marc21_using_subcommand cnt" -l compression -d 'Specify compression level' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand cnt" -l control-fields -d 'A comma-separated list of tag matchers, which decides whether a field is a control field (default: `00.`). Local control fields can be added, e.g. `00.,FMT,SYS`' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand cnt" -l threads -d 'Number of threads used to parse and process records. The value `0` selects the number of available CPUs' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand cnt" -l oversize -d 'How to write records, which exceed the maximum record length of 99999 bytes. By default, such records are written in the (non-standard) form in which they were read, which is also the default of the library' -r -f -a "error\t'Reject the record with an error'
+split\t'Split the record into records linked by a 773 field'
+truncate\t'Drop the fields, which don\'t fit into the limit'
+keep\t'Write the non-standard oversize form'"
 complete -c marc21 -n "__fish_marc21_using_subcommand cnt" -s s -l skip-invalid -d 'Skip invalid records that can\'t be decoded'
+complete -c marc21 -n "__fish_marc21_using_subcommand cnt" -l filter-ignore-case -d 'Compare strings regardless of their case'
+complete -c marc21 -n "__fish_marc21_using_subcommand cnt" -l filter-ignore-diacritics -d 'Compare strings regardless of their diacritics'
+complete -c marc21 -n "__fish_marc21_using_subcommand cnt" -l filter-ignore-normalization -d 'Compare strings regardless of their Unicode normal form'
 complete -c marc21 -n "__fish_marc21_using_subcommand cnt" -s p -l progress -d 'If set, show a progress bar'
-complete -c marc21 -n "__fish_marc21_using_subcommand cnt" -s h -l help -d 'Print help'
+complete -c marc21 -n "__fish_marc21_using_subcommand cnt" -l bgzf -d 'If set, gzip compressed output is written as block-gzip (BGZF), which allows `marc21 lookup` to seek directly to a record'
+complete -c marc21 -n "__fish_marc21_using_subcommand cnt" -l marc8 -d 'If set, MARC-8 encoded records are transcoded into UTF-8'
+complete -c marc21 -n "__fish_marc21_using_subcommand cnt" -l lenient -d 'If set, malformed records are repaired instead of rejected. Each repair is reported as a warning'
+complete -c marc21 -n "__fish_marc21_using_subcommand cnt" -s h -l help -d 'Print help (see more with \'--help\')'
 complete -c marc21 -n "__fish_marc21_using_subcommand dedup" -s o -l output -d 'Write output to FILENAME instead of stdout' -r -F
 complete -c marc21 -n "__fish_marc21_using_subcommand dedup" -s l -l limit -d 'Limit the result to first <n> records (a limit value `0` means no limit)' -r
-complete -c marc21 -n "__fish_marc21_using_subcommand dedup" -l strsim-threshold -d 'The minimum score for string similarity comparisons. The value must be between 0 and 100' -r
-complete -c marc21 -n "__fish_marc21_using_subcommand dedup" -l where -d 'An expression for filtering records' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand dedup" -l strsim-threshold -d 'The minimum score for string similarity comparisons. The value must be between 0 and 100. If not set, the default threshold of the algorithm is used (90 for `jaro-winkler`, otherwise 80)' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand dedup" -l strsim-algorithm -d 'The algorithm for string similarity comparisons, which don\'t specify an algorithm of their own (e.g. `=*(token-set)`)' -r -f -a "levenshtein\t'Normalized Levenshtein distance'
+jaro-winkler\t'Jaro-Winkler similarity'
+sorensen-dice\t'Sørensen-Dice coefficient of character bigrams'
+token-set\t'Similarity of the sets of words (ignores the word order)'"
+complete -c marc21 -n "__fish_marc21_using_subcommand dedup" -l where -d 'An expression for filtering records. Only if invalid records are skipped, records that can\'t match are rejected before they are decoded' -r
 complete -c marc21 -n "__fish_marc21_using_subcommand dedup" -l filter-normalization -d 'Transliterate the given filter or query expression into the specified Unicode normal form' -r -f -a "nfd\t''
 nfkd\t''
 nfc\t''
 nfkc\t''"
 complete -c marc21 -n "__fish_marc21_using_subcommand dedup" -l compression -d 'Specify compression level' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand dedup" -l control-fields -d 'A comma-separated list of tag matchers, which decides whether a field is a control field (default: `00.`). Local control fields can be added, e.g. `00.,FMT,SYS`' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand dedup" -l threads -d 'Number of threads used to parse and process records. The value `0` selects the number of available CPUs' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand dedup" -l oversize -d 'How to write records, which exceed the maximum record length of 99999 bytes. By default, such records are written in the (non-standard) form in which they were read, which is also the default of the library' -r -f -a "error\t'Reject the record with an error'
+split\t'Split the record into records linked by a 773 field'
+truncate\t'Drop the fields, which don\'t fit into the limit'
+keep\t'Write the non-standard oversize form'"
 complete -c marc21 -n "__fish_marc21_using_subcommand dedup" -s s -l skip-invalid -d 'Skip invalid records that can\'t be decoded'
+complete -c marc21 -n "__fish_marc21_using_subcommand dedup" -l filter-ignore-case -d 'Compare strings regardless of their case'
+complete -c marc21 -n "__fish_marc21_using_subcommand dedup" -l filter-ignore-diacritics -d 'Compare strings regardless of their diacritics'
+complete -c marc21 -n "__fish_marc21_using_subcommand dedup" -l filter-ignore-normalization -d 'Compare strings regardless of their Unicode normal form'
 complete -c marc21 -n "__fish_marc21_using_subcommand dedup" -s p -l progress -d 'If set, show a progress bar'
+complete -c marc21 -n "__fish_marc21_using_subcommand dedup" -l bgzf -d 'If set, gzip compressed output is written as block-gzip (BGZF), which allows `marc21 lookup` to seek directly to a record'
+complete -c marc21 -n "__fish_marc21_using_subcommand dedup" -l marc8 -d 'If set, MARC-8 encoded records are transcoded into UTF-8'
+complete -c marc21 -n "__fish_marc21_using_subcommand dedup" -l lenient -d 'If set, malformed records are repaired instead of rejected. Each repair is reported as a warning'
 complete -c marc21 -n "__fish_marc21_using_subcommand dedup" -s h -l help -d 'Print help (see more with \'--help\')'
 complete -c marc21 -n "__fish_marc21_using_subcommand describe" -s o -l output -d 'Write output to <path> instead of stdout' -r -F
 complete -c marc21 -n "__fish_marc21_using_subcommand describe" -s l -l limit -d 'Limit the result to first <n> records (a limit value `0` means no limit)' -r
-complete -c marc21 -n "__fish_marc21_using_subcommand describe" -l strsim-threshold -d 'The minimum score for string similarity comparisons. The value must be between 0 and 100' -r
-complete -c marc21 -n "__fish_marc21_using_subcommand describe" -l where -d 'An expression for filtering records' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand describe" -l strsim-threshold -d 'The minimum score for string similarity comparisons. The value must be between 0 and 100. If not set, the default threshold of the algorithm is used (90 for `jaro-winkler`, otherwise 80)' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand describe" -l strsim-algorithm -d 'The algorithm for string similarity comparisons, which don\'t specify an algorithm of their own (e.g. `=*(token-set)`)' -r -f -a "levenshtein\t'Normalized Levenshtein distance'
+jaro-winkler\t'Jaro-Winkler similarity'
+sorensen-dice\t'Sørensen-Dice coefficient of character bigrams'
+token-set\t'Similarity of the sets of words (ignores the word order)'"
+complete -c marc21 -n "__fish_marc21_using_subcommand describe" -l where -d 'An expression for filtering records. Only if invalid records are skipped, records that can\'t match are rejected before they are decoded' -r
 complete -c marc21 -n "__fish_marc21_using_subcommand describe" -l filter-normalization -d 'Transliterate the given filter or query expression into the specified Unicode normal form' -r -f -a "nfd\t''
 nfkd\t''
 nfc\t''
 nfkc\t''"
 complete -c marc21 -n "__fish_marc21_using_subcommand describe" -l compression -d 'Specify compression level' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand describe" -l control-fields -d 'A comma-separated list of tag matchers, which decides whether a field is a control field (default: `00.`). Local control fields can be added, e.g. `00.,FMT,SYS`' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand describe" -l threads -d 'Number of threads used to parse and process records. The value `0` selects the number of available CPUs' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand describe" -l oversize -d 'How to write records, which exceed the maximum record length of 99999 bytes. By default, such records are written in the (non-standard) form in which they were read, which is also the default of the library' -r -f -a "error\t'Reject the record with an error'
+split\t'Split the record into records linked by a 773 field'
+truncate\t'Drop the fields, which don\'t fit into the limit'
+keep\t'Write the non-standard oversize form'"
 complete -c marc21 -n "__fish_marc21_using_subcommand describe" -l tsv -d 'Write output tab-separated (TSV)'
 complete -c marc21 -n "__fish_marc21_using_subcommand describe" -s s -l skip-invalid -d 'Skip invalid records that can\'t be decoded'
+complete -c marc21 -n "__fish_marc21_using_subcommand describe" -l filter-ignore-case -d 'Compare strings regardless of their case'
+complete -c marc21 -n "__fish_marc21_using_subcommand describe" -l filter-ignore-diacritics -d 'Compare strings regardless of their diacritics'
+complete -c marc21 -n "__fish_marc21_using_subcommand describe" -l filter-ignore-normalization -d 'Compare strings regardless of their Unicode normal form'
 complete -c marc21 -n "__fish_marc21_using_subcommand describe" -s p -l progress -d 'If set, show a progress bar'
-complete -c marc21 -n "__fish_marc21_using_subcommand describe" -s h -l help -d 'Print help'
+complete -c marc21 -n "__fish_marc21_using_subcommand describe" -l bgzf -d 'If set, gzip compressed output is written as block-gzip (BGZF), which allows `marc21 lookup` to seek directly to a record'
+complete -c marc21 -n "__fish_marc21_using_subcommand describe" -l marc8 -d 'If set, MARC-8 encoded records are transcoded into UTF-8'
+complete -c marc21 -n "__fish_marc21_using_subcommand describe" -l lenient -d 'If set, malformed records are repaired instead of rejected. Each repair is reported as a warning'
+complete -c marc21 -n "__fish_marc21_using_subcommand describe" -s h -l help -d 'Print help (see more with \'--help\')'
 complete -c marc21 -n "__fish_marc21_using_subcommand filter" -s l -l limit -d 'Limit the result to first <n> records (a limit value `0` means no limit)' -r
-complete -c marc21 -n "__fish_marc21_using_subcommand filter" -l strsim-threshold -d 'The minimum score for string similarity comparisons (0 <= score <= 100)' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand filter" -l strsim-threshold -d 'The minimum score for string similarity comparisons. The value must be between 0 and 100. If not set, the default threshold of the algorithm is used (90 for `jaro-winkler`, otherwise 80)' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand filter" -l strsim-algorithm -d 'The algorithm for string similarity comparisons, which don\'t specify an algorithm of their own (e.g. `=*(token-set)`)' -r -f -a "levenshtein\t'Normalized Levenshtein distance'
+jaro-winkler\t'Jaro-Winkler similarity'
+sorensen-dice\t'Sørensen-Dice coefficient of character bigrams'
+token-set\t'Similarity of the sets of words (ignores the word order)'"
 complete -c marc21 -n "__fish_marc21_using_subcommand filter" -l filter-normalization -d 'Transliterate the given filter expression into the specified Unicode normal form' -r -f -a "nfd\t''
 nfkd\t''
 nfc\t''
 nfkc\t''"
 complete -c marc21 -n "__fish_marc21_using_subcommand filter" -s o -l output -d 'Write output to FILENAME instead of stdout' -r -F
 complete -c marc21 -n "__fish_marc21_using_subcommand filter" -l compression -d 'Specify compression level' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand filter" -l control-fields -d 'A comma-separated list of tag matchers, which decides whether a field is a control field (default: `00.`). Local control fields can be added, e.g. `00.,FMT,SYS`' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand filter" -l threads -d 'Number of threads used to parse and process records. The value `0` selects the number of available CPUs' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand filter" -l oversize -d 'How to write records, which exceed the maximum record length of 99999 bytes. By default, such records are written in the (non-standard) form in which they were read, which is also the default of the library' -r -f -a "error\t'Reject the record with an error'
+split\t'Split the record into records linked by a 773 field'
+truncate\t'Drop the fields, which don\'t fit into the limit'
+keep\t'Write the non-standard oversize form'"
 complete -c marc21 -n "__fish_marc21_using_subcommand filter" -s s -l skip-invalid -d 'Skip invalid records that can\'t be decoded'
 complete -c marc21 -n "__fish_marc21_using_subcommand filter" -s v -l invert-match -d 'Inverts the specified filter criterion, which means that only records that do not match the criterion are returned'
+complete -c marc21 -n "__fish_marc21_using_subcommand filter" -l filter-ignore-case -d 'Compare strings regardless of their case'
+complete -c marc21 -n "__fish_marc21_using_subcommand filter" -l filter-ignore-diacritics -d 'Compare strings regardless of their diacritics'
+complete -c marc21 -n "__fish_marc21_using_subcommand filter" -l filter-ignore-normalization -d 'Compare strings regardless of their Unicode normal form'
 complete -c marc21 -n "__fish_marc21_using_subcommand filter" -s p -l progress -d 'If set, show a progress bar'
-complete -c marc21 -n "__fish_marc21_using_subcommand filter" -s h -l help -d 'Print help'
+complete -c marc21 -n "__fish_marc21_using_subcommand filter" -l bgzf -d 'If set, gzip compressed output is written as block-gzip (BGZF), which allows `marc21 lookup` to seek directly to a record'
+complete -c marc21 -n "__fish_marc21_using_subcommand filter" -l marc8 -d 'If set, MARC-8 encoded records are transcoded into UTF-8'
+complete -c marc21 -n "__fish_marc21_using_subcommand filter" -l lenient -d 'If set, malformed records are repaired instead of rejected. Each repair is reported as a warning'
+complete -c marc21 -n "__fish_marc21_using_subcommand filter" -s h -l help -d 'Print help (see more with \'--help\')'
 complete -c marc21 -n "__fish_marc21_using_subcommand frequency" -s t -l threshold -d 'Ignore rows with a frequency less than <n>' -r
 complete -c marc21 -n "__fish_marc21_using_subcommand frequency" -s n -l num -d 'Limit result to the <n> most frequent subfield values' -r
 complete -c marc21 -n "__fish_marc21_using_subcommand frequency" -s H -l header -d 'Insert a header row before the data. The header should be entered as a comma-separated list. Leading and trailing spaces in each column are automatically removed' -r
 complete -c marc21 -n "__fish_marc21_using_subcommand frequency" -s o -l output -d 'Write output to <path> instead of stdout' -r -F
 complete -c marc21 -n "__fish_marc21_using_subcommand frequency" -s l -l limit -d 'Limit the result to first <n> records (a limit value `0` means no limit)' -r
-complete -c marc21 -n "__fish_marc21_using_subcommand frequency" -l strsim-threshold -d 'The minimum score for string similarity comparisons. The value must be between 0 and 100' -r
-complete -c marc21 -n "__fish_marc21_using_subcommand frequency" -l where -d 'An expression for filtering records' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand frequency" -l strsim-threshold -d 'The minimum score for string similarity comparisons. The value must be between 0 and 100. If not set, the default threshold of the algorithm is used (90 for `jaro-winkler`, otherwise 80)' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand frequency" -l strsim-algorithm -d 'The algorithm for string similarity comparisons, which don\'t specify an algorithm of their own (e.g. `=*(token-set)`)' -r -f -a "levenshtein\t'Normalized Levenshtein distance'
+jaro-winkler\t'Jaro-Winkler similarity'
+sorensen-dice\t'Sørensen-Dice coefficient of character bigrams'
+token-set\t'Similarity of the sets of words (ignores the word order)'"
+complete -c marc21 -n "__fish_marc21_using_subcommand frequency" -l where -d 'An expression for filtering records. Only if invalid records are skipped, records that can\'t match are rejected before they are decoded' -r
 complete -c marc21 -n "__fish_marc21_using_subcommand frequency" -l filter-normalization -d 'Transliterate the given filter or query expression into the specified Unicode normal form' -r -f -a "nfd\t''
 nfkd\t''
 nfc\t''
 nfkc\t''"
 complete -c marc21 -n "__fish_marc21_using_subcommand frequency" -l compression -d 'Specify compression level' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand frequency" -l control-fields -d 'A comma-separated list of tag matchers, which decides whether a field is a control field (default: `00.`). Local control fields can be added, e.g. `00.,FMT,SYS`' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand frequency" -l threads -d 'Number of threads used to parse and process records. The value `0` selects the number of available CPUs' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand frequency" -l oversize -d 'How to write records, which exceed the maximum record length of 99999 bytes. By default, such records are written in the (non-standard) form in which they were read, which is also the default of the library' -r -f -a "error\t'Reject the record with an error'
+split\t'Split the record into records linked by a 773 field'
+truncate\t'Drop the fields, which don\'t fit into the limit'
+keep\t'Write the non-standard oversize form'"
 complete -c marc21 -n "__fish_marc21_using_subcommand frequency" -s u -l unique -d 'This flag ensures that all values generated for a record are counted only once in the frequency table'
 complete -c marc21 -n "__fish_marc21_using_subcommand frequency" -s r -l reverse -d 'Sort results in reverse order'
 complete -c marc21 -n "__fish_marc21_using_subcommand frequency" -l tsv -d 'Write output tab-separated (TSV)'
 complete -c marc21 -n "__fish_marc21_using_subcommand frequency" -s s -l skip-invalid -d 'Skip invalid records that can\'t be decoded'
+complete -c marc21 -n "__fish_marc21_using_subcommand frequency" -l filter-ignore-case -d 'Compare strings regardless of their case'
+complete -c marc21 -n "__fish_marc21_using_subcommand frequency" -l filter-ignore-diacritics -d 'Compare strings regardless of their diacritics'
+complete -c marc21 -n "__fish_marc21_using_subcommand frequency" -l filter-ignore-normalization -d 'Compare strings regardless of their Unicode normal form'
 complete -c marc21 -n "__fish_marc21_using_subcommand frequency" -s p -l progress -d 'If set, show a progress bar'
+complete -c marc21 -n "__fish_marc21_using_subcommand frequency" -l bgzf -d 'If set, gzip compressed output is written as block-gzip (BGZF), which allows `marc21 lookup` to seek directly to a record'
+complete -c marc21 -n "__fish_marc21_using_subcommand frequency" -l marc8 -d 'If set, MARC-8 encoded records are transcoded into UTF-8'
+complete -c marc21 -n "__fish_marc21_using_subcommand frequency" -l lenient -d 'If set, malformed records are repaired instead of rejected. Each repair is reported as a warning'
 complete -c marc21 -n "__fish_marc21_using_subcommand frequency" -s h -l help -d 'Print help (see more with \'--help\')'
 complete -c marc21 -n "__fish_marc21_using_subcommand freq" -s t -l threshold -d 'Ignore rows with a frequency less than <n>' -r
 complete -c marc21 -n "__fish_marc21_using_subcommand freq" -s n -l num -d 'Limit result to the <n> most frequent subfield values' -r
 complete -c marc21 -n "__fish_marc21_using_subcommand freq" -s H -l header -d 'Insert a header row before the data. The header should be entered as a comma-separated list. Leading and trailing spaces in each column are automatically removed' -r
 complete -c marc21 -n "__fish_marc21_using_subcommand freq" -s o -l output -d 'Write output to <path> instead of stdout' -r -F
 complete -c marc21 -n "__fish_marc21_using_subcommand freq" -s l -l limit -d 'Limit the result to first <n> records (a limit value `0` means no limit)' -r
-complete -c marc21 -n "__fish_marc21_using_subcommand freq" -l strsim-threshold -d 'The minimum score for string similarity comparisons. The value must be between 0 and 100' -r
-complete -c marc21 -n "__fish_marc21_using_subcommand freq" -l where -d 'An expression for filtering records' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand freq" -l strsim-threshold -d 'The minimum score for string similarity comparisons. The value must be between 0 and 100. If not set, the default threshold of the algorithm is used (90 for `jaro-winkler`, otherwise 80)' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand freq" -l strsim-algorithm -d 'The algorithm for string similarity comparisons, which don\'t specify an algorithm of their own (e.g. `=*(token-set)`)' -r -f -a "levenshtein\t'Normalized Levenshtein distance'
+jaro-winkler\t'Jaro-Winkler similarity'
+sorensen-dice\t'Sørensen-Dice coefficient of character bigrams'
+token-set\t'Similarity of the sets of words (ignores the word order)'"
+complete -c marc21 -n "__fish_marc21_using_subcommand freq" -l where -d 'An expression for filtering records. Only if invalid records are skipped, records that can\'t match are rejected before they are decoded' -r
 complete -c marc21 -n "__fish_marc21_using_subcommand freq" -l filter-normalization -d 'Transliterate the given filter or query expression into the specified Unicode normal form' -r -f -a "nfd\t''
 nfkd\t''
 nfc\t''
 nfkc\t''"
 complete -c marc21 -n "__fish_marc21_using_subcommand freq" -l compression -d 'Specify compression level' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand freq" -l control-fields -d 'A comma-separated list of tag matchers, which decides whether a field is a control field (default: `00.`). Local control fields can be added, e.g. `00.,FMT,SYS`' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand freq" -l threads -d 'Number of threads used to parse and process records. The value `0` selects the number of available CPUs' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand freq" -l oversize -d 'How to write records, which exceed the maximum record length of 99999 bytes. By default, such records are written in the (non-standard) form in which they were read, which is also the default of the library' -r -f -a "error\t'Reject the record with an error'
+split\t'Split the record into records linked by a 773 field'
+truncate\t'Drop the fields, which don\'t fit into the limit'
+keep\t'Write the non-standard oversize form'"
 complete -c marc21 -n "__fish_marc21_using_subcommand freq" -s u -l unique -d 'This flag ensures that all values generated for a record are counted only once in the frequency table'
 complete -c marc21 -n "__fish_marc21_using_subcommand freq" -s r -l reverse -d 'Sort results in reverse order'
 complete -c marc21 -n "__fish_marc21_using_subcommand freq" -l tsv -d 'Write output tab-separated (TSV)'
 complete -c marc21 -n "__fish_marc21_using_subcommand freq" -s s -l skip-invalid -d 'Skip invalid records that can\'t be decoded'
+complete -c marc21 -n "__fish_marc21_using_subcommand freq" -l filter-ignore-case -d 'Compare strings regardless of their case'
+complete -c marc21 -n "__fish_marc21_using_subcommand freq" -l filter-ignore-diacritics -d 'Compare strings regardless of their diacritics'
+complete -c marc21 -n "__fish_marc21_using_subcommand freq" -l filter-ignore-normalization -d 'Compare strings regardless of their Unicode normal form'
 complete -c marc21 -n "__fish_marc21_using_subcommand freq" -s p -l progress -d 'If set, show a progress bar'
+complete -c marc21 -n "__fish_marc21_using_subcommand freq" -l bgzf -d 'If set, gzip compressed output is written as block-gzip (BGZF), which allows `marc21 lookup` to seek directly to a record'
+complete -c marc21 -n "__fish_marc21_using_subcommand freq" -l marc8 -d 'If set, MARC-8 encoded records are transcoded into UTF-8'
+complete -c marc21 -n "__fish_marc21_using_subcommand freq" -l lenient -d 'If set, malformed records are repaired instead of rejected. Each repair is reported as a warning'
 complete -c marc21 -n "__fish_marc21_using_subcommand freq" -s h -l help -d 'Print help (see more with \'--help\')'
 complete -c marc21 -n "__fish_marc21_using_subcommand glimpse" -s n -l max-values -d 'Maximum number of values to show per subfield' -r
 complete -c marc21 -n "__fish_marc21_using_subcommand glimpse" -s o -l output -d 'Write output to FILENAME instead of stdout' -r -F
 complete -c marc21 -n "__fish_marc21_using_subcommand glimpse" -s l -l limit -d 'Limit the result to first <n> records (a limit value `0` means no limit)' -r
-complete -c marc21 -n "__fish_marc21_using_subcommand glimpse" -l strsim-threshold -d 'The minimum score for string similarity comparisons. The value must be between 0 and 100' -r
-complete -c marc21 -n "__fish_marc21_using_subcommand glimpse" -l where -d 'An expression for filtering records' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand glimpse" -l strsim-threshold -d 'The minimum score for string similarity comparisons. The value must be between 0 and 100. If not set, the default threshold of the algorithm is used (90 for `jaro-winkler`, otherwise 80)' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand glimpse" -l strsim-algorithm -d 'The algorithm for string similarity comparisons, which don\'t specify an algorithm of their own (e.g. `=*(token-set)`)' -r -f -a "levenshtein\t'Normalized Levenshtein distance'
+jaro-winkler\t'Jaro-Winkler similarity'
+sorensen-dice\t'Sørensen-Dice coefficient of character bigrams'
+token-set\t'Similarity of the sets of words (ignores the word order)'"
+complete -c marc21 -n "__fish_marc21_using_subcommand glimpse" -l where -d 'An expression for filtering records. Only if invalid records are skipped, records that can\'t match are rejected before they are decoded' -r
 complete -c marc21 -n "__fish_marc21_using_subcommand glimpse" -l filter-normalization -d 'Transliterate the given filter or query expression into the specified Unicode normal form' -r -f -a "nfd\t''
 nfkd\t''
 nfc\t''
 nfkc\t''"
 complete -c marc21 -n "__fish_marc21_using_subcommand glimpse" -l compression -d 'Specify compression level' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand glimpse" -l control-fields -d 'A comma-separated list of tag matchers, which decides whether a field is a control field (default: `00.`). Local control fields can be added, e.g. `00.,FMT,SYS`' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand glimpse" -l threads -d 'Number of threads used to parse and process records. The value `0` selects the number of available CPUs' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand glimpse" -l oversize -d 'How to write records, which exceed the maximum record length of 99999 bytes. By default, such records are written in the (non-standard) form in which they were read, which is also the default of the library' -r -f -a "error\t'Reject the record with an error'
+split\t'Split the record into records linked by a 773 field'
+truncate\t'Drop the fields, which don\'t fit into the limit'
+keep\t'Write the non-standard oversize form'"
 complete -c marc21 -n "__fish_marc21_using_subcommand glimpse" -s s -l skip-invalid -d 'Skip invalid records that can\'t be decoded'
+complete -c marc21 -n "__fish_marc21_using_subcommand glimpse" -l filter-ignore-case -d 'Compare strings regardless of their case'
+complete -c marc21 -n "__fish_marc21_using_subcommand glimpse" -l filter-ignore-diacritics -d 'Compare strings regardless of their diacritics'
+complete -c marc21 -n "__fish_marc21_using_subcommand glimpse" -l filter-ignore-normalization -d 'Compare strings regardless of their Unicode normal form'
 complete -c marc21 -n "__fish_marc21_using_subcommand glimpse" -s p -l progress -d 'If set, show a progress bar'
-complete -c marc21 -n "__fish_marc21_using_subcommand glimpse" -s h -l help -d 'Print help'
+complete -c marc21 -n "__fish_marc21_using_subcommand glimpse" -l bgzf -d 'If set, gzip compressed output is written as block-gzip (BGZF), which allows `marc21 lookup` to seek directly to a record'
+complete -c marc21 -n "__fish_marc21_using_subcommand glimpse" -l marc8 -d 'If set, MARC-8 encoded records are transcoded into UTF-8'
+complete -c marc21 -n "__fish_marc21_using_subcommand glimpse" -l lenient -d 'If set, malformed records are repaired instead of rejected. Each repair is reported as a warning'
+complete -c marc21 -n "__fish_marc21_using_subcommand glimpse" -s h -l help -d 'Print help (see more with \'--help\')'
 complete -c marc21 -n "__fish_marc21_using_subcommand grep" -l or -d 'Search for multiple, possibly overlapping, regexes in a single search. The regular expression consists of the main pattern and all other pattern passed by this option. The regex matches if a subfield is found that matches against at least one pattern' -r
 complete -c marc21 -n "__fish_marc21_using_subcommand grep" -s o -l output -d 'Write output to <filename> instead of stdout' -r -F
 complete -c marc21 -n "__fish_marc21_using_subcommand grep" -s l -l limit -d 'Limit the result to first <n> records (a limit value `0` means no limit)' -r
-complete -c marc21 -n "__fish_marc21_using_subcommand grep" -l strsim-threshold -d 'The minimum score for string similarity comparisons. The value must be between 0 and 100' -r
-complete -c marc21 -n "__fish_marc21_using_subcommand grep" -l where -d 'An expression for filtering records' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand grep" -l strsim-threshold -d 'The minimum score for string similarity comparisons. The value must be between 0 and 100. If not set, the default threshold of the algorithm is used (90 for `jaro-winkler`, otherwise 80)' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand grep" -l strsim-algorithm -d 'The algorithm for string similarity comparisons, which don\'t specify an algorithm of their own (e.g. `=*(token-set)`)' -r -f -a "levenshtein\t'Normalized Levenshtein distance'
+jaro-winkler\t'Jaro-Winkler similarity'
+sorensen-dice\t'Sørensen-Dice coefficient of character bigrams'
+token-set\t'Similarity of the sets of words (ignores the word order)'"
+complete -c marc21 -n "__fish_marc21_using_subcommand grep" -l where -d 'An expression for filtering records. Only if invalid records are skipped, records that can\'t match are rejected before they are decoded' -r
 complete -c marc21 -n "__fish_marc21_using_subcommand grep" -l filter-normalization -d 'Transliterate the given filter or query expression into the specified Unicode normal form' -r -f -a "nfd\t''
 nfkd\t''
 nfc\t''
 nfkc\t''"
 complete -c marc21 -n "__fish_marc21_using_subcommand grep" -l compression -d 'Specify compression level' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand grep" -l control-fields -d 'A comma-separated list of tag matchers, which decides whether a field is a control field (default: `00.`). Local control fields can be added, e.g. `00.,FMT,SYS`' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand grep" -l threads -d 'Number of threads used to parse and process records. The value `0` selects the number of available CPUs' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand grep" -l oversize -d 'How to write records, which exceed the maximum record length of 99999 bytes. By default, such records are written in the (non-standard) form in which they were read, which is also the default of the library' -r -f -a "error\t'Reject the record with an error'
+split\t'Split the record into records linked by a 773 field'
+truncate\t'Drop the fields, which don\'t fit into the limit'
+keep\t'Write the non-standard oversize form'"
 complete -c marc21 -n "__fish_marc21_using_subcommand grep" -s i -l ignore-case -d 'If this flag is set, matching will be performed case insensitive'
 complete -c marc21 -n "__fish_marc21_using_subcommand grep" -s v -l invert-match -d 'Inverts the specified regular expression, which means that only records that do not match the criterion are returned'
 complete -c marc21 -n "__fish_marc21_using_subcommand grep" -s s -l skip-invalid -d 'Skip invalid records that can\'t be decoded'
+complete -c marc21 -n "__fish_marc21_using_subcommand grep" -l filter-ignore-case -d 'Compare strings regardless of their case'
+complete -c marc21 -n "__fish_marc21_using_subcommand grep" -l filter-ignore-diacritics -d 'Compare strings regardless of their diacritics'
+complete -c marc21 -n "__fish_marc21_using_subcommand grep" -l filter-ignore-normalization -d 'Compare strings regardless of their Unicode normal form'
 complete -c marc21 -n "__fish_marc21_using_subcommand grep" -s p -l progress -d 'If set, show a progress bar'
+complete -c marc21 -n "__fish_marc21_using_subcommand grep" -l bgzf -d 'If set, gzip compressed output is written as block-gzip (BGZF), which allows `marc21 lookup` to seek directly to a record'
+complete -c marc21 -n "__fish_marc21_using_subcommand grep" -l marc8 -d 'If set, MARC-8 encoded records are transcoded into UTF-8'
+complete -c marc21 -n "__fish_marc21_using_subcommand grep" -l lenient -d 'If set, malformed records are repaired instead of rejected. Each repair is reported as a warning'
 complete -c marc21 -n "__fish_marc21_using_subcommand grep" -s h -l help -d 'Print help (see more with \'--help\')'
 complete -c marc21 -n "__fish_marc21_using_subcommand hash" -s o -l output -d 'Write output to FILENAME instead of stdout' -r -F
 complete -c marc21 -n "__fish_marc21_using_subcommand hash" -s l -l limit -d 'Limit the result to first <n> records (a limit value `0` means no limit)' -r
-complete -c marc21 -n "__fish_marc21_using_subcommand hash" -l strsim-threshold -d 'The minimum score for string similarity comparisons. The value must be between 0 and 100' -r
-complete -c marc21 -n "__fish_marc21_using_subcommand hash" -l where -d 'An expression for filtering records' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand hash" -l strsim-threshold -d 'The minimum score for string similarity comparisons. The value must be between 0 and 100. If not set, the default threshold of the algorithm is used (90 for `jaro-winkler`, otherwise 80)' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand hash" -l strsim-algorithm -d 'The algorithm for string similarity comparisons, which don\'t specify an algorithm of their own (e.g. `=*(token-set)`)' -r -f -a "levenshtein\t'Normalized Levenshtein distance'
+jaro-winkler\t'Jaro-Winkler similarity'
+sorensen-dice\t'Sørensen-Dice coefficient of character bigrams'
+token-set\t'Similarity of the sets of words (ignores the word order)'"
+complete -c marc21 -n "__fish_marc21_using_subcommand hash" -l where -d 'An expression for filtering records. Only if invalid records are skipped, records that can\'t match are rejected before they are decoded' -r
 complete -c marc21 -n "__fish_marc21_using_subcommand hash" -l filter-normalization -d 'Transliterate the given filter or query expression into the specified Unicode normal form' -r -f -a "nfd\t''
 nfkd\t''
 nfc\t''
 nfkc\t''"
 complete -c marc21 -n "__fish_marc21_using_subcommand hash" -l compression -d 'Specify compression level' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand hash" -l control-fields -d 'A comma-separated list of tag matchers, which decides whether a field is a control field (default: `00.`). Local control fields can be added, e.g. `00.,FMT,SYS`' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand hash" -l threads -d 'Number of threads used to parse and process records. The value `0` selects the number of available CPUs' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand hash" -l oversize -d 'How to write records, which exceed the maximum record length of 99999 bytes. By default, such records are written in the (non-standard) form in which they were read, which is also the default of the library' -r -f -a "error\t'Reject the record with an error'
+split\t'Split the record into records linked by a 773 field'
+truncate\t'Drop the fields, which don\'t fit into the limit'
+keep\t'Write the non-standard oversize form'"
 complete -c marc21 -n "__fish_marc21_using_subcommand hash" -l tsv -d 'Write output tab-separated (TSV)'
 complete -c marc21 -n "__fish_marc21_using_subcommand hash" -s s -l skip-invalid -d 'Skip invalid records that can\'t be decoded'
+complete -c marc21 -n "__fish_marc21_using_subcommand hash" -l filter-ignore-case -d 'Compare strings regardless of their case'
+complete -c marc21 -n "__fish_marc21_using_subcommand hash" -l filter-ignore-diacritics -d 'Compare strings regardless of their diacritics'
+complete -c marc21 -n "__fish_marc21_using_subcommand hash" -l filter-ignore-normalization -d 'Compare strings regardless of their Unicode normal form'
 complete -c marc21 -n "__fish_marc21_using_subcommand hash" -s p -l progress -d 'If set, show a progress bar'
-complete -c marc21 -n "__fish_marc21_using_subcommand hash" -s h -l help -d 'Print help'
+complete -c marc21 -n "__fish_marc21_using_subcommand hash" -l bgzf -d 'If set, gzip compressed output is written as block-gzip (BGZF), which allows `marc21 lookup` to seek directly to a record'
+complete -c marc21 -n "__fish_marc21_using_subcommand hash" -l marc8 -d 'If set, MARC-8 encoded records are transcoded into UTF-8'
+complete -c marc21 -n "__fish_marc21_using_subcommand hash" -l lenient -d 'If set, malformed records are repaired instead of rejected. Each repair is reported as a warning'
+complete -c marc21 -n "__fish_marc21_using_subcommand hash" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c marc21 -n "__fish_marc21_using_subcommand index" -s k -l key -d 'A path expression, whose values are the keys of the index' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand index" -s o -l output -d 'Write the index to <filename> instead of stdout' -r -F
+complete -c marc21 -n "__fish_marc21_using_subcommand index" -s l -l limit -d 'Limit the result to first <n> records (a limit value `0` means no limit)' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand index" -l strsim-threshold -d 'The minimum score for string similarity comparisons. The value must be between 0 and 100. If not set, the default threshold of the algorithm is used (90 for `jaro-winkler`, otherwise 80)' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand index" -l strsim-algorithm -d 'The algorithm for string similarity comparisons, which don\'t specify an algorithm of their own (e.g. `=*(token-set)`)' -r -f -a "levenshtein\t'Normalized Levenshtein distance'
+jaro-winkler\t'Jaro-Winkler similarity'
+sorensen-dice\t'Sørensen-Dice coefficient of character bigrams'
+token-set\t'Similarity of the sets of words (ignores the word order)'"
+complete -c marc21 -n "__fish_marc21_using_subcommand index" -l where -d 'An expression for filtering records. Only if invalid records are skipped, records that can\'t match are rejected before they are decoded' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand index" -l filter-normalization -d 'Transliterate the given filter or query expression into the specified Unicode normal form' -r -f -a "nfd\t''
+nfkd\t''
+nfc\t''
+nfkc\t''"
+complete -c marc21 -n "__fish_marc21_using_subcommand index" -l compression -d 'Specify compression level' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand index" -l control-fields -d 'A comma-separated list of tag matchers, which decides whether a field is a control field (default: `00.`). Local control fields can be added, e.g. `00.,FMT,SYS`' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand index" -l threads -d 'Number of threads used to parse and process records. The value `0` selects the number of available CPUs' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand index" -l oversize -d 'How to write records, which exceed the maximum record length of 99999 bytes. By default, such records are written in the (non-standard) form in which they were read, which is also the default of the library' -r -f -a "error\t'Reject the record with an error'
+split\t'Split the record into records linked by a 773 field'
+truncate\t'Drop the fields, which don\'t fit into the limit'
+keep\t'Write the non-standard oversize form'"
+complete -c marc21 -n "__fish_marc21_using_subcommand index" -s s -l skip-invalid -d 'Skip invalid records that can\'t be decoded'
+complete -c marc21 -n "__fish_marc21_using_subcommand index" -l filter-ignore-case -d 'Compare strings regardless of their case'
+complete -c marc21 -n "__fish_marc21_using_subcommand index" -l filter-ignore-diacritics -d 'Compare strings regardless of their diacritics'
+complete -c marc21 -n "__fish_marc21_using_subcommand index" -l filter-ignore-normalization -d 'Compare strings regardless of their Unicode normal form'
+complete -c marc21 -n "__fish_marc21_using_subcommand index" -s p -l progress -d 'If set, show a progress bar'
+complete -c marc21 -n "__fish_marc21_using_subcommand index" -l bgzf -d 'If set, gzip compressed output is written as block-gzip (BGZF), which allows `marc21 lookup` to seek directly to a record'
+complete -c marc21 -n "__fish_marc21_using_subcommand index" -l marc8 -d 'If set, MARC-8 encoded records are transcoded into UTF-8'
+complete -c marc21 -n "__fish_marc21_using_subcommand index" -l lenient -d 'If set, malformed records are repaired instead of rejected. Each repair is reported as a warning'
+complete -c marc21 -n "__fish_marc21_using_subcommand index" -s h -l help -d 'Print help (see more with \'--help\')'
 complete -c marc21 -n "__fish_marc21_using_subcommand invalid" -s o -l output -d 'Write output to FILENAME instead of stdout' -r -F
 complete -c marc21 -n "__fish_marc21_using_subcommand invalid" -l compression -d 'Specify compression level' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand invalid" -l control-fields -d 'A comma-separated list of tag matchers, which decides whether a field is a control field (default: `00.`). Local control fields can be added, e.g. `00.,FMT,SYS`' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand invalid" -l threads -d 'Number of threads used to parse and process records. The value `0` selects the number of available CPUs' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand invalid" -l oversize -d 'How to write records, which exceed the maximum record length of 99999 bytes. By default, such records are written in the (non-standard) form in which they were read, which is also the default of the library' -r -f -a "error\t'Reject the record with an error'
+split\t'Split the record into records linked by a 773 field'
+truncate\t'Drop the fields, which don\'t fit into the limit'
+keep\t'Write the non-standard oversize form'"
+complete -c marc21 -n "__fish_marc21_using_subcommand invalid" -s v -l verbose -d 'If set, the reason why a record is invalid is printed to stderr'
 complete -c marc21 -n "__fish_marc21_using_subcommand invalid" -s p -l progress -d 'If set, show a progress bar'
-complete -c marc21 -n "__fish_marc21_using_subcommand invalid" -s h -l help -d 'Print help'
-complete -c marc21 -n "__fish_marc21_using_subcommand partition" -s t -l template -d 'A template for naming the individual partitions. The placeholder `{}` is replaced by the value of the path expression. If the template ends with the suffix `.gz`, the partitions are compressed in Gzip format' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand invalid" -l bgzf -d 'If set, gzip compressed output is written as block-gzip (BGZF), which allows `marc21 lookup` to seek directly to a record'
+complete -c marc21 -n "__fish_marc21_using_subcommand invalid" -l marc8 -d 'If set, MARC-8 encoded records are transcoded into UTF-8'
+complete -c marc21 -n "__fish_marc21_using_subcommand invalid" -l lenient -d 'If set, malformed records are repaired instead of rejected. Each repair is reported as a warning'
+complete -c marc21 -n "__fish_marc21_using_subcommand invalid" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c marc21 -n "__fish_marc21_using_subcommand lookup" -s o -l output -d 'Write output to <filename> instead of stdout' -r -F
+complete -c marc21 -n "__fish_marc21_using_subcommand lookup" -l compression -d 'Specify compression level' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand lookup" -l control-fields -d 'A comma-separated list of tag matchers, which decides whether a field is a control field (default: `00.`). Local control fields can be added, e.g. `00.,FMT,SYS`' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand lookup" -l threads -d 'Number of threads used to parse and process records. The value `0` selects the number of available CPUs' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand lookup" -l oversize -d 'How to write records, which exceed the maximum record length of 99999 bytes. By default, such records are written in the (non-standard) form in which they were read, which is also the default of the library' -r -f -a "error\t'Reject the record with an error'
+split\t'Split the record into records linked by a 773 field'
+truncate\t'Drop the fields, which don\'t fit into the limit'
+keep\t'Write the non-standard oversize form'"
+complete -c marc21 -n "__fish_marc21_using_subcommand lookup" -s p -l progress -d 'If set, show a progress bar'
+complete -c marc21 -n "__fish_marc21_using_subcommand lookup" -l bgzf -d 'If set, gzip compressed output is written as block-gzip (BGZF), which allows `marc21 lookup` to seek directly to a record'
+complete -c marc21 -n "__fish_marc21_using_subcommand lookup" -l marc8 -d 'If set, MARC-8 encoded records are transcoded into UTF-8'
+complete -c marc21 -n "__fish_marc21_using_subcommand lookup" -l lenient -d 'If set, malformed records are repaired instead of rejected. Each repair is reported as a warning'
+complete -c marc21 -n "__fish_marc21_using_subcommand lookup" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c marc21 -n "__fish_marc21_using_subcommand partition" -s t -l template -d 'A template for naming the individual partitions. The placeholder `{}` is replaced by the value of the path expression. If the template ends with the suffix `.gz`, `.zst`, `.bz2` or `.xz`, the partitions are compressed in the corresponding format' -r
 complete -c marc21 -n "__fish_marc21_using_subcommand partition" -s o -l output -d 'Write output to <path>; by default all partitions are written to the current working directory' -r -F
 complete -c marc21 -n "__fish_marc21_using_subcommand partition" -s l -l limit -d 'Limit the result to first <n> records (a limit value `0` means no limit)' -r
-complete -c marc21 -n "__fish_marc21_using_subcommand partition" -l strsim-threshold -d 'The minimum score for string similarity comparisons. The value must be between 0 and 100' -r
-complete -c marc21 -n "__fish_marc21_using_subcommand partition" -l where -d 'An expression for filtering records' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand partition" -l strsim-threshold -d 'The minimum score for string similarity comparisons. The value must be between 0 and 100. If not set, the default threshold of the algorithm is used (90 for `jaro-winkler`, otherwise 80)' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand partition" -l strsim-algorithm -d 'The algorithm for string similarity comparisons, which don\'t specify an algorithm of their own (e.g. `=*(token-set)`)' -r -f -a "levenshtein\t'Normalized Levenshtein distance'
+jaro-winkler\t'Jaro-Winkler similarity'
+sorensen-dice\t'Sørensen-Dice coefficient of character bigrams'
+token-set\t'Similarity of the sets of words (ignores the word order)'"
+complete -c marc21 -n "__fish_marc21_using_subcommand partition" -l where -d 'An expression for filtering records. Only if invalid records are skipped, records that can\'t match are rejected before they are decoded' -r
 complete -c marc21 -n "__fish_marc21_using_subcommand partition" -l filter-normalization -d 'Transliterate the given filter or query expression into the specified Unicode normal form' -r -f -a "nfd\t''
 nfkd\t''
 nfc\t''
 nfkc\t''"
 complete -c marc21 -n "__fish_marc21_using_subcommand partition" -l compression -d 'Specify compression level' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand partition" -l control-fields -d 'A comma-separated list of tag matchers, which decides whether a field is a control field (default: `00.`). Local control fields can be added, e.g. `00.,FMT,SYS`' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand partition" -l threads -d 'Number of threads used to parse and process records. The value `0` selects the number of available CPUs' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand partition" -l oversize -d 'How to write records, which exceed the maximum record length of 99999 bytes. By default, such records are written in the (non-standard) form in which they were read, which is also the default of the library' -r -f -a "error\t'Reject the record with an error'
+split\t'Split the record into records linked by a 773 field'
+truncate\t'Drop the fields, which don\'t fit into the limit'
+keep\t'Write the non-standard oversize form'"
 complete -c marc21 -n "__fish_marc21_using_subcommand partition" -s s -l skip-invalid -d 'Skip invalid records that can\'t be decoded'
+complete -c marc21 -n "__fish_marc21_using_subcommand partition" -l filter-ignore-case -d 'Compare strings regardless of their case'
+complete -c marc21 -n "__fish_marc21_using_subcommand partition" -l filter-ignore-diacritics -d 'Compare strings regardless of their diacritics'
+complete -c marc21 -n "__fish_marc21_using_subcommand partition" -l filter-ignore-normalization -d 'Compare strings regardless of their Unicode normal form'
 complete -c marc21 -n "__fish_marc21_using_subcommand partition" -s p -l progress -d 'If set, show a progress bar'
+complete -c marc21 -n "__fish_marc21_using_subcommand partition" -l bgzf -d 'If set, gzip compressed output is written as block-gzip (BGZF), which allows `marc21 lookup` to seek directly to a record'
+complete -c marc21 -n "__fish_marc21_using_subcommand partition" -l marc8 -d 'If set, MARC-8 encoded records are transcoded into UTF-8'
+complete -c marc21 -n "__fish_marc21_using_subcommand partition" -l lenient -d 'If set, malformed records are repaired instead of rejected. Each repair is reported as a warning'
 complete -c marc21 -n "__fish_marc21_using_subcommand partition" -s h -l help -d 'Print help (see more with \'--help\')'
 complete -c marc21 -n "__fish_marc21_using_subcommand print" -l translit -d 'Transliterate the output into the specified Unicode normal form' -r -f -a "nfd\t''
 nfkd\t''
@@ -250,70 +562,150 @@ nfc\t''
 nfkc\t''"
 complete -c marc21 -n "__fish_marc21_using_subcommand print" -s o -l output -d 'Write output to <path> instead of stdout' -r -F
 complete -c marc21 -n "__fish_marc21_using_subcommand print" -s l -l limit -d 'Limit the result to first <n> records (a limit value `0` means no limit)' -r
-complete -c marc21 -n "__fish_marc21_using_subcommand print" -l strsim-threshold -d 'The minimum score for string similarity comparisons. The value must be between 0 and 100' -r
-complete -c marc21 -n "__fish_marc21_using_subcommand print" -l where -d 'An expression for filtering records' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand print" -l strsim-threshold -d 'The minimum score for string similarity comparisons. The value must be between 0 and 100. If not set, the default threshold of the algorithm is used (90 for `jaro-winkler`, otherwise 80)' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand print" -l strsim-algorithm -d 'The algorithm for string similarity comparisons, which don\'t specify an algorithm of their own (e.g. `=*(token-set)`)' -r -f -a "levenshtein\t'Normalized Levenshtein distance'
+jaro-winkler\t'Jaro-Winkler similarity'
+sorensen-dice\t'Sørensen-Dice coefficient of character bigrams'
+token-set\t'Similarity of the sets of words (ignores the word order)'"
+complete -c marc21 -n "__fish_marc21_using_subcommand print" -l where -d 'An expression for filtering records. Only if invalid records are skipped, records that can\'t match are rejected before they are decoded' -r
 complete -c marc21 -n "__fish_marc21_using_subcommand print" -l filter-normalization -d 'Transliterate the given filter or query expression into the specified Unicode normal form' -r -f -a "nfd\t''
 nfkd\t''
 nfc\t''
 nfkc\t''"
 complete -c marc21 -n "__fish_marc21_using_subcommand print" -l compression -d 'Specify compression level' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand print" -l control-fields -d 'A comma-separated list of tag matchers, which decides whether a field is a control field (default: `00.`). Local control fields can be added, e.g. `00.,FMT,SYS`' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand print" -l threads -d 'Number of threads used to parse and process records. The value `0` selects the number of available CPUs' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand print" -l oversize -d 'How to write records, which exceed the maximum record length of 99999 bytes. By default, such records are written in the (non-standard) form in which they were read, which is also the default of the library' -r -f -a "error\t'Reject the record with an error'
+split\t'Split the record into records linked by a 773 field'
+truncate\t'Drop the fields, which don\'t fit into the limit'
+keep\t'Write the non-standard oversize form'"
 complete -c marc21 -n "__fish_marc21_using_subcommand print" -s s -l skip-invalid -d 'Skip invalid records that can\'t be decoded'
+complete -c marc21 -n "__fish_marc21_using_subcommand print" -l filter-ignore-case -d 'Compare strings regardless of their case'
+complete -c marc21 -n "__fish_marc21_using_subcommand print" -l filter-ignore-diacritics -d 'Compare strings regardless of their diacritics'
+complete -c marc21 -n "__fish_marc21_using_subcommand print" -l filter-ignore-normalization -d 'Compare strings regardless of their Unicode normal form'
 complete -c marc21 -n "__fish_marc21_using_subcommand print" -s p -l progress -d 'If set, show a progress bar'
-complete -c marc21 -n "__fish_marc21_using_subcommand print" -s h -l help -d 'Print help'
+complete -c marc21 -n "__fish_marc21_using_subcommand print" -l bgzf -d 'If set, gzip compressed output is written as block-gzip (BGZF), which allows `marc21 lookup` to seek directly to a record'
+complete -c marc21 -n "__fish_marc21_using_subcommand print" -l marc8 -d 'If set, MARC-8 encoded records are transcoded into UTF-8'
+complete -c marc21 -n "__fish_marc21_using_subcommand print" -l lenient -d 'If set, malformed records are repaired instead of rejected. Each repair is reported as a warning'
+complete -c marc21 -n "__fish_marc21_using_subcommand print" -s h -l help -d 'Print help (see more with \'--help\')'
 complete -c marc21 -n "__fish_marc21_using_subcommand sample" -l seed -d 'Initialize the RNG with a seed value to get deterministic random record' -r
 complete -c marc21 -n "__fish_marc21_using_subcommand sample" -s o -l output -d 'Write output to FILENAME instead of stdout' -r -F
 complete -c marc21 -n "__fish_marc21_using_subcommand sample" -s l -l limit -d 'Limit the result to first <n> records (a limit value `0` means no limit)' -r
-complete -c marc21 -n "__fish_marc21_using_subcommand sample" -l strsim-threshold -d 'The minimum score for string similarity comparisons. The value must be between 0 and 100' -r
-complete -c marc21 -n "__fish_marc21_using_subcommand sample" -l where -d 'An expression for filtering records' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand sample" -l strsim-threshold -d 'The minimum score for string similarity comparisons. The value must be between 0 and 100. If not set, the default threshold of the algorithm is used (90 for `jaro-winkler`, otherwise 80)' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand sample" -l strsim-algorithm -d 'The algorithm for string similarity comparisons, which don\'t specify an algorithm of their own (e.g. `=*(token-set)`)' -r -f -a "levenshtein\t'Normalized Levenshtein distance'
+jaro-winkler\t'Jaro-Winkler similarity'
+sorensen-dice\t'Sørensen-Dice coefficient of character bigrams'
+token-set\t'Similarity of the sets of words (ignores the word order)'"
+complete -c marc21 -n "__fish_marc21_using_subcommand sample" -l where -d 'An expression for filtering records. Only if invalid records are skipped, records that can\'t match are rejected before they are decoded' -r
 complete -c marc21 -n "__fish_marc21_using_subcommand sample" -l filter-normalization -d 'Transliterate the given filter or query expression into the specified Unicode normal form' -r -f -a "nfd\t''
 nfkd\t''
 nfc\t''
 nfkc\t''"
 complete -c marc21 -n "__fish_marc21_using_subcommand sample" -l compression -d 'Specify compression level' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand sample" -l control-fields -d 'A comma-separated list of tag matchers, which decides whether a field is a control field (default: `00.`). Local control fields can be added, e.g. `00.,FMT,SYS`' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand sample" -l threads -d 'Number of threads used to parse and process records. The value `0` selects the number of available CPUs' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand sample" -l oversize -d 'How to write records, which exceed the maximum record length of 99999 bytes. By default, such records are written in the (non-standard) form in which they were read, which is also the default of the library' -r -f -a "error\t'Reject the record with an error'
+split\t'Split the record into records linked by a 773 field'
+truncate\t'Drop the fields, which don\'t fit into the limit'
+keep\t'Write the non-standard oversize form'"
 complete -c marc21 -n "__fish_marc21_using_subcommand sample" -s s -l skip-invalid -d 'Skip invalid records that can\'t be decoded'
+complete -c marc21 -n "__fish_marc21_using_subcommand sample" -l filter-ignore-case -d 'Compare strings regardless of their case'
+complete -c marc21 -n "__fish_marc21_using_subcommand sample" -l filter-ignore-diacritics -d 'Compare strings regardless of their diacritics'
+complete -c marc21 -n "__fish_marc21_using_subcommand sample" -l filter-ignore-normalization -d 'Compare strings regardless of their Unicode normal form'
 complete -c marc21 -n "__fish_marc21_using_subcommand sample" -s p -l progress -d 'If set, show a progress bar'
-complete -c marc21 -n "__fish_marc21_using_subcommand sample" -s h -l help -d 'Print help'
+complete -c marc21 -n "__fish_marc21_using_subcommand sample" -l bgzf -d 'If set, gzip compressed output is written as block-gzip (BGZF), which allows `marc21 lookup` to seek directly to a record'
+complete -c marc21 -n "__fish_marc21_using_subcommand sample" -l marc8 -d 'If set, MARC-8 encoded records are transcoded into UTF-8'
+complete -c marc21 -n "__fish_marc21_using_subcommand sample" -l lenient -d 'If set, malformed records are repaired instead of rejected. Each repair is reported as a warning'
+complete -c marc21 -n "__fish_marc21_using_subcommand sample" -s h -l help -d 'Print help (see more with \'--help\')'
 complete -c marc21 -n "__fish_marc21_using_subcommand select" -s H -l header -d 'Insert a header row before the data. The header should be entered as a comma-separated list. Leading and trailing spaces in each column are automatically removed' -r
 complete -c marc21 -n "__fish_marc21_using_subcommand select" -s o -l output -d 'Write output to <path> instead of stdout' -r -F
 complete -c marc21 -n "__fish_marc21_using_subcommand select" -s l -l limit -d 'Limit the result to first <n> records (a limit value `0` means no limit)' -r
-complete -c marc21 -n "__fish_marc21_using_subcommand select" -l strsim-threshold -d 'The minimum score for string similarity comparisons. The value must be between 0 and 100' -r
-complete -c marc21 -n "__fish_marc21_using_subcommand select" -l where -d 'An expression for filtering records' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand select" -l strsim-threshold -d 'The minimum score for string similarity comparisons. The value must be between 0 and 100. If not set, the default threshold of the algorithm is used (90 for `jaro-winkler`, otherwise 80)' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand select" -l strsim-algorithm -d 'The algorithm for string similarity comparisons, which don\'t specify an algorithm of their own (e.g. `=*(token-set)`)' -r -f -a "levenshtein\t'Normalized Levenshtein distance'
+jaro-winkler\t'Jaro-Winkler similarity'
+sorensen-dice\t'Sørensen-Dice coefficient of character bigrams'
+token-set\t'Similarity of the sets of words (ignores the word order)'"
+complete -c marc21 -n "__fish_marc21_using_subcommand select" -l where -d 'An expression for filtering records. Only if invalid records are skipped, records that can\'t match are rejected before they are decoded' -r
 complete -c marc21 -n "__fish_marc21_using_subcommand select" -l filter-normalization -d 'Transliterate the given filter or query expression into the specified Unicode normal form' -r -f -a "nfd\t''
 nfkd\t''
 nfc\t''
 nfkc\t''"
 complete -c marc21 -n "__fish_marc21_using_subcommand select" -l compression -d 'Specify compression level' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand select" -l control-fields -d 'A comma-separated list of tag matchers, which decides whether a field is a control field (default: `00.`). Local control fields can be added, e.g. `00.,FMT,SYS`' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand select" -l threads -d 'Number of threads used to parse and process records. The value `0` selects the number of available CPUs' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand select" -l oversize -d 'How to write records, which exceed the maximum record length of 99999 bytes. By default, such records are written in the (non-standard) form in which they were read, which is also the default of the library' -r -f -a "error\t'Reject the record with an error'
+split\t'Split the record into records linked by a 773 field'
+truncate\t'Drop the fields, which don\'t fit into the limit'
+keep\t'Write the non-standard oversize form'"
 complete -c marc21 -n "__fish_marc21_using_subcommand select" -l tsv -d 'Write output tab-separated (TSV)'
 complete -c marc21 -n "__fish_marc21_using_subcommand select" -s s -l skip-invalid -d 'Skip invalid records that can\'t be decoded'
+complete -c marc21 -n "__fish_marc21_using_subcommand select" -l filter-ignore-case -d 'Compare strings regardless of their case'
+complete -c marc21 -n "__fish_marc21_using_subcommand select" -l filter-ignore-diacritics -d 'Compare strings regardless of their diacritics'
+complete -c marc21 -n "__fish_marc21_using_subcommand select" -l filter-ignore-normalization -d 'Compare strings regardless of their Unicode normal form'
 complete -c marc21 -n "__fish_marc21_using_subcommand select" -s p -l progress -d 'If set, show a progress bar'
+complete -c marc21 -n "__fish_marc21_using_subcommand select" -l bgzf -d 'If set, gzip compressed output is written as block-gzip (BGZF), which allows `marc21 lookup` to seek directly to a record'
+complete -c marc21 -n "__fish_marc21_using_subcommand select" -l marc8 -d 'If set, MARC-8 encoded records are transcoded into UTF-8'
+complete -c marc21 -n "__fish_marc21_using_subcommand select" -l lenient -d 'If set, malformed records are repaired instead of rejected. Each repair is reported as a warning'
 complete -c marc21 -n "__fish_marc21_using_subcommand select" -s h -l help -d 'Print help (see more with \'--help\')'
 complete -c marc21 -n "__fish_marc21_using_subcommand skosify" -s c -l config -r -F
 complete -c marc21 -n "__fish_marc21_using_subcommand skosify" -l format -r -f -a "turtle\t''
 nt\t''"
 complete -c marc21 -n "__fish_marc21_using_subcommand skosify" -s o -l output -d 'Write output to <filename> instead of stdout' -r -F
 complete -c marc21 -n "__fish_marc21_using_subcommand skosify" -s l -l limit -d 'Limit the result to first <n> records (a limit value `0` means no limit)' -r
-complete -c marc21 -n "__fish_marc21_using_subcommand skosify" -l strsim-threshold -d 'The minimum score for string similarity comparisons. The value must be between 0 and 100' -r
-complete -c marc21 -n "__fish_marc21_using_subcommand skosify" -l where -d 'An expression for filtering records' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand skosify" -l strsim-threshold -d 'The minimum score for string similarity comparisons. The value must be between 0 and 100. If not set, the default threshold of the algorithm is used (90 for `jaro-winkler`, otherwise 80)' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand skosify" -l strsim-algorithm -d 'The algorithm for string similarity comparisons, which don\'t specify an algorithm of their own (e.g. `=*(token-set)`)' -r -f -a "levenshtein\t'Normalized Levenshtein distance'
+jaro-winkler\t'Jaro-Winkler similarity'
+sorensen-dice\t'Sørensen-Dice coefficient of character bigrams'
+token-set\t'Similarity of the sets of words (ignores the word order)'"
+complete -c marc21 -n "__fish_marc21_using_subcommand skosify" -l where -d 'An expression for filtering records. Only if invalid records are skipped, records that can\'t match are rejected before they are decoded' -r
 complete -c marc21 -n "__fish_marc21_using_subcommand skosify" -l filter-normalization -d 'Transliterate the given filter or query expression into the specified Unicode normal form' -r -f -a "nfd\t''
 nfkd\t''
 nfc\t''
 nfkc\t''"
 complete -c marc21 -n "__fish_marc21_using_subcommand skosify" -l compression -d 'Specify compression level' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand skosify" -l control-fields -d 'A comma-separated list of tag matchers, which decides whether a field is a control field (default: `00.`). Local control fields can be added, e.g. `00.,FMT,SYS`' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand skosify" -l threads -d 'Number of threads used to parse and process records. The value `0` selects the number of available CPUs' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand skosify" -l oversize -d 'How to write records, which exceed the maximum record length of 99999 bytes. By default, such records are written in the (non-standard) form in which they were read, which is also the default of the library' -r -f -a "error\t'Reject the record with an error'
+split\t'Split the record into records linked by a 773 field'
+truncate\t'Drop the fields, which don\'t fit into the limit'
+keep\t'Write the non-standard oversize form'"
 complete -c marc21 -n "__fish_marc21_using_subcommand skosify" -s s -l skip-invalid -d 'Skip invalid records that can\'t be decoded'
+complete -c marc21 -n "__fish_marc21_using_subcommand skosify" -l filter-ignore-case -d 'Compare strings regardless of their case'
+complete -c marc21 -n "__fish_marc21_using_subcommand skosify" -l filter-ignore-diacritics -d 'Compare strings regardless of their diacritics'
+complete -c marc21 -n "__fish_marc21_using_subcommand skosify" -l filter-ignore-normalization -d 'Compare strings regardless of their Unicode normal form'
 complete -c marc21 -n "__fish_marc21_using_subcommand skosify" -s p -l progress -d 'If set, show a progress bar'
-complete -c marc21 -n "__fish_marc21_using_subcommand skosify" -s h -l help -d 'Print help'
+complete -c marc21 -n "__fish_marc21_using_subcommand skosify" -l bgzf -d 'If set, gzip compressed output is written as block-gzip (BGZF), which allows `marc21 lookup` to seek directly to a record'
+complete -c marc21 -n "__fish_marc21_using_subcommand skosify" -l marc8 -d 'If set, MARC-8 encoded records are transcoded into UTF-8'
+complete -c marc21 -n "__fish_marc21_using_subcommand skosify" -l lenient -d 'If set, malformed records are repaired instead of rejected. Each repair is reported as a warning'
+complete -c marc21 -n "__fish_marc21_using_subcommand skosify" -s h -l help -d 'Print help (see more with \'--help\')'
 complete -c marc21 -n "__fish_marc21_using_subcommand split" -l filename -d 'Filename template ("{}" is replaced by the chunk number)' -r
 complete -c marc21 -n "__fish_marc21_using_subcommand split" -s o -l outdir -d 'Write partitions into <path>' -r -F
 complete -c marc21 -n "__fish_marc21_using_subcommand split" -s l -l limit -d 'Limit the result to first <n> records (a limit value `0` means no limit)' -r
-complete -c marc21 -n "__fish_marc21_using_subcommand split" -l strsim-threshold -d 'The minimum score for string similarity comparisons. The value must be between 0 and 100' -r
-complete -c marc21 -n "__fish_marc21_using_subcommand split" -l where -d 'An expression for filtering records' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand split" -l strsim-threshold -d 'The minimum score for string similarity comparisons. The value must be between 0 and 100. If not set, the default threshold of the algorithm is used (90 for `jaro-winkler`, otherwise 80)' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand split" -l strsim-algorithm -d 'The algorithm for string similarity comparisons, which don\'t specify an algorithm of their own (e.g. `=*(token-set)`)' -r -f -a "levenshtein\t'Normalized Levenshtein distance'
+jaro-winkler\t'Jaro-Winkler similarity'
+sorensen-dice\t'Sørensen-Dice coefficient of character bigrams'
+token-set\t'Similarity of the sets of words (ignores the word order)'"
+complete -c marc21 -n "__fish_marc21_using_subcommand split" -l where -d 'An expression for filtering records. Only if invalid records are skipped, records that can\'t match are rejected before they are decoded' -r
 complete -c marc21 -n "__fish_marc21_using_subcommand split" -l filter-normalization -d 'Transliterate the given filter or query expression into the specified Unicode normal form' -r -f -a "nfd\t''
 nfkd\t''
 nfc\t''
 nfkc\t''"
 complete -c marc21 -n "__fish_marc21_using_subcommand split" -l compression -d 'Specify compression level' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand split" -l control-fields -d 'A comma-separated list of tag matchers, which decides whether a field is a control field (default: `00.`). Local control fields can be added, e.g. `00.,FMT,SYS`' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand split" -l threads -d 'Number of threads used to parse and process records. The value `0` selects the number of available CPUs' -r
+complete -c marc21 -n "__fish_marc21_using_subcommand split" -l oversize -d 'How to write records, which exceed the maximum record length of 99999 bytes. By default, such records are written in the (non-standard) form in which they were read, which is also the default of the library' -r -f -a "error\t'Reject the record with an error'
+split\t'Split the record into records linked by a 773 field'
+truncate\t'Drop the fields, which don\'t fit into the limit'
+keep\t'Write the non-standard oversize form'"
 complete -c marc21 -n "__fish_marc21_using_subcommand split" -s s -l skip-invalid -d 'Skip invalid records that can\'t be decoded'
+complete -c marc21 -n "__fish_marc21_using_subcommand split" -l filter-ignore-case -d 'Compare strings regardless of their case'
+complete -c marc21 -n "__fish_marc21_using_subcommand split" -l filter-ignore-diacritics -d 'Compare strings regardless of their diacritics'
+complete -c marc21 -n "__fish_marc21_using_subcommand split" -l filter-ignore-normalization -d 'Compare strings regardless of their Unicode normal form'
 complete -c marc21 -n "__fish_marc21_using_subcommand split" -s p -l progress -d 'If set, show a progress bar'
+complete -c marc21 -n "__fish_marc21_using_subcommand split" -l bgzf -d 'If set, gzip compressed output is written as block-gzip (BGZF), which allows `marc21 lookup` to seek directly to a record'
+complete -c marc21 -n "__fish_marc21_using_subcommand split" -l marc8 -d 'If set, MARC-8 encoded records are transcoded into UTF-8'
+complete -c marc21 -n "__fish_marc21_using_subcommand split" -l lenient -d 'If set, malformed records are repaired instead of rejected. Each repair is reported as a warning'
 complete -c marc21 -n "__fish_marc21_using_subcommand split" -s h -l help -d 'Print help (see more with \'--help\')'
 complete -c marc21 -n "__fish_marc21_using_subcommand build-completion" -s o -l output -d 'Write output to <filename>' -r -F
 complete -c marc21 -n "__fish_marc21_using_subcommand build-completion" -s h -l help -d 'Print help'
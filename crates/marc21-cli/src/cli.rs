@@ -1,9 +1,14 @@
+use std::io::{self, Write};
+
 use clap::{Parser, Subcommand, value_parser};
+use marc21::io::{MarcReadOptions, ParseWarning};
 use marc21::matcher::{MatchOptions, ParseMatcherError, RecordMatcher};
+use marc21::{ByteRecord, ControlFieldRule, OversizePolicy};
 use unicode_normalization::UnicodeNormalization;
 
 use crate::commands::*;
 use crate::unicode::NormalizationForm;
+use crate::warnings;
 
 #[derive(Debug, Parser)]
 #[clap(version, author, infer_subcommands = true, max_term_width = 72)]
@@ -23,6 +28,7 @@ pub(crate) struct Args {
 pub(crate) enum Command {
     Check(Box<Check>),
     Concat(Box<Concat>),
+    Convert(Box<Convert>),
     Count(Box<Count>),
     Dedup(Box<Dedup>),
     Describe(Box<Describe>),
@@ -31,7 +37,9 @@ pub(crate) enum Command {
     Glimpse(Box<Glimpse>),
     Grep(Box<Grep>),
     Hash(Box<Hash>),
+    Index(Box<Index>),
     Invalid(Box<Invalid>),
+    Lookup(Box<Lookup>),
     Partition(Box<Partition>),
     Print(Box<Print>),
     Sample(Box<Sample>),
@@ -60,6 +68,172 @@ pub(crate) struct CommonOpts {
         requires = "output"
     )]
     pub(crate) compression: u32,
+
+    /// If set, gzip compressed output is written as block-gzip (BGZF),
+    /// which allows `marc21 lookup` to seek directly to a record.
+    #[arg(long, requires = "output")]
+    pub(crate) bgzf: bool,
+
+    /// If set, MARC-8 encoded records are transcoded into UTF-8
+    #[arg(long, global = true)]
+    pub(crate) marc8: bool,
+
+    /// If set, malformed records are repaired instead of rejected.
+    /// Each repair is reported as a warning.
+    #[arg(long, global = true)]
+    pub(crate) lenient: bool,
+
+    /// A comma-separated list of tag matchers, which decides whether a
+    /// field is a control field (default: `00.`). Local control fields
+    /// can be added, e.g. `00.,FMT,SYS`.
+    #[arg(
+        long,
+        global = true,
+        value_name = "tags",
+        env = "MARC21_CONTROL_FIELDS"
+    )]
+    pub(crate) control_fields: Option<ControlFieldRule>,
+
+    /// Number of threads used to parse and process records. The value
+    /// `0` selects the number of available CPUs.
+    #[arg(
+        long,
+        global = true,
+        default_value_t = 1,
+        value_name = "n",
+        env = "MARC21_THREADS"
+    )]
+    pub(crate) threads: usize,
+
+    /// How to write records, which exceed the maximum record length of
+    /// 99999 bytes. By default, such records are written in the
+    /// (non-standard) form in which they were read, which is also the
+    /// default of the library.
+    #[arg(
+        long,
+        global = true,
+        default_value = "keep",
+        value_name = "policy",
+        env = "MARC21_OVERSIZE"
+    )]
+    pub(crate) oversize: Oversize,
+}
+
+impl CommonOpts {
+    /// Writes the record according to the oversize policy. The tags of
+    /// the fields, which are dropped by the `truncate` policy, are
+    /// reported as a warning.
+    pub(crate) fn write_record<W: Write>(
+        &self,
+        record: &ByteRecord,
+        out: &mut W,
+    ) -> io::Result<()> {
+        let dropped = record.write_with(out, self.oversize.into())?;
+        if !dropped.is_empty() {
+            let cn = record
+                .control_number()
+                .map(|cn| cn.to_str_lossy().to_string())
+                .unwrap_or_default();
+
+            let message = format!(
+                "oversize record {cn} truncated; dropped fields: {}",
+                dropped
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+
+            let _ =
+                warnings::sender().send(ParseWarning::new(message, 0));
+        }
+
+        Ok(())
+    }
+}
+
+/// The policy to write records, which exceed the maximum record
+/// length.
+#[derive(Debug, Clone, Copy, PartialEq, clap::ValueEnum)]
+pub(crate) enum Oversize {
+    /// Reject the record with an error
+    Error,
+    /// Split the record into records linked by a 773 field
+    Split,
+    /// Drop the fields, which don't fit into the limit
+    Truncate,
+    /// Write the non-standard oversize form
+    Keep,
+}
+
+impl From<Oversize> for OversizePolicy {
+    fn from(oversize: Oversize) -> Self {
+        match oversize {
+            Oversize::Error => Self::Error,
+            Oversize::Split => Self::Split,
+            Oversize::Truncate => Self::Truncate,
+            Oversize::Keep => Self::Oversize,
+        }
+    }
+}
+
+/// The algorithm for string similarity comparisons.
+#[derive(Debug, Clone, Copy, PartialEq, clap::ValueEnum)]
+pub(crate) enum StrsimAlgorithm {
+    /// Normalized Levenshtein distance
+    Levenshtein,
+    /// Jaro-Winkler similarity
+    JaroWinkler,
+    /// Sørensen-Dice coefficient of character bigrams
+    SorensenDice,
+    /// Similarity of the sets of words (ignores the word order)
+    TokenSet,
+}
+
+impl From<StrsimAlgorithm> for marc21::matcher::StrsimAlgorithm {
+    fn from(algorithm: StrsimAlgorithm) -> Self {
+        match algorithm {
+            StrsimAlgorithm::Levenshtein => Self::Levenshtein,
+            StrsimAlgorithm::JaroWinkler => Self::JaroWinkler,
+            StrsimAlgorithm::SorensenDice => Self::SorensenDice,
+            StrsimAlgorithm::TokenSet => Self::TokenSet,
+        }
+    }
+}
+
+/// The options of a record matcher, which are shared by all commands
+/// with a filter expression.
+#[derive(Debug, Clone, clap::Args)]
+pub(crate) struct MatchOpts {
+    /// The minimum score for string similarity comparisons. The value
+    /// must be between 0 and 100. If not set, the default threshold of
+    /// the algorithm is used (90 for `jaro-winkler`, otherwise 80).
+    #[arg(long,
+        value_parser = value_parser!(u8).range(0..=100),
+        value_name = "value"
+    )]
+    strsim_threshold: Option<u8>,
+
+    /// The algorithm for string similarity comparisons, which don't
+    /// specify an algorithm of their own (e.g. `=*(token-set)`).
+    #[arg(
+        long,
+        default_value = "levenshtein",
+        value_name = "algorithm"
+    )]
+    strsim_algorithm: StrsimAlgorithm,
+
+    /// Compare strings regardless of their case.
+    #[arg(long)]
+    filter_ignore_case: bool,
+
+    /// Compare strings regardless of their diacritics.
+    #[arg(long)]
+    filter_ignore_diacritics: bool,
+
+    /// Compare strings regardless of their Unicode normal form.
+    #[arg(long)]
+    filter_ignore_normalization: bool,
 }
 
 #[derive(Debug, Clone, clap::Args)]
@@ -73,16 +247,12 @@ pub(crate) struct FilterOpts {
     #[arg(long, short, value_name = "n", default_value = "0")]
     pub(crate) limit: usize,
 
-    /// The minimum score for string similarity comparisons. The value
-    /// must be between 0 and 100.
-    #[arg(long,
-        value_parser = value_parser!(u8).range(0..=100),
-        default_value = "80",
-        value_name = "value"
-    )]
-    pub(crate) strsim_threshold: u8,
+    #[command(flatten)]
+    pub(crate) match_opts: MatchOpts,
 
-    /// An expression for filtering records
+    /// An expression for filtering records. Only if invalid records
+    /// are skipped, records that can't match are rejected before they
+    /// are decoded.
     #[arg(long = "where", value_name = "predicate")]
     filter: Option<String>,
 
@@ -118,9 +288,41 @@ impl FilterOpts {
     }
 }
 
+impl From<&CommonOpts> for MarcReadOptions {
+    fn from(opts: &CommonOpts) -> Self {
+        let mut options =
+            Self::default().marc8(opts.marc8).lenient(opts.lenient);
+
+        if let Some(ref rule) = opts.control_fields {
+            options = options.control_fields(rule.clone());
+        }
+
+        if opts.lenient {
+            options.warnings(warnings::sender())
+        } else {
+            options
+        }
+    }
+}
+
 impl From<&FilterOpts> for MatchOptions {
     fn from(opts: &FilterOpts) -> Self {
-        Self::default()
-            .strsim_threshold(opts.strsim_threshold as f64 / 100f64)
+        Self::from(&opts.match_opts)
+    }
+}
+
+impl From<&MatchOpts> for MatchOptions {
+    fn from(opts: &MatchOpts) -> Self {
+        let options = match opts.strsim_threshold {
+            Some(threshold) => Self::default()
+                .strsim_threshold(threshold as f64 / 100f64),
+            None => Self::default(),
+        };
+
+        options
+            .strsim_algorithm(opts.strsim_algorithm.into())
+            .case_insensitive(opts.filter_ignore_case)
+            .diacritic_insensitive(opts.filter_ignore_diacritics)
+            .normalization_insensitive(opts.filter_ignore_normalization)
     }
 }
@@ -1,3 +1,4 @@
+use std::ops::ControlFlow;
 use std::path::PathBuf;
 
 use crate::commands::check::rule::RuleSet;
@@ -57,46 +58,46 @@ impl Check {
         let options = MatchOptions::from(&self.filter_opts);
         let filter = self.filter_opts.filter()?;
         let mut count = 0;
-        let mut line = 0;
 
-        'outer: for path in self.input.iter() {
-            let mut reader = MarcReadOptions::default()
-                .try_into_reader_from_path(path)?;
-
-            while let Some(result) = reader.next_byte_record() {
-                line += 1;
-
-                match result {
-                    Err(ReadMarcError::Parse(_))
-                        if self.filter_opts.skip_invalid =>
+        Pipeline::new(&self.common)
+            .skip_invalid(self.filter_opts.skip_invalid)
+            .prefilter(
+                filter.as_ref().and_then(|m| m.prefilter(&options)),
+            )
+            .run(
+                &self.input,
+                &mut progress,
+                |record| {
+                    if let Some(ref m) = filter
+                        && !m.is_match(record, &options)
                     {
-                        progress.update(true);
-                        continue;
+                        return Ok(None);
                     }
-                    Err(e) => {
-                        return Err(CliError::from_parse(e, line));
+
+                    let mut violations = vec![];
+                    for rs in rulesets.iter() {
+                        rs.validate(record, &mut violations);
                     }
-                    Ok(ref record) => {
-                        progress.update(false);
-
-                        if let Some(ref m) = filter
-                            && !m.is_match(record, &options)
-                        {
-                            continue;
-                        }
-
-                        for rs in rulesets.iter() {
-                            rs.validate(record, &mut writer)?;
-                        }
-
-                        count += 1;
-                        if self.filter_opts.limit == count {
-                            break 'outer;
-                        }
+
+                    Ok(Some(violations))
+                },
+                |violations| {
+                    let Some(violations) = violations else {
+                        return Ok(ControlFlow::Continue(()));
+                    };
+
+                    for violation in violations {
+                        writer.write_record(violation)?;
                     }
-                }
-            }
-        }
+
+                    count += 1;
+                    if self.filter_opts.limit == count {
+                        return Ok(ControlFlow::Break(()));
+                    }
+
+                    Ok(ControlFlow::Continue(()))
+                },
+            )?;
 
         progress.finish();
         writer.finish()?;
@@ -9,7 +9,6 @@ use unicode_normalization::UnicodeNormalization;
 use crate::commands::check::level::Level;
 use crate::commands::check::record::Record;
 use crate::commands::check::validator::Validator;
-use crate::commands::check::writer::Writer;
 use crate::prelude::*;
 use crate::unicode::NormalizationForm::{self, Nfc, Nfd, Nfkc, Nfkd};
 
@@ -57,22 +56,21 @@ impl RuleSet {
         self.rules.is_empty()
     }
 
+    /// Validates a record and appends the violations to `out`.
     pub(crate) fn validate(
         &self,
         record: &ByteRecord,
-        writer: &mut Writer,
-    ) -> Result<(), CliError> {
+        out: &mut Vec<Record>,
+    ) {
         if let Some(ref matcher) = self.scope
             && !matcher.is_match(record, &MatchOptions::default())
         {
-            return Ok(());
+            return;
         }
 
         for rule in self.rules.values() {
-            rule.validate(record, writer)?;
+            rule.validate(record, out);
         }
-
-        Ok(())
     }
 }
 
@@ -102,14 +100,14 @@ impl Rule {
     pub(crate) fn validate(
         &self,
         record: &ByteRecord,
-        writer: &mut Writer,
-    ) -> Result<(), CliError> {
+        out: &mut Vec<Record>,
+    ) {
         let result = match self.validator {
             Validator::Filter(ref v) => v.is_valid(record),
         };
 
         if !result {
-            writer.write_record(Record {
+            out.push(Record {
                 message: self.message.clone(),
                 level: self.level.clone(),
                 rule: self.id.clone(),
@@ -118,9 +116,7 @@ impl Rule {
                     .expect("missing control number")
                     .to_str_unchecked()
                     .to_string(),
-            })?
+            })
         }
-
-        Ok(())
     }
 }
@@ -5,7 +5,7 @@ use crate::commands::check::writer::csv::CsvWriter;
 use crate::commands::check::writer::dvrf::DvrfWriter;
 use crate::commands::check::writer::text::TextWriter;
 use crate::error::CliError;
-use crate::utils::WriterBuilder;
+use crate::utils::{WriterBuilder, has_extension};
 
 mod csv;
 mod dvrf;
@@ -29,23 +29,17 @@ impl Writer {
             .and_then(|path| path.to_str().map(|s| s.to_owned()))
             .unwrap_or_default();
 
-        let writer = if output.is_none()
-            || filename.ends_with(".csv.gz")
-            || filename.ends_with(".csv")
-        {
-            CsvWriter::from_writer(wtr)?.into()
-        } else if filename.ends_with(".txt.gz")
-            || filename.ends_with(".txt")
-        {
-            TextWriter::from_writer(wtr)?.into()
-        } else if filename.ends_with(".json.gz")
-            || filename.ends_with(".json")
-        {
-            DvrfWriter::from_writer(wtr)?.into()
-        } else {
-            // Use DVRF output by default
-            DvrfWriter::from_writer(wtr)?.into()
-        };
+        let writer =
+            if output.is_none() || has_extension(&filename, "csv") {
+                CsvWriter::from_writer(wtr)?.into()
+            } else if has_extension(&filename, "txt") {
+                TextWriter::from_writer(wtr)?.into()
+            } else if has_extension(&filename, "json") {
+                DvrfWriter::from_writer(wtr)?.into()
+            } else {
+                // Use DVRF output by default
+                DvrfWriter::from_writer(wtr)?.into()
+            };
 
         Ok(writer)
     }
@@ -1,3 +1,5 @@
+use std::io::Write;
+use std::ops::ControlFlow;
 use std::path::PathBuf;
 
 use crate::prelude::*;
@@ -8,7 +10,7 @@ use crate::prelude::*;
 pub(crate) struct Concat {
     /// Append to the given file, do not overwrite.
     ///
-    /// This option is not supported when writing to Gzip compressed
+    /// This option is not supported when writing to compressed
     /// output. When writing to `stdout` this flag is ignored.
     #[arg(long, short)]
     append: bool,
@@ -41,10 +43,10 @@ impl Concat {
         let options = MatchOptions::from(&self.filter_opts);
         let filter = self.filter_opts.filter()?;
         let mut count = 0;
-        let mut line = 0;
 
         let mut output = WriterBuilder::default()
             .with_compression(self.common.compression)
+            .bgzf(self.common.bgzf)
             .append(self.append)
             .try_from_path_or_stdout(self.output)?;
 
@@ -52,52 +54,51 @@ impl Concat {
             Some(
                 WriterBuilder::default()
                     .with_compression(self.common.compression)
+                    .bgzf(self.common.bgzf)
                     .try_from_path_or_stdout(Some(path))?,
             )
         } else {
             None
         };
 
-        'outer: for path in self.path.iter() {
-            let mut reader = MarcReadOptions::default()
-                .try_into_reader_from_path(path)?;
-
-            while let Some(result) = reader.next_byte_record() {
-                line += 1;
-
-                match result {
-                    Err(ReadMarcError::Parse(_))
-                        if self.filter_opts.skip_invalid =>
+        Pipeline::new(&self.common)
+            .skip_invalid(self.filter_opts.skip_invalid)
+            .prefilter(
+                filter.as_ref().and_then(|m| m.prefilter(&options)),
+            )
+            .run(
+                &self.path,
+                &mut progress,
+                |record| {
+                    if let Some(ref m) = filter
+                        && !m.is_match(record, &options)
                     {
-                        progress.update(true);
-                        continue;
-                    }
-                    Err(e) => {
-                        return Err(CliError::from_parse(e, line));
+                        return Ok(None);
                     }
-                    Ok(ref record) => {
-                        progress.update(false);
 
-                        if let Some(ref m) = filter
-                            && !m.is_match(record, &options)
-                        {
-                            continue;
-                        }
+                    let mut data = Vec::new();
+                    self.common.write_record(record, &mut data)?;
+                    Ok(Some(data))
+                },
+                |data| {
+                    let Some(data) = data else {
+                        return Ok(ControlFlow::Continue(()));
+                    };
 
-                        record.write_to(&mut output)?;
+                    output.write_all(&data)?;
 
-                        if let Some(ref mut wtr) = tee_writer {
-                            record.write_to(wtr)?;
-                        }
+                    if let Some(ref mut wtr) = tee_writer {
+                        wtr.write_all(&data)?;
+                    }
 
-                        count += 1;
-                        if self.filter_opts.limit == count {
-                            break 'outer;
-                        }
+                    count += 1;
+                    if self.filter_opts.limit == count {
+                        return Ok(ControlFlow::Break(()));
                     }
-                }
-            }
-        }
+
+                    Ok(ControlFlow::Continue(()))
+                },
+            )?;
 
         if let Some(wtr) = tee_writer {
             wtr.finish()?;
@@ -0,0 +1,142 @@
+use std::io::Write;
+use std::ops::ControlFlow;
+use std::path::PathBuf;
+
+use clap::ValueEnum;
+use marc21::io::{
+    MarcJsonWriteOptions, MarcJsonWriter, MarcXmlWriteOptions,
+    MarcXmlWriter,
+};
+
+use crate::prelude::*;
+
+/// Convert records into another format
+#[derive(Debug, clap::Parser)]
+pub(crate) struct Convert {
+    /// The output format.
+    #[arg(long, value_name = "format")]
+    to: Format,
+
+    /// If set, the output is indented (MARCXML only).
+    #[arg(long)]
+    pretty: bool,
+
+    /// If set, the records aren't wrapped into a collection element.
+    /// Instead, each record is written on its own line (MARCXML only).
+    #[arg(long)]
+    no_collection: bool,
+
+    /// Write output to <path> instead of stdout.
+    #[arg(short, long, value_name = "path")]
+    output: Option<PathBuf>,
+
+    #[arg(default_value = "-", hide_default_value = true)]
+    path: Vec<PathBuf>,
+
+    #[command(flatten, next_help_heading = "Filter options")]
+    pub(crate) filter_opts: FilterOpts,
+
+    #[command(flatten, next_help_heading = "Common options")]
+    pub(crate) common: CommonOpts,
+}
+
+#[derive(Debug, Clone, PartialEq, ValueEnum)]
+enum Format {
+    /// MARC 21 slim XML
+    Marcxml,
+    /// MARC-in-JSON (a JSON array of records)
+    Json,
+    /// MARC-in-JSON (one record per line)
+    Jsonl,
+}
+
+enum RecordWriter<W: Write> {
+    Xml(MarcXmlWriter<W>),
+    Json(MarcJsonWriter<W>),
+}
+
+impl<W: Write> RecordWriter<W> {
+    fn write_record(&mut self, record: &ByteRecord) -> CliResult {
+        match self {
+            Self::Xml(wtr) => wtr.write_record(record)?,
+            Self::Json(wtr) => wtr.write_record(record)?,
+        }
+
+        Ok(())
+    }
+
+    fn finish(self) -> Result<W, CliError> {
+        Ok(match self {
+            Self::Xml(wtr) => wtr.finish()?,
+            Self::Json(wtr) => wtr.finish()?,
+        })
+    }
+}
+
+impl Convert {
+    pub(crate) fn execute(self) -> CliResult {
+        let mut progress = Progress::new(self.common.progress);
+        let options = MatchOptions::from(&self.filter_opts);
+        let filter = self.filter_opts.filter()?;
+        let mut count = 0;
+
+        let output = WriterBuilder::default()
+            .with_compression(self.common.compression)
+            .bgzf(self.common.bgzf)
+            .try_from_path_or_stdout(self.output)?;
+
+        let mut writer = match self.to {
+            Format::Marcxml => RecordWriter::Xml(MarcXmlWriter::new(
+                output,
+                MarcXmlWriteOptions::default()
+                    .pretty(self.pretty)
+                    .collection(!self.no_collection),
+            )),
+            Format::Json | Format::Jsonl => {
+                RecordWriter::Json(MarcJsonWriter::new(
+                    output,
+                    MarcJsonWriteOptions::default()
+                        .lines(self.to == Format::Jsonl),
+                ))
+            }
+        };
+
+        Pipeline::new(&self.common)
+            .skip_invalid(self.filter_opts.skip_invalid)
+            .prefilter(
+                filter.as_ref().and_then(|m| m.prefilter(&options)),
+            )
+            .run(
+                &self.path,
+                &mut progress,
+                |record| {
+                    if let Some(ref m) = filter
+                        && !m.is_match(record, &options)
+                    {
+                        return Ok(None);
+                    }
+
+                    Ok(Some(RecordBuf::from(record)))
+                },
+                |record| {
+                    let Some(record) = record else {
+                        return Ok(ControlFlow::Continue(()));
+                    };
+
+                    writer.write_record(&record.as_byte_record())?;
+
+                    count += 1;
+                    if self.filter_opts.limit == count {
+                        return Ok(ControlFlow::Break(()));
+                    }
+
+                    Ok(ControlFlow::Continue(()))
+                },
+            )?;
+
+        progress.finish();
+        writer.finish()?.finish()?;
+
+        Ok(())
+    }
+}
@@ -1,4 +1,5 @@
 use std::io::Write;
+use std::ops::ControlFlow;
 use std::path::PathBuf;
 
 use crate::prelude::*;
@@ -27,46 +28,36 @@ impl Count {
         let options = MatchOptions::from(&self.filter_opts);
         let filter = self.filter_opts.filter()?;
         let mut count = 0;
-        let mut line = 0;
 
         let mut output = WriterBuilder::default()
             .with_compression(self.common.compression)
+            .bgzf(self.common.bgzf)
             .try_from_path_or_stdout(self.output)?;
 
-        'outer: for path in self.path.iter() {
-            let mut reader = MarcReadOptions::default()
-                .try_into_reader_from_path(path)?;
-
-            while let Some(result) = reader.next_byte_record() {
-                line += 1;
-
-                match result {
-                    Err(ReadMarcError::Parse(_))
-                        if self.filter_opts.skip_invalid =>
-                    {
-                        progress.update(true);
-                        continue;
-                    }
-                    Err(e) => {
-                        return Err(CliError::from_parse(e, line));
-                    }
-                    Ok(ref record) => {
-                        progress.update(false);
-
-                        if let Some(ref m) = filter
-                            && !m.is_match(record, &options)
-                        {
-                            continue;
-                        }
-
+        Pipeline::new(&self.common)
+            .skip_invalid(self.filter_opts.skip_invalid)
+            .prefilter(
+                filter.as_ref().and_then(|m| m.prefilter(&options)),
+            )
+            .run(
+                &self.path,
+                &mut progress,
+                |record| {
+                    Ok(filter
+                        .as_ref()
+                        .is_none_or(|m| m.is_match(record, &options)))
+                },
+                |is_match| {
+                    if is_match {
                         count += 1;
                         if self.filter_opts.limit == count {
-                            break 'outer;
+                            return Ok(ControlFlow::Break(()));
                         }
                     }
-                }
-            }
-        }
+
+                    Ok(ControlFlow::Continue(()))
+                },
+            )?;
 
         progress.finish();
 
@@ -1,4 +1,6 @@
 use std::collections::HashSet;
+use std::io::Write;
+use std::ops::ControlFlow;
 use std::path::PathBuf;
 
 use crate::prelude::*;
@@ -31,55 +33,55 @@ impl Dedup {
         let filter = self.filter_opts.filter()?;
         let mut seen = HashSet::new();
         let mut count = 0;
-        let mut line = 0;
 
         let mut output = WriterBuilder::default()
             .with_compression(self.common.compression)
+            .bgzf(self.common.bgzf)
             .try_from_path_or_stdout(self.output)?;
 
-        'outer: for path in self.path.iter() {
-            let mut reader = MarcReadOptions::default()
-                .try_into_reader_from_path(path)?;
-
-            while let Some(result) = reader.next_byte_record() {
-                line += 1;
-
-                match result {
-                    Err(ReadMarcError::Parse(_))
-                        if self.filter_opts.skip_invalid =>
+        Pipeline::new(&self.common)
+            .skip_invalid(self.filter_opts.skip_invalid)
+            .prefilter(
+                filter.as_ref().and_then(|m| m.prefilter(&options)),
+            )
+            .run(
+                &self.path,
+                &mut progress,
+                |record| {
+                    if let Some(ref m) = filter
+                        && !m.is_match(record, &options)
                     {
-                        progress.update(true);
-                        continue;
+                        return Ok(None);
                     }
-                    Err(e) => {
-                        return Err(CliError::from_parse(e, line));
-                    }
-                    Ok(ref record) => {
-                        progress.update(false);
-
-                        if let Some(ref m) = filter
-                            && !m.is_match(record, &options)
-                        {
-                            continue;
-                        }
 
-                        if let Some(cn) = record.control_number() {
-                            let key = cn.to_vec();
-
-                            if !seen.contains(&key) {
-                                record.write_to(&mut output)?;
-                                seen.insert(key);
-                            }
-                        }
+                    let Some(cn) = record.control_number() else {
+                        return Ok(Some(None));
+                    };
+
+                    let mut data = Vec::new();
+                    self.common.write_record(record, &mut data)?;
+                    Ok(Some(Some((cn.to_vec(), data))))
+                },
+                |item| {
+                    let Some(item) = item else {
+                        return Ok(ControlFlow::Continue(()));
+                    };
+
+                    if let Some((key, data)) = item
+                        && !seen.contains(&key)
+                    {
+                        output.write_all(&data)?;
+                        seen.insert(key);
+                    }
 
-                        count += 1;
-                        if self.filter_opts.limit == count {
-                            break 'outer;
-                        }
+                    count += 1;
+                    if self.filter_opts.limit == count {
+                        return Ok(ControlFlow::Break(()));
                     }
-                }
-            }
-        }
+
+                    Ok(ControlFlow::Continue(()))
+                },
+            )?;
 
         progress.finish();
         output.finish()?;
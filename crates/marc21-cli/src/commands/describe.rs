@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use std::ops::ControlFlow;
 use std::path::PathBuf;
 
 use marc21::Field;
@@ -39,68 +40,68 @@ impl Describe {
         let options = MatchOptions::from(&self.filter_opts);
         let filter = self.filter_opts.filter()?;
         let mut count = 0;
-        let mut line = 0;
 
         let mut fields: HashMap<Key, HashMap<u8, usize>> =
             HashMap::new();
 
-        'outer: for path in self.path.iter() {
-            let mut reader = MarcReadOptions::default()
-                .try_into_reader_from_path(path)?;
-
-            while let Some(result) = reader.next_byte_record() {
-                line += 1;
-
-                match result {
-                    Err(ReadMarcError::Parse(_))
-                        if self.filter_opts.skip_invalid =>
+        Pipeline::new(&self.common)
+            .skip_invalid(self.filter_opts.skip_invalid)
+            .prefilter(
+                filter.as_ref().and_then(|m| m.prefilter(&options)),
+            )
+            .run(
+                &self.path,
+                &mut progress,
+                |record| {
+                    if let Some(ref m) = filter
+                        && !m.is_match(record, &options)
                     {
-                        progress.update(true);
-                        continue;
-                    }
-                    Err(e) => {
-                        return Err(CliError::from_parse(e, line));
+                        return Ok(None);
                     }
-                    Ok(ref record) => {
-                        progress.update(false);
-
-                        if let Some(ref m) = filter
-                            && !m.is_match(record, &options)
-                        {
-                            continue;
-                        }
 
-                        for field in record.fields() {
-                            match field {
-                                Field::Control(_) => continue,
-                                Field::Data(df) => {
-                                    let key = Key {
-                                        tag: field.tag().to_vec(),
-                                        ind1: *df.indicator1(),
-                                        ind2: *df.indicator2(),
-                                    };
-
-                                    let subfields =
-                                        fields.entry(key).or_default();
-
-                                    for subfield in df.subfields() {
-                                        subfields
-                                            .entry(*subfield.code())
-                                            .and_modify(|e| *e += 1)
-                                            .or_insert(1);
-                                    }
-                                }
-                            }
+                    let keys = record
+                        .fields()
+                        .filter_map(|field| match field {
+                            Field::Control(_) => None,
+                            Field::Data(df) => Some((
+                                Key {
+                                    tag: field.tag().to_vec(),
+                                    ind1: *df.indicator1(),
+                                    ind2: *df.indicator2(),
+                                },
+                                df.subfields()
+                                    .map(|subfield| *subfield.code())
+                                    .collect::<Vec<_>>(),
+                            )),
+                        })
+                        .collect::<Vec<_>>();
+
+                    Ok(Some(keys))
+                },
+                |keys| {
+                    let Some(keys) = keys else {
+                        return Ok(ControlFlow::Continue(()));
+                    };
+
+                    for (key, codes) in keys {
+                        let subfields = fields.entry(key).or_default();
+
+                        for code in codes {
+                            subfields
+                                .entry(code)
+                                .and_modify(|e| *e += 1)
+                                .or_insert(1);
                         }
+                    }
 
-                        count += 1;
-                        if self.filter_opts.limit == count {
-                            break 'outer;
-                        }
+                    count += 1;
+                    if self.filter_opts.limit == count {
+                        return Ok(ControlFlow::Break(()));
                     }
-                }
-            }
-        }
+
+                    Ok(ControlFlow::Continue(()))
+                },
+            )?;
 
         if fields.is_empty() {
             return Ok(());
@@ -114,12 +115,10 @@ impl Describe {
 
         let output = WriterBuilder::default()
             .with_compression(self.common.compression)
+            .bgzf(self.common.bgzf)
             .try_from_path_or_stdout(self.output.clone())?;
 
-        let delimiter = if self.tsv
-            || filename.ends_with(".tsv")
-            || filename.ends_with(".tsv.gz")
-        {
+        let delimiter = if self.tsv || has_extension(filename, "tsv") {
             b'\t'
         } else {
             b','
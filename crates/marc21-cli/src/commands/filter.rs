@@ -1,9 +1,11 @@
+use std::io::Write;
+use std::ops::ControlFlow;
 use std::path::PathBuf;
 
-use clap::value_parser;
 use marc21::matcher::RecordMatcher;
 use unicode_normalization::UnicodeNormalization;
 
+use crate::cli::MatchOpts;
 use crate::prelude::*;
 use crate::unicode::NormalizationForm;
 
@@ -24,14 +26,8 @@ pub(crate) struct Filter {
     #[arg(long, short, value_name = "n", default_value = "0")]
     limit: usize,
 
-    /// The minimum score for string similarity comparisons (0 <= score
-    /// <= 100).
-    #[arg(long,
-        value_parser = value_parser!(u8).range(0..=100),
-        default_value = "80",
-        value_name = "n"
-    )]
-    strsim_threshold: u8,
+    #[command(flatten)]
+    match_opts: MatchOpts,
 
     /// Transliterate the given filter expression into the specified
     /// Unicode normal form.
@@ -42,7 +38,9 @@ pub(crate) struct Filter {
     )]
     filter_normalization: Option<NormalizationForm>,
 
-    /// An expression for filtering records
+    /// An expression for filtering records. Only if invalid records
+    /// are skipped, records that can't match are rejected before they
+    /// are decoded.
     #[arg(value_name = "filter")]
     filter: String,
 
@@ -64,10 +62,10 @@ impl Filter {
         let mut progress = Progress::new(self.common.progress);
         let mut output = WriterBuilder::default()
             .with_compression(self.common.compression)
+            .bgzf(self.common.bgzf)
             .try_from_path_or_stdout(self.output)?;
 
-        let options = MatchOptions::default()
-            .strsim_threshold(self.strsim_threshold as f64 / 100f64);
+        let options = MatchOptions::from(&self.match_opts);
 
         let matcher =
             RecordMatcher::new(match self.filter_normalization {
@@ -79,48 +77,53 @@ impl Filter {
             })?;
 
         let mut count = 0;
-        let mut line = 0;
-
-        'outer: for path in self.path.iter() {
-            let mut reader = MarcReadOptions::default()
-                .try_into_reader_from_path(path)?;
 
-            while let Some(result) = reader.next_byte_record() {
-                match result {
+        // Unlike the other commands, an error refers to the number of
+        // records read before the invalid record.
+        Pipeline::new(&self.common)
+            .skip_invalid(self.skip_invalid)
+            .prefilter(if self.invert_match {
+                None
+            } else {
+                matcher.prefilter(&options)
+            })
+            .run_raw(
+                &self.path,
+                |result, line| match result {
                     Err(ReadMarcError::Parse(_))
                         if self.skip_invalid =>
                     {
-                        progress.update(true);
-                        continue;
-                    }
-                    Err(e) => {
-                        return Err(CliError::from_parse(e, line));
+                        Ok(None)
                     }
+                    Err(e) => Err(CliError::from_parse(e, line - 1)),
                     Ok(ref record) => {
-                        progress.update(false);
-                        line += 1;
-
-                        let mut result =
-                            matcher.is_match(record, &options);
-
-                        if self.invert_match {
-                            result = !result;
+                        if matcher.is_match(record, &options)
+                            == self.invert_match
+                        {
+                            return Ok(Some(None));
                         }
 
-                        if !result {
-                            continue;
-                        }
-
-                        record.write_to(&mut output)?;
-
-                        count += 1;
-                        if self.limit > 0 && (count > self.limit) {
-                            break 'outer;
-                        }
+                        let mut data = Vec::new();
+                        self.common.write_record(record, &mut data)?;
+                        Ok(Some(Some(data)))
                     }
-                }
-            }
-        }
+                },
+                |item| {
+                    progress.update(item.is_none());
+                    let Some(Some(data)) = item else {
+                        return Ok(ControlFlow::Continue(()));
+                    };
+
+                    output.write_all(&data)?;
+
+                    count += 1;
+                    if self.limit > 0 && (count > self.limit) {
+                        return Ok(ControlFlow::Break(()));
+                    }
+
+                    Ok(ControlFlow::Continue(()))
+                },
+            )?;
 
         progress.finish();
         output.finish()?;
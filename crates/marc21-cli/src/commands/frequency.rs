@@ -1,5 +1,6 @@
 use std::cmp::Ordering;
 use std::collections::{BTreeSet, HashMap};
+use std::ops::ControlFlow;
 use std::path::PathBuf;
 
 use bstr::ByteSlice;
@@ -81,13 +82,12 @@ impl Frequency {
         let options = MatchOptions::from(&self.filter_opts);
         let filter = self.filter_opts.filter()?;
         let mut count = 0;
-        let mut line = 0;
 
         let mut ftable: HashMap<Vec<Vec<u8>>, u64> = HashMap::new();
-        let mut seen: BTreeSet<Vec<Vec<u8>>> = BTreeSet::new();
 
         let output = WriterBuilder::default()
             .with_compression(self.common.compression)
+            .bgzf(self.common.bgzf)
             .try_from_path_or_stdout(self.output.clone())?;
 
         let filename = if let Some(ref path) = self.output {
@@ -96,10 +96,7 @@ impl Frequency {
             ""
         };
 
-        let delimiter = if self.tsv
-            || filename.ends_with(".tsv")
-            || filename.ends_with(".tsv.gz")
-        {
+        let delimiter = if self.tsv || has_extension(filename, "tsv") {
             b'\t'
         } else {
             b','
@@ -113,55 +110,57 @@ impl Frequency {
             wtr.write_record(header.split(',').map(str::trim))?;
         }
 
-        'outer: for path in self.path.iter() {
-            let mut reader = MarcReadOptions::default()
-                .try_into_reader_from_path(path)?;
+        Pipeline::new(&self.common)
+            .skip_invalid(self.filter_opts.skip_invalid)
+            .prefilter(
+                filter.as_ref().and_then(|m| m.prefilter(&options)),
+            )
+            .run(
+                &self.path,
+                &mut progress,
+                |record| {
+                    if let Some(ref m) = filter
+                        && !m.is_match(record, &options)
+                    {
+                        return Ok(None);
+                    }
 
-            while let Some(result) = reader.next_byte_record() {
-                line += 1;
+                    let mut seen = BTreeSet::new();
+                    let mut keys = vec![];
 
-                match result {
-                    Err(ReadMarcError::Parse(_))
-                        if self.filter_opts.skip_invalid =>
+                    for row in
+                        record.query(&self.query, &options).iter()
                     {
-                        progress.update(true);
-                        continue;
-                    }
-                    Err(e) => {
-                        return Err(CliError::from_parse(e, line));
-                    }
-                    Ok(ref record) => {
-                        progress.update(false);
+                        let key: Vec<Vec<u8>> =
+                            row.iter().map(Value::to_vec).collect();
 
-                        if let Some(ref m) = filter
-                            && !m.is_match(record, &options)
-                        {
+                        if self.unique && seen.contains(&key) {
                             continue;
                         }
 
-                        let rows = record.query(&self.query, &options);
-                        seen.clear();
-
-                        for row in rows.iter() {
-                            let key: Vec<Vec<u8>> =
-                                row.iter().map(Value::to_vec).collect();
+                        seen.insert(key.clone());
+                        keys.push(key);
+                    }
 
-                            if self.unique && seen.contains(&key) {
-                                continue;
-                            }
+                    Ok(Some(keys))
+                },
+                |keys| {
+                    let Some(keys) = keys else {
+                        return Ok(ControlFlow::Continue(()));
+                    };
 
-                            seen.insert(key.clone());
-                            *ftable.entry(key).or_insert(0) += 1;
-                        }
+                    for key in keys {
+                        *ftable.entry(key).or_insert(0) += 1;
+                    }
 
-                        count += 1;
-                        if self.filter_opts.limit == count {
-                            break 'outer;
-                        }
+                    count += 1;
+                    if self.filter_opts.limit == count {
+                        return Ok(ControlFlow::Break(()));
                     }
-                }
-            }
-        }
+
+                    Ok(ControlFlow::Continue(()))
+                },
+            )?;
 
         let mut ftable_sorted: Vec<(&Vec<Vec<u8>>, &u64)> =
             ftable.iter().collect();
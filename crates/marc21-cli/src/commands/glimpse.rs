@@ -1,5 +1,6 @@
 use std::collections::BTreeMap;
 use std::io::Write;
+use std::ops::ControlFlow;
 use std::path::PathBuf;
 
 use bstr::ByteSlice;
@@ -44,87 +45,79 @@ impl Glimpse {
         let filter = self.filter_opts.filter()?;
         let max_values = self.max_values.unwrap_or_default() as usize;
         let mut count = 0;
-        let mut line = 0;
 
         let mut output = WriterBuilder::default()
             .with_compression(self.common.compression)
+            .bgzf(self.common.bgzf)
             .try_from_path_or_stdout(self.output)?;
 
         let mut summary: BTreeMap<u8, Vec<String>> = BTreeMap::new();
         let codes = self.path.codes();
 
-        'outer: for path in self.input.iter() {
-            let mut reader = MarcReadOptions::default()
-                .try_into_reader_from_path(path)?;
+        Pipeline::new(&self.common)
+            .skip_invalid(self.filter_opts.skip_invalid)
+            .prefilter(
+                filter.as_ref().and_then(|m| m.prefilter(&options)),
+            )
+            .run(
+                &self.input,
+                &mut progress,
+                |record| {
+                    if let Some(ref m) = filter
+                        && !m.is_match(record, &options)
+                    {
+                        return Ok(None);
+                    }
 
-            while let Some(result) = reader.next_byte_record() {
-                line += 1;
+                    let values = record
+                        .fields()
+                        .filter(|field| self.path.is_match(field))
+                        .filter_map(|field| match field {
+                            Field::Data(df) => Some(df),
+                            Field::Control(_) => None,
+                        })
+                        .flat_map(|df| df.subfields())
+                        .filter(|subfield| {
+                            codes.is_empty()
+                                || codes.contains(subfield.code())
+                        })
+                        .map(|subfield| {
+                            (
+                                *subfield.code(),
+                                subfield
+                                    .value()
+                                    .to_str_lossy()
+                                    .to_string(),
+                            )
+                        })
+                        .collect::<Vec<_>>();
+
+                    Ok(Some(values))
+                },
+                |values| {
+                    let Some(values) = values else {
+                        return Ok(ControlFlow::Continue(()));
+                    };
+
+                    for (code, value) in values {
+                        summary.entry(code).or_default().push(value);
+                    }
 
-                match result {
-                    Err(ReadMarcError::Parse(_))
-                        if self.filter_opts.skip_invalid =>
+                    if summary
+                        .values()
+                        .all(|values| values.len() > max_values)
                     {
-                        progress.update(true);
-                        continue;
-                    }
-                    Err(e) => {
-                        return Err(CliError::from_parse(e, line));
+                        return Ok(ControlFlow::Break(()));
                     }
-                    Ok(ref record) => {
-                        progress.update(false);
-
-                        if let Some(ref m) = filter
-                            && !m.is_match(record, &options)
-                        {
-                            continue;
-                        }
-
-                        record
-                            .fields()
-                            .filter(|field| self.path.is_match(field))
-                            .filter_map(|field| match field {
-                                Field::Data(df) => Some(df),
-                                Field::Control(_) => None,
-                            })
-                            .for_each(|df| {
-                                for subfield in df.subfields() {
-                                    if !codes.is_empty()
-                                        && !codes
-                                            .contains(subfield.code())
-                                    {
-                                        continue;
-                                    }
-
-                                    let value =
-                                        subfield.value().to_str_lossy();
-
-                                    summary
-                                        .entry(*subfield.code())
-                                        .and_modify(|values| {
-                                            values
-                                                .push(value.to_string())
-                                        })
-                                        .or_insert(vec![
-                                            value.to_string(),
-                                        ]);
-                                }
-                            });
-
-                        if summary
-                            .values()
-                            .all(|values| values.len() > max_values)
-                        {
-                            break;
-                        }
-
-                        count += 1;
-                        if self.filter_opts.limit == count {
-                            break 'outer;
-                        }
+
+                    count += 1;
+                    if self.filter_opts.limit == count {
+                        return Ok(ControlFlow::Break(()));
                     }
-                }
-            }
-        }
+
+                    Ok(ControlFlow::Continue(()))
+                },
+            )?;
 
         let mut keys: Vec<u8> = summary.keys().cloned().collect();
         keys.sort_unstable();
@@ -1,3 +1,5 @@
+use std::io::Write;
+use std::ops::ControlFlow;
 use std::path::PathBuf;
 
 use marc21::Field;
@@ -57,7 +59,6 @@ impl Grep {
         let options = MatchOptions::from(&self.filter_opts);
         let filter = self.filter_opts.filter()?;
         let mut count = 0;
-        let mut line = 0;
 
         let mut patterns = Vec::with_capacity(self.patterns.len() + 1);
         patterns.push(self.pattern.clone());
@@ -69,62 +70,61 @@ impl Grep {
 
         let mut output = WriterBuilder::default()
             .with_compression(self.common.compression)
+            .bgzf(self.common.bgzf)
             .try_from_path_or_stdout(self.output)?;
 
-        'outer: for path in self.input.iter() {
-            let mut reader = MarcReadOptions::default()
-                .try_into_reader_from_path(path)?;
-
-            while let Some(result) = reader.next_byte_record() {
-                line += 1;
-
-                match result {
-                    Err(ReadMarcError::Parse(_))
-                        if self.filter_opts.skip_invalid =>
+        Pipeline::new(&self.common)
+            .skip_invalid(self.filter_opts.skip_invalid)
+            .prefilter(
+                filter.as_ref().and_then(|m| m.prefilter(&options)),
+            )
+            .run(
+                &self.input,
+                &mut progress,
+                |record| {
+                    if let Some(ref m) = filter
+                        && !m.is_match(record, &options)
                     {
-                        progress.update(true);
-                        continue;
+                        return Ok(None);
                     }
-                    Err(e) => {
-                        return Err(CliError::from_parse(e, line));
+
+                    let result =
+                        record.fields().any(|field| match field {
+                            Field::Data(df) => {
+                                df.subfields().any(|subfield| {
+                                    re.is_match(subfield.value())
+                                })
+                            }
+                            Field::Control(cf) => {
+                                re.is_match(cf.value())
+                            }
+                        });
+
+                    if result == self.invert_match {
+                        return Ok(Some(None));
                     }
-                    Ok(ref record) => {
-                        progress.update(false);
-
-                        if let Some(ref m) = filter
-                            && !m.is_match(record, &options)
-                        {
-                            continue;
-                        }
-
-                        let mut result =
-                            record.fields().any(|field| match field {
-                                Field::Data(df) => {
-                                    df.subfields().any(|subfield| {
-                                        re.is_match(subfield.value())
-                                    })
-                                }
-                                Field::Control(cf) => {
-                                    re.is_match(cf.value())
-                                }
-                            });
-
-                        if self.invert_match {
-                            result = !result;
-                        }
-
-                        if result {
-                            record.write_to(&mut output)?;
-                        }
-
-                        count += 1;
-                        if self.filter_opts.limit == count {
-                            break 'outer;
-                        }
+
+                    let mut data = Vec::new();
+                    self.common.write_record(record, &mut data)?;
+                    Ok(Some(Some(data)))
+                },
+                |data| {
+                    let Some(data) = data else {
+                        return Ok(ControlFlow::Continue(()));
+                    };
+
+                    if let Some(data) = data {
+                        output.write_all(&data)?;
                     }
-                }
-            }
-        }
+
+                    count += 1;
+                    if self.filter_opts.limit == count {
+                        return Ok(ControlFlow::Break(()));
+                    }
+
+                    Ok(ControlFlow::Continue(()))
+                },
+            )?;
 
         progress.finish();
         output.finish()?;
@@ -1,7 +1,9 @@
 use std::fmt::Write as _;
 use std::io::Cursor;
+use std::ops::ControlFlow;
 use std::path::PathBuf;
 
+use marc21::OversizePolicy;
 use sha2::{Digest, Sha256};
 
 use crate::prelude::*;
@@ -33,7 +35,6 @@ impl Hash {
         let options = MatchOptions::from(&self.filter_opts);
         let filter = self.filter_opts.filter()?;
         let mut count = 0;
-        let mut line = 0;
 
         let separator = if self.tsv {
             b'\t'
@@ -41,9 +42,7 @@ impl Hash {
             let filename = path.file_name().unwrap_or_default();
             let filename_str = filename.to_str().unwrap_or_default();
 
-            if filename_str.ends_with(".tsv")
-                || filename_str.ends_with(".tsv.gz")
-            {
+            if has_extension(filename_str, "tsv") {
                 b'\t'
             } else {
                 b','
@@ -54,6 +53,7 @@ impl Hash {
 
         let output = WriterBuilder::default()
             .with_compression(self.common.compression)
+            .bgzf(self.common.bgzf)
             .try_from_path_or_stdout(self.output)?;
 
         let mut wtr = csv::WriterBuilder::new()
@@ -62,69 +62,66 @@ impl Hash {
 
         wtr.write_record(["cn", "hash"])?;
 
-        'outer: for path in self.path.iter() {
-            let mut reader = MarcReadOptions::default()
-                .try_into_reader_from_path(path)?;
-
-            while let Some(result) = reader.next_byte_record() {
-                line += 1;
-
-                match result {
-                    Err(ReadMarcError::Parse(_))
-                        if self.filter_opts.skip_invalid =>
+        Pipeline::new(&self.common)
+            .skip_invalid(self.filter_opts.skip_invalid)
+            .prefilter(
+                filter.as_ref().and_then(|m| m.prefilter(&options)),
+            )
+            .run(
+                &self.path,
+                &mut progress,
+                |record| {
+                    if let Some(ref m) = filter
+                        && !m.is_match(record, &options)
                     {
-                        progress.update(true);
-                        continue;
+                        return Ok(None);
                     }
-                    Err(e) => {
-                        return Err(CliError::from_parse(e, line));
+
+                    let mut hasher = Sha256::new();
+
+                    if let Some(data) = record.raw_data() {
+                        hasher.update(data);
+                    } else {
+                        let mut output = Cursor::new(Vec::<u8>::new());
+                        record.write_with(
+                            &mut output,
+                            OversizePolicy::Oversize,
+                        )?;
+                        let data = output.into_inner();
+                        hasher.update(data);
                     }
-                    Ok(ref record) => {
-                        progress.update(false);
-
-                        if let Some(ref m) = filter
-                            && !m.is_match(record, &options)
-                        {
-                            continue;
-                        }
-
-                        let mut hasher = Sha256::new();
-
-                        if let Some(data) = record.raw_data() {
-                            hasher.update(data);
-                        } else {
-                            let mut output =
-                                Cursor::new(Vec::<u8>::new());
-                            record.write_to(&mut output)?;
-                            let data = output.into_inner();
-                            hasher.update(data);
-                        }
-
-                        let hash = hasher
-                            .finalize()
-                            .to_vec()
-                            .iter()
-                            .fold(String::new(), |mut out, b| {
-                                let _ = write!(out, "{b:02x}");
-                                out
-                            });
-
-                        let cn = record
-                            .control_number()
-                            .unwrap_or_default()
-                            .to_str_lossy()
-                            .to_string();
-
-                        wtr.write_record([cn, hash])?;
-
-                        count += 1;
-                        if self.filter_opts.limit == count {
-                            break 'outer;
-                        }
+
+                    let hash = hasher.finalize().to_vec().iter().fold(
+                        String::new(),
+                        |mut out, b| {
+                            let _ = write!(out, "{b:02x}");
+                            out
+                        },
+                    );
+
+                    let cn = record
+                        .control_number()
+                        .unwrap_or_default()
+                        .to_str_lossy()
+                        .to_string();
+
+                    Ok(Some([cn, hash]))
+                },
+                |row| {
+                    let Some(row) = row else {
+                        return Ok(ControlFlow::Continue(()));
+                    };
+
+                    wtr.write_record(row)?;
+
+                    count += 1;
+                    if self.filter_opts.limit == count {
+                        return Ok(ControlFlow::Break(()));
                     }
-                }
-            }
-        }
+
+                    Ok(ControlFlow::Continue(()))
+                },
+            )?;
 
         progress.finish();
         wtr.flush()?;
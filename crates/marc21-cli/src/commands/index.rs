@@ -0,0 +1,119 @@
+use std::path::PathBuf;
+
+use marc21::io::Index as RecordIndex;
+
+use crate::prelude::*;
+
+/// Build an index of record offsets
+///
+/// This command maps the values of a path expression (by default the
+/// control number) to the file and offset of the corresponding
+/// records. The index can be used by `marc21 lookup` to read records
+/// without scanning the input files. Records of uncompressed files and
+/// of block-gzip (BGZF) compressed files are read directly at their
+/// offset; other compressed files must be decompressed up to the
+/// offset of a record.
+///
+/// The paths of the input files are stored as absolute paths, so the
+/// index can be used from any working directory.
+#[derive(Debug, clap::Parser)]
+pub(crate) struct Index {
+    /// A path expression, whose values are the keys of the index.
+    #[arg(short, long, value_name = "path", default_value = "001")]
+    key: Path,
+
+    #[arg(required = true)]
+    path: Vec<PathBuf>,
+
+    /// Write the index to <filename> instead of stdout.
+    #[arg(short, long, value_name = "filename")]
+    output: Option<PathBuf>,
+
+    #[command(flatten, next_help_heading = "Filter options")]
+    pub(crate) filter_opts: FilterOpts,
+
+    #[command(flatten, next_help_heading = "Common options")]
+    pub(crate) common: CommonOpts,
+}
+
+impl Index {
+    pub(crate) fn execute(self) -> CliResult {
+        let mut progress = Progress::new(self.common.progress);
+        let read_options = MarcReadOptions::from(&self.common);
+        let options = MatchOptions::from(&self.filter_opts);
+        let filter = self.filter_opts.filter()?;
+        let mut index = RecordIndex::default();
+        let mut count = 0;
+        let mut line = 0;
+
+        'outer: for path in self.path.iter() {
+            if path.to_str() == Some("-") {
+                return Err(CliError::AdHoc(
+                    "Indexing stdin is not supported.".into(),
+                ));
+            }
+
+            let file = index.add_file(path)?;
+            let mut reader = index.reader(file, &read_options)?;
+
+            while let Some(result) = reader.next_byte_record() {
+                line += 1;
+
+                let record = match result {
+                    Err(ReadMarcError::Parse(_))
+                        if self.filter_opts.skip_invalid =>
+                    {
+                        progress.update(true);
+                        continue;
+                    }
+                    Err(e) => {
+                        return Err(CliError::from_parse(e, line));
+                    }
+                    Ok(record) => record,
+                };
+
+                progress.update(false);
+
+                if let Some(ref m) = filter
+                    && !m.is_match(&record, &options)
+                {
+                    continue;
+                }
+
+                let keys: Vec<_> = record
+                    .path(&self.key, &options)
+                    .iter()
+                    .map(|value| value.to_vec())
+                    .collect();
+
+                let Some(offset) = reader.offset() else {
+                    return Err(CliError::AdHoc(format!(
+                        "could not determine offset of record (line \
+                         {line})"
+                    )));
+                };
+
+                for key in keys {
+                    index.insert(key, file, offset);
+                }
+
+                count += 1;
+                if self.filter_opts.limit == count {
+                    break 'outer;
+                }
+            }
+        }
+
+        let mut output = WriterBuilder::default()
+            .with_compression(self.common.compression)
+            .bgzf(self.common.bgzf)
+            .try_from_path_or_stdout(self.output)?;
+
+        index.write_to(&mut output)?;
+
+        progress.finish();
+        output.finish()?;
+
+        Ok(())
+    }
+}
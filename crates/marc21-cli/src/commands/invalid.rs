@@ -1,6 +1,9 @@
 use std::io::Write;
+use std::ops::ControlFlow;
 use std::path::PathBuf;
+use std::slice;
 
+use marc21::ParseRecordError;
 use marc21::io::ReadMarcError;
 
 use crate::prelude::*;
@@ -15,6 +18,11 @@ pub(crate) struct Invalid {
     #[arg(short, long, value_name = "FILENAME")]
     output: Option<PathBuf>,
 
+    /// If set, the reason why a record is invalid is printed to
+    /// stderr.
+    #[arg(short, long)]
+    verbose: bool,
+
     #[command(flatten, next_help_heading = "Common options")]
     pub(crate) common: CommonOpts,
 }
@@ -24,26 +32,40 @@ impl Invalid {
         let mut progress = Progress::new(self.common.progress);
         let mut output = WriterBuilder::default()
             .with_compression(self.common.compression)
+            .bgzf(self.common.bgzf)
             .try_from_path_or_stdout(self.output)?;
 
-        for path in self.path.iter() {
-            let mut reader = MarcReadOptions::default()
-                .try_into_reader_from_path(path)?;
+        let pipeline = Pipeline::new(&self.common);
 
-            while let Some(result) = reader.next_byte_record() {
-                match result {
-                    Err(ReadMarcError::IO(e)) => {
-                        return Err(e.into());
-                    }
+        // Each path is processed separately, so that the reasons can
+        // refer to the path of the invalid record.
+        for path in self.path.iter() {
+            pipeline.run_raw(
+                slice::from_ref(path),
+                |result, _| match result {
+                    Err(ReadMarcError::IO(e)) => Err(e.into()),
                     Err(ReadMarcError::Parse(e)) => {
-                        let _ = output.write(e.data())?;
-                        progress.update(true);
+                        let reason = self.verbose.then(|| reason(&e));
+                        Ok(Some((e.data().to_vec(), reason)))
                     }
-                    Ok(_) => {
+                    Ok(_) => Ok(None),
+                },
+                |item| {
+                    let Some((data, reason)) = item else {
                         progress.update(false);
+                        return Ok(ControlFlow::Continue(()));
+                    };
+
+                    if let Some(reason) = reason {
+                        eprintln!("{}: {reason}", path.display());
                     }
-                }
-            }
+
+                    output.write_all(&data)?;
+                    progress.update(true);
+
+                    Ok(ControlFlow::Continue(()))
+                },
+            )?;
         }
 
         progress.finish();
@@ -52,3 +74,12 @@ impl Invalid {
         Ok(())
     }
 }
+
+/// Describes the location and the kind of a parse error.
+fn reason(e: &ParseRecordError) -> String {
+    let record = e.record().unwrap_or_default();
+    match e.offset() {
+        Some(offset) => format!("record {record} (byte {offset}): {e}"),
+        None => format!("record {record}: {e}"),
+    }
+}
@@ -0,0 +1,82 @@
+use std::io::{self, Write};
+use std::path::PathBuf;
+
+use marc21::io::Index;
+
+use crate::prelude::*;
+
+/// Read records by key from an index
+///
+/// This command reads the records, which belong to the given keys,
+/// from the files of an index (see `marc21 index`). The records are
+/// written in the order of the keys; a key without a record is
+/// ignored. Unless the records are transcoded (`--marc8`) or repaired
+/// (`--lenient`), the raw data of a record is copied unchanged.
+#[derive(Debug, clap::Parser)]
+pub(crate) struct Lookup {
+    /// The index file created by `marc21 index`.
+    index: PathBuf,
+
+    /// The keys (e.g. control numbers) of the records.
+    #[arg(required = true)]
+    keys: Vec<String>,
+
+    /// Write output to <filename> instead of stdout.
+    #[arg(short, long, value_name = "filename")]
+    output: Option<PathBuf>,
+
+    #[command(flatten, next_help_heading = "Common options")]
+    pub(crate) common: CommonOpts,
+}
+
+impl Lookup {
+    pub(crate) fn execute(self) -> CliResult {
+        let mut progress = Progress::new(self.common.progress);
+        let options = MarcReadOptions::from(&self.common);
+        let index = Index::from_path(&self.index)?;
+
+        let mut output = WriterBuilder::default()
+            .with_compression(self.common.compression)
+            .bgzf(self.common.bgzf)
+            .try_from_path_or_stdout(self.output)?;
+
+        let locations: Vec<_> = self
+            .keys
+            .iter()
+            .flat_map(|key| index.get(key))
+            .copied()
+            .collect();
+
+        for (i, data) in
+            index.read_raw(&locations)?.into_iter().enumerate()
+        {
+            let mut reader =
+                MarcReader::new(&data[..], options.clone());
+            let Some(result) = reader.next_byte_record() else {
+                return Err(CliError::from_parse(
+                    ReadMarcError::IO(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        "index entry does not point to a record",
+                    )),
+                    i + 1,
+                ));
+            };
+
+            let record =
+                result.map_err(|e| CliError::from_parse(e, i + 1))?;
+
+            if self.common.marc8 || self.common.lenient {
+                self.common.write_record(&record, &mut output)?;
+            } else {
+                output.write_all(&data)?;
+            }
+
+            progress.update(false);
+        }
+
+        progress.finish();
+        output.finish()?;
+
+        Ok(())
+    }
+}
@@ -4,6 +4,7 @@ pub(crate) use build_completion::BuildCompletion;
 pub(crate) use build_man::BuildMan;
 pub(crate) use check::Check;
 pub(crate) use concat::Concat;
+pub(crate) use convert::Convert;
 pub(crate) use count::Count;
 pub(crate) use dedup::Dedup;
 pub(crate) use describe::Describe;
@@ -12,7 +13,9 @@ pub(crate) use frequency::Frequency;
 pub(crate) use glimpse::Glimpse;
 pub(crate) use grep::Grep;
 pub(crate) use hash::Hash;
+pub(crate) use index::Index;
 pub(crate) use invalid::Invalid;
+pub(crate) use lookup::Lookup;
 pub(crate) use partition::Partition;
 pub(crate) use print::Print;
 pub(crate) use sample::Sample;
@@ -26,6 +29,7 @@ mod build_completion;
 mod build_man;
 mod check;
 mod concat;
+mod convert;
 mod count;
 mod dedup;
 mod describe;
@@ -34,7 +38,9 @@ mod frequency;
 mod glimpse;
 mod grep;
 mod hash;
+mod index;
 mod invalid;
+mod lookup;
 mod partition;
 mod print;
 mod sample;
@@ -1,6 +1,8 @@
 use std::collections::BTreeMap;
 use std::collections::btree_map::Entry;
 use std::fs;
+use std::io::Write;
+use std::ops::ControlFlow;
 use std::path::PathBuf;
 
 use crate::prelude::*;
@@ -22,8 +24,9 @@ use crate::utils::Writer;
 pub(crate) struct Partition {
     /// A template for naming the individual partitions. The
     /// placeholder `{}` is replaced by the value of the path
-    /// expression. If the template ends with the suffix `.gz`, the
-    /// partitions are compressed in Gzip format.
+    /// expression. If the template ends with the suffix `.gz`, `.zst`,
+    /// `.bz2` or `.xz`, the partitions are compressed in the
+    /// corresponding format.
     #[arg(long, short, value_name = "template")]
     template: Option<String>,
 
@@ -57,7 +60,6 @@ impl Partition {
         let options = MatchOptions::from(&self.filter_opts);
         let filter = self.filter_opts.filter()?;
         let mut count = 0;
-        let mut line = 0;
 
         if self.path.width() == 0 {
             // If a path's width is zero, it is impossible to produce a
@@ -74,77 +76,77 @@ impl Partition {
         let template = self.template.unwrap_or("{}.mrc".into());
         let mut writers: BTreeMap<String, Writer> = BTreeMap::new();
 
-        'outer: for filename in self.filenames.iter() {
-            let mut reader = MarcReadOptions::default()
-                .try_into_reader_from_path(filename)?;
+        Pipeline::new(&self.common)
+            .skip_invalid(self.filter_opts.skip_invalid)
+            .prefilter(
+                filter.as_ref().and_then(|m| m.prefilter(&options)),
+            )
+            .run(
+                &self.filenames,
+                &mut progress,
+                |record| {
+                    if let Some(ref m) = filter
+                        && !m.is_match(record, &options)
+                    {
+                        return Ok(None);
+                    }
 
-            while let Some(result) = reader.next_byte_record() {
-                line += 1;
+                    let mut values: Vec<_> = record
+                        .path(&self.path, &options)
+                        .into_iter()
+                        .map(|value| value.to_str_lossy().to_string())
+                        .collect();
 
-                match result {
-                    Err(ReadMarcError::Parse(_))
-                        if self.filter_opts.skip_invalid =>
-                    {
-                        progress.update(true);
-                        continue;
+                    values.sort_unstable();
+                    values.dedup();
+
+                    let mut data = Vec::new();
+                    if !values.is_empty() {
+                        self.common.write_record(record, &mut data)?;
                     }
-                    Err(e) => {
-                        return Err(CliError::from_parse(e, line));
+
+                    Ok(Some((values, data)))
+                },
+                |item| {
+                    let Some((values, data)) = item else {
+                        return Ok(ControlFlow::Continue(()));
+                    };
+
+                    for name in values {
+                        let mut entry = writers.entry(name);
+
+                        let writer = match entry {
+                            Entry::Vacant(entry) => {
+                                let filename =
+                                    template.replace("{}", entry.key());
+                                let path = self.output.join(filename);
+                                let wtr = WriterBuilder::default()
+                                    .with_compression(
+                                        self.common.compression,
+                                    )
+                                    .bgzf(self.common.bgzf)
+                                    .try_from_path_or_stdout(Some(
+                                        path,
+                                    ))?;
+
+                                entry.insert(wtr)
+                            }
+                            Entry::Occupied(ref mut entry) => {
+                                entry.get_mut()
+                            }
+                        };
+
+                        writer.write_all(&data)?;
                     }
-                    Ok(ref record) => {
-                        progress.update(false);
-
-                        if let Some(ref m) = filter
-                            && !m.is_match(record, &options)
-                        {
-                            continue;
-                        }
-
-                        let mut values: Vec<_> = record
-                            .path(&self.path, &options)
-                            .into_iter()
-                            .collect();
-
-                        values.sort_unstable();
-                        values.dedup();
-
-                        for value in values {
-                            let name = value.to_str_lossy();
-                            let mut entry =
-                                writers.entry(name.to_string());
-
-                            let mut writer = match entry {
-                                Entry::Vacant(entry) => {
-                                    let filename =
-                                        template.replace("{}", &name);
-                                    let path =
-                                        self.output.join(filename);
-                                    let wtr = WriterBuilder::default()
-                                        .with_compression(
-                                            self.common.compression,
-                                        )
-                                        .try_from_path_or_stdout(
-                                            Some(path),
-                                        )?;
-
-                                    entry.insert(wtr)
-                                }
-                                Entry::Occupied(ref mut entry) => {
-                                    entry.get_mut()
-                                }
-                            };
-
-                            record.write_to(&mut writer)?;
-                        }
-
-                        count += 1;
-                        if self.filter_opts.limit == count {
-                            break 'outer;
-                        }
+
+                    count += 1;
+                    if self.filter_opts.limit == count {
+                        return Ok(ControlFlow::Break(()));
                     }
-                }
-            }
-        }
+
+                    Ok(ControlFlow::Continue(()))
+                },
+            )?;
 
         progress.finish();
         for (_, writer) in writers {
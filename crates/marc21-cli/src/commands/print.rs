@@ -1,4 +1,5 @@
 use std::io::Write;
+use std::ops::ControlFlow;
 use std::path::PathBuf;
 
 use unicode_normalization::UnicodeNormalization;
@@ -35,57 +36,51 @@ impl Print {
         let options = MatchOptions::from(&self.filter_opts);
         let filter = self.filter_opts.filter()?;
         let mut count = 0;
-        let mut line = 0;
 
         let mut output = WriterBuilder::default()
             .with_compression(self.common.compression)
+            .bgzf(self.common.bgzf)
             .try_from_path_or_stdout(self.output)?;
 
-        'outer: for path in self.path.iter() {
-            let mut reader = MarcReadOptions::default()
-                .try_into_reader_from_path(path)?;
-
-            while let Some(result) = reader.next_byte_record() {
-                line += 1;
-
-                match result {
-                    Err(ReadMarcError::Parse(_))
-                        if self.filter_opts.skip_invalid =>
+        Pipeline::new(&self.common)
+            .skip_invalid(self.filter_opts.skip_invalid)
+            .prefilter(
+                filter.as_ref().and_then(|m| m.prefilter(&options)),
+            )
+            .run(
+                &self.path,
+                &mut progress,
+                |record| {
+                    if let Some(ref m) = filter
+                        && !m.is_match(record, &options)
                     {
-                        progress.update(true);
-                        continue;
+                        return Ok(None);
                     }
-                    Err(e) => {
-                        return Err(CliError::from_parse(e, line));
-                    }
-                    Ok(ref record) => {
-                        progress.update(false);
-
-                        if let Some(ref m) = filter
-                            && !m.is_match(record, &options)
-                        {
-                            continue;
-                        }
 
-                        let record_str = record.to_string();
-                        let out = match self.translit {
-                            Some(Nfc) => record_str.nfc().collect(),
-                            Some(Nfkc) => record_str.nfkc().collect(),
-                            Some(Nfd) => record_str.nfd().collect(),
-                            Some(Nfkd) => record_str.nfkd().collect(),
-                            _ => record_str,
-                        };
-
-                        writeln!(output, "{out}")?;
-
-                        count += 1;
-                        if self.filter_opts.limit == count {
-                            break 'outer;
-                        }
+                    let record_str = record.to_string();
+                    Ok(Some(match self.translit {
+                        Some(Nfc) => record_str.nfc().collect(),
+                        Some(Nfkc) => record_str.nfkc().collect(),
+                        Some(Nfd) => record_str.nfd().collect(),
+                        Some(Nfkd) => record_str.nfkd().collect(),
+                        _ => record_str,
+                    }))
+                },
+                |out| {
+                    let Some(out) = out else {
+                        return Ok(ControlFlow::Continue(()));
+                    };
+
+                    writeln!(output, "{out}")?;
+
+                    count += 1;
+                    if self.filter_opts.limit == count {
+                        return Ok(ControlFlow::Break(()));
                     }
-                }
-            }
-        }
+
+                    Ok(ControlFlow::Continue(()))
+                },
+            )?;
 
         output.finish()?;
         Ok(())
@@ -1,4 +1,5 @@
 use std::io::Write;
+use std::ops::ControlFlow;
 use std::path::PathBuf;
 
 use clap::value_parser;
@@ -40,10 +41,10 @@ impl Sample {
         let options = MatchOptions::from(&self.filter_opts);
         let filter = self.filter_opts.filter()?;
         let mut count = 0;
-        let mut line = 0;
 
         let mut output = WriterBuilder::default()
             .with_compression(self.common.compression)
+            .bgzf(self.common.bgzf)
             .try_from_path_or_stdout(self.output)?;
 
         let mut rng: StdRng = match self.seed {
@@ -54,54 +55,47 @@ impl Sample {
         let mut reservoir: Vec<Vec<u8>> =
             Vec::with_capacity(sample_size);
 
-        'outer: for path in self.path.iter() {
-            let mut reader = MarcReadOptions::default()
-                .try_into_reader_from_path(path)?;
-
-            while let Some(result) = reader.next_byte_record() {
-                line += 1;
-
-                match result {
-                    Err(ReadMarcError::Parse(_))
-                        if self.filter_opts.skip_invalid =>
+        Pipeline::new(&self.common)
+            .skip_invalid(self.filter_opts.skip_invalid)
+            .prefilter(
+                filter.as_ref().and_then(|m| m.prefilter(&options)),
+            )
+            .run(
+                &self.path,
+                &mut progress,
+                |record| {
+                    if let Some(ref m) = filter
+                        && !m.is_match(record, &options)
                     {
-                        progress.update(true);
-                        continue;
-                    }
-                    Err(e) => {
-                        return Err(CliError::from_parse(e, line));
+                        return Ok(None);
                     }
-                    Ok(ref record) => {
-                        progress.update(false);
-
-                        if let Some(ref m) = filter
-                            && !m.is_match(record, &options)
-                        {
-                            continue;
-                        }
 
-                        if count < sample_size {
-                            let mut data = Vec::<u8>::new();
-                            record.write_to(&mut data)?;
-                            reservoir.push(data);
-                        } else {
-                            let j = rng.random_range(0..count);
-                            if j < sample_size {
-                                let mut data = Vec::<u8>::new();
-                                record.write_to(&mut data)?;
-
-                                reservoir[j] = data;
-                            }
+                    let mut data = Vec::<u8>::new();
+                    self.common.write_record(record, &mut data)?;
+                    Ok(Some(data))
+                },
+                |data| {
+                    let Some(data) = data else {
+                        return Ok(ControlFlow::Continue(()));
+                    };
+
+                    if count < sample_size {
+                        reservoir.push(data);
+                    } else {
+                        let j = rng.random_range(0..count);
+                        if j < sample_size {
+                            reservoir[j] = data;
                         }
+                    }
 
-                        count += 1;
-                        if self.filter_opts.limit == count {
-                            break 'outer;
-                        }
+                    count += 1;
+                    if self.filter_opts.limit == count {
+                        return Ok(ControlFlow::Break(()));
                     }
-                }
-            }
-        }
+
+                    Ok(ControlFlow::Continue(()))
+                },
+            )?;
 
         for data in reservoir.iter() {
             output.write_all(data)?;
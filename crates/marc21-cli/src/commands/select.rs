@@ -1,5 +1,8 @@
+use std::ops::ControlFlow;
 use std::path::PathBuf;
 
+use marc21::Value;
+
 use crate::prelude::*;
 
 /// Transforms records into CSV or TSV format
@@ -41,7 +44,6 @@ impl Select {
         let options = MatchOptions::from(&self.filter_opts);
         let filter = self.filter_opts.filter()?;
         let mut count = 0;
-        let mut line = 0;
 
         let filename = if let Some(ref path) = self.output {
             path.to_str().unwrap_or_default()
@@ -51,12 +53,10 @@ impl Select {
 
         let output = WriterBuilder::default()
             .with_compression(self.common.compression)
+            .bgzf(self.common.bgzf)
             .try_from_path_or_stdout(self.output.clone())?;
 
-        let delimiter = if self.tsv
-            || filename.ends_with(".tsv")
-            || filename.ends_with(".tsv.gz")
-        {
+        let delimiter = if self.tsv || has_extension(filename, "tsv") {
             b'\t'
         } else {
             b','
@@ -70,44 +70,50 @@ impl Select {
             wtr.write_record(header.split(',').map(str::trim))?;
         }
 
-        'outer: for path in self.filenames.iter() {
-            let mut reader = MarcReadOptions::default()
-                .try_into_reader_from_path(path)?;
-
-            while let Some(result) = reader.next_byte_record() {
-                line += 1;
-
-                match result {
-                    Err(ReadMarcError::Parse(_))
-                        if self.filter_opts.skip_invalid =>
+        Pipeline::new(&self.common)
+            .skip_invalid(self.filter_opts.skip_invalid)
+            .prefilter(
+                filter.as_ref().and_then(|m| m.prefilter(&options)),
+            )
+            .run(
+                &self.filenames,
+                &mut progress,
+                |record| {
+                    if let Some(ref m) = filter
+                        && !m.is_match(record, &options)
                     {
-                        progress.update(true);
-                        continue;
+                        return Ok(None);
                     }
-                    Err(e) => {
-                        return Err(CliError::from_parse(e, line));
+
+                    let rows = record
+                        .query(&self.query, &options)
+                        .iter()
+                        .map(|row| {
+                            row.iter()
+                                .map(Value::to_vec)
+                                .collect::<Vec<_>>()
+                        })
+                        .collect::<Vec<_>>();
+
+                    Ok(Some(rows))
+                },
+                |rows| {
+                    let Some(rows) = rows else {
+                        return Ok(ControlFlow::Continue(()));
+                    };
+
+                    for row in rows {
+                        wtr.write_record(row)?;
                     }
-                    Ok(ref record) => {
-                        progress.update(false);
-
-                        if let Some(ref m) = filter
-                            && !m.is_match(record, &options)
-                        {
-                            continue;
-                        }
-
-                        for row in record.query(&self.query, &options) {
-                            wtr.write_record(row)?;
-                        }
-
-                        count += 1;
-                        if self.filter_opts.limit == count {
-                            break 'outer;
-                        }
+
+                    count += 1;
+                    if self.filter_opts.limit == count {
+                        return Ok(ControlFlow::Break(()));
                     }
-                }
-            }
-        }
+
+                    Ok(ControlFlow::Continue(()))
+                },
+            )?;
 
         let wtr = wtr.into_inner().map_err(|e| e.into_error())?;
         wtr.finish()?;
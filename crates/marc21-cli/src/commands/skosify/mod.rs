@@ -1,4 +1,5 @@
 use std::fmt::Debug;
+use std::ops::ControlFlow;
 use std::path::PathBuf;
 
 use crate::commands::skosify::graph::SkosGraph;
@@ -24,11 +25,9 @@ impl Format {
         let path = path?;
         let filename = path.as_ref().to_str().unwrap_or_default();
 
-        if filename.ends_with(".ttl") || filename.ends_with(".ttl.gz") {
+        if has_extension(filename, "ttl") {
             Some(Self::Turtle)
-        } else if filename.ends_with(".nt")
-            || filename.ends_with(".nt.gz")
-        {
+        } else if has_extension(filename, "nt") {
             Some(Self::Nt)
         } else {
             None
@@ -68,7 +67,6 @@ impl Skosify {
         let options = MatchOptions::from(&self.filter_opts);
         let filter = self.filter_opts.filter()?;
         let mut count = 0;
-        let mut line = 0;
 
         let format = self
             .format
@@ -77,46 +75,46 @@ impl Skosify {
 
         let mut output = WriterBuilder::default()
             .with_compression(self.common.compression)
+            .bgzf(self.common.bgzf)
             .try_from_path_or_stdout(self.output)?;
 
         let mut graph = SkosGraph::from_path(&self.config)?;
 
-        'outer: for path in self.input.iter() {
-            let mut reader = MarcReadOptions::default()
-                .try_into_reader_from_path(path)?;
-
-            while let Some(result) = reader.next_byte_record() {
-                line += 1;
-
-                match result {
-                    Err(ReadMarcError::Parse(_))
-                        if self.filter_opts.skip_invalid =>
+        Pipeline::new(&self.common)
+            .skip_invalid(self.filter_opts.skip_invalid)
+            .prefilter(
+                filter.as_ref().and_then(|m| m.prefilter(&options)),
+            )
+            .run(
+                &self.input,
+                &mut progress,
+                |record| {
+                    if let Some(ref m) = filter
+                        && !m.is_match(record, &options)
                     {
-                        progress.update(true);
-                        continue;
+                        return Ok(None);
                     }
-                    Err(e) => {
-                        return Err(CliError::from_parse(e, line));
-                    }
-                    Ok(record) => {
-                        progress.update(false);
-
-                        if let Some(ref m) = filter
-                            && !m.is_match(&record, &options)
-                        {
-                            continue;
-                        }
 
-                        graph.process_record(record, &options)?;
-
-                        count += 1;
-                        if self.filter_opts.limit == count {
-                            break 'outer;
-                        }
+                    Ok(Some(RecordBuf::from(record)))
+                },
+                |record| {
+                    let Some(record) = record else {
+                        return Ok(ControlFlow::Continue(()));
+                    };
+
+                    graph.process_record(
+                        record.as_byte_record(),
+                        &options,
+                    )?;
+
+                    count += 1;
+                    if self.filter_opts.limit == count {
+                        return Ok(ControlFlow::Break(()));
                     }
-                }
-            }
-        }
+
+                    Ok(ControlFlow::Continue(()))
+                },
+            )?;
 
         graph.serialize_graph(&mut output, &format)?;
         progress.finish();
@@ -1,6 +1,7 @@
-use std::fs;
 use std::io::Write;
+use std::ops::ControlFlow;
 use std::path::PathBuf;
+use std::{fs, mem};
 
 use clap::value_parser;
 
@@ -45,6 +46,7 @@ pub(crate) struct Split {
 fn create_writer(chunk: u32, opts: &Split) -> Result<Writer, CliError> {
     WriterBuilder::default()
         .with_compression(opts.common.compression)
+        .bgzf(opts.common.bgzf)
         .try_from_path_or_stdout(Some(
             opts.output
                 .join(opts.filename.replace("{}", &chunk.to_string())),
@@ -58,7 +60,6 @@ impl Split {
         let filter = self.filter_opts.filter()?;
         let mut chunk: u32 = 0;
         let mut count: u32 = 0;
-        let mut line = 0;
 
         if !self.output.exists() {
             fs::create_dir_all(&self.output)?;
@@ -66,50 +67,48 @@ impl Split {
 
         let mut output = create_writer(chunk, &self)?;
 
-        'outer: for path in self.paths.iter() {
-            let mut reader = MarcReadOptions::default()
-                .try_into_reader_from_path(path)?;
-
-            while let Some(result) = reader.next_byte_record() {
-                line += 1;
-
-                match result {
-                    Err(ReadMarcError::Parse(_))
-                        if self.filter_opts.skip_invalid =>
+        Pipeline::new(&self.common)
+            .skip_invalid(self.filter_opts.skip_invalid)
+            .prefilter(
+                filter.as_ref().and_then(|m| m.prefilter(&options)),
+            )
+            .run(
+                &self.paths,
+                &mut progress,
+                |record| {
+                    if let Some(ref m) = filter
+                        && !m.is_match(record, &options)
                     {
-                        progress.update(true);
-                        continue;
+                        return Ok(None);
                     }
-                    Err(e) => {
-                        return Err(CliError::from_parse(e, line));
+
+                    let mut data = Vec::new();
+                    self.common.write_record(record, &mut data)?;
+                    Ok(Some(data))
+                },
+                |data| {
+                    let Some(data) = data else {
+                        return Ok(ControlFlow::Continue(()));
+                    };
+
+                    if count.is_multiple_of(self.chunk_size)
+                        && count > 0
+                    {
+                        chunk += 1;
+                        let next = create_writer(chunk, &self)?;
+                        mem::replace(&mut output, next).finish()?;
                     }
-                    Ok(ref record) => {
-                        progress.update(false);
-
-                        if let Some(ref m) = filter
-                            && !m.is_match(record, &options)
-                        {
-                            continue;
-                        }
-
-                        if count.is_multiple_of(self.chunk_size)
-                            && count > 0
-                        {
-                            output.finish()?;
-                            chunk += 1;
-                            output = create_writer(chunk, &self)?;
-                        }
-
-                        record.write_to(&mut output)?;
-
-                        count += 1;
-                        if self.filter_opts.limit == count as usize {
-                            break 'outer;
-                        }
+
+                    output.write_all(&data)?;
+
+                    count += 1;
+                    if self.filter_opts.limit == count as usize {
+                        return Ok(ControlFlow::Break(()));
                     }
-                }
-            }
-        }
+
+                    Ok(ControlFlow::Continue(()))
+                },
+            )?;
 
         output.flush()?;
 
@@ -25,9 +25,15 @@ impl CliError {
         line: usize,
     ) -> Self {
         match error {
-            ReadMarcError::Parse(e) => Self::Parse(format!(
-                "could not parse record (line {line}, {e})"
-            )),
+            ReadMarcError::Parse(e) => match e.offset() {
+                Some(offset) => Self::Parse(format!(
+                    "could not parse record (line {line}, byte {offset}): \
+                     {e}"
+                )),
+                None => Self::Parse(format!(
+                    "could not parse record (line {line}): {e}"
+                )),
+            },
             ReadMarcError::IO(e) => Self::Parse(format!(
                 "could not read record (line {line}, {e})"
             )),
@@ -10,10 +10,12 @@ use crate::cli::{Args, Command};
 mod cli;
 mod commands;
 mod error;
+mod pipeline;
 pub(crate) mod prelude;
 mod progress;
 mod unicode;
 mod utils;
+mod warnings;
 
 fn main() -> ExitCode {
     let args = Args::parse();
@@ -21,6 +23,7 @@ fn main() -> ExitCode {
     let result = match *args.cmd {
         Command::Check(cmd) => cmd.execute(),
         Command::Concat(cmd) => cmd.execute(),
+        Command::Convert(cmd) => cmd.execute(),
         Command::Count(cmd) => cmd.execute(),
         Command::Dedup(cmd) => cmd.execute(),
         Command::Describe(cmd) => cmd.execute(),
@@ -29,7 +32,9 @@ fn main() -> ExitCode {
         Command::Glimpse(cmd) => cmd.execute(),
         Command::Grep(cmd) => cmd.execute(),
         Command::Hash(cmd) => cmd.execute(),
+        Command::Index(cmd) => cmd.execute(),
         Command::Invalid(cmd) => cmd.execute(),
+        Command::Lookup(cmd) => cmd.execute(),
         Command::Partition(cmd) => cmd.execute(),
         Command::Print(cmd) => cmd.execute(),
         Command::Sample(cmd) => cmd.execute(),
@@ -43,6 +48,8 @@ fn main() -> ExitCode {
         Command::BuildMan(cmd) => cmd.execute(&Args::command()),
     };
 
+    warnings::finish();
+
     match result {
         Ok(_) => ExitCode::SUCCESS,
         Err(error::CliError::IO(e))
@@ -0,0 +1,235 @@
+use std::ops::ControlFlow;
+use std::path::PathBuf;
+use std::thread;
+
+use marc21::matcher::Prefilter;
+
+use crate::prelude::*;
+
+/// Reads records and processes them either sequentially or on a pool
+/// of worker threads.
+///
+/// In parallel mode, the input is split at record terminators into
+/// chunks, which are parsed and mapped by the workers. The results
+/// are passed to the sink in input order, so that the output doesn't
+/// depend on the number of threads.
+pub(crate) struct Pipeline {
+    options: MarcReadOptions,
+    threads: usize,
+    skip_invalid: bool,
+    prefilter: Option<Prefilter>,
+}
+
+impl Pipeline {
+    pub(crate) fn new(common: &CommonOpts) -> Self {
+        let threads = match common.threads {
+            0 => thread::available_parallelism()
+                .map(usize::from)
+                .unwrap_or(1),
+            n => n,
+        };
+
+        Self {
+            options: MarcReadOptions::from(common),
+            skip_invalid: false,
+            prefilter: None,
+            threads,
+        }
+    }
+
+    /// Whether to skip invalid records or to abort the processing
+    /// (default: `false`).
+    pub(crate) fn skip_invalid(mut self, yes: bool) -> Self {
+        self.skip_invalid = yes;
+        self
+    }
+
+    /// Sets a prefilter, which rejects records before they are parsed.
+    /// Since the rejected records aren't validated, the prefilter is
+    /// only applied if invalid records are skipped.
+    pub(crate) fn prefilter(
+        mut self,
+        prefilter: Option<Prefilter>,
+    ) -> Self {
+        self.prefilter = prefilter;
+        self
+    }
+
+    /// Returns the read options, which include the prefilter if
+    /// invalid records are skipped.
+    fn read_options(&self) -> MarcReadOptions {
+        match self.prefilter {
+            Some(ref prefilter) if self.skip_invalid => {
+                self.options.clone().prefilter(prefilter.clone())
+            }
+            _ => self.options.clone(),
+        }
+    }
+
+    /// Applies `map` to each valid record and passes the results in
+    /// input order to `sink`, which stops the processing by returning
+    /// [`ControlFlow::Break`].
+    pub(crate) fn run<T, M, S>(
+        &self,
+        paths: &[PathBuf],
+        progress: &mut Progress,
+        map: M,
+        mut sink: S,
+    ) -> CliResult
+    where
+        T: Send,
+        M: Fn(&ByteRecord) -> Result<T, CliError> + Sync,
+        S: FnMut(T) -> Result<ControlFlow<()>, CliError>,
+    {
+        let skip_invalid = self.skip_invalid;
+
+        self.run_raw(
+            paths,
+            |result, line| match result {
+                Err(ReadMarcError::Parse(_)) if skip_invalid => {
+                    Ok(None)
+                }
+                Err(e) => Err(CliError::from_parse(e, line)),
+                Ok(record) => map(&record).map(Some),
+            },
+            |item| {
+                progress.update(item.is_none());
+                match item {
+                    Some(item) => sink(item),
+                    None => Ok(ControlFlow::Continue(())),
+                }
+            },
+        )
+    }
+
+    /// Applies `map` to each record (or the error, if the record is
+    /// invalid) and its number and passes the results in input order
+    /// to `sink`, which stops the processing by returning
+    /// [`ControlFlow::Break`].
+    pub(crate) fn run_raw<T, M, S>(
+        &self,
+        paths: &[PathBuf],
+        map: M,
+        sink: S,
+    ) -> CliResult
+    where
+        T: Send,
+        M: Fn(
+                Result<ByteRecord<'_>, ReadMarcError<'_>>,
+                usize,
+            ) -> Result<T, CliError>
+            + Sync,
+        S: FnMut(T) -> Result<ControlFlow<()>, CliError>,
+    {
+        if self.threads > 1 {
+            self.run_parallel(paths, map, sink)
+        } else {
+            self.run_sequential(paths, map, sink)
+        }
+    }
+
+    fn run_sequential<T, M, S>(
+        &self,
+        paths: &[PathBuf],
+        map: M,
+        mut sink: S,
+    ) -> CliResult
+    where
+        M: Fn(
+            Result<ByteRecord<'_>, ReadMarcError<'_>>,
+            usize,
+        ) -> Result<T, CliError>,
+        S: FnMut(T) -> Result<ControlFlow<()>, CliError>,
+    {
+        let mut line = 0;
+
+        for path in paths.iter() {
+            let mut reader =
+                self.read_options().try_into_reader_from_path(path)?;
+
+            // The records, which are rejected by the prefilter, are
+            // counted by the reader.
+            let base = line;
+            while let Some((number, result)) =
+                reader.next_numbered_record()
+            {
+                line = base + number;
+
+                if sink(map(result, line)?)?.is_break() {
+                    return Ok(());
+                }
+            }
+
+            line = base + reader.records_read();
+        }
+
+        Ok(())
+    }
+
+    fn run_parallel<T, M, S>(
+        &self,
+        paths: &[PathBuf],
+        map: M,
+        mut sink: S,
+    ) -> CliResult
+    where
+        T: Send,
+        M: Fn(
+                Result<ByteRecord<'_>, ReadMarcError<'_>>,
+                usize,
+            ) -> Result<T, CliError>
+            + Sync,
+        S: FnMut(T) -> Result<ControlFlow<()>, CliError>,
+    {
+        let mut line = 0;
+
+        for path in paths.iter() {
+            let mut reader = self
+                .read_options()
+                .try_into_chunk_reader_from_path(path)?;
+
+            let base = line;
+            let mut error = None;
+            let mut stop = false;
+
+            let result = reader.par_map(
+                self.threads,
+                |number, result| map(result, base + number),
+                |item| match item.and_then(&mut sink) {
+                    Ok(ControlFlow::Continue(())) => {
+                        ControlFlow::Continue(())
+                    }
+                    Ok(ControlFlow::Break(())) => {
+                        stop = true;
+                        ControlFlow::Break(())
+                    }
+                    Err(e) => {
+                        error = Some(e);
+                        ControlFlow::Break(())
+                    }
+                },
+            );
+
+            if let Some(e) = error {
+                return Err(e);
+            }
+
+            // The reader counts the records of all chunks read so far,
+            // including the records rejected by the prefilter.
+            line = base + reader.records_read();
+
+            if let Err(e) = result {
+                return Err(CliError::from_parse(
+                    ReadMarcError::IO(e),
+                    line + 1,
+                ));
+            }
+
+            if stop {
+                break;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -4,5 +4,6 @@ pub(crate) use marc21::prelude::*;
 
 pub(crate) use crate::cli::{CommonOpts, FilterOpts};
 pub(crate) use crate::error::{CliError, CliResult};
+pub(crate) use crate::pipeline::Pipeline;
 pub(crate) use crate::progress::Progress;
-pub(crate) use crate::utils::WriterBuilder;
+pub(crate) use crate::utils::{WriterBuilder, has_extension};
@@ -1,21 +1,69 @@
 use std::fs::{File, OpenOptions};
 use std::io::{self, BufWriter, Write, stdout};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
-use flate2::Compression;
+use bzip2::write::BzEncoder;
 use flate2::write::GzEncoder;
+use liblzma::write::XzEncoder;
+use marc21::io::BgzfWriter;
 
 use crate::error::CliError;
 
+/// The compression format of an output file, which is selected by the
+/// file extension.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Compression {
+    Gzip,
+    Zstd,
+    Bzip2,
+    Xz,
+}
+
+impl Compression {
+    const EXTENSIONS: [(&str, Self); 4] = [
+        ("gz", Self::Gzip),
+        ("zst", Self::Zstd),
+        ("bz2", Self::Bzip2),
+        ("xz", Self::Xz),
+    ];
+
+    fn from_path(path: &Path) -> Option<Self> {
+        let ext = path.extension()?;
+        Self::EXTENSIONS
+            .iter()
+            .find(|(value, _)| ext == *value)
+            .map(|(_, compression)| *compression)
+    }
+}
+
+/// Returns `true` if the filename ends with the given extension,
+/// which may be followed by the extension of a compression format,
+/// e.g. `out.tsv` or `out.tsv.zst` for the extension `tsv`.
+pub(crate) fn has_extension(filename: &str, ext: &str) -> bool {
+    let filename = Compression::EXTENSIONS
+        .iter()
+        .find_map(|(value, _)| {
+            filename
+                .strip_suffix(value)
+                .and_then(|s| s.strip_suffix('.'))
+        })
+        .unwrap_or(filename);
+
+    filename.strip_suffix(ext).is_some_and(|s| s.ends_with('.'))
+}
+
 #[derive(Default)]
 pub(crate) struct WriterBuilder {
-    compression: Compression,
+    compression: Option<u32>,
     append: bool,
+    bgzf: bool,
 }
 
 impl WriterBuilder {
+    /// Sets the compression level (0..=9). If not set, the default
+    /// level of the compression format is used.
     pub fn with_compression(mut self, level: u32) -> Self {
-        self.compression = Compression::new(level);
+        self.compression = Some(level);
         self
     }
 
@@ -24,6 +72,16 @@ impl WriterBuilder {
         self
     }
 
+    /// If set, gzip output is written as block-gzip (BGZF), which
+    /// allows random access to the records of an index.
+    pub fn bgzf(mut self, yes: bool) -> Self {
+        self.bgzf = yes;
+        self
+    }
+
+    /// Creates a writer for the given path, or for stdout if no path
+    /// is given. If the path ends with `.gz`, `.zst`, `.bz2` or `.xz`,
+    /// the output is compressed in the corresponding format.
     pub fn try_from_path_or_stdout(
         self,
         path: Option<PathBuf>,
@@ -34,7 +92,14 @@ impl WriterBuilder {
             ))));
         };
 
-        if path.extension().unwrap_or_default() != "gz" {
+        let compression = Compression::from_path(&path);
+        if self.bgzf && compression != Some(Compression::Gzip) {
+            return Err(CliError::AdHoc(
+                "BGZF output requires a `.gz` file extension.".into(),
+            ));
+        }
+
+        let Some(compression) = compression else {
             let file = OpenOptions::new()
                 .write(true)
                 .create(true)
@@ -42,19 +107,46 @@ impl WriterBuilder {
                 .append(self.append)
                 .open(path)?;
 
-            Ok(Writer::File(BufWriter::new(Box::new(file))))
-        } else {
-            if self.append {
-                return Err(CliError::AdHoc(
-                    "Appending to Gzip compressed output is not supported.".into(),
-                ));
-            }
+            return Ok(Writer::File(BufWriter::new(Box::new(file))));
+        };
 
-            Ok(Writer::Gzip(GzEncoder::new(
-                Box::new(File::create(path)?),
-                self.compression,
-            )))
+        if self.append {
+            return Err(CliError::AdHoc(
+                "Appending to compressed output is not supported."
+                    .into(),
+            ));
         }
+
+        let file: Box<dyn Write> = Box::new(File::create(path)?);
+        let level = self.compression;
+
+        Ok(match compression {
+            Compression::Gzip if self.bgzf => {
+                Writer::Bgzf(BgzfWriter::new(
+                    file,
+                    level
+                        .map(flate2::Compression::new)
+                        .unwrap_or_default(),
+                ))
+            }
+            Compression::Gzip => Writer::Gzip(GzEncoder::new(
+                file,
+                level.map(flate2::Compression::new).unwrap_or_default(),
+            )),
+            Compression::Zstd => Writer::Zstd(zstd::Encoder::new(
+                file,
+                level.map(|level| level as i32).unwrap_or(0),
+            )?),
+            Compression::Bzip2 => Writer::Bzip2(BzEncoder::new(
+                file,
+                level
+                    .map(|level| bzip2::Compression::new(level.max(1)))
+                    .unwrap_or_default(),
+            )),
+            Compression::Xz => {
+                Writer::Xz(XzEncoder::new(file, level.unwrap_or(6)))
+            }
+        })
     }
 }
 
@@ -62,12 +154,20 @@ pub(crate) enum Writer {
     File(BufWriter<Box<dyn Write>>),
     Stdout(BufWriter<Box<dyn Write>>),
     Gzip(GzEncoder<Box<dyn Write>>),
+    Bgzf(BgzfWriter<Box<dyn Write>>),
+    Zstd(zstd::Encoder<'static, Box<dyn Write>>),
+    Bzip2(BzEncoder<Box<dyn Write>>),
+    Xz(XzEncoder<Box<dyn Write>>),
 }
 
 impl Writer {
     pub fn finish(self) -> io::Result<()> {
         match self {
             Self::Gzip(wtr) => wtr.finish()?.flush(),
+            Self::Bgzf(wtr) => wtr.finish()?.flush(),
+            Self::Zstd(wtr) => wtr.finish()?.flush(),
+            Self::Bzip2(wtr) => wtr.finish()?.flush(),
+            Self::Xz(wtr) => wtr.finish()?.flush(),
             Self::Stdout(mut wtr) => wtr.flush(),
             Self::File(mut wtr) => wtr.flush(),
         }
@@ -80,6 +180,10 @@ impl Write for Writer {
             Self::File(wtr) => wtr.write(buf),
             Self::Stdout(wtr) => wtr.write(buf),
             Self::Gzip(wtr) => wtr.write(buf),
+            Self::Bgzf(wtr) => wtr.write(buf),
+            Self::Zstd(wtr) => wtr.write(buf),
+            Self::Bzip2(wtr) => wtr.write(buf),
+            Self::Xz(wtr) => wtr.write(buf),
         }
     }
 
@@ -88,6 +192,10 @@ impl Write for Writer {
             Self::File(wtr) => wtr.flush(),
             Self::Stdout(wtr) => wtr.flush(),
             Self::Gzip(wtr) => wtr.flush(),
+            Self::Bgzf(wtr) => wtr.flush(),
+            Self::Zstd(wtr) => wtr.flush(),
+            Self::Bzip2(wtr) => wtr.flush(),
+            Self::Xz(wtr) => wtr.flush(),
         }
     }
 }
@@ -0,0 +1,39 @@
+use std::sync::Mutex;
+use std::sync::mpsc::{Sender, channel};
+use std::thread::{self, JoinHandle};
+
+use marc21::io::ParseWarning;
+
+type Channel = (Sender<ParseWarning>, JoinHandle<()>);
+
+static CHANNEL: Mutex<Option<Channel>> = Mutex::new(None);
+
+/// Returns a sender for warnings, e.g. the repairs applied in lenient
+/// mode. The warnings are printed to stderr by a background thread; a
+/// warning without a record number is printed without its position.
+pub(crate) fn sender() -> Sender<ParseWarning> {
+    let mut guard = CHANNEL.lock().unwrap();
+    let (sender, _) = guard.get_or_insert_with(|| {
+        let (sender, receiver) = channel::<ParseWarning>();
+        let handle = thread::spawn(move || {
+            for warning in receiver {
+                match warning.record() {
+                    0 => eprintln!("warning: {}", warning.message()),
+                    n => eprintln!("warning: record {n}: {warning}"),
+                }
+            }
+        });
+
+        (sender, handle)
+    });
+
+    sender.clone()
+}
+
+/// Waits until all pending warnings are printed.
+pub(crate) fn finish() {
+    if let Some((sender, handle)) = CHANNEL.lock().unwrap().take() {
+        drop(sender);
+        let _ = handle.join();
+    }
+}
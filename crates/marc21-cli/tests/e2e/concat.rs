@@ -2,6 +2,10 @@ use std::fs::{self, File};
 use std::io::Read;
 
 use flate2::read::GzDecoder;
+use marc21::{
+    ControlField, DataField, Field, Leader, OversizePolicy, Subfield,
+    Tag,
+};
 
 use crate::prelude::*;
 
@@ -120,6 +124,47 @@ fn concat_output_gzip() -> TestResult {
     Ok(())
 }
 
+#[test]
+fn concat_output_compressed() -> TestResult {
+    let temp_dir = TempDir::new()?;
+
+    for ext in ["gz", "zst", "bz2", "xz"] {
+        let output = temp_dir.child(format!("out.mrc.{ext}"));
+
+        let mut cmd = marc21_cmd();
+        let assert = cmd
+            .args(["concat", "--compression", "9"])
+            .arg(data_dir().join("ada.mrc"))
+            .args(["-o", output.to_str().unwrap()])
+            .assert();
+
+        assert
+            .success()
+            .code(0)
+            .stdout(predicates::str::is_empty())
+            .stderr(predicates::str::is_empty());
+
+        let data = fs::read(output.path())?;
+        assert_ne!(data, fs::read(data_dir().join("ada.mrc"))?);
+
+        // The compression is detected by the content of the input,
+        // also on stdin.
+        let mut cmd = marc21_cmd();
+        let assert = cmd.arg("concat").write_stdin(data).assert();
+
+        assert
+            .success()
+            .code(0)
+            .stdout(predicates::ord::eq(fs::read(
+                data_dir().join("ada.mrc"),
+            )?))
+            .stderr(predicates::str::is_empty());
+    }
+
+    temp_dir.close()?;
+    Ok(())
+}
+
 #[test]
 fn concat_skip_invalid() -> TestResult {
     let mut cmd = marc21_cmd();
@@ -261,7 +306,7 @@ fn concat_append() -> TestResult {
 
     assert_eq!(actual, expected);
 
-    // append to gzip is not supported
+    // append to compressed output is not supported
     let temp_dir = TempDir::new()?;
     let output = temp_dir.child("out.mrc.gz");
 
@@ -277,7 +322,7 @@ fn concat_append() -> TestResult {
         .code(1)
         .stdout(predicates::str::is_empty())
         .stderr(predicates::str::starts_with(
-            "error: Appending to Gzip compressed output is not supported."
+            "error: Appending to compressed output is not supported.",
         ));
 
     temp_dir.close()?;
@@ -316,3 +361,89 @@ fn concat_tee() -> TestResult {
     temp_dir.close()?;
     Ok(())
 }
+
+#[test]
+fn concat_oversize() -> TestResult {
+    let temp_dir = TempDir::new().unwrap();
+    let input = temp_dir.child("oversize.mrc");
+
+    let value = vec![b'x'; 9000];
+    let mut fields = vec![Field::Control(ControlField::new(
+        Tag::from_bytes(b"001")?,
+        b"123",
+    ))];
+
+    for _ in 0..12 {
+        fields.push(Field::Data(DataField::new(
+            Tag::from_bytes(b"856")?,
+            b'4',
+            b' ',
+            vec![Subfield::new(b'u', &value)],
+        )));
+    }
+
+    let mut data = Vec::<u8>::new();
+    marc21::ByteRecord::from_fields(
+        Leader::new(b"00000nz  a2200000nc 4500")?,
+        fields,
+    )
+    .write_with(&mut data, OversizePolicy::Oversize)?;
+    data.extend_from_slice(&fs::read(data_dir().join("ada.mrc"))?);
+    input.write_binary(&data)?;
+
+    let mut cmd = marc21_cmd();
+    let assert = cmd.arg("concat").arg(input.path()).assert();
+    assert
+        .success()
+        .code(0)
+        .stdout(predicates::ord::eq(data.clone()))
+        .stderr(predicates::str::is_empty());
+
+    let mut cmd = marc21_cmd();
+    let assert = cmd
+        .args(["concat", "--oversize", "error"])
+        .arg(input.path())
+        .assert();
+    assert
+        .failure()
+        .code(1)
+        .stderr(predicates::str::contains("exceeds 99999"));
+
+    let output = temp_dir.child("split.mrc");
+    let mut cmd = marc21_cmd();
+    let assert = cmd
+        .args(["concat", "--oversize", "split", "-o"])
+        .arg(output.path())
+        .arg(input.path())
+        .assert();
+    assert.success().code(0).stderr(predicates::str::is_empty());
+
+    let mut cmd = marc21_cmd();
+    let assert = cmd.arg("count").arg(output.path()).assert();
+    assert.success().code(0).stdout("3\n");
+
+    for (filter, count) in [
+        ("773.w == '123'", "2\n"),
+        ("773.g == 'part 1/2'", "1\n"),
+        ("773.g == 'part 2/2'", "1\n"),
+    ] {
+        let mut cmd = marc21_cmd();
+        let assert = cmd
+            .args(["count", "--where", filter])
+            .arg(output.path())
+            .assert();
+        assert.success().code(0).stdout(count);
+    }
+
+    let mut cmd = marc21_cmd();
+    let assert = cmd
+        .args(["concat", "--oversize", "truncate"])
+        .arg(input.path())
+        .assert();
+    assert.success().code(0).stderr(predicates::str::contains(
+        "warning: oversize record 123 truncated; dropped fields: 856",
+    ));
+
+    temp_dir.close().unwrap();
+    Ok(())
+}
@@ -0,0 +1,197 @@
+use std::fs::{File, read_to_string};
+use std::io::Read;
+
+use flate2::read::GzDecoder;
+
+use crate::prelude::*;
+
+#[test]
+fn convert_marcxml_pretty() -> TestResult {
+    let mut cmd = marc21_cmd();
+    let assert = cmd
+        .args(["convert", "--to", "marcxml", "--pretty"])
+        .arg(data_dir().join("ada.mrc"))
+        .assert();
+
+    let mut expected = read_to_string(data_dir().join("ada.xml"))?;
+    if cfg!(windows) {
+        expected = expected.replace('\r', "");
+    }
+
+    assert
+        .success()
+        .code(0)
+        .stdout(predicates::ord::eq(expected))
+        .stderr(predicates::str::is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn convert_marcxml_no_collection() -> TestResult {
+    let mut cmd = marc21_cmd();
+    let assert = cmd
+        .args(["convert", "--to", "marcxml", "--no-collection"])
+        .arg(data_dir().join("ada.mrc"))
+        .arg(data_dir().join("ada.mrc"))
+        .assert();
+
+    assert
+        .success()
+        .code(0)
+        .stdout(predicates::function::function(|out: &str| {
+            out.lines().count() == 2
+                && out.lines().all(|line| {
+                    line.starts_with("<record xmlns=")
+                        && line.ends_with("</record>")
+                })
+        }))
+        .stderr(predicates::str::is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn convert_marcxml_filter() -> TestResult {
+    let mut cmd = marc21_cmd();
+    let assert = cmd
+        .args(["convert", "--to", "marcxml", "-s"])
+        .args(["--where", "001 == '119232022'"])
+        .arg(data_dir().join("ada.mrc"))
+        .arg(data_dir().join("invalid.mrc"))
+        .assert();
+
+    assert
+        .success()
+        .code(0)
+        .stdout(predicates::str::contains("<record>").count(1))
+        .stderr(predicates::str::is_empty());
+
+    let mut cmd = marc21_cmd();
+    let assert = cmd
+        .args(["convert", "--to", "marcxml"])
+        .args(["--where", "001 == '123'"])
+        .arg(data_dir().join("ada.mrc"))
+        .assert();
+
+    assert
+        .success()
+        .code(0)
+        .stdout(predicates::ord::eq(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+             <collection xmlns=\"http://www.loc.gov/MARC21/slim\">\
+             </collection>\n",
+        ))
+        .stderr(predicates::str::is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn convert_marcxml_gzip() -> TestResult {
+    let temp_dir = TempDir::new()?;
+    let output = temp_dir.child("out.xml.gz");
+
+    let mut cmd = marc21_cmd();
+    let assert = cmd
+        .args(["convert", "--to", "marcxml", "--pretty"])
+        .arg(data_dir().join("ada.mrc"))
+        .args(["-o", output.to_str().unwrap()])
+        .assert();
+
+    assert
+        .success()
+        .code(0)
+        .stdout(predicates::str::is_empty())
+        .stderr(predicates::str::is_empty());
+
+    let mut gz = GzDecoder::new(File::open(output.path())?);
+    let mut actual = String::new();
+    gz.read_to_string(&mut actual)?;
+
+    let mut expected = read_to_string(data_dir().join("ada.xml"))?;
+    if cfg!(windows) {
+        expected = expected.replace('\r', "");
+    }
+
+    assert_eq!(expected, actual);
+    temp_dir.close()?;
+    Ok(())
+}
+
+#[test]
+fn convert_json() -> TestResult {
+    let mut cmd = marc21_cmd();
+    let assert = cmd
+        .args(["convert", "--to", "json"])
+        .arg(data_dir().join("ada.mrc"))
+        .assert();
+
+    let mut expected = read_to_string(data_dir().join("ada.json"))?;
+    if cfg!(windows) {
+        expected = expected.replace('\r', "");
+    }
+
+    assert
+        .success()
+        .code(0)
+        .stdout(predicates::ord::eq(expected))
+        .stderr(predicates::str::is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn convert_jsonl() -> TestResult {
+    let mut cmd = marc21_cmd();
+    let assert = cmd
+        .args(["convert", "--to", "jsonl"])
+        .arg(data_dir().join("ada.mrc"))
+        .arg(data_dir().join("ada.mrc"))
+        .assert();
+
+    assert
+        .success()
+        .code(0)
+        .stdout(predicates::function::function(|out: &str| {
+            out.lines().count() == 2
+                && out.lines().all(|line| {
+                    line.starts_with("{\"leader\":")
+                        && line.ends_with('}')
+                })
+        }))
+        .stderr(predicates::str::is_empty());
+
+    let mut cmd = marc21_cmd();
+    let assert = cmd
+        .args(["convert", "--to", "jsonl"])
+        .args(["--where", "001 == '123'"])
+        .arg(data_dir().join("ada.mrc"))
+        .assert();
+
+    assert
+        .success()
+        .code(0)
+        .stdout(predicates::str::is_empty())
+        .stderr(predicates::str::is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn convert_invalid() -> TestResult {
+    let mut cmd = marc21_cmd();
+    let assert = cmd
+        .args(["convert", "--to", "marcxml"])
+        .arg(data_dir().join("invalid.mrc"))
+        .assert();
+
+    assert
+        .failure()
+        .code(1)
+        .stderr(predicates::str::starts_with(
+            "error: could not parse record",
+        ));
+
+    Ok(())
+}
@@ -1,7 +1,9 @@
-use std::fs::File;
-use std::io::{Read, read_to_string};
+use std::fs::{self, File};
+use std::io::{Read, Write, read_to_string};
 
+use flate2::Compression;
 use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
 
 use crate::prelude::*;
 
@@ -128,6 +130,44 @@ fn count_where() -> TestResult {
     Ok(())
 }
 
+#[test]
+fn count_where_skipped_line() -> TestResult {
+    let mut data = vec![];
+    GzDecoder::new(File::open(data_dir().join("DUMP.mrc.gz"))?)
+        .read_to_end(&mut data)?;
+
+    // The gzip stream isn't finished, so that reading fails after the
+    // last (invalid) record.
+    let mut encoder = GzEncoder::new(vec![], Compression::default());
+    encoder.write_all(&data)?;
+    encoder.flush()?;
+
+    let temp_dir = TempDir::new()?;
+    let input = temp_dir.child("truncated.mrc.gz");
+    fs::write(input.path(), encoder.get_ref())?;
+
+    // The records, which are rejected by the prefilter of the `--where`
+    // expression, are still counted.
+    for threads in ["1", "2"] {
+        let mut cmd = marc21_cmd();
+        let assert = cmd
+            .args(["count", "-s", "--threads", threads])
+            .args(["--where", "001 == '000000000'"])
+            .arg(input.path())
+            .assert();
+
+        assert
+            .failure()
+            .code(1)
+            .stdout(predicates::str::is_empty())
+            .stderr(predicates::str::starts_with(
+                "error: could not read record (line 9, ",
+            ));
+    }
+
+    Ok(())
+}
+
 #[test]
 fn count_skip_invalid() -> TestResult {
     let mut cmd = marc21_cmd();
@@ -141,7 +181,8 @@ fn count_skip_invalid() -> TestResult {
         .code(1)
         .stdout(predicates::str::is_empty())
         .stderr(predicates::str::starts_with(
-            "error: could not parse record (line 1",
+            "error: could not parse record (line 1, byte 0): record \
+             length doesn't match data at position 0\n",
         ));
 
     let mut cmd = marc21_cmd();
@@ -159,6 +200,26 @@ fn count_skip_invalid() -> TestResult {
     Ok(())
 }
 
+#[test]
+fn count_threads_invalid() -> TestResult {
+    let mut cmd = marc21_cmd();
+    let assert = cmd
+        .args(["count", "--threads", "2"])
+        .arg(data_dir().join("ada.mrc"))
+        .arg(data_dir().join("invalid.mrc"))
+        .assert();
+
+    assert
+        .failure()
+        .code(1)
+        .stdout(predicates::str::is_empty())
+        .stderr(predicates::str::starts_with(
+            "error: could not parse record (line 2, byte 0)",
+        ));
+
+    Ok(())
+}
+
 #[test]
 fn count_limit() -> TestResult {
     let mut cmd = marc21_cmd();
@@ -278,3 +339,174 @@ fn count_filter_normalization_nfkd() -> TestResult {
 
     Ok(())
 }
+
+#[test]
+fn count_ignore_case_and_diacritics() -> TestResult {
+    let mut cmd = marc21_cmd();
+    let assert = cmd
+        .args(["count", "-s"])
+        .args(["--filter-ignore-case", "--filter-ignore-diacritics"])
+        .arg(data_dir().join("minna.mrc"))
+        .args(["--where", "400/1#.t =? 'SOLDATENGLUCK'"])
+        .assert();
+
+    assert
+        .success()
+        .code(0)
+        .stdout(predicates::ord::eq("1\n"))
+        .stderr(predicates::str::is_empty());
+
+    let mut cmd = marc21_cmd();
+    let assert = cmd
+        .args(["count", "-s"])
+        .arg(data_dir().join("minna.mrc"))
+        .args(["--where", "400/1#.t =? 'SOLDATENGLUCK'"])
+        .assert();
+
+    assert
+        .success()
+        .code(0)
+        .stdout(predicates::ord::eq("0\n"))
+        .stderr(predicates::str::is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn count_strsim_algorithm() -> TestResult {
+    let mut cmd = marc21_cmd();
+    let assert = cmd
+        .args(["count", "-s"])
+        .args(["--strsim-algorithm", "jaro-winkler"])
+        .arg(data_dir().join("ada.mrc"))
+        .args(["--where", "100/1#.a =* 'Lovelace, A.'"])
+        .assert();
+
+    assert
+        .success()
+        .code(0)
+        .stdout(predicates::ord::eq("1\n"))
+        .stderr(predicates::str::is_empty());
+
+    let mut cmd = marc21_cmd();
+    let assert = cmd
+        .args(["count", "-s"])
+        .args(["--strsim-algorithm", "jaro-winkler"])
+        .args(["--strsim-threshold", "99"])
+        .arg(data_dir().join("ada.mrc"))
+        .args(["--where", "100/1#.a =* 'Lovelace, A.'"])
+        .assert();
+
+    assert
+        .success()
+        .code(0)
+        .stdout(predicates::ord::eq("0\n"))
+        .stderr(predicates::str::is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn count_member_file() -> TestResult {
+    let mut cmd = marc21_cmd();
+    let assert = cmd
+        .args(["count", "-s"])
+        .arg(data_dir().join("DUMP.mrc.gz"))
+        .arg("--where")
+        .arg(format!(
+            "001 in @'{}'",
+            data_dir().join("ids.txt.gz").display()
+        ))
+        .assert();
+
+    assert
+        .success()
+        .code(0)
+        .stdout(predicates::ord::eq("1\n"))
+        .stderr(predicates::str::is_empty());
+
+    let mut cmd = marc21_cmd();
+    let assert = cmd
+        .args(["count", "-s"])
+        .arg(data_dir().join("DUMP.mrc.gz"))
+        .args(["--where", "001 in @missing.txt"])
+        .assert();
+
+    assert
+        .failure()
+        .code(1)
+        .stdout(predicates::str::is_empty())
+        .stderr(predicates::str::contains("missing.txt"));
+
+    Ok(())
+}
+
+#[test]
+fn count_prefilter() -> TestResult {
+    let mut cmd = marc21_cmd();
+    let assert = cmd
+        .args(["count", "-s", "--threads", "2"])
+        .arg(data_dir().join("ada.mrc"))
+        .arg(data_dir().join("invalid.mrc"))
+        .arg(data_dir().join("minna.mrc"))
+        .args(["--where", "001 in ['119232022', '040992918']"])
+        .assert();
+
+    assert
+        .success()
+        .code(0)
+        .stdout(predicates::ord::eq("2\n"))
+        .stderr(predicates::str::is_empty());
+
+    // Without `--skip-invalid`, invalid records are reported, even if
+    // they can't match.
+    let mut cmd = marc21_cmd();
+    let assert = cmd
+        .arg("count")
+        .arg(data_dir().join("invalid.mrc"))
+        .args(["--where", "001 == '119232022'"])
+        .assert();
+
+    assert
+        .failure()
+        .code(1)
+        .stdout(predicates::str::is_empty())
+        .stderr(predicates::str::starts_with(
+            "error: could not parse record (line 1, byte 0)",
+        ));
+
+    // The record of `invalid.mrc` lacks the literal of the expression.
+    // With `--skip-invalid`, it's rejected before it's decoded; thus,
+    // the repair of the record length isn't reported.
+    let mut cmd = marc21_cmd();
+    let assert = cmd
+        .args(["count", "-s", "--lenient"])
+        .arg(data_dir().join("ada.mrc"))
+        .arg(data_dir().join("invalid.mrc"))
+        .args(["--where", "001 == '119232022'"])
+        .assert();
+
+    assert
+        .success()
+        .code(0)
+        .stdout(predicates::ord::eq("1\n"))
+        .stderr(predicates::str::is_empty());
+
+    let mut cmd = marc21_cmd();
+    let assert = cmd
+        .args(["count", "--lenient"])
+        .arg(data_dir().join("ada.mrc"))
+        .arg(data_dir().join("invalid.mrc"))
+        .args(["--where", "001 == '119232022'"])
+        .assert();
+
+    assert
+        .success()
+        .code(0)
+        .stdout(predicates::ord::eq("1\n"))
+        .stderr(predicates::str::starts_with(
+            "warning: record 1: record length '01686' corrected",
+        ));
+
+    Ok(())
+}
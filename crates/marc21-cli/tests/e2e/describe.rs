@@ -5,7 +5,7 @@ use flate2::read::GzDecoder;
 
 use crate::prelude::*;
 
-const EXPECTED: &'static str = r#"field,ind1,ind2,0,2,4,9,S,a,b,c,d,e,i,q,u,w,z
+const EXPECTED: &str = r#"field,ind1,ind2,0,2,4,9,S,a,b,c,d,e,i,q,u,w,z
 024,7, ,1,1,0,0,0,1,0,0,0,0,0,0,0,0,0
 035, , ,0,0,0,3,0,2,0,0,0,0,0,0,0,0,4
 040, , ,0,0,0,1,0,1,1,1,1,0,0,0,0,0,0
@@ -183,6 +183,55 @@ fn filter_strsim_threshold() -> TestResult {
     Ok(())
 }
 
+#[test]
+fn filter_strsim_algorithm() -> TestResult {
+    let mut cmd = marc21_cmd();
+    let assert = cmd
+        .args(["filter", "-s"])
+        .arg("100/1#.a =* 'Ada Lovelace'")
+        .arg(data_dir().join("ada.mrc.gz"))
+        .assert();
+
+    assert
+        .success()
+        .code(0)
+        .stdout(predicates::str::is_empty())
+        .stderr(predicates::str::is_empty());
+
+    let mut cmd = marc21_cmd();
+    let assert = cmd
+        .args(["filter", "-s"])
+        .args(["--strsim-algorithm", "token-set"])
+        .arg("100/1#.a =* 'Ada Lovelace'")
+        .arg(data_dir().join("ada.mrc.gz"))
+        .assert();
+
+    assert
+        .success()
+        .code(0)
+        .stdout(predicates::ord::eq(fs::read(
+            data_dir().join("ada.mrc"),
+        )?))
+        .stderr(predicates::str::is_empty());
+
+    let mut cmd = marc21_cmd();
+    let assert = cmd
+        .args(["filter", "-s"])
+        .arg("100/1#.a =*(token-set) 'Ada Lovelace'")
+        .arg(data_dir().join("ada.mrc.gz"))
+        .assert();
+
+    assert
+        .success()
+        .code(0)
+        .stdout(predicates::ord::eq(fs::read(
+            data_dir().join("ada.mrc"),
+        )?))
+        .stderr(predicates::str::is_empty());
+
+    Ok(())
+}
+
 #[test]
 fn filter_invalid_filter() -> TestResult {
     let mut cmd = marc21_cmd();
@@ -232,6 +281,43 @@ fn filter_limit() -> TestResult {
     Ok(())
 }
 
+#[test]
+fn filter_threads() -> TestResult {
+    let temp_dir = TempDir::new()?;
+    let output = temp_dir.child("out.mrc");
+
+    let mut cmd = marc21_cmd();
+    let assert = cmd
+        .args(["filter", "-s", "--threads", "4"])
+        .arg("075.b == 'p'")
+        .arg(data_dir().join("DUMP.mrc.gz"))
+        .arg(data_dir().join("ada.mrc"))
+        .arg(data_dir().join("DUMP.mrc.gz"))
+        .args(["-o", output.to_str().unwrap()])
+        .assert();
+
+    assert
+        .success()
+        .code(0)
+        .stdout(predicates::str::is_empty())
+        .stderr(predicates::str::is_empty());
+
+    let mut cmd = marc21_cmd();
+    let expected = cmd
+        .args(["filter", "-s"])
+        .arg("075.b == 'p'")
+        .arg(data_dir().join("DUMP.mrc.gz"))
+        .arg(data_dir().join("ada.mrc"))
+        .arg(data_dir().join("DUMP.mrc.gz"))
+        .output()?
+        .stdout;
+
+    assert!(!expected.is_empty());
+    assert_eq!(fs::read(output.path())?, expected);
+
+    Ok(())
+}
+
 #[test]
 fn filter_normalization_nfc() -> TestResult {
     let temp_dir = TempDir::new()?;
@@ -384,3 +470,36 @@ fn filter_invert_match() -> TestResult {
 
     Ok(())
 }
+
+#[test]
+fn filter_ignore_normalization() -> TestResult {
+    let mut cmd = marc21_cmd();
+    let assert = cmd
+        .args(["filter", "--filter-ignore-normalization"])
+        .arg("400/1#.t =$ 'Soldatengl\u{fc}ck'")
+        .arg(data_dir().join("minna.mrc"))
+        .assert();
+
+    assert
+        .success()
+        .code(0)
+        .stdout(predicates::ord::eq(fs::read(
+            data_dir().join("minna.mrc"),
+        )?))
+        .stderr(predicates::str::is_empty());
+
+    let mut cmd = marc21_cmd();
+    let assert = cmd
+        .args(["filter"])
+        .arg("400/1#.t =$ 'Soldatengl\u{fc}ck'")
+        .arg(data_dir().join("minna.mrc"))
+        .assert();
+
+    assert
+        .success()
+        .code(0)
+        .stdout(predicates::str::is_empty())
+        .stderr(predicates::str::is_empty());
+
+    Ok(())
+}
@@ -5,7 +5,7 @@ use flate2::read::GzDecoder;
 
 use crate::prelude::*;
 
-const EXPECTED: &'static str = r#"$9 r:DE-101, r:DE-101, r:DE-101, r:DE-101, r:DE-101, r:DE-101, r:DE-101
+const EXPECTED: &str = r#"$9 r:DE-101, r:DE-101, r:DE-101, r:DE-101, r:DE-101, r:DE-101, r:DE-101
 $a DE-101, DE-101, DE-101, DE-101, DE-101, DE-101, DE-101
 $b ger, ger, ger, ger, ger, ger, ger
 $c DE-101, DE-101, DE-101, DE-101, DE-101, DE-101, DE-101
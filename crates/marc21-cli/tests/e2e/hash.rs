@@ -204,6 +204,28 @@ fn hash_where() -> TestResult {
     Ok(())
 }
 
+#[test]
+fn hash_prefilter() -> TestResult {
+    // The record of `invalid.mrc` is rejected by the prefilter of the
+    // `--where` expression, before its record length is repaired.
+    let mut cmd = marc21_cmd();
+    let assert = cmd
+        .args(["hash", "-s", "--lenient", "--threads", "2"])
+        .arg(data_dir().join("invalid.mrc"))
+        .arg(data_dir().join("ada.mrc"))
+        .args(["--where", "001 == '119232022'"])
+        .assert();
+
+    assert
+        .success()
+        .code(0)
+        .stdout(predicates::ord::eq(
+            "cn,hash\n119232022,59e1a2702f5e1dab410c07dc8c37961b4e873b2712c680b034ae452fada48c33\n"))
+        .stderr(predicates::str::is_empty());
+
+    Ok(())
+}
+
 #[test]
 fn hash_skip_invalid() -> TestResult {
     let mut cmd = marc21_cmd();
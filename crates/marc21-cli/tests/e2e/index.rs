@@ -0,0 +1,98 @@
+use std::fs::read_to_string;
+
+use crate::prelude::*;
+
+#[test]
+fn index_stdout() -> TestResult {
+    let path = data_dir().join("ada.mrc");
+
+    let mut cmd = marc21_cmd();
+    let assert = cmd.arg("index").arg(&path).assert();
+
+    assert
+        .success()
+        .code(0)
+        .stdout(predicates::ord::eq(format!(
+            "marc21-index\t1\nfile\tplain\t{}\nentry\t0\t0\t119232022\n",
+            path.display()
+        )))
+        .stderr(predicates::str::is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn index_key() -> TestResult {
+    let mut cmd = marc21_cmd();
+    let assert = cmd
+        .args(["index", "-k", "075{ b | 2 == 'gndgen' }"])
+        .arg(data_dir().join("ada.mrc"))
+        .assert();
+
+    assert
+        .success()
+        .code(0)
+        .stdout(predicates::str::ends_with("entry\t0\t0\tp\n"))
+        .stderr(predicates::str::is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn index_output() -> TestResult {
+    let temp_dir = TempDir::new()?;
+    let output = temp_dir.child("index.txt");
+
+    let mut cmd = marc21_cmd();
+    let assert = cmd
+        .args(["index", "-s"])
+        .arg(data_dir().join("DUMP.mrc.gz"))
+        .arg(data_dir().join("ada.mrc"))
+        .arg("-o")
+        .arg(output.path())
+        .assert();
+
+    assert
+        .success()
+        .code(0)
+        .stdout(predicates::str::is_empty())
+        .stderr(predicates::str::is_empty());
+
+    let actual = read_to_string(output.path())?;
+    assert!(actual.starts_with("marc21-index\t1\nfile\tcompressed\t"));
+    assert_eq!(actual.matches("\nentry\t0\t").count(), 7);
+    assert_eq!(actual.matches("\nentry\t1\t").count(), 1);
+
+    temp_dir.close().unwrap();
+    Ok(())
+}
+
+#[test]
+fn index_invalid() -> TestResult {
+    let mut cmd = marc21_cmd();
+    let assert = cmd
+        .arg("index")
+        .arg(data_dir().join("invalid.mrc"))
+        .assert();
+
+    assert
+        .failure()
+        .code(1)
+        .stdout(predicates::str::is_empty())
+        .stderr(predicates::str::starts_with(
+            "error: could not parse record (line 1, byte 0)",
+        ));
+
+    let mut cmd = marc21_cmd();
+    let assert = cmd.args(["index", "-"]).assert();
+
+    assert
+        .failure()
+        .code(1)
+        .stdout(predicates::str::is_empty())
+        .stderr(predicates::ord::eq(
+            "error: Indexing stdin is not supported.\n\n",
+        ));
+
+    Ok(())
+}
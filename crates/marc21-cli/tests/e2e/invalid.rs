@@ -39,6 +39,28 @@ fn invalid_stdout() -> TestResult {
     Ok(())
 }
 
+#[test]
+fn invalid_verbose() -> TestResult {
+    let path = data_dir().join("invalid.mrc");
+
+    let mut cmd = marc21_cmd();
+    let assert = cmd.args(["invalid", "--verbose"]).arg(&path).assert();
+
+    assert
+        .success()
+        .code(0)
+        .stdout(predicates::ord::eq(fs::read(&path)?))
+        .stderr(predicates::ord::eq(format!(
+            "{path}: record 1 (byte 0): record length doesn't match \
+             data at position 0\n\
+             {path}: record 2 (byte 1687): invalid leader at position \
+             0\n",
+            path = path.display()
+        )));
+
+    Ok(())
+}
+
 #[test]
 fn invalid_output() -> TestResult {
     let temp_dir = TempDir::new()?;
@@ -3,6 +3,7 @@ mod build_completion;
 #[cfg(feature = "build")]
 mod build_man;
 mod concat;
+mod convert;
 mod count;
 mod dedup;
 mod describe;
@@ -11,7 +12,9 @@ mod frequency;
 mod glimpse;
 mod grep;
 mod hash;
+mod index;
 mod invalid;
+mod lookup;
 mod partition;
 mod print;
 mod sample;
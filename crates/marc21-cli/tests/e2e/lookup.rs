@@ -0,0 +1,144 @@
+use std::fs::{read, read_to_string};
+
+use crate::prelude::*;
+
+#[test]
+fn lookup_stdout() -> TestResult {
+    let temp_dir = TempDir::new()?;
+    let index = temp_dir.child("index.txt");
+
+    let mut cmd = marc21_cmd();
+    cmd.args(["index", "-s"])
+        .arg(data_dir().join("DUMP.mrc.gz"))
+        .arg(data_dir().join("ada.mrc"))
+        .arg("-o")
+        .arg(index.path())
+        .assert()
+        .success();
+
+    let mut cmd = marc21_cmd();
+    let assert = cmd
+        .arg("lookup")
+        .arg(index.path())
+        .args(["119232022", "040992918"])
+        .assert();
+
+    let expected = read(data_dir().join("ada.mrc"))?;
+    let output = assert
+        .success()
+        .code(0)
+        .stderr(predicates::str::is_empty())
+        .get_output()
+        .stdout
+        .clone();
+
+    assert!(output.starts_with(&expected));
+    assert!(output.len() > expected.len());
+
+    let mut cmd = marc21_cmd();
+    let assert = cmd
+        .arg("lookup")
+        .arg(index.path())
+        .arg("040992918X")
+        .assert();
+
+    assert
+        .success()
+        .code(0)
+        .stdout(predicates::str::is_empty())
+        .stderr(predicates::str::is_empty());
+
+    temp_dir.close().unwrap();
+    Ok(())
+}
+
+#[test]
+fn lookup_bgzf() -> TestResult {
+    let temp_dir = TempDir::new()?;
+    let dump = temp_dir.child("dump.mrc.gz");
+    let index = temp_dir.child("index.txt");
+
+    let mut cmd = marc21_cmd();
+    cmd.args(["concat", "-s", "--bgzf"])
+        .arg(data_dir().join("DUMP.mrc.gz"))
+        .arg(data_dir().join("ada.mrc"))
+        .arg("-o")
+        .arg(dump.path())
+        .assert()
+        .success();
+
+    let mut cmd = marc21_cmd();
+    cmd.arg("index")
+        .arg(dump.path())
+        .arg("-o")
+        .arg(index.path())
+        .assert()
+        .success();
+
+    assert!(read_to_string(index.path())?.contains("\tbgzf\t"));
+
+    let mut cmd = marc21_cmd();
+    let assert = cmd
+        .arg("lookup")
+        .arg(index.path())
+        .arg("119232022")
+        .assert();
+
+    assert
+        .success()
+        .code(0)
+        .stdout(predicates::ord::eq(read(data_dir().join("ada.mrc"))?))
+        .stderr(predicates::str::is_empty());
+
+    temp_dir.close().unwrap();
+    Ok(())
+}
+
+#[test]
+fn lookup_invalid_index() -> TestResult {
+    let mut cmd = marc21_cmd();
+    let assert = cmd
+        .arg("lookup")
+        .arg(data_dir().join("ada.mrc"))
+        .arg("119232022")
+        .assert();
+
+    assert
+        .failure()
+        .code(1)
+        .stdout(predicates::str::is_empty())
+        .stderr(predicates::ord::eq("error: invalid index header\n\n"));
+
+    Ok(())
+}
+
+#[test]
+fn lookup_other_working_dir() -> TestResult {
+    let temp_dir = TempDir::new()?;
+    let data = temp_dir.child("data");
+    data.create_dir_all()?;
+    data.child("ada.mrc")
+        .write_binary(&read(data_dir().join("ada.mrc"))?)?;
+
+    let mut cmd = marc21_cmd();
+    cmd.current_dir(data.path())
+        .args(["index", "ada.mrc", "-o", "index.txt"])
+        .assert()
+        .success();
+
+    let mut cmd = marc21_cmd();
+    let assert = cmd
+        .current_dir(temp_dir.path())
+        .args(["lookup", "data/index.txt", "119232022", "119232022"])
+        .assert();
+
+    let expected = read(data_dir().join("ada.mrc"))?.repeat(2);
+    assert
+        .success()
+        .code(0)
+        .stdout(predicates::ord::eq(expected))
+        .stderr(predicates::str::is_empty());
+
+    temp_dir.close().unwrap();
+    Ok(())
+}
@@ -28,6 +28,30 @@ fn print_stdout() -> TestResult {
     Ok(())
 }
 
+#[test]
+fn print_compressed() -> TestResult {
+    let mut output = read_to_string(data_dir().join("ada.txt"))?;
+    if cfg!(windows) {
+        output = output.replace('\r', "");
+    }
+
+    for ext in ["gz", "zst", "bz2", "xz"] {
+        let mut cmd = marc21_cmd();
+        let assert = cmd
+            .arg("print")
+            .arg(data_dir().join(format!("ada.mrc.{ext}")))
+            .assert();
+
+        assert
+            .success()
+            .code(0)
+            .stdout(predicates::ord::eq(output.clone()))
+            .stderr(predicates::str::is_empty());
+    }
+
+    Ok(())
+}
+
 #[test]
 fn print_output_text() -> TestResult {
     let mut cmd = marc21_cmd();
@@ -270,3 +294,126 @@ fn print_translit_nfkd() -> TestResult {
 
     Ok(())
 }
+
+#[test]
+fn print_marc8() -> TestResult {
+    let mut cmd = marc21_cmd();
+    let assert = cmd
+        .args(["print", "--marc8"])
+        .arg(data_dir().join("marc8.mrc"))
+        .assert();
+
+    assert
+        .success()
+        .code(0)
+        .stdout(
+            predicates::str::contains("LDR 00254nam a2200085   4500\n")
+                .and(predicates::str::contains(
+                    "100/1# $a Go\u{308}del, Kurt $d 1906-1978\n",
+                ))
+                .and(predicates::str::contains(
+                    "500 $a Łodz\u{301}, Dvor\u{30c}a\u{301}k, m², H₂O\n",
+                )),
+        )
+        .stderr(predicates::str::is_empty());
+
+    let mut cmd = marc21_cmd();
+    let assert = cmd
+        .args(["print", "--marc8"])
+        .arg(data_dir().join("ada.mrc"))
+        .assert();
+
+    let mut output = read_to_string(data_dir().join("ada.txt"))?;
+    if cfg!(windows) {
+        output = output.replace('\r', "");
+    }
+
+    assert
+        .success()
+        .code(0)
+        .stdout(predicates::ord::eq(output))
+        .stderr(predicates::str::is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn print_lenient() -> TestResult {
+    let mut cmd = marc21_cmd();
+    let assert = cmd
+        .args(["print", "--lenient"])
+        .arg(data_dir().join("invalid.mrc"))
+        .assert();
+
+    assert
+        .success()
+        .code(0)
+        .stdout(predicates::str::starts_with(
+            "LDR 01687ncm a2200373 c 4500\n001 350117799\n",
+        ))
+        .stderr(predicates::ord::eq(
+            "warning: record 1: record length '01686' corrected to \
+             '01687' at position 0\n",
+        ));
+
+    let mut cmd = marc21_cmd();
+    let assert = cmd
+        .args(["print", "--lenient"])
+        .arg(data_dir().join("ada.mrc"))
+        .assert();
+
+    let mut output = read_to_string(data_dir().join("ada.txt"))?;
+    if cfg!(windows) {
+        output = output.replace('\r', "");
+    }
+
+    assert
+        .success()
+        .code(0)
+        .stdout(predicates::ord::eq(output))
+        .stderr(predicates::str::is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn print_control_fields() -> TestResult {
+    let mut cmd = marc21_cmd();
+    let assert =
+        cmd.arg("print").arg(data_dir().join("aleph.mrc")).assert();
+
+    assert
+        .failure()
+        .code(1)
+        .stdout(predicates::str::is_empty())
+        .stderr(predicates::str::starts_with(
+            "error: could not parse record (line 1, byte 65)",
+        ));
+
+    let mut cmd = marc21_cmd();
+    let assert = cmd
+        .args(["print", "--control-fields", "00.,FMT"])
+        .arg(data_dir().join("aleph.mrc"))
+        .assert();
+
+    assert
+        .success()
+        .code(0)
+        .stdout(predicates::ord::eq(
+            "LDR 00077nz  a2200061nc 4500\n001 123\nFMT AU\n\
+             100/1# $a Ada\n\n",
+        ))
+        .stderr(predicates::str::is_empty());
+
+    let mut cmd = marc21_cmd();
+    let assert = cmd
+        .args(["print", "--control-fields", "00.,F-T"])
+        .arg(data_dir().join("aleph.mrc"))
+        .assert();
+
+    assert.failure().code(2).stderr(predicates::str::contains(
+        "invalid value '00.,F-T' for '--control-fields <tags>'",
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,315 @@
+use crate::buf::RecordBuf;
+use crate::buf::field::*;
+use crate::error::BuildRecordError;
+use crate::{ControlFieldRule, Leader};
+
+/// A builder to create an owned record from scratch.
+///
+/// Invalid input (e.g. an invalid tag or indicator) doesn't stop the
+/// method chain; instead, the first error is reported when the record
+/// is built.
+///
+/// The control fields are moved in front of the data fields when the
+/// record is built; apart from that, the fields retain the order in
+/// which they were added.
+///
+/// # Example
+///
+/// ```rust
+/// use marc21::{Leader, RecordBuilder};
+///
+/// let record = RecordBuilder::default()
+///     .leader(Leader::new(b"00000nz  a2200000nc 4500")?)
+///     .data_field("100", b'1', b' ', [(b'a', "Lovelace, Ada")])
+///     .control_field("001", "119232022")
+///     .build()?;
+///
+/// let mut fields = record.fields();
+/// assert_eq!(fields.next().unwrap().tag(), "001");
+/// assert_eq!(fields.next().unwrap().tag(), "100");
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Default)]
+pub struct RecordBuilder {
+    leader: Option<Leader>,
+    fields: Vec<FieldBuf>,
+    control_fields: ControlFieldRule,
+    error: Option<BuildRecordError>,
+}
+
+impl RecordBuilder {
+    /// Sets the leader of the record.
+    pub fn leader(mut self, leader: Leader) -> Self {
+        self.leader = Some(leader);
+        self
+    }
+
+    /// Sets the rule, which decides whether a tag belongs to a control
+    /// field (default: all tags starting with `00`). The rule applies
+    /// to the fields, which are added afterwards.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{ControlFieldRule, Leader, RecordBuilder};
+    ///
+    /// let record = RecordBuilder::default()
+    ///     .leader(Leader::new(b"00000nam a2200000 a 4500")?)
+    ///     .control_fields("00., FMT".parse::<ControlFieldRule>()?)
+    ///     .control_field("001", "123")
+    ///     .control_field("FMT", "BK")
+    ///     .build()?;
+    ///
+    /// assert_eq!(record.fields().count(), 2);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn control_fields(mut self, rule: ControlFieldRule) -> Self {
+        self.control_fields = rule;
+        self
+    }
+
+    /// Adds a control field.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{Leader, RecordBuilder};
+    ///
+    /// let result = RecordBuilder::default()
+    ///     .leader(Leader::new(b"00000nz  a2200000nc 4500")?)
+    ///     .control_field("001", "119232022")
+    ///     .build();
+    /// assert!(result.is_ok());
+    ///
+    /// let result = RecordBuilder::default()
+    ///     .leader(Leader::new(b"00000nz  a2200000nc 4500")?)
+    ///     .control_field("100", "119232022")
+    ///     .build();
+    /// assert!(result.is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn control_field<T, V>(self, tag: T, value: V) -> Self
+    where
+        T: AsRef<[u8]>,
+        V: Into<Vec<u8>>,
+    {
+        let result =
+            ControlFieldBuf::new_with(tag, value, &self.control_fields);
+        self.push(result.map(FieldBuf::Control))
+    }
+
+    /// Adds a data field with the given subfields.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{Leader, RecordBuilder};
+    ///
+    /// let record = RecordBuilder::default()
+    ///     .leader(Leader::new(b"00000nz  a2200000nc 4500")?)
+    ///     .data_field(
+    ///         "100",
+    ///         b'1',
+    ///         b' ',
+    ///         [(b'a', "Lovelace, Ada"), (b'd', "1815-1852")],
+    ///     )
+    ///     .build()?;
+    ///
+    /// assert_eq!(
+    ///     record.to_string(),
+    ///     "LDR 00000nz  a2200000nc 4500\n\
+    ///      100/1# $a Lovelace, Ada $d 1815-1852\n"
+    /// );
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn data_field<T, I, V>(
+        self,
+        tag: T,
+        indicator1: u8,
+        indicator2: u8,
+        subfields: I,
+    ) -> Self
+    where
+        T: AsRef<[u8]>,
+        I: IntoIterator<Item = (u8, V)>,
+        V: Into<Vec<u8>>,
+    {
+        let result = DataFieldBuf::new_with(
+            tag,
+            indicator1,
+            indicator2,
+            &self.control_fields,
+        )
+        .and_then(|mut df| {
+            for (code, value) in subfields {
+                df.push(SubfieldBuf::new(code, value)?);
+            }
+
+            Ok(FieldBuf::Data(df))
+        });
+
+        self.push(result)
+    }
+
+    /// Adds an already constructed field.
+    pub fn field<F: Into<FieldBuf>>(self, field: F) -> Self {
+        self.push(Ok(field.into()))
+    }
+
+    /// Builds the record.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error that occurred while adding fields, an
+    /// error if no leader was set or if the leader describes a record
+    /// structure, which isn't supported (more than two indicators or a
+    /// subfield code length other than two, see [`Leader`]).
+    pub fn build(self) -> Result<RecordBuf, BuildRecordError> {
+        if let Some(err) = self.error {
+            return Err(err);
+        }
+
+        let Some(leader) = self.leader else {
+            return Err(BuildRecordError::MissingLeader);
+        };
+
+        leader.check_supported()?;
+
+        let mut fields = self.fields;
+        fields.sort_by_key(FieldBuf::is_data_field);
+
+        Ok(RecordBuf { leader, fields })
+    }
+
+    fn push(
+        mut self,
+        result: Result<FieldBuf, BuildRecordError>,
+    ) -> Self {
+        match result {
+            Ok(field) => self.fields.push(field),
+            Err(err) => {
+                self.error.get_or_insert(err);
+            }
+        }
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::TestResult;
+
+    #[test]
+    fn test_builder_errors() -> TestResult {
+        let ldr = Leader::new(b"00000nz  a2200000nc 4500")?;
+
+        assert_eq!(
+            RecordBuilder::default().build().unwrap_err(),
+            BuildRecordError::MissingLeader
+        );
+
+        assert_eq!(
+            RecordBuilder::default()
+                .leader(ldr.clone())
+                .control_field("00-", "123")
+                .control_field("100", "123")
+                .build()
+                .unwrap_err(),
+            BuildRecordError::InvalidTag("00-".into())
+        );
+
+        assert_eq!(
+            RecordBuilder::default()
+                .leader(ldr.clone())
+                .data_field("100", b'A', b' ', [(b'a', "abc")])
+                .build()
+                .unwrap_err(),
+            BuildRecordError::InvalidIndicator(b'A')
+        );
+
+        assert_eq!(
+            RecordBuilder::default()
+                .leader(ldr.clone())
+                .data_field("100", b' ', b' ', [(b'$', "abc")])
+                .build()
+                .unwrap_err(),
+            BuildRecordError::InvalidSubfieldCode(b'$')
+        );
+
+        assert_eq!(
+            RecordBuilder::default()
+                .leader(ldr)
+                .data_field("100", b' ', b' ', [(b'a', "a\x1ebc")])
+                .build()
+                .unwrap_err(),
+            BuildRecordError::InvalidValue("a\x1ebc".into())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_builder_control_fields() -> TestResult {
+        let ldr = Leader::new(b"00000nz  a2200000nc 4500")?;
+        let rule: ControlFieldRule = "00., FMT".parse()?;
+
+        let record = RecordBuilder::default()
+            .leader(ldr.clone())
+            .control_fields(rule.clone())
+            .control_field("FMT", "BK")
+            .build()?;
+        assert_eq!(
+            record.to_string(),
+            "LDR 00000nz  a2200000nc 4500\nFMT BK\n"
+        );
+
+        assert_eq!(
+            RecordBuilder::default()
+                .leader(ldr.clone())
+                .control_field("FMT", "BK")
+                .build()
+                .unwrap_err(),
+            BuildRecordError::InvalidTag("FMT".into())
+        );
+
+        assert_eq!(
+            RecordBuilder::default()
+                .leader(ldr)
+                .control_fields(rule)
+                .data_field("FMT", b' ', b' ', [(b'a', "BK")])
+                .build()
+                .unwrap_err(),
+            BuildRecordError::InvalidTag("FMT".into())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_builder_write_to() -> TestResult {
+        let record = RecordBuilder::default()
+            .leader(Leader::new(b"00000nz  a2200000nc 4500")?)
+            .data_field("100", b'1', b' ', [(b'a', "Lovelace, Ada")])
+            .control_field("001", "119232022")
+            .build()?;
+
+        let mut out = Vec::<u8>::new();
+        record.write_to(&mut out)?;
+
+        assert_eq!(
+            out,
+            b"00078nz  a2200049nc 4500\
+              001001000000100001800010\x1e\
+              119232022\x1e\
+              1 \x1faLovelace, Ada\x1e\x1d"
+        );
+
+        Ok(())
+    }
+}
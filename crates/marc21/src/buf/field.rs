@@ -0,0 +1,1005 @@
+use std::fmt::{self, Display};
+
+use bstr::BString;
+use winnow::Parser;
+
+use crate::common::{is_delimiter, is_indicator, is_subfield_code};
+use crate::error::BuildRecordError;
+use crate::tag::parse_tag;
+use crate::{
+    ControlField, ControlFieldRule, DataField, Field, Subfield, Tag,
+};
+
+/// Checks that the given bytes are a valid tag and returns the tag as a
+/// fixed-size array.
+pub(crate) fn tag_buf(tag: &[u8]) -> Result<[u8; 3], BuildRecordError> {
+    parse_tag
+        .parse(tag)
+        .map(|tag| [tag[0], tag[1], tag[2]])
+        .map_err(|_| BuildRecordError::InvalidTag(BString::from(tag)))
+}
+
+/// Checks that the value doesn't contain any delimiter.
+pub(crate) fn value_buf<V: Into<Vec<u8>>>(
+    value: V,
+) -> Result<Vec<u8>, BuildRecordError> {
+    let value = value.into();
+    if value.iter().any(|b| is_delimiter(*b)) {
+        return Err(BuildRecordError::InvalidValue(value.into()));
+    }
+
+    Ok(value)
+}
+
+/// Checks that the given byte is a valid indicator.
+pub(crate) fn indicator(value: u8) -> Result<u8, BuildRecordError> {
+    if !is_indicator(value) {
+        return Err(BuildRecordError::InvalidIndicator(value));
+    }
+
+    Ok(value)
+}
+
+/// An owned subfield.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SubfieldBuf {
+    pub(crate) code: u8,
+    pub(crate) value: Vec<u8>,
+}
+
+impl SubfieldBuf {
+    /// Creates a new owned subfield.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the code isn't an alphanumeric ASCII
+    /// character or the value contains a delimiter.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::SubfieldBuf;
+    ///
+    /// let subfield = SubfieldBuf::new(b'a', "abc")?;
+    /// assert_eq!(subfield.value(), b"abc");
+    ///
+    /// assert!(SubfieldBuf::new(b'!', "abc").is_err());
+    /// assert!(SubfieldBuf::new(b'a', "a\x1fbc").is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn new<V: Into<Vec<u8>>>(
+        code: u8,
+        value: V,
+    ) -> Result<Self, BuildRecordError> {
+        if !is_subfield_code(code) {
+            return Err(BuildRecordError::InvalidSubfieldCode(code));
+        }
+
+        Ok(Self {
+            code,
+            value: value_buf(value)?,
+        })
+    }
+
+    /// Returns the subfield code.
+    #[inline(always)]
+    pub fn code(&self) -> &u8 {
+        &self.code
+    }
+
+    /// Returns the subfield value.
+    #[inline(always)]
+    pub fn value(&self) -> &[u8] {
+        &self.value
+    }
+
+    /// Replaces the subfield value.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the value contains a delimiter.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::SubfieldBuf;
+    ///
+    /// let mut subfield = SubfieldBuf::new(b'a', "abc")?;
+    /// subfield.set_value("def")?;
+    /// assert_eq!(subfield.value(), b"def");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_value<V: Into<Vec<u8>>>(
+        &mut self,
+        value: V,
+    ) -> Result<(), BuildRecordError> {
+        self.value = value_buf(value)?;
+        Ok(())
+    }
+}
+
+impl From<&Subfield<'_>> for SubfieldBuf {
+    fn from(subfield: &Subfield<'_>) -> Self {
+        Self {
+            code: subfield.code,
+            value: subfield.value.to_vec(),
+        }
+    }
+}
+
+impl<'a> From<&'a SubfieldBuf> for Subfield<'a> {
+    fn from(subfield: &'a SubfieldBuf) -> Self {
+        Subfield::new(subfield.code, &subfield.value)
+    }
+}
+
+/// An owned control field.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ControlFieldBuf {
+    pub(crate) tag: [u8; 3],
+    pub(crate) value: Vec<u8>,
+}
+
+impl ControlFieldBuf {
+    /// Creates a new owned control field.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the tag isn't a control field tag or the
+    /// value contains a delimiter.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::ControlFieldBuf;
+    ///
+    /// let cf = ControlFieldBuf::new("001", "119232022")?;
+    /// assert_eq!(cf.tag(), "001");
+    /// assert_eq!(cf.value(), b"119232022");
+    ///
+    /// assert!(ControlFieldBuf::new("100", "119232022").is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn new<T, V>(tag: T, value: V) -> Result<Self, BuildRecordError>
+    where
+        T: AsRef<[u8]>,
+        V: Into<Vec<u8>>,
+    {
+        Self::new_with(tag, value, &ControlFieldRule::default())
+    }
+
+    /// Creates a new owned control field, whose tag is a control field
+    /// tag according to the given rule (e.g. a local control field
+    /// like `FMT`).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{ControlFieldBuf, ControlFieldRule};
+    ///
+    /// let rule = "00., FMT".parse::<ControlFieldRule>()?;
+    /// let cf = ControlFieldBuf::new_with("FMT", "BK", &rule)?;
+    /// assert_eq!(cf.tag(), "FMT");
+    ///
+    /// assert!(ControlFieldBuf::new("FMT", "BK").is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn new_with<T, V>(
+        tag: T,
+        value: V,
+        rule: &ControlFieldRule,
+    ) -> Result<Self, BuildRecordError>
+    where
+        T: AsRef<[u8]>,
+        V: Into<Vec<u8>>,
+    {
+        let tag = tag_buf(tag.as_ref())?;
+        if !rule.is_control_field(&Tag(&tag)) {
+            return Err(BuildRecordError::InvalidTag(tag.into()));
+        }
+
+        Ok(Self {
+            tag,
+            value: value_buf(value)?,
+        })
+    }
+
+    /// Returns the tag of the control field.
+    #[inline(always)]
+    pub fn tag(&self) -> Tag<'_> {
+        Tag(&self.tag)
+    }
+
+    /// Returns the value of the control field.
+    #[inline(always)]
+    pub fn value(&self) -> &[u8] {
+        &self.value
+    }
+
+    /// Replaces the value of the control field.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the value contains a delimiter.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::ControlFieldBuf;
+    ///
+    /// let mut cf = ControlFieldBuf::new("001", "119232022")?;
+    /// cf.set_value("119232023")?;
+    /// assert_eq!(cf.value(), b"119232023");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_value<V: Into<Vec<u8>>>(
+        &mut self,
+        value: V,
+    ) -> Result<(), BuildRecordError> {
+        self.value = value_buf(value)?;
+        Ok(())
+    }
+}
+
+impl From<&ControlField<'_>> for ControlFieldBuf {
+    fn from(cf: &ControlField<'_>) -> Self {
+        Self {
+            tag: [cf.tag[0], cf.tag[1], cf.tag[2]],
+            value: cf.value.to_vec(),
+        }
+    }
+}
+
+impl<'a> From<&'a ControlFieldBuf> for ControlField<'a> {
+    fn from(cf: &'a ControlFieldBuf) -> Self {
+        ControlField::new(Tag(&cf.tag), &cf.value)
+    }
+}
+
+/// An owned data field.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DataFieldBuf {
+    pub(crate) tag: [u8; 3],
+    pub(crate) indicator1: u8,
+    pub(crate) indicator2: u8,
+    pub(crate) subfields: Vec<SubfieldBuf>,
+}
+
+impl DataFieldBuf {
+    /// Creates a new owned data field without subfields.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the tag isn't a data field tag or an
+    /// indicator is invalid.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::DataFieldBuf;
+    ///
+    /// let df = DataFieldBuf::new("100", b'1', b' ')?;
+    /// assert_eq!(df.tag(), "100");
+    /// assert_eq!(df.subfields().count(), 0);
+    ///
+    /// assert!(DataFieldBuf::new("001", b'1', b' ').is_err());
+    /// assert!(DataFieldBuf::new("100", b'#', b' ').is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn new<T: AsRef<[u8]>>(
+        tag: T,
+        indicator1: u8,
+        indicator2: u8,
+    ) -> Result<Self, BuildRecordError> {
+        Self::new_with(
+            tag,
+            indicator1,
+            indicator2,
+            &ControlFieldRule::default(),
+        )
+    }
+
+    /// Creates a new owned data field without subfields, whose tag
+    /// isn't a control field tag according to the given rule.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{ControlFieldRule, DataFieldBuf};
+    ///
+    /// let rule = "00., FMT".parse::<ControlFieldRule>()?;
+    /// assert!(DataFieldBuf::new("FMT", b' ', b' ').is_ok());
+    /// assert!(DataFieldBuf::new_with("FMT", b' ', b' ', &rule).is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn new_with<T: AsRef<[u8]>>(
+        tag: T,
+        indicator1: u8,
+        indicator2: u8,
+        rule: &ControlFieldRule,
+    ) -> Result<Self, BuildRecordError> {
+        let tag = tag_buf(tag.as_ref())?;
+        if rule.is_control_field(&Tag(&tag)) {
+            return Err(BuildRecordError::InvalidTag(tag.into()));
+        }
+
+        Ok(Self {
+            tag,
+            indicator1: indicator(indicator1)?,
+            indicator2: indicator(indicator2)?,
+            subfields: vec![],
+        })
+    }
+
+    /// Returns the tag of the data field.
+    #[inline(always)]
+    pub fn tag(&self) -> Tag<'_> {
+        Tag(&self.tag)
+    }
+
+    /// Returns the first indicator.
+    #[inline(always)]
+    pub fn indicator1(&self) -> &u8 {
+        &self.indicator1
+    }
+
+    /// Returns the second indicator.
+    #[inline(always)]
+    pub fn indicator2(&self) -> &u8 {
+        &self.indicator2
+    }
+
+    /// Returns an iterator over the subfields.
+    #[inline(always)]
+    pub fn subfields(&self) -> impl Iterator<Item = &SubfieldBuf> {
+        self.subfields.iter()
+    }
+
+    /// Appends a subfield to the data field.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{DataFieldBuf, SubfieldBuf};
+    ///
+    /// let mut df = DataFieldBuf::new("100", b'1', b' ')?;
+    /// df.push(SubfieldBuf::new(b'a', "Lovelace, Ada")?);
+    /// assert_eq!(df.subfields().count(), 1);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn push(&mut self, subfield: SubfieldBuf) {
+        self.subfields.push(subfield);
+    }
+
+    /// Sets the first indicator.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the indicator is not a blank, a
+    /// lowercase letter or a digit.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::DataFieldBuf;
+    ///
+    /// let mut df = DataFieldBuf::new("100", b'1', b' ')?;
+    /// df.set_indicator1(b'0')?;
+    /// assert_eq!(df.indicator1(), &b'0');
+    /// assert!(df.set_indicator1(b'#').is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_indicator1(
+        &mut self,
+        value: u8,
+    ) -> Result<(), BuildRecordError> {
+        self.indicator1 = indicator(value)?;
+        Ok(())
+    }
+
+    /// Sets the second indicator.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the indicator is not a blank, a
+    /// lowercase letter or a digit.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::DataFieldBuf;
+    ///
+    /// let mut df = DataFieldBuf::new("100", b'1', b' ')?;
+    /// df.set_indicator2(b'4')?;
+    /// assert_eq!(df.indicator2(), &b'4');
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_indicator2(
+        &mut self,
+        value: u8,
+    ) -> Result<(), BuildRecordError> {
+        self.indicator2 = indicator(value)?;
+        Ok(())
+    }
+
+    /// Returns an iterator over the subfields, which allows modifying
+    /// each subfield.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{DataFieldBuf, SubfieldBuf};
+    ///
+    /// let mut df = DataFieldBuf::new("100", b'1', b' ')?;
+    /// df.push(SubfieldBuf::new(b'a', "Lovelace, Ada")?);
+    ///
+    /// for subfield in df.subfields_mut() {
+    ///     subfield.set_value("Lovelace, Augusta Ada")?;
+    /// }
+    ///
+    /// assert_eq!(df.to_string(), "100/1# $a Lovelace, Augusta Ada");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline(always)]
+    pub fn subfields_mut(
+        &mut self,
+    ) -> impl Iterator<Item = &mut SubfieldBuf> {
+        self.subfields.iter_mut()
+    }
+
+    /// Inserts a subfield at position `index`, shifting all subfields
+    /// after it to the right.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`, where `len` is the number of subfields.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{DataFieldBuf, SubfieldBuf};
+    ///
+    /// let mut df = DataFieldBuf::new("100", b'1', b' ')?;
+    /// df.push(SubfieldBuf::new(b'd', "1815-1852")?);
+    /// df.insert(0, SubfieldBuf::new(b'a', "Lovelace, Ada")?);
+    ///
+    /// assert_eq!(df.to_string(), "100/1# $a Lovelace, Ada $d 1815-1852");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn insert(&mut self, index: usize, subfield: SubfieldBuf) {
+        self.subfields.insert(index, subfield);
+    }
+
+    /// Replaces the values of all subfields with the given code and
+    /// returns the number of replaced values.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the value contains a delimiter.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{DataFieldBuf, SubfieldBuf};
+    ///
+    /// let mut df = DataFieldBuf::new("100", b'1', b' ')?;
+    /// df.push(SubfieldBuf::new(b'a', "Lovelace, Ada")?);
+    /// df.push(SubfieldBuf::new(b'd', "1815-1852")?);
+    ///
+    /// assert_eq!(df.replace_values(b'd', "1815-1852 [!]")?, 1);
+    /// assert_eq!(df.replace_values(b'x', "abc")?, 0);
+    /// assert_eq!(
+    ///     df.to_string(),
+    ///     "100/1# $a Lovelace, Ada $d 1815-1852 [!]"
+    /// );
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn replace_values<V: Into<Vec<u8>>>(
+        &mut self,
+        code: u8,
+        value: V,
+    ) -> Result<usize, BuildRecordError> {
+        let value = value_buf(value)?;
+        let mut count = 0;
+
+        for subfield in self.subfields.iter_mut() {
+            if subfield.code == code {
+                subfield.value.clone_from(&value);
+                count += 1;
+            }
+        }
+
+        Ok(count)
+    }
+
+    /// Removes all subfields with the given code and returns the number
+    /// of removed subfields.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{DataFieldBuf, SubfieldBuf};
+    ///
+    /// let mut df = DataFieldBuf::new("100", b'1', b' ')?;
+    /// df.push(SubfieldBuf::new(b'a', "Lovelace, Ada")?);
+    /// df.push(SubfieldBuf::new(b'd', "1815-1852")?);
+    ///
+    /// assert_eq!(df.remove_subfields(b'd'), 1);
+    /// assert_eq!(df.to_string(), "100/1# $a Lovelace, Ada");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn remove_subfields(&mut self, code: u8) -> usize {
+        self.retain_subfields(|subfield| subfield.code != code)
+    }
+
+    /// Retains only the subfields specified by the predicate and
+    /// returns the number of removed subfields.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{DataFieldBuf, SubfieldBuf};
+    ///
+    /// let mut df = DataFieldBuf::new("100", b'1', b' ')?;
+    /// df.push(SubfieldBuf::new(b'a', "Lovelace, Ada")?);
+    /// df.push(SubfieldBuf::new(b'0', "(DE-588)119232022")?);
+    /// df.push(SubfieldBuf::new(b'0', "(DE-101)119232022")?);
+    ///
+    /// let removed = df.retain_subfields(|subfield| {
+    ///     !subfield.value().starts_with(b"(DE-101)")
+    /// });
+    ///
+    /// assert_eq!(removed, 1);
+    /// assert_eq!(df.subfields().count(), 2);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn retain_subfields<F>(&mut self, f: F) -> usize
+    where
+        F: FnMut(&SubfieldBuf) -> bool,
+    {
+        let len = self.subfields.len();
+        self.subfields.retain(f);
+        len - self.subfields.len()
+    }
+}
+
+impl From<&DataField<'_>> for DataFieldBuf {
+    fn from(df: &DataField<'_>) -> Self {
+        Self {
+            tag: [df.tag[0], df.tag[1], df.tag[2]],
+            indicator1: df.indicator1,
+            indicator2: df.indicator2,
+            subfields: df.subfields().map(SubfieldBuf::from).collect(),
+        }
+    }
+}
+
+impl<'a> From<&'a DataFieldBuf> for DataField<'a> {
+    fn from(df: &'a DataFieldBuf) -> Self {
+        DataField::new(
+            Tag(&df.tag),
+            df.indicator1,
+            df.indicator2,
+            df.subfields.iter().map(Subfield::from).collect(),
+        )
+    }
+}
+
+/// An owned field.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FieldBuf {
+    Control(ControlFieldBuf),
+    Data(DataFieldBuf),
+}
+
+impl FieldBuf {
+    /// Returns the tag of the field.
+    pub fn tag(&self) -> Tag<'_> {
+        match self {
+            Self::Control(cf) => cf.tag(),
+            Self::Data(df) => df.tag(),
+        }
+    }
+
+    #[inline(always)]
+    pub fn is_control_field(&self) -> bool {
+        matches!(self, Self::Control(_))
+    }
+
+    #[inline(always)]
+    pub fn is_data_field(&self) -> bool {
+        matches!(self, Self::Data(_))
+    }
+
+    /// Returns a mutable reference to the control field, or `None` if
+    /// the field is a data field.
+    #[inline(always)]
+    pub fn as_control_field_mut(
+        &mut self,
+    ) -> Option<&mut ControlFieldBuf> {
+        match self {
+            Self::Control(cf) => Some(cf),
+            Self::Data(_) => None,
+        }
+    }
+
+    /// Returns a mutable reference to the data field, or `None` if the
+    /// field is a control field.
+    #[inline(always)]
+    pub fn as_data_field_mut(&mut self) -> Option<&mut DataFieldBuf> {
+        match self {
+            Self::Data(df) => Some(df),
+            Self::Control(_) => None,
+        }
+    }
+}
+
+impl From<&Field<'_>> for FieldBuf {
+    fn from(field: &Field<'_>) -> Self {
+        match field {
+            Field::Control(cf) => Self::Control(cf.into()),
+            Field::Data(df) => Self::Data(df.into()),
+        }
+    }
+}
+
+impl<'a> From<&'a FieldBuf> for Field<'a> {
+    fn from(field: &'a FieldBuf) -> Self {
+        match field {
+            FieldBuf::Control(cf) => Field::Control(cf.into()),
+            FieldBuf::Data(df) => Field::Data(df.into()),
+        }
+    }
+}
+
+impl From<ControlFieldBuf> for FieldBuf {
+    fn from(cf: ControlFieldBuf) -> Self {
+        Self::Control(cf)
+    }
+}
+
+impl From<DataFieldBuf> for FieldBuf {
+    fn from(df: DataFieldBuf) -> Self {
+        Self::Data(df)
+    }
+}
+
+impl Display for FieldBuf {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", Field::from(self))
+    }
+}
+
+impl Display for ControlFieldBuf {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", ControlField::from(self))
+    }
+}
+
+impl Display for DataFieldBuf {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", DataField::from(self))
+    }
+}
+
+impl Display for SubfieldBuf {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", Subfield::from(self))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for SubfieldBuf {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        Subfield::from(self).serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for SubfieldBuf {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::Error;
+
+        #[derive(serde::Deserialize)]
+        #[serde(rename = "Subfield")]
+        struct Repr {
+            code: char,
+            #[serde(
+                deserialize_with = "crate::common::deserialize_value"
+            )]
+            value: Vec<u8>,
+        }
+
+        let repr = Repr::deserialize(deserializer)?;
+        let code = u8::try_from(repr.code).map_err(|_| {
+            D::Error::custom(format!(
+                "invalid subfield code '{}'",
+                repr.code
+            ))
+        })?;
+
+        Self::new(code, repr.value).map_err(D::Error::custom)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for ControlFieldBuf {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        ControlField::from(self).serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ControlFieldBuf {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(serde::Deserialize)]
+        #[serde(rename = "ControlField")]
+        struct Repr {
+            tag: String,
+            #[serde(
+                deserialize_with = "crate::common::deserialize_value"
+            )]
+            value: Vec<u8>,
+        }
+
+        let repr = Repr::deserialize(deserializer)?;
+        Self::new(repr.tag, repr.value)
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for DataFieldBuf {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        DataField::from(self).serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for DataFieldBuf {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::Error;
+
+        #[derive(serde::Deserialize)]
+        #[serde(rename = "DataField")]
+        struct Repr {
+            tag: String,
+            ind1: char,
+            ind2: char,
+            subfields: Vec<SubfieldBuf>,
+        }
+
+        let repr = Repr::deserialize(deserializer)?;
+        let indicator = |ind: char| {
+            u8::try_from(ind).map_err(|_| {
+                D::Error::custom(format!("invalid indicator '{ind}'"))
+            })
+        };
+
+        let mut df = Self::new(
+            repr.tag,
+            indicator(repr.ind1)?,
+            indicator(repr.ind2)?,
+        )
+        .map_err(D::Error::custom)?;
+
+        df.subfields = repr.subfields;
+        Ok(df)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for FieldBuf {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        Field::from(self).serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for FieldBuf {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(serde::Deserialize)]
+        #[serde(rename = "Field", rename_all = "lowercase")]
+        enum Repr {
+            Control(ControlFieldBuf),
+            Data(DataFieldBuf),
+        }
+
+        Ok(match Repr::deserialize(deserializer)? {
+            Repr::Control(cf) => Self::Control(cf),
+            Repr::Data(df) => Self::Data(df),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::TestResult;
+
+    #[test]
+    fn test_tag_buf() {
+        assert_eq!(tag_buf(b"001").unwrap(), *b"001");
+        assert!(tag_buf(b"01").is_err());
+        assert!(tag_buf(b"0011").is_err());
+        assert!(tag_buf(b"00-").is_err());
+        assert_eq!(tag_buf(b"CAT").unwrap(), *b"CAT");
+    }
+
+    #[test]
+    fn test_value_buf() {
+        assert_eq!(value_buf("abc").unwrap(), b"abc");
+        assert!(value_buf("a\x1dbc").is_err());
+        assert!(value_buf("a\x1ebc").is_err());
+        assert!(value_buf("a\x1fbc").is_err());
+    }
+
+    #[test]
+    fn test_field_buf_roundtrip() -> TestResult {
+        let mut df = DataFieldBuf::new("100", b'1', b' ')?;
+        df.push(SubfieldBuf::new(b'a', "Lovelace, Ada")?);
+        df.push(SubfieldBuf::new(b'd', "1815-1852")?);
+
+        let field = FieldBuf::from(df);
+        assert_eq!(
+            field.to_string(),
+            "100/1# $a Lovelace, Ada $d 1815-1852"
+        );
+        assert_eq!(FieldBuf::from(&Field::from(&field)), field);
+
+        let field = FieldBuf::from(ControlFieldBuf::new("001", "123")?);
+        assert_eq!(field.to_string(), "001 123");
+        assert_eq!(FieldBuf::from(&Field::from(&field)), field);
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_field_buf_serde() -> TestResult {
+        use serde_test::{
+            Token, assert_de_tokens_error, assert_tokens,
+        };
+
+        assert_tokens(
+            &SubfieldBuf::new(b'a', "abc")?,
+            &[
+                Token::Struct {
+                    name: "Subfield",
+                    len: 2,
+                },
+                Token::Str("code"),
+                Token::Char('a'),
+                Token::Str("value"),
+                Token::Str("abc"),
+                Token::StructEnd,
+            ],
+        );
+
+        assert_tokens(
+            &FieldBuf::from(ControlFieldBuf::new("001", "123")?),
+            &[
+                Token::NewtypeVariant {
+                    name: "Field",
+                    variant: "control",
+                },
+                Token::Struct {
+                    name: "ControlField",
+                    len: 2,
+                },
+                Token::Str("tag"),
+                Token::Str("001"),
+                Token::Str("value"),
+                Token::Str("123"),
+                Token::StructEnd,
+            ],
+        );
+
+        let mut df = DataFieldBuf::new("100", b'1', b' ')?;
+        df.push(SubfieldBuf::new(b'a', b"Ada\xff")?);
+
+        assert_tokens(
+            &FieldBuf::from(df),
+            &[
+                Token::NewtypeVariant {
+                    name: "Field",
+                    variant: "data",
+                },
+                Token::Struct {
+                    name: "DataField",
+                    len: 4,
+                },
+                Token::Str("tag"),
+                Token::Str("100"),
+                Token::Str("ind1"),
+                Token::Char('1'),
+                Token::Str("ind2"),
+                Token::Char(' '),
+                Token::Str("subfields"),
+                Token::Seq { len: Some(1) },
+                Token::Struct {
+                    name: "Subfield",
+                    len: 2,
+                },
+                Token::Str("code"),
+                Token::Char('a'),
+                Token::Str("value"),
+                Token::Bytes(b"Ada\xff"),
+                Token::StructEnd,
+                Token::SeqEnd,
+                Token::StructEnd,
+            ],
+        );
+
+        assert_de_tokens_error::<ControlFieldBuf>(
+            &[
+                Token::Struct {
+                    name: "ControlField",
+                    len: 2,
+                },
+                Token::Str("tag"),
+                Token::Str("100"),
+                Token::Str("value"),
+                Token::Str("123"),
+                Token::StructEnd,
+            ],
+            "invalid tag '100'",
+        );
+
+        assert_de_tokens_error::<SubfieldBuf>(
+            &[
+                Token::Struct {
+                    name: "Subfield",
+                    len: 2,
+                },
+                Token::Str("code"),
+                Token::Char('ä'),
+                Token::Str("value"),
+                Token::Str("abc"),
+                Token::StructEnd,
+            ],
+            "invalid subfield code 'ä'",
+        );
+
+        Ok(())
+    }
+}
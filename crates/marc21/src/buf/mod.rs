@@ -0,0 +1,473 @@
+use std::fmt::{self, Display};
+use std::io::{self, Write};
+
+pub use builder::RecordBuilder;
+pub use field::{ControlFieldBuf, DataFieldBuf, FieldBuf, SubfieldBuf};
+
+use crate::matcher::{FieldMatcher, MatchOptions, TagMatcher};
+use crate::{ByteRecord, Field, Leader};
+
+mod builder;
+mod field;
+
+/// An owned record.
+///
+/// In contrast to a [ByteRecord], which borrows its data from an
+/// underlying buffer, an owned record holds its data and can be kept
+/// beyond the lifetime of the buffer it was read from. The record can
+/// be created from scratch with a [RecordBuilder].
+///
+/// If the `serde` feature is enabled, records can be serialized with
+/// any serde data format and deserialized into a `RecordBuf`.
+///
+/// # Example
+///
+/// ```rust
+/// use marc21::RecordBuf;
+/// use marc21::matcher::RecordMatcher;
+/// use marc21::prelude::*;
+///
+/// let data = include_bytes!("../../tests/data/ada.mrc");
+/// let record = RecordBuf::from(ByteRecord::from_bytes(data)?);
+///
+/// let matcher = RecordMatcher::new("001 == '119232022'")?;
+/// assert!(
+///     matcher.is_match(&record.as_byte_record(), &Default::default())
+/// );
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecordBuf {
+    pub(crate) leader: Leader,
+    pub(crate) fields: Vec<FieldBuf>,
+}
+
+impl RecordBuf {
+    /// Creates a new record with the given leader and without any
+    /// fields.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{Leader, RecordBuf};
+    ///
+    /// let ldr = Leader::new(b"00000nz  a2200000nc 4500")?;
+    /// let record = RecordBuf::new(ldr);
+    /// assert_eq!(record.fields().count(), 0);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn new(leader: Leader) -> Self {
+        Self {
+            leader,
+            fields: vec![],
+        }
+    }
+
+    /// Returns a builder to create a new record.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{Leader, RecordBuf};
+    ///
+    /// let record = RecordBuf::builder()
+    ///     .leader(Leader::new(b"00000nz  a2200000nc 4500")?)
+    ///     .control_field("001", "119232022")
+    ///     .data_field("100", b'1', b' ', [(b'a', "Lovelace, Ada")])
+    ///     .build()?;
+    ///
+    /// assert_eq!(record.fields().count(), 2);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn builder() -> RecordBuilder {
+        RecordBuilder::default()
+    }
+
+    /// Returns the leader of the record.
+    ///
+    /// Note that the record length and the base address of data are
+    /// only updated when the record is written.
+    #[inline(always)]
+    pub fn leader(&self) -> &Leader {
+        &self.leader
+    }
+
+    /// Returns an iterator over the record's fields.
+    #[inline(always)]
+    pub fn fields(&self) -> impl Iterator<Item = &FieldBuf> {
+        self.fields.iter()
+    }
+
+    /// Returns an iterator over the record's fields, which allows
+    /// modifying each field.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::RecordBuf;
+    /// use marc21::prelude::*;
+    ///
+    /// let data = include_bytes!("../../tests/data/ada.mrc");
+    /// let mut record = RecordBuf::from(ByteRecord::from_bytes(data)?);
+    ///
+    /// for field in record.fields_mut() {
+    ///     if field.tag() == "100" {
+    ///         let df = field.as_data_field_mut().unwrap();
+    ///         df.set_indicator1(b'0')?;
+    ///     }
+    /// }
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline(always)]
+    pub fn fields_mut(
+        &mut self,
+    ) -> impl Iterator<Item = &mut FieldBuf> {
+        self.fields.iter_mut()
+    }
+
+    /// Inserts a field in tag order.
+    ///
+    /// The field is inserted after the last field whose tag is less
+    /// than or equal to the tag of the new field. Thus, a field is
+    /// appended to a group of fields with the same tag.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{ControlFieldBuf, DataFieldBuf, Leader, RecordBuf};
+    ///
+    /// let mut record = RecordBuf::builder()
+    ///     .leader(Leader::new(b"00000nz  a2200000nc 4500")?)
+    ///     .control_field("001", "119232022")
+    ///     .data_field("100", b'1', b' ', [(b'a', "Lovelace, Ada")])
+    ///     .build()?;
+    ///
+    /// record.insert(DataFieldBuf::new("075", b' ', b'7')?);
+    /// record.insert(ControlFieldBuf::new("003", "DE-101")?);
+    ///
+    /// let tags = record
+    ///     .fields()
+    ///     .map(|field| field.tag().to_string())
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(tags, vec!["001", "003", "075", "100"]);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn insert<F: Into<FieldBuf>>(&mut self, field: F) {
+        let field = field.into();
+        let index = self
+            .fields
+            .iter()
+            .rposition(|other| other.tag().0 <= field.tag().0)
+            .map_or(0, |i| i + 1);
+
+        self.fields.insert(index, field);
+    }
+
+    /// Removes all fields whose tag matches the given [TagMatcher] and
+    /// returns the number of removed fields.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::RecordBuf;
+    /// use marc21::matcher::TagMatcher;
+    /// use marc21::prelude::*;
+    ///
+    /// let data = include_bytes!("../../tests/data/ada.mrc");
+    /// let mut record = RecordBuf::from(ByteRecord::from_bytes(data)?);
+    ///
+    /// let matcher = TagMatcher::new("8..")?;
+    /// assert_eq!(record.remove_fields_by_tag(&matcher), 0);
+    ///
+    /// let matcher = TagMatcher::new("0[0-2].")?;
+    /// assert_eq!(record.remove_fields_by_tag(&matcher), 5);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn remove_fields_by_tag(
+        &mut self,
+        matcher: &TagMatcher,
+    ) -> usize {
+        self.retain_fields(|field| !matcher.is_match(&field.tag()))
+    }
+
+    /// Removes all fields that match the given [FieldMatcher] and
+    /// returns the number of removed fields.
+    ///
+    /// The matcher is applied to each field on its own.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::RecordBuf;
+    /// use marc21::matcher::FieldMatcher;
+    /// use marc21::prelude::*;
+    ///
+    /// let data = include_bytes!("../../tests/data/ada.mrc");
+    /// let mut record = RecordBuf::from(ByteRecord::from_bytes(data)?);
+    ///
+    /// let matcher = FieldMatcher::new("075{ b == 'piz' }")?;
+    /// assert_eq!(record.remove_fields(&matcher, &Default::default()), 1);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn remove_fields(
+        &mut self,
+        matcher: &FieldMatcher,
+        options: &MatchOptions,
+    ) -> usize {
+        self.retain_fields(|field| {
+            let field = Field::from(field);
+            !matcher.is_match(std::iter::once(&field), options)
+        })
+    }
+
+    /// Retains only the fields specified by the predicate and returns
+    /// the number of removed fields.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::RecordBuf;
+    /// use marc21::prelude::*;
+    ///
+    /// let data = include_bytes!("../../tests/data/ada.mrc");
+    /// let mut record = RecordBuf::from(ByteRecord::from_bytes(data)?);
+    ///
+    /// let removed = record.retain_fields(|field| field.is_data_field());
+    /// assert_eq!(removed, 4);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn retain_fields<F>(&mut self, f: F) -> usize
+    where
+        F: FnMut(&FieldBuf) -> bool,
+    {
+        let len = self.fields.len();
+        self.fields.retain(f);
+        len - self.fields.len()
+    }
+
+    /// Returns a borrowed view of the record, which can be used with
+    /// matchers, queries and paths.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{Leader, Path, RecordBuf};
+    ///
+    /// let record = RecordBuf::builder()
+    ///     .leader(Leader::new(b"00000nz  a2200000nc 4500")?)
+    ///     .control_field("001", "119232022")
+    ///     .build()?;
+    ///
+    /// let record = record.as_byte_record();
+    /// let path = Path::new("001")?;
+    /// assert_eq!(
+    ///     record.path(&path, &Default::default()),
+    ///     vec!["119232022"]
+    /// );
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn as_byte_record(&self) -> ByteRecord<'_> {
+        ByteRecord::from(self)
+    }
+
+    /// Write the record into the given writer.
+    ///
+    /// The record length, the base address of data and the directory
+    /// are computed from the fields of the record.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::RecordBuf;
+    /// use marc21::prelude::*;
+    ///
+    /// let data = include_bytes!("../../tests/data/ada.mrc");
+    /// let record = RecordBuf::from(ByteRecord::from_bytes(data)?);
+    ///
+    /// let mut out = Vec::<u8>::new();
+    /// record.write_to(&mut out)?;
+    /// assert_eq!(out, data);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn write_to<W: Write>(&self, out: &mut W) -> io::Result<()> {
+        self.as_byte_record().write_to(out)
+    }
+}
+
+impl From<&ByteRecord<'_>> for RecordBuf {
+    fn from(record: &ByteRecord<'_>) -> Self {
+        Self {
+            leader: record.leader().clone(),
+            fields: record.fields().map(FieldBuf::from).collect(),
+        }
+    }
+}
+
+impl From<ByteRecord<'_>> for RecordBuf {
+    #[inline]
+    fn from(record: ByteRecord<'_>) -> Self {
+        Self::from(&record)
+    }
+}
+
+impl<'a> From<&'a RecordBuf> for ByteRecord<'a> {
+    fn from(record: &'a RecordBuf) -> Self {
+        ByteRecord::from_fields(
+            record.leader.clone(),
+            record.fields.iter().map(Field::from).collect(),
+        )
+    }
+}
+
+impl Display for RecordBuf {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_byte_record())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for RecordBuf {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.as_byte_record().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for RecordBuf {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(serde::Deserialize)]
+        #[serde(rename = "Record")]
+        struct Repr {
+            leader: Leader,
+            fields: Vec<FieldBuf>,
+        }
+
+        let repr = Repr::deserialize(deserializer)?;
+        Ok(Self {
+            leader: repr.leader,
+            fields: repr.fields,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::TestResult;
+
+    #[test]
+    fn test_record_buf_from_byte_record() -> TestResult {
+        let data = include_bytes!("../../../../tests/data/ada.mrc");
+        let record = ByteRecord::from_bytes(data)?;
+        let record_buf = RecordBuf::from(&record);
+
+        assert_eq!(record_buf.leader(), record.leader());
+        assert_eq!(record_buf.fields().count(), 47);
+        assert_eq!(record_buf.to_string(), record.to_string());
+
+        let mut out = Vec::<u8>::new();
+        record_buf.write_to(&mut out)?;
+        assert_eq!(out, data);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_record_buf_edit_write_to() -> TestResult {
+        let data = include_bytes!("../../../../tests/data/ada.mrc");
+        let mut record = RecordBuf::from(ByteRecord::from_bytes(data)?);
+
+        let matcher = TagMatcher::new("4..")?;
+        assert_eq!(record.remove_fields_by_tag(&matcher), 13);
+
+        for field in record.fields_mut() {
+            if let Some(df) = field.as_data_field_mut()
+                && df.tag() == "100"
+            {
+                df.set_indicator1(b'0')?;
+                df.replace_values(b'a', "Lovelace, Augusta Ada")?;
+                df.remove_subfields(b'd');
+                df.push(SubfieldBuf::new(
+                    b'c',
+                    "Countess of Lovelace",
+                )?);
+            }
+        }
+
+        record.insert(DataFieldBuf::new("400", b'1', b' ')?);
+
+        let mut out = Vec::<u8>::new();
+        record.write_to(&mut out)?;
+
+        let result = ByteRecord::from_bytes(&out).unwrap();
+        assert_eq!(result.leader().length() as usize, out.len());
+        assert_eq!(result.fields().count(), 35);
+        assert!(RecordBuf::from(&result).fields().eq(record.fields()));
+
+        let df =
+            result.fields().find(|field| field.tag() == "100").unwrap();
+        assert_eq!(
+            df.to_string(),
+            "100/0# $a Lovelace, Augusta Ada $c Countess of Lovelace"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_record_buf_outlives_buffer() -> TestResult {
+        let record = {
+            let data = include_bytes!("../../../../tests/data/ada.mrc")
+                .to_vec();
+            RecordBuf::from(ByteRecord::from_bytes(&data).unwrap())
+        };
+
+        assert_eq!(
+            record.as_byte_record().control_number().unwrap(),
+            "119232022"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_record_buf_serde_json() -> TestResult {
+        use crate::StringRecord;
+
+        let data = include_bytes!("../../../../tests/data/ada.mrc");
+        let record = ByteRecord::from_bytes(data)?;
+
+        let json = serde_json::to_string(&record)?;
+        assert!(json.starts_with(
+            "{\"leader\":\"03612nz  a2200589nc 4500\",\"fields\":\
+             [{\"control\":{\"tag\":\"001\",\"value\":\"119232022\"}}"
+        ));
+
+        let record_buf: RecordBuf = serde_json::from_str(&json)?;
+        assert_eq!(record_buf, RecordBuf::from(&record));
+        assert_eq!(serde_json::to_string(&record_buf)?, json);
+
+        let string_record = StringRecord::try_from(record)?;
+        assert_eq!(serde_json::to_string(&string_record)?, json);
+
+        Ok(())
+    }
+}
@@ -6,9 +6,109 @@ use winnow::token::one_of;
 #[cfg(test)]
 pub(crate) type TestResult = Result<(), Box<dyn std::error::Error>>;
 
+pub(crate) const GROUP_SEPARATOR: u8 = b'\x1d';
 pub(crate) const RECORD_SEPARATOR: u8 = b'\x1e';
 pub(crate) const UNIT_SEPARATOR: u8 = b'\x1f';
 
+/// The maximum length of a record (five decimal digits).
+pub(crate) const MAX_RECORD_LENGTH: usize = 99999;
+
+/// Returns true if the byte is a valid indicator value (a blank, a
+/// lowercase ASCII letter or an ASCII digit).
+#[inline(always)]
+pub(crate) fn is_indicator(b: u8) -> bool {
+    b == b' ' || b.is_ascii_lowercase() || b.is_ascii_digit()
+}
+
+/// Returns true if the byte is a valid subfield code.
+#[inline(always)]
+pub(crate) fn is_subfield_code(b: u8) -> bool {
+    b.is_ascii_alphanumeric()
+}
+
+/// Returns true if the byte is one of the structural delimiters, which
+/// must not occur within a field value.
+#[inline(always)]
+pub(crate) fn is_delimiter(b: u8) -> bool {
+    b == GROUP_SEPARATOR || b == RECORD_SEPARATOR || b == UNIT_SEPARATOR
+}
+
+/// Serializes a value as string, if it's valid UTF-8, and as a byte
+/// array otherwise.
+#[cfg(feature = "serde")]
+pub(crate) fn serialize_value<S>(
+    value: &[u8],
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    match std::str::from_utf8(value) {
+        Ok(s) => serializer.serialize_str(s),
+        Err(_) => serializer.serialize_bytes(value),
+    }
+}
+
+/// Deserializes a value, that was serialized either as string or as
+/// byte array.
+#[cfg(feature = "serde")]
+pub(crate) fn deserialize_value<'de, D>(
+    deserializer: D,
+) -> Result<Vec<u8>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    struct ValueVisitor;
+
+    impl<'de> serde::de::Visitor<'de> for ValueVisitor {
+        type Value = Vec<u8>;
+
+        fn expecting(
+            &self,
+            f: &mut std::fmt::Formatter,
+        ) -> std::fmt::Result {
+            f.write_str("a string or a byte array")
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> {
+            Ok(v.as_bytes().to_vec())
+        }
+
+        fn visit_string<E>(self, v: String) -> Result<Self::Value, E> {
+            Ok(v.into_bytes())
+        }
+
+        fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E> {
+            Ok(v.to_vec())
+        }
+
+        fn visit_byte_buf<E>(
+            self,
+            v: Vec<u8>,
+        ) -> Result<Self::Value, E> {
+            Ok(v)
+        }
+
+        fn visit_seq<A>(
+            self,
+            mut seq: A,
+        ) -> Result<Self::Value, A::Error>
+        where
+            A: serde::de::SeqAccess<'de>,
+        {
+            let mut value =
+                Vec::with_capacity(seq.size_hint().unwrap_or(0));
+            while let Some(b) = seq.next_element()? {
+                value.push(b);
+            }
+
+            Ok(value)
+        }
+    }
+
+    deserializer.deserialize_byte_buf(ValueVisitor)
+}
+
 #[cfg_attr(feature = "perf-inline", inline(always))]
 pub(crate) fn parse_digits_u32(i: &mut &[u8]) -> ModalResult<u32> {
     repeat(5usize, one_of(AsChar::is_dec_digit))
@@ -16,11 +116,25 @@ pub(crate) fn parse_digits_u32(i: &mut &[u8]) -> ModalResult<u32> {
         .parse_next(i)
 }
 
+/// Parses a number consisting of exactly `width` decimal digits.
+///
+/// The width must not exceed nine digits, so that the value always
+/// fits into an `u32`.
+pub(crate) fn parse_digits<'a>(
+    width: usize,
+) -> impl FnMut(&mut &'a [u8]) -> ModalResult<u32> {
+    debug_assert!(width <= 9);
+
+    move |i: &mut &'a [u8]| {
+        repeat(width, one_of(AsChar::is_dec_digit))
+            .fold(|| 0u32, |acc, i| acc * 10 + (i - b'0') as u32)
+            .parse_next(i)
+    }
+}
+
 #[cfg_attr(feature = "perf-inline", inline(always))]
-pub(crate) fn parse_digits_u16(i: &mut &[u8]) -> ModalResult<u16> {
-    repeat(4usize, one_of(AsChar::is_dec_digit))
-        .fold(|| 0u16, |acc, i| acc * 10 + (i - b'0') as u16)
-        .parse_next(i)
+pub(crate) fn parse_digit(i: &mut &[u8]) -> ModalResult<u8> {
+    one_of(AsChar::is_dec_digit).map(|b| b - b'0').parse_next(i)
 }
 
 #[cfg_attr(feature = "perf-inline", inline(always))]
@@ -48,6 +162,19 @@ mod tests {
         assert!(parse_digits_u32.parse(b"0000a").is_err());
     }
 
+    #[test]
+    fn test_parse_digits() {
+        assert_eq!(parse_digits(3).parse(b"123").unwrap(), 123u32);
+        assert_eq!(parse_digits(1).parse(b"7").unwrap(), 7u32);
+        assert_eq!(parse_digits(0).parse(b"").unwrap(), 0u32);
+        assert_eq!(
+            parse_digits(9).parse(b"999999999").unwrap(),
+            999_999_999u32
+        );
+        assert!(parse_digits(3).parse(b"1234").is_err());
+        assert!(parse_digits(3).parse(b"12a").is_err());
+    }
+
     #[test]
     fn test_parse_ascii_graphic() {
         assert_eq!(parse_ascii_graphic.parse(b"A").unwrap(), b'A');
@@ -1,18 +1,20 @@
 use std::fmt::Debug;
+use std::io::{self, Write};
 
 use winnow::combinator::{repeat, seq, terminated};
 use winnow::prelude::*;
+use winnow::token::take;
 
-use crate::Tag;
 use crate::common::*;
-use crate::error::ParseRecordError;
+use crate::error::{ParseRecordError, ParseRecordErrorKind};
 use crate::tag::parse_tag;
+use crate::{Field, Leader, Tag};
 
 /// An index entry containing metadata about a variable field.
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Entry<'a> {
     tag: Tag<'a>,
-    length: u16,
+    length: u32,
     start: u32,
 }
 
@@ -35,9 +37,11 @@ impl<'a> Entry<'a> {
     where
         B: AsRef<[u8]>,
     {
-        parse_entry
-            .parse(bytes.as_ref())
-            .map_err(ParseRecordError::from_parse)
+        parse_entry.parse(bytes.as_ref()).map_err(|e| {
+            ParseRecordError::from_parse(e).with_kind(
+                ParseRecordErrorKind::InvalidDirectoryEntry(0),
+            )
+        })
     }
 
     /// Returns the tag of the directory entry.
@@ -113,19 +117,77 @@ impl<'a> Entry<'a> {
     pub fn is_data_field(&self) -> bool {
         self.tag.is_data_field()
     }
+
+    /// Write the directory entry into the given writer.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::prelude::*;
+    ///
+    /// let entry = Entry::from_bytes(b"001012300005")?;
+    /// let mut out = Vec::<u8>::new();
+    /// entry.write_to(&mut out)?;
+    ///
+    /// assert_eq!(out, b"001012300005");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn write_to<W: Write>(&self, out: &mut W) -> io::Result<()> {
+        write!(out, "{}{:0>4}{:0>5}", self.tag, self.length, self.start)
+    }
+
+    /// Write the directory entry according to the entry map of the
+    /// given leader. The implementation-defined portion is filled
+    /// with zeros.
+    fn write_iso2709<W: Write>(
+        &self,
+        out: &mut W,
+        leader: &Leader,
+    ) -> io::Result<()> {
+        write!(
+            out,
+            "{}{:0>lf$}{:0>ls$}{:0>li$}",
+            self.tag,
+            self.length,
+            self.start,
+            "",
+            lf = leader.length_of_field_length() as usize,
+            ls = leader.length_of_starting_position() as usize,
+            li = leader.length_of_implementation_defined() as usize,
+        )
+    }
+}
+
+/// Parses a directory entry of a MARC 21 record (entry map `4500`).
+pub(crate) fn parse_entry<'a>(
+    i: &mut &'a [u8],
+) -> ModalResult<Entry<'a>> {
+    parse_entry_with(4, 5, 0).parse_next(i)
 }
 
-fn parse_entry<'a>(i: &mut &'a [u8]) -> ModalResult<Entry<'a>> {
-    seq! { Entry {
-        tag: parse_tag,
-        length: parse_digits_u16.verify(|value| *value > 0),
-        start: parse_digits_u32,
-    }}
-    .parse_next(i)
+/// Parses a directory entry, whose portions have the given lengths
+/// (positions 20-22 of the leader). The implementation-defined
+/// portion is skipped.
+pub(crate) fn parse_entry_with<'a>(
+    field_length: usize,
+    starting_position: usize,
+    implementation_defined: usize,
+) -> impl FnMut(&mut &'a [u8]) -> ModalResult<Entry<'a>> {
+    move |i: &mut &'a [u8]| {
+        seq! { Entry {
+            tag: parse_tag,
+            length: parse_digits(field_length)
+                .verify(|value| *value > 0),
+            start: parse_digits(starting_position),
+            _: take(implementation_defined),
+        }}
+        .parse_next(i)
+    }
 }
 
 /// Index to variable fields (control and data).
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Directory<'a>(Vec<Entry<'a>>);
 
 impl<'a> Directory<'a> {
@@ -147,9 +209,13 @@ impl<'a> Directory<'a> {
     where
         B: AsRef<[u8]>,
     {
-        parse_directory
-            .parse(bytes.as_ref())
-            .map_err(ParseRecordError::from_parse)
+        parse_directory.parse(bytes.as_ref()).map_err(|e| {
+            let e = ParseRecordError::from_parse(e);
+            let index = e.position() / 12;
+            e.with_kind(ParseRecordErrorKind::InvalidDirectoryEntry(
+                index,
+            ))
+        })
     }
 
     /// Returns an iterator over all entries.
@@ -187,6 +253,98 @@ impl<'a> Directory<'a> {
     pub fn length(&self) -> usize {
         self.0.len()
     }
+
+    /// Write the directory (including the field terminator) into the
+    /// given writer.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::prelude::*;
+    ///
+    /// let dir = Directory::from_bytes(b"001001000000003000700010\x1e")?;
+    /// let mut out = Vec::<u8>::new();
+    /// dir.write_to(&mut out)?;
+    ///
+    /// assert_eq!(out, b"001001000000003000700010\x1e");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn write_to<W: Write>(&self, out: &mut W) -> io::Result<()> {
+        for entry in self.entries() {
+            entry.write_to(out)?;
+        }
+
+        out.write_all(&[RECORD_SEPARATOR])
+    }
+
+    /// Write the directory (including the field terminator) according
+    /// to the entry map of the given leader.
+    pub(crate) fn write_iso2709<W: Write>(
+        &self,
+        out: &mut W,
+        leader: &Leader,
+    ) -> io::Result<()> {
+        for entry in self.entries() {
+            entry.write_iso2709(out, leader)?;
+        }
+
+        out.write_all(&[RECORD_SEPARATOR])
+    }
+
+    /// Creates a new directory from a list of fields and writes the
+    /// serialized fields into `data`. The data fields are written with
+    /// the number of indicators of the given leader.
+    ///
+    /// An error is returned, if the length of a field or its starting
+    /// position can't be represented by a directory entry or if an
+    /// indicator can't be represented by the record. In the oversize
+    /// form, such lengths and starting positions are written as nines.
+    pub(crate) fn layout<'b, F>(
+        fields: F,
+        leader: &Leader,
+        oversize: bool,
+        data: &mut Vec<u8>,
+    ) -> io::Result<Self>
+    where
+        F: IntoIterator<Item = &'b Field<'a>>,
+        'a: 'b,
+    {
+        let max = |width: u8| 10usize.pow(width as u32) - 1;
+        let max_length = max(leader.length_of_field_length());
+        let max_start = max(leader.length_of_starting_position());
+        let mut entries = vec![];
+
+        for field in fields {
+            let start = data.len();
+            match field {
+                Field::Data(df) => df.write_iso2709(
+                    data,
+                    leader.indicator_count() as usize,
+                )?,
+                field => field.write_to(data)?,
+            }
+
+            let length = data.len() - start;
+            if !oversize && (length > max_length || start > max_start) {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!(
+                        "field {} exceeds the directory limits",
+                        field.tag()
+                    ),
+                ));
+            }
+
+            entries.push(Entry {
+                tag: field.tag().clone(),
+                length: length.min(max_length) as u32,
+                start: start.min(max_start) as u32,
+            });
+        }
+
+        Ok(Self(entries))
+    }
 }
 
 #[cfg_attr(feature = "perf-inline", inline(always))]
@@ -198,6 +356,27 @@ pub(crate) fn parse_directory<'a>(
         .parse_next(i)
 }
 
+/// Parses a directory according to the entry map of the given leader.
+#[cfg_attr(feature = "perf-inline", inline(always))]
+pub(crate) fn parse_directory_with<'a>(
+    leader: &Leader,
+) -> impl FnMut(&mut &'a [u8]) -> ModalResult<Directory<'a>> {
+    let (lf, ls, li) = (
+        leader.length_of_field_length() as usize,
+        leader.length_of_starting_position() as usize,
+        leader.length_of_implementation_defined() as usize,
+    );
+
+    move |i: &mut &'a [u8]| {
+        terminated(
+            repeat(1.., parse_entry_with(lf, ls, li)),
+            RECORD_SEPARATOR,
+        )
+        .map(Directory)
+        .parse_next(i)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -214,12 +393,31 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_parse_entry_with() {
+        assert_eq!(
+            parse_entry_with(3, 4, 2).parse(b"CAT0100012xy").unwrap(),
+            Entry {
+                tag: Tag::from_bytes(b"CAT").unwrap(),
+                length: 10,
+                start: 12,
+            }
+        );
+
+        assert!(
+            parse_entry_with(3, 4, 2).parse(b"CAT0100012x").is_err()
+        );
+        assert!(
+            parse_entry_with(3, 4, 0).parse(b"CAT0000012").is_err()
+        );
+    }
+
     #[test]
     fn test_entry_from_bytes() {
         let result = Entry::from_bytes(b"001001200123");
         assert!(result.is_ok());
 
-        let result = Entry::from_bytes(b"00X001200123");
+        let result = Entry::from_bytes(b"00-001200123");
         assert!(result.is_err());
     }
 
@@ -0,0 +1,56 @@
+use std::fmt::{self, Display};
+
+use bstr::{BString, ByteSlice};
+
+use crate::error::{Error, ErrorKind};
+
+/// An error that can occur when building or modifying records.
+#[derive(Debug, PartialEq)]
+pub enum BuildRecordError {
+    /// The record builder has no leader.
+    MissingLeader,
+    /// The tag is invalid or doesn't belong to the kind of field.
+    InvalidTag(BString),
+    /// The indicator is not a blank, a lowercase letter or a digit.
+    InvalidIndicator(u8),
+    /// The subfield code is not an alphanumeric ASCII character.
+    InvalidSubfieldCode(u8),
+    /// The value contains a delimiter (0x1d, 0x1e or 0x1f).
+    InvalidValue(BString),
+    /// The value at the leader position isn't allowed in the record
+    /// format.
+    InvalidLeaderValue(usize, u8),
+}
+
+impl Display for BuildRecordError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingLeader => write!(f, "missing leader"),
+            Self::InvalidTag(tag) => {
+                write!(f, "invalid tag '{}'", tag.as_bstr())
+            }
+            Self::InvalidIndicator(ind) => {
+                write!(f, "invalid indicator '{}'", *ind as char)
+            }
+            Self::InvalidSubfieldCode(code) => {
+                write!(f, "invalid subfield code '{}'", *code as char)
+            }
+            Self::InvalidValue(value) => {
+                write!(f, "invalid value '{}'", value.as_bstr())
+            }
+            Self::InvalidLeaderValue(position, value) => write!(
+                f,
+                "invalid value '{}' at leader position {position:02}",
+                *value as char
+            ),
+        }
+    }
+}
+
+impl std::error::Error for BuildRecordError {}
+
+impl From<BuildRecordError> for Error<'_> {
+    fn from(err: BuildRecordError) -> Self {
+        ErrorKind::Build(err).into()
+    }
+}
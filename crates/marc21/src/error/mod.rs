@@ -1,11 +1,13 @@
 use std::fmt::{self, Display};
 
+pub use build::BuildRecordError;
 pub use matcher::ParseMatcherError;
-pub use record::ParseRecordError;
+pub use record::{ParseRecordError, ParseRecordErrorKind};
 
 use crate::ParsePathError;
 use crate::query::ParseQueryError;
 
+mod build;
 mod matcher;
 mod record;
 
@@ -20,6 +22,7 @@ pub struct Error<'a> {
 #[derive(Debug)]
 pub(crate) enum ErrorKind<'a> {
     Record(ParseRecordError<'a>),
+    Build(BuildRecordError),
     Matcher(ParseMatcherError),
     Query(ParseQueryError),
     Path(ParsePathError),
@@ -29,6 +32,7 @@ impl Display for Error<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self.kind {
             ErrorKind::Record(ref err) => err.fmt(f),
+            ErrorKind::Build(ref err) => err.fmt(f),
             ErrorKind::Matcher(ref err) => err.fmt(f),
             ErrorKind::Query(ref err) => err.fmt(f),
             ErrorKind::Path(ref err) => err.fmt(f),
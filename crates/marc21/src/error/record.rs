@@ -5,24 +5,202 @@ use winnow::error::{ContextError, ParseError};
 
 use crate::error::{Error, ErrorKind};
 
+/// The kind of a [ParseRecordError].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseRecordErrorKind {
+    /// The leader is malformed or describes an unsupported record
+    /// structure, i.e. more than two indicators or a subfield code
+    /// length other than two (see [`Leader`](crate::Leader)).
+    InvalidLeader,
+    /// The directory entry with the given (zero-based) index is
+    /// malformed.
+    InvalidDirectoryEntry(usize),
+    /// The directory doesn't contain any entries.
+    EmptyDirectory,
+    /// The directory isn't terminated by a field terminator.
+    MissingDirectoryTerminator,
+    /// The base address of data doesn't match the end of the
+    /// directory.
+    BaseAddressMismatch,
+    /// The record length doesn't match the length of the data.
+    RecordLengthMismatch,
+    /// The record isn't terminated by a record terminator.
+    MissingRecordTerminator,
+    /// The length of the field with the given tag doesn't match its
+    /// directory entry.
+    FieldLengthMismatch(String),
+    /// The field with the given tag isn't terminated by a field
+    /// terminator.
+    MissingFieldTerminator(String),
+    /// The field with the given tag has an invalid indicator.
+    InvalidIndicator(String),
+    /// The field with the given tag has an invalid subfield, e.g. a
+    /// subfield with an invalid code.
+    InvalidSubfield(String),
+    /// The tag is malformed.
+    InvalidTag,
+    /// The record is invalid for another reason, e.g. an invalid
+    /// element of a MARCXML record.
+    Invalid(String),
+    /// The input couldn't be parsed; no further details are available.
+    Syntax,
+}
+
+impl Display for ParseRecordErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidLeader => write!(f, "invalid leader"),
+            Self::InvalidDirectoryEntry(index) => {
+                write!(f, "invalid directory entry {index}")
+            }
+            Self::EmptyDirectory => write!(f, "empty directory"),
+            Self::MissingDirectoryTerminator => {
+                write!(f, "missing directory terminator")
+            }
+            Self::BaseAddressMismatch => {
+                write!(
+                    f,
+                    "base address of data doesn't match directory"
+                )
+            }
+            Self::RecordLengthMismatch => {
+                write!(f, "record length doesn't match data")
+            }
+            Self::MissingRecordTerminator => {
+                write!(f, "missing record terminator")
+            }
+            Self::FieldLengthMismatch(tag) => {
+                write!(
+                    f,
+                    "length of field {tag} doesn't match directory"
+                )
+            }
+            Self::MissingFieldTerminator(tag) => {
+                write!(f, "missing terminator of field {tag}")
+            }
+            Self::InvalidIndicator(tag) => {
+                write!(f, "invalid indicator of field {tag}")
+            }
+            Self::InvalidSubfield(tag) => {
+                write!(f, "invalid subfield of field {tag}")
+            }
+            Self::InvalidTag => write!(f, "invalid tag"),
+            Self::Invalid(message) => write!(f, "{message}"),
+            Self::Syntax => write!(f, "parse error"),
+        }
+    }
+}
+
 /// An error that can occur when parsing MARC 21 records.
 #[derive(Debug)]
 pub struct ParseRecordError<'a> {
-    #[allow(dead_code)]
-    message: String,
+    kind: ParseRecordErrorKind,
     span: Range<usize>,
     data: &'a [u8],
+    record: Option<usize>,
+    start: Option<usize>,
 }
 
 impl<'a> ParseRecordError<'a> {
     pub fn from_parse(err: ParseError<&'a [u8], ContextError>) -> Self {
         Self {
-            message: err.inner().to_string(),
+            kind: ParseRecordErrorKind::Syntax,
             span: err.char_span(),
             data: err.input(),
+            record: None,
+            start: None,
+        }
+    }
+
+    /// Creates a new error of the given kind at the given position of
+    /// the data.
+    pub(crate) fn new(
+        kind: ParseRecordErrorKind,
+        position: usize,
+        data: &'a [u8],
+    ) -> Self {
+        Self {
+            kind,
+            span: position..position,
+            data,
+            record: None,
+            start: None,
         }
     }
 
+    /// Sets the kind of the error.
+    pub(crate) fn with_kind(
+        mut self,
+        kind: ParseRecordErrorKind,
+    ) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// Sets the (one-based) number of the record within the input and
+    /// the byte offset at which the data starts.
+    pub(crate) fn with_location(
+        mut self,
+        record: usize,
+        start: usize,
+    ) -> Self {
+        self.record = Some(record);
+        self.start = Some(start);
+        self
+    }
+
+    /// Returns the kind of the error.
+    pub fn kind(&self) -> &ParseRecordErrorKind {
+        &self.kind
+    }
+
+    /// Returns the position of the error relative to the start of the
+    /// data.
+    pub fn position(&self) -> usize {
+        self.span.start
+    }
+
+    /// Returns the (one-based) number of the record within the input,
+    /// if the error was returned by a reader.
+    pub fn record(&self) -> Option<usize> {
+        self.record
+    }
+
+    /// Returns the byte offset of the error within the input, if the
+    /// error was returned by a reader.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    ///
+    /// use marc21::ParseRecordErrorKind;
+    /// use marc21::io::ReadMarcError;
+    /// use marc21::prelude::*;
+    ///
+    /// let mut data = include_bytes!("../../tests/data/ada.mrc").to_vec();
+    /// data.extend_from_slice(b"00000nz  a2200000nc 45x0\x1d");
+    ///
+    /// let mut rdr =
+    ///     MarcReader::new(Cursor::new(data), MarcReadOptions::default());
+    /// assert!(rdr.next_byte_record().unwrap().is_ok());
+    ///
+    /// let Some(Err(ReadMarcError::Parse(e))) = rdr.next_byte_record()
+    /// else {
+    ///     panic!("expected parse error");
+    /// };
+    ///
+    /// assert_eq!(e.kind(), &ParseRecordErrorKind::InvalidLeader);
+    /// assert_eq!(e.record(), Some(2));
+    /// assert_eq!(e.offset(), Some(3612));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn offset(&self) -> Option<usize> {
+        self.start.map(|start| start + self.span.start)
+    }
+
     pub fn data(&self) -> &'a [u8] {
         self.data
     }
@@ -34,9 +212,9 @@ impl Display for ParseRecordError<'_> {
         let end = self.span.end;
 
         if end > start {
-            write!(f, "parse error at span {start}:{end}")
+            write!(f, "{} at span {start}:{end}", self.kind)
         } else {
-            write!(f, "parse error at position {start}")
+            write!(f, "{} at position {start}", self.kind)
         }
     }
 }
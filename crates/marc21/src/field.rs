@@ -1,11 +1,15 @@
 use std::fmt::{self, Display};
+use std::io::{self, Write};
 use std::str::Utf8Error;
 
 use bstr::ByteSlice;
 
+#[cfg(feature = "serde")]
+use crate::Value;
+use crate::common::RECORD_SEPARATOR;
 use crate::{Subfield, Tag};
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum Field<'a> {
     Control(ControlField<'a>),
     Data(DataField<'a>),
@@ -37,6 +41,15 @@ impl<'a> Field<'a> {
     pub fn is_data_field(&self) -> bool {
         matches!(self, Self::Data(_))
     }
+
+    /// Write the field (including the field terminator) into the given
+    /// writer.
+    pub fn write_to<W: Write>(&self, out: &mut W) -> io::Result<()> {
+        match self {
+            Self::Control(cf) => cf.write_to(out),
+            Self::Data(df) => df.write_to(out),
+        }
+    }
 }
 
 impl Display for Field<'_> {
@@ -48,13 +61,45 @@ impl Display for Field<'_> {
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[cfg(feature = "serde")]
+impl serde::Serialize for Field<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Self::Control(cf) => serializer
+                .serialize_newtype_variant("Field", 0, "control", cf),
+            Self::Data(df) => serializer
+                .serialize_newtype_variant("Field", 1, "data", df),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
 pub struct ControlField<'a> {
     pub(crate) tag: Tag<'a>,
     pub(crate) value: &'a [u8],
 }
 
 impl<'a> ControlField<'a> {
+    /// Creates a new control field.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::ControlField;
+    /// use marc21::prelude::*;
+    ///
+    /// let cf = ControlField::new(Tag::from_bytes(b"001")?, b"123");
+    /// assert_eq!(cf.value(), b"123");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn new(tag: Tag<'a>, value: &'a [u8]) -> Self {
+        Self { tag, value }
+    }
+
     pub fn tag(&self) -> &Tag<'a> {
         &self.tag
     }
@@ -73,6 +118,27 @@ impl<'a> ControlField<'a> {
         let _ = std::str::from_utf8(self.value)?;
         Ok(())
     }
+
+    /// Write the control field into the given writer.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::ControlField;
+    /// use marc21::prelude::*;
+    ///
+    /// let cf = ControlField::new(Tag::from_bytes(b"001")?, b"123");
+    /// let mut out = Vec::<u8>::new();
+    /// cf.write_to(&mut out)?;
+    ///
+    /// assert_eq!(out, b"123\x1e");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn write_to<W: Write>(&self, out: &mut W) -> io::Result<()> {
+        out.write_all(self.value)?;
+        out.write_all(&[RECORD_SEPARATOR])
+    }
 }
 
 impl Display for ControlField<'_> {
@@ -81,7 +147,23 @@ impl Display for ControlField<'_> {
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[cfg(feature = "serde")]
+impl serde::Serialize for ControlField<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+
+        let mut state =
+            serializer.serialize_struct("ControlField", 2)?;
+        state.serialize_field("tag", &self.tag)?;
+        state.serialize_field("value", &Value::from(self.value))?;
+        state.end()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
 pub struct DataField<'a> {
     pub(crate) tag: Tag<'a>,
     pub(crate) indicator1: u8,
@@ -90,6 +172,39 @@ pub struct DataField<'a> {
 }
 
 impl<'a> DataField<'a> {
+    /// Creates a new data field.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::DataField;
+    /// use marc21::prelude::*;
+    ///
+    /// let df = DataField::new(
+    ///     Tag::from_bytes(b"100")?,
+    ///     b'1',
+    ///     b' ',
+    ///     vec![Subfield::new(b'a', b"Lovelace, Ada")],
+    /// );
+    ///
+    /// assert_eq!(df.subfields().count(), 1);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn new(
+        tag: Tag<'a>,
+        indicator1: u8,
+        indicator2: u8,
+        subfields: Vec<Subfield<'a>>,
+    ) -> Self {
+        Self {
+            tag,
+            indicator1,
+            indicator2,
+            subfields,
+        }
+    }
+
     #[inline(always)]
     pub fn tag(&self) -> &Tag<'a> {
         &self.tag
@@ -121,6 +236,71 @@ impl<'a> DataField<'a> {
 
         Ok(())
     }
+
+    /// Write the data field into the given writer.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::DataField;
+    /// use marc21::prelude::*;
+    ///
+    /// let df = DataField::new(
+    ///     Tag::from_bytes(b"100")?,
+    ///     b'1',
+    ///     b' ',
+    ///     vec![Subfield::new(b'a', b"Lovelace, Ada")],
+    /// );
+    ///
+    /// let mut out = Vec::<u8>::new();
+    /// df.write_to(&mut out)?;
+    /// assert_eq!(out, b"1 \x1faLovelace, Ada\x1e");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn write_to<W: Write>(&self, out: &mut W) -> io::Result<()> {
+        self.write_iso2709(out, 2)
+    }
+
+    /// Write the data field with the given number of indicators (at
+    /// most two) into the given writer. An error is returned, if an
+    /// omitted indicator isn't blank or if more than two indicators
+    /// are requested.
+    pub(crate) fn write_iso2709<W: Write>(
+        &self,
+        out: &mut W,
+        indicator_count: usize,
+    ) -> io::Result<()> {
+        if indicator_count > 2 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "field {} can't be written with {indicator_count} \
+                     indicators (at most 2 are supported)",
+                    self.tag
+                ),
+            ));
+        }
+
+        let indicators = [self.indicator1, self.indicator2];
+        if indicators[indicator_count..].iter().any(|b| *b != b' ') {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "field {} has more than {indicator_count} \
+                     indicator(s)",
+                    self.tag
+                ),
+            ));
+        }
+
+        out.write_all(&indicators[..indicator_count])?;
+        for subfield in self.subfields() {
+            subfield.write_to(out)?;
+        }
+
+        out.write_all(&[RECORD_SEPARATOR])
+    }
 }
 
 impl Display for DataField<'_> {
@@ -151,6 +331,23 @@ impl Display for DataField<'_> {
     }
 }
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for DataField<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("DataField", 4)?;
+        state.serialize_field("tag", &self.tag)?;
+        state.serialize_field("ind1", &char::from(self.indicator1))?;
+        state.serialize_field("ind2", &char::from(self.indicator2))?;
+        state.serialize_field("subfields", &self.subfields)?;
+        state.end()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -188,4 +385,31 @@ mod tests {
         assert_eq!(df.to_string(), "024/7# $a 119232022 $2 gnd");
         Ok(())
     }
+
+    #[test]
+    fn test_data_field_write_iso2709() -> TestResult {
+        let df = DataField {
+            tag: Tag::from_bytes(b"100")?,
+            indicator1: b'1',
+            indicator2: b' ',
+            subfields: vec![Subfield {
+                code: b'a',
+                value: b"Ada",
+            }],
+        };
+
+        let mut out = Vec::new();
+        df.write_iso2709(&mut out, 1)?;
+        assert_eq!(out, b"1\x1faAda\x1e");
+
+        let mut out = Vec::new();
+        let err = df.write_iso2709(&mut out, 0).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+        let err = df.write_iso2709(&mut out, 3).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        assert!(out.is_empty());
+
+        Ok(())
+    }
 }
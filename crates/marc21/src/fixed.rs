@@ -0,0 +1,898 @@
+//! Typed decoding of the fixed-length control fields 006, 007 and
+//! 008.
+//!
+//! The character positions of these fields are defined by the record
+//! format and, in case of bibliographic records, by the type of
+//! material. A [`FixedField`] determines the applicable definition from
+//! the leader (008), the form of material (006) or the category of
+//! material (007) and gives access to the data elements by name.
+//!
+//! # Example
+//!
+//! ```rust
+//! use marc21::fixed::{FixedField, Material};
+//! use marc21::prelude::*;
+//!
+//! let data = include_bytes!("../tests/data/ada.mrc");
+//! let record = ByteRecord::from_bytes(data)?;
+//!
+//! let field = record.fixed_fields().next().unwrap();
+//! assert_eq!(field.material(), Some(Material::Authority));
+//! assert_eq!(field.date_entered(), Some(&b"950316"[..]));
+//! assert_eq!(field.get("kind_of_record"), Some(&b"a"[..]));
+//!
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use crate::leader::RecordFormat;
+use crate::{ControlField, Leader, Tag};
+
+/// A named data element and its character positions `start..end`.
+type Element = (&'static str, usize, usize);
+
+/// The type of material, which determines the definition of the
+/// material specific positions of the fields 006 and 008.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Material {
+    Books,
+    ComputerFiles,
+    ContinuingResources,
+    Maps,
+    MixedMaterials,
+    Music,
+    VisualMaterials,
+    Authority,
+    Classification,
+    CommunityInformation,
+    Holdings,
+}
+
+impl Material {
+    /// Returns the material of the field 008 based on the type of
+    /// record and the bibliographic level of the leader.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Leader;
+    /// use marc21::fixed::Material;
+    ///
+    /// let ldr = Leader::new("00000nas a2200000 c 4500")?;
+    /// assert_eq!(
+    ///     Material::from_leader(&ldr),
+    ///     Some(Material::ContinuingResources)
+    /// );
+    ///
+    /// let ldr = Leader::new("00000nz  a2200000nc 4500")?;
+    /// assert_eq!(Material::from_leader(&ldr), Some(Material::Authority));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn from_leader(ldr: &Leader) -> Option<Self> {
+        match ldr.format()? {
+            RecordFormat::Bibliographic => match ldr.r#type() {
+                b'a' | b't'
+                    if matches!(
+                        ldr.bibliographic_level(),
+                        Some(b'b' | b'i' | b's')
+                    ) =>
+                {
+                    Some(Self::ContinuingResources)
+                }
+                code => Self::from_form(code),
+            },
+            RecordFormat::Authority => Some(Self::Authority),
+            RecordFormat::Holdings => Some(Self::Holdings),
+            RecordFormat::Classification => Some(Self::Classification),
+            RecordFormat::CommunityInformation => {
+                Some(Self::CommunityInformation)
+            }
+        }
+    }
+
+    /// Returns the material of the given form of material (006/00).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::fixed::Material;
+    ///
+    /// assert_eq!(Material::from_form(b'e'), Some(Material::Maps));
+    /// assert_eq!(Material::from_form(b'z'), None);
+    /// ```
+    pub fn from_form(code: u8) -> Option<Self> {
+        Some(match code {
+            b'a' | b't' => Self::Books,
+            b'c' | b'd' | b'i' | b'j' => Self::Music,
+            b'e' | b'f' => Self::Maps,
+            b'g' | b'k' | b'o' | b'r' => Self::VisualMaterials,
+            b'm' => Self::ComputerFiles,
+            b'p' => Self::MixedMaterials,
+            b's' => Self::ContinuingResources,
+            _ => return None,
+        })
+    }
+
+    /// Returns the elements of the positions 18-34 of the field 008.
+    fn elements(&self) -> &'static [Element] {
+        match self {
+            Self::Books => BOOKS,
+            Self::ComputerFiles => COMPUTER_FILES,
+            Self::ContinuingResources => CONTINUING_RESOURCES,
+            Self::Maps => MAPS,
+            Self::MixedMaterials => MIXED_MATERIALS,
+            Self::Music => MUSIC,
+            Self::VisualMaterials => VISUAL_MATERIALS,
+            _ => &[],
+        }
+    }
+}
+
+/// The category of material (007/00), which determines the definition
+/// of the remaining positions of the field 007.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Category {
+    Map,
+    ElectronicResource,
+    Globe,
+    TactileMaterial,
+    ProjectedGraphic,
+    Microform,
+    NonprojectedGraphic,
+    MotionPicture,
+    Kit,
+    NotatedMusic,
+    RemoteSensingImage,
+    SoundRecording,
+    Text,
+    Videorecording,
+    Unspecified,
+}
+
+impl Category {
+    /// Returns the category of the given code (007/00).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::fixed::Category;
+    ///
+    /// assert_eq!(Category::from_code(b'h'), Some(Category::Microform));
+    /// assert_eq!(Category::from_code(b'x'), None);
+    /// ```
+    pub fn from_code(code: u8) -> Option<Self> {
+        Some(match code {
+            b'a' => Self::Map,
+            b'c' => Self::ElectronicResource,
+            b'd' => Self::Globe,
+            b'f' => Self::TactileMaterial,
+            b'g' => Self::ProjectedGraphic,
+            b'h' => Self::Microform,
+            b'k' => Self::NonprojectedGraphic,
+            b'm' => Self::MotionPicture,
+            b'o' => Self::Kit,
+            b'q' => Self::NotatedMusic,
+            b'r' => Self::RemoteSensingImage,
+            b's' => Self::SoundRecording,
+            b't' => Self::Text,
+            b'v' => Self::Videorecording,
+            b'z' => Self::Unspecified,
+            _ => return None,
+        })
+    }
+
+    /// Returns the elements of the positions following the specific
+    /// material designation (007/01).
+    fn elements(&self) -> &'static [Element] {
+        match self {
+            Self::Map => MAP,
+            Self::ElectronicResource => ELECTRONIC_RESOURCE,
+            Self::Globe => GLOBE,
+            Self::TactileMaterial => TACTILE_MATERIAL,
+            Self::ProjectedGraphic => PROJECTED_GRAPHIC,
+            Self::Microform => MICROFORM,
+            Self::NonprojectedGraphic => NONPROJECTED_GRAPHIC,
+            Self::MotionPicture => MOTION_PICTURE,
+            Self::RemoteSensingImage => REMOTE_SENSING_IMAGE,
+            Self::SoundRecording => SOUND_RECORDING,
+            Self::Videorecording => VIDEORECORDING,
+            _ => &[],
+        }
+    }
+}
+
+/// A decoded fixed-length control field (006, 007 or 008).
+#[derive(Debug, Clone, PartialEq)]
+pub struct FixedField<'a> {
+    tag: Tag<'a>,
+    value: &'a [u8],
+    material: Option<Material>,
+    category: Option<Category>,
+    common: &'static [Element],
+    specific: &'static [Element],
+    // The positions of the material specific elements are given
+    // relative to the field 008; in the field 006 they are shifted by
+    // this offset.
+    shift: usize,
+}
+
+impl<'a> FixedField<'a> {
+    /// Decodes a control field with respect to the given leader.
+    ///
+    /// Returns `None`, if the field isn't a fixed-length control
+    /// field.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::fixed::{FixedField, Material};
+    /// use marc21::prelude::*;
+    /// use marc21::{ControlField, Leader};
+    ///
+    /// let ldr = Leader::new("00000nam a2200000 c 4500")?;
+    /// let cf = ControlField::new(
+    ///     Tag::from_bytes(b"008")?,
+    ///     b"240101s2023    gw      r     000 0 ger d",
+    /// );
+    ///
+    /// let field = FixedField::new(&cf, &ldr).unwrap();
+    /// assert_eq!(field.material(), Some(Material::Books));
+    /// assert_eq!(field.date1(), Some(&b"2023"[..]));
+    /// assert_eq!(field.place(), Some(&b"gw "[..]));
+    /// assert_eq!(field.language(), Some(&b"ger"[..]));
+    /// assert_eq!(field.form_of_item(), Some(&b"r"[..]));
+    ///
+    /// let cf = ControlField::new(Tag::from_bytes(b"001")?, b"123");
+    /// assert!(FixedField::new(&cf, &ldr).is_none());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn new(field: &ControlField<'a>, ldr: &Leader) -> Option<Self> {
+        let (tag, value) = (field.tag(), field.value());
+        let mut field = Self {
+            tag: tag.clone(),
+            value,
+            material: None,
+            category: None,
+            common: &[],
+            specific: &[],
+            shift: 0,
+        };
+
+        match tag.0 {
+            b"006" => {
+                field.material = value
+                    .first()
+                    .copied()
+                    .and_then(Material::from_form);
+                field.common = FORM_OF_MATERIAL;
+                field.shift = 17;
+            }
+            b"007" => {
+                field.category = value
+                    .first()
+                    .copied()
+                    .and_then(Category::from_code);
+                field.common = CATEGORY_OF_MATERIAL;
+            }
+            b"008" => {
+                field.material = Material::from_leader(ldr);
+                field.common = match field.material {
+                    Some(Material::Authority) => AUTHORITY,
+                    Some(Material::Classification) => CLASSIFICATION,
+                    Some(Material::CommunityInformation) => {
+                        COMMUNITY_INFORMATION
+                    }
+                    Some(Material::Holdings) => HOLDINGS,
+                    Some(_) => BIBLIOGRAPHIC,
+                    None => &[],
+                };
+            }
+            _ => return None,
+        }
+
+        if let Some(material) = field.material {
+            field.specific = material.elements();
+        } else if let Some(category) = field.category {
+            field.specific = category.elements();
+        }
+
+        Some(field)
+    }
+
+    /// Returns the tag of the field.
+    #[inline]
+    pub fn tag(&self) -> &Tag<'a> {
+        &self.tag
+    }
+
+    /// Returns the raw value of the field.
+    #[inline]
+    pub fn value(&self) -> &'a [u8] {
+        self.value
+    }
+
+    /// Returns the type of material (006 and 008) or `None`, if the
+    /// material can't be determined.
+    #[inline]
+    pub fn material(&self) -> Option<Material> {
+        self.material
+    }
+
+    /// Returns the category of material (007) or `None`, if the
+    /// category can't be determined.
+    #[inline]
+    pub fn category(&self) -> Option<Category> {
+        self.category
+    }
+
+    /// Returns an iterator over the names and values of all data
+    /// elements, which are defined for the field and are present in
+    /// the field value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::fixed::FixedField;
+    /// use marc21::prelude::*;
+    /// use marc21::{ControlField, Leader};
+    ///
+    /// let ldr = Leader::new("00000nam a2200000 c 4500")?;
+    /// let cf = ControlField::new(Tag::from_bytes(b"007")?, b"ta");
+    /// let field = FixedField::new(&cf, &ldr).unwrap();
+    ///
+    /// assert_eq!(
+    ///     field.elements().collect::<Vec<_>>(),
+    ///     vec![
+    ///         ("category_of_material", &b"t"[..]),
+    ///         ("specific_material_designation", &b"a"[..]),
+    ///     ]
+    /// );
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn elements(
+        &self,
+    ) -> impl Iterator<Item = (&'static str, &'a [u8])> + '_ {
+        self.common
+            .iter()
+            .map(|&(name, start, end)| (name, start, end))
+            .chain(self.specific.iter().map(|&(name, start, end)| {
+                (name, start - self.shift, end - self.shift)
+            }))
+            .filter_map(|(name, start, end)| {
+                self.value.get(start..end).map(|value| (name, value))
+            })
+    }
+
+    /// Returns the value of the data element with the given name or
+    /// `None`, if the element isn't defined for the field or if the
+    /// field value is too short.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::fixed::FixedField;
+    /// use marc21::prelude::*;
+    /// use marc21::{ControlField, Leader};
+    ///
+    /// let ldr = Leader::new("00000nem a2200000 c 4500")?;
+    /// let cf = ControlField::new(Tag::from_bytes(b"007")?, b"aj canzn");
+    /// let field = FixedField::new(&cf, &ldr).unwrap();
+    ///
+    /// assert_eq!(field.get("color"), Some(&b"c"[..]));
+    /// assert_eq!(field.get("physical_medium"), Some(&b"a"[..]));
+    /// assert_eq!(field.get("lang"), None);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn get(&self, name: &str) -> Option<&'a [u8]> {
+        self.elements()
+            .find(|(element, _)| *element == name)
+            .map(|(_, value)| value)
+    }
+
+    /// Returns the date entered on file (008/00-05).
+    #[inline]
+    pub fn date_entered(&self) -> Option<&'a [u8]> {
+        self.get("date_entered")
+    }
+
+    /// Returns the type of date/publication status (008/06).
+    #[inline]
+    pub fn type_of_date(&self) -> Option<&'a [u8]> {
+        self.get("type_of_date")
+    }
+
+    /// Returns the first date (008/07-10).
+    #[inline]
+    pub fn date1(&self) -> Option<&'a [u8]> {
+        self.get("date1")
+    }
+
+    /// Returns the second date (008/11-14).
+    #[inline]
+    pub fn date2(&self) -> Option<&'a [u8]> {
+        self.get("date2")
+    }
+
+    /// Returns the place of publication, production, or execution
+    /// (008/15-17).
+    #[inline]
+    pub fn place(&self) -> Option<&'a [u8]> {
+        self.get("place")
+    }
+
+    /// Returns the language (008/35-37 or 008/22-24 of a holdings
+    /// record).
+    #[inline]
+    pub fn language(&self) -> Option<&'a [u8]> {
+        self.get("lang")
+    }
+
+    /// Returns the form of item.
+    #[inline]
+    pub fn form_of_item(&self) -> Option<&'a [u8]> {
+        self.get("form_of_item")
+    }
+
+    /// Returns the target audience.
+    #[inline]
+    pub fn target_audience(&self) -> Option<&'a [u8]> {
+        self.get("target_audience")
+    }
+
+    /// Returns the government publication code.
+    #[inline]
+    pub fn government_publication(&self) -> Option<&'a [u8]> {
+        self.get("government_publication")
+    }
+
+    /// Returns the modified record code (008/38).
+    #[inline]
+    pub fn modified_record(&self) -> Option<&'a [u8]> {
+        self.get("modified_record")
+    }
+
+    /// Returns the cataloging source (008/39).
+    #[inline]
+    pub fn cataloging_source(&self) -> Option<&'a [u8]> {
+        self.get("cataloging_source")
+    }
+}
+
+/// Returns true, if an element with the given name is defined for the
+/// field `tag` in any record format or type of material.
+pub(crate) fn is_element(tag: &[u8], name: &[u8]) -> bool {
+    let tables: &[&[Element]] = match tag {
+        b"006" => &[FORM_OF_MATERIAL, MATERIALS],
+        b"007" => CATEGORIES,
+        b"008" => &[
+            BIBLIOGRAPHIC,
+            MATERIALS,
+            AUTHORITY,
+            CLASSIFICATION,
+            COMMUNITY_INFORMATION,
+            HOLDINGS,
+        ],
+        _ => return false,
+    };
+
+    tables
+        .iter()
+        .flat_map(|table| table.iter())
+        .any(|(element, _, _)| element.as_bytes() == name)
+}
+
+const BIBLIOGRAPHIC: &[Element] = &[
+    ("date_entered", 0, 6),
+    ("type_of_date", 6, 7),
+    ("date1", 7, 11),
+    ("date2", 11, 15),
+    ("place", 15, 18),
+    ("lang", 35, 38),
+    ("modified_record", 38, 39),
+    ("cataloging_source", 39, 40),
+];
+
+const BOOKS: &[Element] = &[
+    ("illustrations", 18, 22),
+    ("target_audience", 22, 23),
+    ("form_of_item", 23, 24),
+    ("nature_of_contents", 24, 28),
+    ("government_publication", 28, 29),
+    ("conference_publication", 29, 30),
+    ("festschrift", 30, 31),
+    ("index", 31, 32),
+    ("literary_form", 33, 34),
+    ("biography", 34, 35),
+];
+
+const COMPUTER_FILES: &[Element] = &[
+    ("target_audience", 22, 23),
+    ("form_of_item", 23, 24),
+    ("type_of_computer_file", 26, 27),
+    ("government_publication", 28, 29),
+];
+
+const CONTINUING_RESOURCES: &[Element] = &[
+    ("frequency", 18, 19),
+    ("regularity", 19, 20),
+    ("type_of_continuing_resource", 21, 22),
+    ("form_of_original_item", 22, 23),
+    ("form_of_item", 23, 24),
+    ("nature_of_entire_work", 24, 25),
+    ("nature_of_contents", 25, 28),
+    ("government_publication", 28, 29),
+    ("conference_publication", 29, 30),
+    ("original_alphabet", 33, 34),
+    ("entry_convention", 34, 35),
+];
+
+const MAPS: &[Element] = &[
+    ("relief", 18, 22),
+    ("projection", 22, 24),
+    ("type_of_cartographic_material", 25, 26),
+    ("government_publication", 28, 29),
+    ("form_of_item", 29, 30),
+    ("index", 31, 32),
+    ("special_format", 33, 35),
+];
+
+const MIXED_MATERIALS: &[Element] = &[("form_of_item", 23, 24)];
+
+const MUSIC: &[Element] = &[
+    ("form_of_composition", 18, 20),
+    ("format_of_music", 20, 21),
+    ("music_parts", 21, 22),
+    ("target_audience", 22, 23),
+    ("form_of_item", 23, 24),
+    ("accompanying_matter", 24, 30),
+    ("literary_text", 30, 32),
+    ("transposition", 33, 34),
+];
+
+const VISUAL_MATERIALS: &[Element] = &[
+    ("running_time", 18, 21),
+    ("target_audience", 22, 23),
+    ("government_publication", 28, 29),
+    ("form_of_item", 29, 30),
+    ("type_of_visual_material", 33, 34),
+    ("technique", 34, 35),
+];
+
+/// The union of all material specific elements.
+const MATERIALS: &[Element] = &[
+    ("illustrations", 18, 22),
+    ("target_audience", 22, 23),
+    ("form_of_item", 23, 24),
+    ("nature_of_contents", 24, 28),
+    ("government_publication", 28, 29),
+    ("conference_publication", 29, 30),
+    ("festschrift", 30, 31),
+    ("index", 31, 32),
+    ("literary_form", 33, 34),
+    ("biography", 34, 35),
+    ("type_of_computer_file", 26, 27),
+    ("frequency", 18, 19),
+    ("regularity", 19, 20),
+    ("type_of_continuing_resource", 21, 22),
+    ("form_of_original_item", 22, 23),
+    ("nature_of_entire_work", 24, 25),
+    ("original_alphabet", 33, 34),
+    ("entry_convention", 34, 35),
+    ("relief", 18, 22),
+    ("projection", 22, 24),
+    ("type_of_cartographic_material", 25, 26),
+    ("special_format", 33, 35),
+    ("form_of_composition", 18, 20),
+    ("format_of_music", 20, 21),
+    ("music_parts", 21, 22),
+    ("accompanying_matter", 24, 30),
+    ("literary_text", 30, 32),
+    ("transposition", 33, 34),
+    ("running_time", 18, 21),
+    ("type_of_visual_material", 33, 34),
+    ("technique", 34, 35),
+];
+
+const AUTHORITY: &[Element] = &[
+    ("date_entered", 0, 6),
+    ("geographic_subdivision", 6, 7),
+    ("romanization_scheme", 7, 8),
+    ("language_of_catalog", 8, 9),
+    ("kind_of_record", 9, 10),
+    ("descriptive_cataloging_rules", 10, 11),
+    ("subject_heading_system", 11, 12),
+    ("type_of_series", 12, 13),
+    ("numbered_series", 13, 14),
+    ("heading_use_main", 14, 15),
+    ("heading_use_subject", 15, 16),
+    ("heading_use_series", 16, 17),
+    ("type_of_subject_subdivision", 17, 18),
+    ("type_of_government_agency", 28, 29),
+    ("reference_evaluation", 29, 30),
+    ("record_update_in_process", 31, 32),
+    ("undifferentiated_personal_name", 32, 33),
+    ("level_of_establishment", 33, 34),
+    ("modified_record", 38, 39),
+    ("cataloging_source", 39, 40),
+];
+
+const CLASSIFICATION: &[Element] = &[
+    ("date_entered", 0, 6),
+    ("kind_of_record", 6, 7),
+    ("type_of_number", 7, 8),
+    ("classification_validity", 8, 9),
+    ("standard_or_optional_designation", 9, 10),
+    ("record_update_in_process", 10, 11),
+    ("level_of_establishment", 11, 12),
+    ("synthesized_number_indication", 12, 13),
+    ("display_controller", 13, 14),
+];
+
+const COMMUNITY_INFORMATION: &[Element] = &[
+    ("date_entered", 0, 6),
+    ("type_of_date", 6, 7),
+    ("date1", 7, 11),
+    ("date2", 11, 15),
+    ("lang", 35, 38),
+];
+
+const HOLDINGS: &[Element] = &[
+    ("date_entered", 0, 6),
+    ("receipt_status", 6, 7),
+    ("method_of_acquisition", 7, 8),
+    ("expected_acquisition_end_date", 8, 12),
+    ("general_retention_policy", 12, 13),
+    ("specific_retention_policy", 13, 16),
+    ("completeness", 16, 17),
+    ("number_of_copies", 17, 20),
+    ("lending_policy", 20, 21),
+    ("reproduction_policy", 21, 22),
+    ("lang", 22, 25),
+    ("separate_or_composite_copy_report", 25, 26),
+    ("date_of_report", 26, 32),
+];
+
+const FORM_OF_MATERIAL: &[Element] = &[("form_of_material", 0, 1)];
+
+const CATEGORY_OF_MATERIAL: &[Element] = &[
+    ("category_of_material", 0, 1),
+    ("specific_material_designation", 1, 2),
+];
+
+const MAP: &[Element] = &[
+    ("color", 3, 4),
+    ("physical_medium", 4, 5),
+    ("type_of_reproduction", 5, 6),
+    ("production_details", 6, 7),
+    ("positive_negative_aspect", 7, 8),
+];
+
+const ELECTRONIC_RESOURCE: &[Element] = &[
+    ("color", 3, 4),
+    ("dimensions", 4, 5),
+    ("sound", 5, 6),
+    ("image_bit_depth", 6, 9),
+    ("file_formats", 9, 10),
+    ("quality_assurance_targets", 10, 11),
+    ("antecedent_source", 11, 12),
+    ("level_of_compression", 12, 13),
+    ("reformatting_quality", 13, 14),
+];
+
+const GLOBE: &[Element] = &[
+    ("color", 3, 4),
+    ("physical_medium", 4, 5),
+    ("type_of_reproduction", 5, 6),
+];
+
+const TACTILE_MATERIAL: &[Element] = &[
+    ("class_of_braille_writing", 3, 5),
+    ("level_of_contraction", 5, 6),
+    ("braille_music_format", 6, 9),
+    ("special_physical_characteristics", 9, 10),
+];
+
+const PROJECTED_GRAPHIC: &[Element] = &[
+    ("color", 3, 4),
+    ("base_of_emulsion", 4, 5),
+    ("sound_on_medium_or_separate", 5, 6),
+    ("medium_for_sound", 6, 7),
+    ("dimensions", 7, 8),
+    ("secondary_support_material", 8, 9),
+];
+
+const MICROFORM: &[Element] = &[
+    ("positive_negative_aspect", 3, 4),
+    ("dimensions", 4, 5),
+    ("reduction_ratio_range", 5, 6),
+    ("reduction_ratio", 6, 9),
+    ("color", 9, 10),
+    ("emulsion_on_film", 10, 11),
+    ("generation", 11, 12),
+    ("base_of_film", 12, 13),
+];
+
+const NONPROJECTED_GRAPHIC: &[Element] = &[
+    ("color", 3, 4),
+    ("primary_support_material", 4, 5),
+    ("secondary_support_material", 5, 6),
+];
+
+const MOTION_PICTURE: &[Element] = &[
+    ("color", 3, 4),
+    ("presentation_format", 4, 5),
+    ("sound_on_medium_or_separate", 5, 6),
+    ("medium_for_sound", 6, 7),
+    ("dimensions", 7, 8),
+    ("configuration_of_playback_channels", 8, 9),
+    ("production_elements", 9, 10),
+    ("positive_negative_aspect", 10, 11),
+    ("generation", 11, 12),
+    ("base_of_film", 12, 13),
+];
+
+const REMOTE_SENSING_IMAGE: &[Element] = &[
+    ("altitude_of_sensor", 3, 4),
+    ("attitude_of_sensor", 4, 5),
+    ("cloud_cover", 5, 6),
+    ("platform_construction_type", 6, 7),
+    ("platform_use_category", 7, 8),
+    ("sensor_type", 8, 9),
+    ("data_type", 9, 11),
+];
+
+const SOUND_RECORDING: &[Element] = &[
+    ("speed", 3, 4),
+    ("configuration_of_playback_channels", 4, 5),
+    ("groove_width", 5, 6),
+    ("dimensions", 6, 7),
+    ("tape_width", 7, 8),
+    ("tape_configuration", 8, 9),
+    ("kind_of_disc", 9, 10),
+    ("kind_of_material", 10, 11),
+    ("kind_of_cutting", 11, 12),
+    ("special_playback_characteristics", 12, 13),
+    ("capture_and_storage_technique", 13, 14),
+];
+
+const VIDEORECORDING: &[Element] = &[
+    ("color", 3, 4),
+    ("videorecording_format", 4, 5),
+    ("sound_on_medium_or_separate", 5, 6),
+    ("medium_for_sound", 6, 7),
+    ("dimensions", 7, 8),
+    ("configuration_of_playback_channels", 8, 9),
+];
+
+/// The elements of all categories of material.
+const CATEGORIES: &[&[Element]] = &[
+    CATEGORY_OF_MATERIAL,
+    MAP,
+    ELECTRONIC_RESOURCE,
+    GLOBE,
+    TACTILE_MATERIAL,
+    PROJECTED_GRAPHIC,
+    MICROFORM,
+    NONPROJECTED_GRAPHIC,
+    MOTION_PICTURE,
+    REMOTE_SENSING_IMAGE,
+    SOUND_RECORDING,
+    VIDEORECORDING,
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::TestResult;
+
+    fn fixed<'a>(
+        tag: &'a [u8],
+        value: &'a [u8],
+        ldr: &str,
+    ) -> Option<FixedField<'a>> {
+        let ldr = Leader::new(ldr).unwrap();
+        let cf = ControlField::new(Tag(tag), value);
+        FixedField::new(&cf, &ldr)
+    }
+
+    #[test]
+    fn test_fixed_field_008() -> TestResult {
+        let value = b"240101s2023    gw      r     000 0 ger d";
+        let field =
+            fixed(b"008", value, "00000nam a2200000 c 4500").unwrap();
+        assert_eq!(field.material(), Some(Material::Books));
+        assert_eq!(field.date_entered(), Some(&b"240101"[..]));
+        assert_eq!(field.type_of_date(), Some(&b"s"[..]));
+        assert_eq!(field.date2(), Some(&b"    "[..]));
+        assert_eq!(field.get("literary_form"), Some(&b"0"[..]));
+        assert_eq!(field.get("festschrift"), Some(&b"0"[..]));
+        assert_eq!(field.modified_record(), Some(&b" "[..]));
+        assert_eq!(field.cataloging_source(), Some(&b"d"[..]));
+        assert_eq!(field.get("frequency"), None);
+
+        let value = b"240101c20239999gw qr p o     0   a0ger d";
+        let field =
+            fixed(b"008", value, "00000nas a2200000 c 4500").unwrap();
+        assert_eq!(
+            field.material(),
+            Some(Material::ContinuingResources)
+        );
+        assert_eq!(field.get("frequency"), Some(&b"q"[..]));
+        assert_eq!(
+            field.get("type_of_continuing_resource"),
+            Some(&b"p"[..])
+        );
+        assert_eq!(field.form_of_item(), Some(&b"o"[..]));
+        assert_eq!(field.language(), Some(&b"ger"[..]));
+
+        let field =
+            fixed(b"008", b"2401", "00000nam a2200000 c 4500").unwrap();
+        assert_eq!(field.date_entered(), None);
+        assert_eq!(field.elements().count(), 0);
+
+        let value = b"2401010u    8   4001aager0240101";
+        let field =
+            fixed(b"008", value, "00000nx  a22000001n 4500").unwrap();
+        assert_eq!(field.material(), Some(Material::Holdings));
+        assert_eq!(field.get("number_of_copies"), Some(&b"001"[..]));
+        assert_eq!(field.date1(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fixed_field_006() -> TestResult {
+        let ldr = "00000nam a2200000 c 4500";
+        let field = fixed(b"006", b"m     o  d        ", ldr).unwrap();
+        assert_eq!(field.material(), Some(Material::ComputerFiles));
+        assert_eq!(field.get("form_of_material"), Some(&b"m"[..]));
+        assert_eq!(field.form_of_item(), Some(&b"o"[..]));
+        assert_eq!(field.get("type_of_computer_file"), Some(&b"d"[..]));
+        assert_eq!(field.date1(), None);
+
+        let field = fixed(b"006", b"b", ldr).unwrap();
+        assert_eq!(field.material(), None);
+        assert_eq!(field.elements().count(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fixed_field_007() -> TestResult {
+        let ldr = "00000nam a2200000 c 4500";
+        let field = fixed(b"007", b"cr |||||||||||", ldr).unwrap();
+        assert_eq!(
+            field.category(),
+            Some(Category::ElectronicResource)
+        );
+        assert_eq!(field.material(), None);
+        assert_eq!(
+            field.get("specific_material_designation"),
+            Some(&b"r"[..])
+        );
+        assert_eq!(field.get("image_bit_depth"), Some(&b"|||"[..]));
+
+        let field = fixed(b"007", b"he bmb024baca", ldr).unwrap();
+        assert_eq!(field.category(), Some(Category::Microform));
+        assert_eq!(field.get("reduction_ratio"), Some(&b"024"[..]));
+
+        assert!(fixed(b"005", b"20240101", ldr).is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_element() {
+        assert!(is_element(b"008", b"lang"));
+        assert!(is_element(b"008", b"kind_of_record"));
+        assert!(is_element(b"008", b"frequency"));
+        assert!(is_element(b"006", b"form_of_material"));
+        assert!(is_element(b"006", b"form_of_item"));
+        assert!(is_element(b"007", b"reduction_ratio"));
+        assert!(!is_element(b"006", b"lang"));
+        assert!(!is_element(b"007", b"lang"));
+        assert!(!is_element(b"001", b"lang"));
+    }
+}
@@ -0,0 +1,431 @@
+use std::io::{self, BufRead, Read, Seek, SeekFrom, Write};
+
+use flate2::read::DeflateDecoder;
+use flate2::write::DeflateEncoder;
+use flate2::{Compression, Crc};
+
+/// The maximum number of uncompressed bytes in a block.
+const BLOCK_SIZE: usize = 0xff00;
+
+/// The maximum size of a compressed block.
+const MAX_BLOCK_SIZE: usize = 0x10000;
+
+/// The size of the header of a block.
+const HEADER_SIZE: usize = 18;
+
+/// The size of the trailer (CRC32 and ISIZE) of a block.
+const TRAILER_SIZE: usize = 8;
+
+/// An empty block, which marks the end of a BGZF file.
+const EOF_BLOCK: [u8; 28] = [
+    0x1f, 0x8b, 0x08, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x06,
+    0x00, 0x42, 0x43, 0x02, 0x00, 0x1b, 0x00, 0x03, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+];
+
+/// Returns `true` if the data starts with the header of a BGZF block.
+pub(crate) fn is_bgzf(data: &[u8]) -> bool {
+    matches!(
+        data,
+        [0x1f, 0x8b, 0x08, flags, _, _, _, _, _, _, 6, 0, b'B', b'C', 2, 0, ..]
+            if flags & 0x04 != 0
+    )
+}
+
+/// A writer, which compresses the data into independent gzip blocks
+/// (BGZF).
+///
+/// A BGZF file is a valid gzip file, which can be decompressed with
+/// any gzip tool. In contrast to a plain gzip file, a position in the
+/// uncompressed data can be addressed by a virtual offset (see
+/// [`BgzfReader`]), so that a record can be read without decompressing
+/// the preceding data.
+///
+/// # Example
+///
+/// ```rust
+/// use std::io::{Read, Write};
+///
+/// use flate2::read::MultiGzDecoder;
+/// use marc21::io::BgzfWriter;
+///
+/// let mut wtr = BgzfWriter::new(Vec::new(), Default::default());
+/// wtr.write_all(b"Ada Lovelace")?;
+/// let data = wtr.finish()?;
+///
+/// let mut out = String::new();
+/// MultiGzDecoder::new(&data[..]).read_to_string(&mut out)?;
+/// assert_eq!(out, "Ada Lovelace");
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug)]
+pub struct BgzfWriter<W: Write> {
+    writer: Option<W>,
+    buffer: Vec<u8>,
+    level: Compression,
+}
+
+impl<W: Write> BgzfWriter<W> {
+    pub fn new(writer: W, level: Compression) -> Self {
+        Self {
+            writer: Some(writer),
+            buffer: Vec::with_capacity(BLOCK_SIZE),
+            level,
+        }
+    }
+
+    /// Writes the buffered data as a block.
+    fn write_block(&mut self) -> io::Result<()> {
+        let Some(ref mut writer) = self.writer else {
+            return Ok(());
+        };
+
+        if self.buffer.is_empty() {
+            return Ok(());
+        }
+
+        let mut payload = deflate(&self.buffer, self.level)?;
+        if payload.len() + HEADER_SIZE + TRAILER_SIZE > MAX_BLOCK_SIZE {
+            // Incompressible data is stored without compression, which
+            // always fits into a block.
+            payload = deflate(&self.buffer, Compression::none())?;
+        }
+
+        let size = HEADER_SIZE + payload.len() + TRAILER_SIZE;
+        let mut crc = Crc::new();
+        crc.update(&self.buffer);
+
+        writer.write_all(&EOF_BLOCK[..16])?;
+        writer.write_all(&((size - 1) as u16).to_le_bytes())?;
+        writer.write_all(&payload)?;
+        writer.write_all(&crc.sum().to_le_bytes())?;
+        writer.write_all(&(self.buffer.len() as u32).to_le_bytes())?;
+
+        self.buffer.clear();
+        Ok(())
+    }
+
+    /// Writes the remaining data and the end-of-file marker and
+    /// returns the underlying writer.
+    pub fn finish(mut self) -> io::Result<W> {
+        self.write_block()?;
+
+        let mut writer = self.writer.take().unwrap();
+        writer.write_all(&EOF_BLOCK)?;
+        Ok(writer)
+    }
+}
+
+impl<W: Write> Write for BgzfWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.buffer.len() == BLOCK_SIZE {
+            self.write_block()?;
+        }
+
+        let n = buf.len().min(BLOCK_SIZE - self.buffer.len());
+        self.buffer.extend_from_slice(&buf[..n]);
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.write_block()?;
+
+        match self.writer {
+            Some(ref mut writer) => writer.flush(),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<W: Write> Drop for BgzfWriter<W> {
+    fn drop(&mut self) {
+        if self.writer.is_some() && self.write_block().is_ok() {
+            let _ = self.writer.take().unwrap().write_all(&EOF_BLOCK);
+        }
+    }
+}
+
+fn deflate(data: &[u8], level: Compression) -> io::Result<Vec<u8>> {
+    let mut encoder = DeflateEncoder::new(Vec::new(), level);
+    encoder.write_all(data)?;
+    encoder.finish()
+}
+
+/// A reader, which decompresses a BGZF file block by block.
+///
+/// A virtual offset addresses a position in the uncompressed data. The
+/// upper 48 bits are the offset of a block in the compressed data and
+/// the lower 16 bits are the offset within the uncompressed block.
+///
+/// # Example
+///
+/// ```rust
+/// use std::io::{Cursor, Read, Write};
+///
+/// use marc21::io::{BgzfReader, BgzfWriter};
+///
+/// let mut wtr = BgzfWriter::new(Vec::new(), Default::default());
+/// wtr.write_all(&b"0123456789".repeat(10_000))?;
+/// let data = wtr.finish()?;
+///
+/// let mut rdr = BgzfReader::new(Cursor::new(&data));
+/// let mut out = Vec::new();
+/// rdr.read_to_end(&mut out)?;
+///
+/// let offset = rdr.virtual_offset(90_005).unwrap();
+/// assert!(offset >> 16 > 0);
+///
+/// let mut buf = [0; 5];
+/// rdr.seek_virtual(offset)?;
+/// rdr.read_exact(&mut buf)?;
+/// assert_eq!(&buf, b"56789");
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug)]
+pub struct BgzfReader<R: Read> {
+    reader: R,
+    block: Vec<u8>,
+    compressed: Vec<u8>,
+    pos: usize,
+    coffset: u64,
+    uoffset: u64,
+    blocks: Vec<(u64, u64)>,
+}
+
+impl<R: Read> BgzfReader<R> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            block: Vec::with_capacity(BLOCK_SIZE),
+            compressed: Vec::with_capacity(MAX_BLOCK_SIZE),
+            pos: 0,
+            coffset: 0,
+            uoffset: 0,
+            blocks: vec![],
+        }
+    }
+
+    /// Translates an offset in the uncompressed data into a virtual
+    /// offset. Only offsets within blocks, that have been read since
+    /// the reader was created or positioned, can be translated.
+    pub fn virtual_offset(&self, offset: u64) -> Option<u64> {
+        let idx = self
+            .blocks
+            .partition_point(|(start, _)| *start <= offset)
+            .checked_sub(1)?;
+
+        let (start, coffset) = self.blocks[idx];
+        let within = offset - start;
+
+        if within >= MAX_BLOCK_SIZE as u64 {
+            return None;
+        }
+
+        Some((coffset << 16) | within)
+    }
+
+    /// Reads the next non-empty block. Returns `false` at the end of
+    /// the input.
+    fn read_block(&mut self) -> io::Result<bool> {
+        loop {
+            self.uoffset += self.block.len() as u64;
+            self.block.clear();
+            self.pos = 0;
+
+            self.compressed.resize(HEADER_SIZE, 0);
+            let n = read_full(&mut self.reader, &mut self.compressed)?;
+            if n == 0 {
+                return Ok(false);
+            }
+
+            if n < HEADER_SIZE || !is_bgzf(&self.compressed) {
+                return Err(invalid_data("invalid BGZF block header"));
+            }
+
+            let size = u16::from_le_bytes([
+                self.compressed[16],
+                self.compressed[17],
+            ]) as usize
+                + 1;
+
+            if size < HEADER_SIZE + TRAILER_SIZE {
+                return Err(invalid_data("invalid BGZF block size"));
+            }
+
+            self.compressed.resize(size, 0);
+            self.reader
+                .read_exact(&mut self.compressed[HEADER_SIZE..])?;
+
+            let payload = &self.compressed[HEADER_SIZE..size - 8];
+            let trailer = &self.compressed[size - 8..];
+
+            DeflateDecoder::new(payload)
+                .read_to_end(&mut self.block)?;
+
+            let mut crc = Crc::new();
+            crc.update(&self.block);
+
+            if trailer[..4] != crc.sum().to_le_bytes()
+                || trailer[4..]
+                    != (self.block.len() as u32).to_le_bytes()
+            {
+                return Err(invalid_data(
+                    "BGZF block checksum mismatch",
+                ));
+            }
+
+            let coffset = self.coffset;
+            self.coffset += size as u64;
+
+            if !self.block.is_empty() {
+                self.blocks.push((self.uoffset, coffset));
+                return Ok(true);
+            }
+        }
+    }
+}
+
+impl<R: Read + Seek> BgzfReader<R> {
+    /// Positions the reader at the given virtual offset.
+    pub fn seek_virtual(&mut self, offset: u64) -> io::Result<()> {
+        let coffset = offset >> 16;
+        let within = (offset & 0xffff) as usize;
+
+        self.reader.seek(SeekFrom::Start(coffset))?;
+        self.coffset = coffset;
+        self.uoffset = 0;
+        self.blocks.clear();
+        self.block.clear();
+        self.pos = 0;
+
+        // At the end of the input, the block is empty.
+        self.read_block()?;
+        if within > self.block.len() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "virtual offset out of range",
+            ));
+        }
+
+        self.pos = within;
+        Ok(())
+    }
+}
+
+impl<R: Read> Read for BgzfReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let data = self.fill_buf()?;
+        let n = data.len().min(buf.len());
+        buf[..n].copy_from_slice(&data[..n]);
+        self.consume(n);
+        Ok(n)
+    }
+}
+
+impl<R: Read> BufRead for BgzfReader<R> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        if self.pos >= self.block.len() {
+            self.read_block()?;
+        }
+
+        Ok(&self.block[self.pos..])
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.pos = (self.pos + amt).min(self.block.len());
+    }
+}
+
+/// Reads until the buffer is full or the end of the input is reached.
+fn read_full<R: Read>(
+    reader: &mut R,
+    buf: &mut [u8],
+) -> io::Result<usize> {
+    let mut n = 0;
+    while n < buf.len() {
+        match reader.read(&mut buf[n..]) {
+            Ok(0) => break,
+            Ok(k) => n += k,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
+
+    Ok(n)
+}
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use flate2::read::MultiGzDecoder;
+
+    use super::*;
+    use crate::common::TestResult;
+
+    #[test]
+    fn test_bgzf_roundtrip() -> TestResult {
+        let data: Vec<u8> = (0..200_000u32)
+            .flat_map(|i| {
+                (i.wrapping_mul(2_654_435_761) >> 13).to_le_bytes()
+            })
+            .collect();
+
+        let mut wtr = BgzfWriter::new(Vec::new(), Compression::new(6));
+        wtr.write_all(&data)?;
+        let compressed = wtr.finish()?;
+
+        assert!(is_bgzf(&compressed));
+        assert!(compressed.ends_with(&EOF_BLOCK));
+
+        // BGZF is a valid multi-member gzip file.
+        let mut out = vec![];
+        MultiGzDecoder::new(&compressed[..]).read_to_end(&mut out)?;
+        assert_eq!(out, data);
+
+        let mut rdr = BgzfReader::new(Cursor::new(&compressed));
+        let mut out = vec![];
+        rdr.read_to_end(&mut out)?;
+        assert_eq!(out, data);
+
+        let offsets: Vec<_> =
+            [0, 1, BLOCK_SIZE, BLOCK_SIZE + 7, data.len() - 3]
+                .into_iter()
+                .map(|offset| {
+                    (offset, rdr.virtual_offset(offset as u64).unwrap())
+                })
+                .collect();
+
+        for (offset, voffset) in offsets {
+            rdr.seek_virtual(voffset)?;
+
+            let mut out = vec![];
+            rdr.read_to_end(&mut out)?;
+            assert_eq!(out, &data[offset..]);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bgzf_reader_invalid() -> TestResult {
+        let data = include_bytes!("../../tests/data/ada.mrc.gz");
+        assert!(!is_bgzf(data));
+
+        let mut rdr = BgzfReader::new(&data[..]);
+        let e = rdr.read_to_end(&mut vec![]).unwrap_err();
+        assert_eq!(e.kind(), io::ErrorKind::InvalidData);
+
+        let mut rdr = BgzfReader::new(&EOF_BLOCK[..]);
+        let mut out = vec![];
+        assert_eq!(rdr.read_to_end(&mut out)?, 0);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,636 @@
+use std::collections::BTreeMap;
+use std::io::{self, BufRead, BufReader, Read};
+use std::ops::ControlFlow;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc::{channel, sync_channel};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use crate::ByteRecord;
+use crate::io::{MarcReadOptions, MarcReader, ReadMarcError};
+
+/// The default minimum size of a chunk in bytes.
+const CHUNK_SIZE: usize = 1 << 20;
+
+/// A chunk of consecutive ISO 2709 records.
+///
+/// A chunk owns the raw data of its records, so that it can be sent to
+/// another thread and parsed there.
+#[derive(Debug, Clone)]
+pub struct Chunk {
+    index: usize,
+    count: usize,
+    offset: usize,
+    len: usize,
+    data: Vec<u8>,
+}
+
+impl Chunk {
+    /// Returns the (zero-based) position of the chunk within the
+    /// sequence of chunks.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the number of records preceding the chunk in the input.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the number of bytes preceding the chunk in the input.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the number of records in the chunk.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the chunk doesn't contain any records.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the raw data of the chunk.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Creates a reader over the records of the chunk. The record
+    /// numbers and byte offsets of errors and warnings refer to the
+    /// whole input.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::io::ChunkReader;
+    /// use marc21::prelude::*;
+    ///
+    /// let data = include_bytes!("../../tests/data/ada.mrc").repeat(3);
+    /// let options = MarcReadOptions::default();
+    ///
+    /// let chunk = ChunkReader::new(&data[..], options.clone())
+    ///     .next()
+    ///     .unwrap()?;
+    ///
+    /// let mut rdr = chunk.records(&options);
+    /// while let Some(result) = rdr.next_byte_record() {
+    ///     assert!(result.is_ok());
+    /// }
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn records(
+        &self,
+        options: &MarcReadOptions,
+    ) -> MarcReader<&[u8]> {
+        MarcReader::new(&self.data[..], options.clone())
+            .with_position(self.count, self.offset)
+    }
+}
+
+/// A reader, which splits the input at record terminators into chunks
+/// of records.
+///
+/// The chunks can be parsed independently of each other, e.g. by a
+/// pool of threads (see [`par_map`](Self::par_map)).
+#[derive(Debug)]
+pub struct ChunkReader<R: Read> {
+    reader: BufReader<R>,
+    options: MarcReadOptions,
+    chunk_size: usize,
+    index: usize,
+    count: usize,
+    offset: usize,
+    error: Option<io::Error>,
+    done: bool,
+}
+
+impl<R: Read> ChunkReader<R> {
+    pub fn new(reader: R, options: MarcReadOptions) -> Self {
+        Self {
+            reader: BufReader::new(reader),
+            options,
+            chunk_size: CHUNK_SIZE,
+            index: 0,
+            count: 0,
+            offset: 0,
+            error: None,
+            done: false,
+        }
+    }
+
+    /// Sets the minimum size of a chunk in bytes (default: 1 MiB). A
+    /// chunk is only split at the end of a record, so it contains at
+    /// least one record, unless the input is exhausted.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::io::ChunkReader;
+    ///
+    /// let data = include_bytes!("../../tests/data/ada.mrc").repeat(3);
+    /// let chunks = ChunkReader::new(&data[..], Default::default())
+    ///     .chunk_size(1)
+    ///     .collect::<Result<Vec<_>, _>>()?;
+    ///
+    /// assert_eq!(chunks.len(), 3);
+    /// assert_eq!(chunks[2].count(), 2);
+    /// assert_eq!(chunks[2].offset(), 7224);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn chunk_size(mut self, size: usize) -> Self {
+        self.chunk_size = size;
+        self
+    }
+
+    /// Returns the number of records in the chunks read so far.
+    pub fn records_read(&self) -> usize {
+        self.count
+    }
+
+    /// Parses the records on a pool of `threads` threads and applies
+    /// `map` to each record (or the error, if the record is invalid)
+    /// and its (one-based) number within the input. The number also
+    /// counts the records, which were rejected by the prefilter (see
+    /// [`MarcReadOptions::prefilter`]). The results are
+    /// passed in input order to `sink`, which stops the processing by
+    /// returning [`ControlFlow::Break`].
+    ///
+    /// The input is read on the calling thread, so the underlying
+    /// reader doesn't need to be [`Send`]. An I/O error is returned
+    /// after the results of all preceding records have been passed to
+    /// `sink`. If `map` panics, the processing is stopped and the panic
+    /// is resumed on the calling thread.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::ops::ControlFlow;
+    ///
+    /// use marc21::prelude::*;
+    ///
+    /// let mut numbers = vec![];
+    /// MarcReadOptions::default()
+    ///     .try_into_chunk_reader_from_path("tests/data/DUMP.mrc.gz")?
+    ///     .par_map(
+    ///         4,
+    ///         |number, result| result.ok().map(|_| number),
+    ///         |item| {
+    ///             numbers.extend(item);
+    ///             ControlFlow::Continue(())
+    ///         },
+    ///     )?;
+    ///
+    /// assert!(numbers.is_sorted());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn par_map<T, M, S>(
+        &mut self,
+        threads: usize,
+        map: M,
+        mut sink: S,
+    ) -> io::Result<()>
+    where
+        T: Send,
+        M: Fn(usize, Result<ByteRecord<'_>, ReadMarcError<'_>>) -> T
+            + Sync,
+        S: FnMut(T) -> ControlFlow<()>,
+    {
+        let options = self.options.clone();
+        let process = |chunk: Chunk| -> Vec<T> {
+            let mut reader = chunk.records(&options);
+            let mut items = Vec::with_capacity(chunk.len);
+
+            while let Some((number, result)) =
+                reader.next_numbered_record()
+            {
+                items.push(map(number, result));
+            }
+
+            items
+        };
+
+        if threads <= 1 {
+            for chunk in self.by_ref() {
+                for item in process(chunk?) {
+                    if sink(item).is_break() {
+                        return Ok(());
+                    }
+                }
+            }
+
+            return Ok(());
+        }
+
+        let (chunks_tx, chunks_rx) = sync_channel::<Chunk>(threads);
+        let (results_tx, results_rx) = channel();
+        let chunks_rx = Arc::new(Mutex::new(chunks_rx));
+
+        let (result, panic) = thread::scope(|scope| {
+            // The sender is moved into the closure, so that the workers
+            // are stopped, even if `sink` panics.
+            let chunks_tx = chunks_tx;
+
+            for _ in 0..threads {
+                let results = results_tx.clone();
+                let chunks = chunks_rx.clone();
+                let process = &process;
+
+                scope.spawn(move || {
+                    loop {
+                        // The lock must be released before the chunk
+                        // is processed.
+                        let chunk = chunks.lock().unwrap().recv();
+                        let Ok(chunk) = chunk else {
+                            break;
+                        };
+
+                        // A panic is passed to the calling thread,
+                        // which stops the processing.
+                        let index = chunk.index;
+                        let items = panic::catch_unwind(
+                            AssertUnwindSafe(|| process(chunk)),
+                        );
+
+                        let failed = items.is_err();
+                        if results.send((index, items)).is_err()
+                            || failed
+                        {
+                            break;
+                        }
+                    }
+                });
+            }
+
+            // The receiver is dropped as soon as all workers have
+            // stopped, even if a worker panics.
+            drop(chunks_rx);
+            drop(results_tx);
+
+            // The number of chunks in flight is bounded, so that the
+            // memory usage doesn't depend on the size of the input.
+            let mut pending = BTreeMap::new();
+            let mut in_flight = 0;
+            let mut next = 0;
+            let mut error = None;
+            let mut panic = None;
+
+            'outer: loop {
+                while in_flight < 2 * threads {
+                    match self.next() {
+                        Some(Ok(chunk)) => {
+                            if chunks_tx.send(chunk).is_err() {
+                                break;
+                            }
+
+                            in_flight += 1;
+                        }
+                        Some(Err(e)) => error = Some(e),
+                        None => break,
+                    }
+                }
+
+                if in_flight == 0 {
+                    break;
+                }
+
+                let Ok((index, items)) = results_rx.recv() else {
+                    break;
+                };
+
+                let items = match items {
+                    Ok(items) => items,
+                    Err(payload) => {
+                        panic = Some(payload);
+                        break;
+                    }
+                };
+
+                pending.insert(index, items);
+                in_flight -= 1;
+
+                while let Some(items) = pending.remove(&next) {
+                    for item in items {
+                        if sink(item).is_break() {
+                            error = None;
+                            break 'outer;
+                        }
+                    }
+
+                    next += 1;
+                }
+            }
+
+            // Stops the workers.
+            drop(chunks_tx);
+
+            match error {
+                Some(e) => (Err(e), panic),
+                None => (Ok(()), panic),
+            }
+        });
+
+        if let Some(payload) = panic {
+            panic::resume_unwind(payload);
+        }
+
+        result
+    }
+
+    /// Parses the records on a pool of `threads` threads and calls `f`
+    /// on each record (or the error, if the record is invalid) and its
+    /// (one-based) number within the input. The order of the calls is
+    /// unspecified.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    ///
+    /// use marc21::prelude::*;
+    ///
+    /// let count = AtomicUsize::new(0);
+    /// MarcReadOptions::default()
+    ///     .try_into_chunk_reader_from_path("tests/data/ada.mrc")?
+    ///     .par_for_each(4, |_, result| {
+    ///         if result.is_ok() {
+    ///             count.fetch_add(1, Ordering::Relaxed);
+    ///         }
+    ///     })?;
+    ///
+    /// assert_eq!(count.into_inner(), 1);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn par_for_each<F>(
+        &mut self,
+        threads: usize,
+        f: F,
+    ) -> io::Result<()>
+    where
+        F: Fn(usize, Result<ByteRecord<'_>, ReadMarcError<'_>>) + Sync,
+    {
+        self.par_map(threads, f, |()| ControlFlow::Continue(()))
+    }
+}
+
+impl<R: Read> Iterator for ChunkReader<R> {
+    type Item = io::Result<Chunk>;
+
+    /// Reads the next chunk. After an I/O error, the records read so
+    /// far are returned as a chunk and the error is returned by the
+    /// subsequent call.
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(e) = self.error.take() {
+            return Some(Err(e));
+        }
+
+        if self.done {
+            return None;
+        }
+
+        let mut chunk = Chunk {
+            index: self.index,
+            count: self.count,
+            offset: self.offset,
+            len: 0,
+            data: Vec::with_capacity(self.chunk_size),
+        };
+
+        while chunk.data.len() < self.chunk_size {
+            let start = chunk.data.len();
+
+            match self.reader.read_until(b'\x1d', &mut chunk.data) {
+                Ok(0) => {
+                    self.done = true;
+                    break;
+                }
+                Ok(n) => {
+                    self.offset += n;
+
+                    // In lenient mode, the reader skips line breaks
+                    // between records.
+                    if !self.options.lenient
+                        || !chunk.data[start..]
+                            .iter()
+                            .all(u8::is_ascii_whitespace)
+                    {
+                        chunk.len += 1;
+                        self.count += 1;
+                    }
+                }
+                Err(e) => {
+                    chunk.data.truncate(start);
+                    self.error = Some(e);
+                    self.done = true;
+                    break;
+                }
+            }
+        }
+
+        if chunk.data.is_empty() {
+            return self.error.take().map(Err);
+        }
+
+        self.index += 1;
+        Some(Ok(chunk))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::TestResult;
+
+    static ADA_LOVELACE: &[u8] =
+        include_bytes!("../../tests/data/ada.mrc");
+
+    #[test]
+    fn test_chunk_reader() -> TestResult {
+        let data = ADA_LOVELACE.repeat(5);
+        let chunks: Vec<_> =
+            ChunkReader::new(&data[..], MarcReadOptions::default())
+                .chunk_size(ADA_LOVELACE.len() * 2)
+                .collect::<Result<_, _>>()?;
+
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(
+            chunks.iter().map(Chunk::len).collect::<Vec<_>>(),
+            vec![2, 2, 1]
+        );
+
+        assert_eq!(chunks[1].index(), 1);
+        assert_eq!(chunks[1].count(), 2);
+        assert_eq!(chunks[1].offset(), 2 * ADA_LOVELACE.len());
+        assert_eq!(chunks[2].as_bytes(), ADA_LOVELACE);
+
+        let data = [ADA_LOVELACE, b"\n", ADA_LOVELACE, b"\n"].concat();
+        let options = MarcReadOptions::default().lenient(true);
+        let chunks: Vec<_> = ChunkReader::new(&data[..], options)
+            .chunk_size(1)
+            .collect::<Result<_, _>>()?;
+
+        assert_eq!(
+            chunks.iter().map(Chunk::len).collect::<Vec<_>>(),
+            vec![1, 1, 0]
+        );
+
+        assert!(
+            ChunkReader::new(&b""[..], MarcReadOptions::default())
+                .next()
+                .is_none()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_chunk_reader_par_map() -> TestResult {
+        let mut data = ADA_LOVELACE.repeat(50);
+        data.extend_from_slice(b"00000nz  a2200000nc 45x0\x1d");
+        data.extend_from_slice(ADA_LOVELACE);
+
+        for threads in [1, 3] {
+            let mut items = vec![];
+            ChunkReader::new(&data[..], MarcReadOptions::default())
+                .chunk_size(4096)
+                .par_map(
+                    threads,
+                    |number, result| match result {
+                        Ok(record) => (number, record.fields().count()),
+                        Err(ReadMarcError::Parse(e)) => {
+                            assert_eq!(e.record(), Some(number));
+                            assert_eq!(
+                                e.offset(),
+                                Some(50 * ADA_LOVELACE.len())
+                            );
+                            (number, 0)
+                        }
+                        Err(ReadMarcError::IO(_)) => unreachable!(),
+                    },
+                    |item| {
+                        items.push(item);
+                        ControlFlow::Continue(())
+                    },
+                )?;
+
+            assert_eq!(items.len(), 52);
+            assert!(
+                items.iter().enumerate().all(|(i, (n, _))| i + 1 == *n)
+            );
+            assert_eq!(items[50], (51, 0));
+            assert_eq!(items[0].0, 1);
+            assert_eq!(items[51].1, items[0].1);
+
+            let mut count = 0;
+            ChunkReader::new(&data[..], MarcReadOptions::default())
+                .chunk_size(4096)
+                .par_map(
+                    threads,
+                    |number, _| number,
+                    |_| {
+                        count += 1;
+                        if count == 7 {
+                            ControlFlow::Break(())
+                        } else {
+                            ControlFlow::Continue(())
+                        }
+                    },
+                )?;
+
+            assert_eq!(count, 7);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_chunk_reader_par_map_prefilter() -> TestResult {
+        use crate::matcher::{MatchOptions, RecordMatcher};
+
+        // The invalid record contains the literal of the prefilter, so
+        // it's the only record, which isn't skipped.
+        let mut data = ADA_LOVELACE.repeat(20);
+        data.extend_from_slice(b"00000nz  a2200000nc 45x0TEST\x1d");
+        data.extend_from_slice(&ADA_LOVELACE.repeat(5));
+
+        let matcher = RecordMatcher::new("001 == 'TEST'")?;
+        let prefilter = matcher.prefilter(&MatchOptions::default());
+        let options =
+            MarcReadOptions::default().prefilter(prefilter.unwrap());
+
+        for threads in [1, 3] {
+            let mut items = vec![];
+            let mut reader =
+                ChunkReader::new(&data[..], options.clone())
+                    .chunk_size(4096);
+
+            reader.par_map(
+                threads,
+                |number, result| match result {
+                    Err(ReadMarcError::Parse(e)) => {
+                        (number, e.record())
+                    }
+                    _ => unreachable!(),
+                },
+                |item| {
+                    items.push(item);
+                    ControlFlow::Continue(())
+                },
+            )?;
+
+            assert_eq!(items, vec![(21, Some(21))]);
+            assert_eq!(reader.records_read(), 26);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_chunk_reader_par_map_panic() {
+        let data = ADA_LOVELACE.repeat(50);
+
+        for threads in [1, 3] {
+            let result = panic::catch_unwind(|| {
+                ChunkReader::new(&data[..], MarcReadOptions::default())
+                    .chunk_size(4096)
+                    .par_map(
+                        threads,
+                        |number, _| {
+                            if number == 17 {
+                                panic!("record {number}");
+                            }
+
+                            number
+                        },
+                        |_| ControlFlow::Continue(()),
+                    )
+            });
+
+            let payload = result.unwrap_err();
+            assert_eq!(
+                payload.downcast_ref::<String>().map(String::as_str),
+                Some("record 17")
+            );
+
+            let result = panic::catch_unwind(|| {
+                ChunkReader::new(&data[..], MarcReadOptions::default())
+                    .chunk_size(4096)
+                    .par_map(
+                        threads,
+                        |number, _| number,
+                        |number| {
+                            assert_ne!(number, 17);
+                            ControlFlow::Continue(())
+                        },
+                    )
+            });
+
+            assert!(result.is_err());
+        }
+    }
+}
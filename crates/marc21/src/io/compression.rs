@@ -0,0 +1,143 @@
+use std::io::{self, Cursor, Read};
+
+#[cfg(feature = "compression")]
+use bzip2::read::MultiBzDecoder;
+use flate2::read::MultiGzDecoder;
+#[cfg(feature = "compression")]
+use liblzma::read::XzDecoder;
+
+/// A compression format, which is detected by the magic bytes at the
+/// start of the data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Compression {
+    Gzip,
+    Zstd,
+    Bzip2,
+    Xz,
+}
+
+impl Compression {
+    /// The maximum length of the magic bytes.
+    const MAGIC_LEN: usize = 6;
+
+    /// Detects the compression format by the magic bytes at the start
+    /// of the data.
+    pub(crate) fn detect(data: &[u8]) -> Option<Self> {
+        match data {
+            [0x1f, 0x8b, ..] => Some(Self::Gzip),
+            [0x28, 0xb5, 0x2f, 0xfd, ..] => Some(Self::Zstd),
+            [b'B', b'Z', b'h', ..] => Some(Self::Bzip2),
+            [0xfd, b'7', b'z', b'X', b'Z', 0x00, ..] => Some(Self::Xz),
+            _ => None,
+        }
+    }
+}
+
+/// Wraps the reader into a decoder, if the data is compressed.
+/// Concatenated streams (e.g. `cat a.gz b.gz`) are decoded as a whole.
+/// Without the `compression` feature, only gzip is supported.
+pub(crate) fn decompress<'a>(
+    mut reader: Box<dyn Read + 'a>,
+) -> io::Result<Box<dyn Read + 'a>> {
+    let mut magic = Vec::with_capacity(Compression::MAGIC_LEN);
+    reader
+        .by_ref()
+        .take(Compression::MAGIC_LEN as u64)
+        .read_to_end(&mut magic)?;
+
+    let compression = Compression::detect(&magic);
+    let reader = Cursor::new(magic).chain(reader);
+
+    Ok(match compression {
+        None => Box::new(reader),
+        Some(Compression::Gzip) => {
+            Box::new(MultiGzDecoder::new(reader))
+        }
+        #[cfg(feature = "compression")]
+        Some(Compression::Zstd) => {
+            Box::new(zstd::Decoder::new(reader)?)
+        }
+        #[cfg(feature = "compression")]
+        Some(Compression::Bzip2) => {
+            Box::new(MultiBzDecoder::new(reader))
+        }
+        #[cfg(feature = "compression")]
+        Some(Compression::Xz) => {
+            Box::new(XzDecoder::new_multi_decoder(reader))
+        }
+        #[cfg(not(feature = "compression"))]
+        Some(compression) => {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                format!(
+                    "{compression:?} compressed data requires the \
+                     `compression` feature"
+                ),
+            ));
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::read;
+
+    use super::*;
+    use crate::common::TestResult;
+
+    #[test]
+    #[cfg(feature = "compression")]
+    fn test_decompress() -> TestResult {
+        let expected = read("tests/data/ada.mrc")?;
+
+        for (ext, compression) in [
+            ("gz", Compression::Gzip),
+            ("zst", Compression::Zstd),
+            ("bz2", Compression::Bzip2),
+            ("xz", Compression::Xz),
+        ] {
+            let data = read(format!("tests/data/ada.mrc.{ext}"))?;
+            assert_eq!(Compression::detect(&data), Some(compression));
+
+            let mut actual = vec![];
+            decompress(Box::new(&data[..]))?
+                .read_to_end(&mut actual)?;
+            assert_eq!(actual, expected);
+
+            // concatenated streams
+            let data = data.repeat(2);
+            let mut actual = vec![];
+            decompress(Box::new(&data[..]))?
+                .read_to_end(&mut actual)?;
+            assert_eq!(actual, expected.repeat(2));
+        }
+
+        assert_eq!(Compression::detect(&expected), None);
+        for data in [&expected[..], b"", b"\x1f"] {
+            let mut actual = vec![];
+            decompress(Box::new(data))?.read_to_end(&mut actual)?;
+            assert_eq!(actual, data);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(not(feature = "compression"))]
+    fn test_decompress_unsupported() -> TestResult {
+        let expected = read("tests/data/ada.mrc")?;
+
+        let data = read("tests/data/ada.mrc.gz")?;
+        let mut actual = vec![];
+        decompress(Box::new(&data[..]))?.read_to_end(&mut actual)?;
+        assert_eq!(actual, expected);
+
+        for ext in ["zst", "bz2", "xz"] {
+            let data = read(format!("tests/data/ada.mrc.{ext}"))?;
+            let err = decompress(Box::new(&data[..])).err().unwrap();
+            assert_eq!(err.kind(), io::ErrorKind::Unsupported);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,732 @@
+use std::collections::BTreeMap;
+use std::fmt::{self, Display};
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use bstr::ByteSlice;
+
+use crate::io::bgzf::is_bgzf;
+use crate::io::compression::Compression;
+use crate::io::{
+    BgzfReader, ByteRecordsIter, MarcReadOptions, MarcReader,
+    ReadMarcError, open,
+};
+use crate::{ByteRecord, RecordBuf};
+
+/// The first line of an index file.
+const MAGIC: &str = "marc21-index\t1";
+
+/// The storage format of an indexed file, which determines how a
+/// record is located by its offset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FileKind {
+    /// An uncompressed file; the offset is a byte offset.
+    Plain,
+    /// A BGZF compressed file; the offset is a virtual offset.
+    Bgzf,
+    /// Any other compressed file; the offset is a byte offset in the
+    /// uncompressed data, which must be decompressed from the start.
+    Compressed,
+}
+
+impl FileKind {
+    fn detect(path: &Path) -> io::Result<Self> {
+        let mut header = Vec::with_capacity(18);
+        File::open(path)?.take(18).read_to_end(&mut header)?;
+
+        Ok(match Compression::detect(&header) {
+            None => Self::Plain,
+            Some(_) if is_bgzf(&header) => Self::Bgzf,
+            Some(_) => Self::Compressed,
+        })
+    }
+}
+
+impl Display for FileKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Plain => write!(f, "plain"),
+            Self::Bgzf => write!(f, "bgzf"),
+            Self::Compressed => write!(f, "compressed"),
+        }
+    }
+}
+
+impl FromStr for FileKind {
+    type Err = io::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "plain" => Ok(Self::Plain),
+            "bgzf" => Ok(Self::Bgzf),
+            "compressed" => Ok(Self::Compressed),
+            _ => Err(invalid_data(format!("invalid file kind '{s}'"))),
+        }
+    }
+}
+
+/// An index, which maps keys (e.g. control numbers) to the files and
+/// offsets of the corresponding records.
+///
+/// The offsets of uncompressed files are byte offsets. The offsets of
+/// BGZF compressed files are virtual offsets, so that a record can be
+/// read without decompressing the preceding data. Other compressed
+/// files are supported, but they have to be decompressed from the
+/// start to look up a record.
+///
+/// An index is stored as a text file. Each line is a tab-separated
+/// list of values: a header line, a `file` line for each indexed file
+/// and an `entry` line (file number, offset and key) for each key.
+/// The paths of the files are stored as absolute paths. Relative paths
+/// of an index file, that was written by hand, are resolved against
+/// the directory of the index file.
+///
+/// # Example
+///
+/// ```rust
+/// use marc21::io::Index;
+/// use marc21::prelude::*;
+///
+/// let mut index = Index::default();
+/// let file = index.add_file("tests/data/ada.mrc")?;
+/// let mut rdr = index.reader(file, &MarcReadOptions::default())?;
+///
+/// while let Some(result) = rdr.next_byte_record() {
+///     let record = result.expect("valid record");
+///     let key = record.control_number().unwrap().to_vec();
+///     let offset = rdr.offset().unwrap();
+///     index.insert(key, file, offset);
+/// }
+///
+/// let records = index.lookup(b"119232022", &Default::default())?;
+/// assert_eq!(records.len(), 1);
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Index {
+    files: Vec<(FileKind, PathBuf)>,
+    entries: BTreeMap<Vec<u8>, Vec<(usize, u64)>>,
+}
+
+impl Index {
+    /// Reads an index from the file at the given path. Compressed
+    /// index files are decompressed on the fly.
+    ///
+    /// Relative paths of the indexed files are resolved against the
+    /// directory of the index file.
+    pub fn from_path<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let path = path.as_ref();
+        let mut index = Self::read_from(BufReader::new(open(path)?))?;
+
+        if let Some(dir) = path.parent() {
+            for (_, file) in index.files.iter_mut() {
+                if file.is_relative() {
+                    *file = dir.join(&file);
+                }
+            }
+        }
+
+        Ok(index)
+    }
+
+    /// Reads an index.
+    pub fn read_from<R: BufRead>(reader: R) -> io::Result<Self> {
+        let mut index = Self::default();
+        let mut lines = reader.split(b'\n');
+
+        match lines.next().transpose()? {
+            Some(line) if line == MAGIC.as_bytes() => (),
+            _ => return Err(invalid_data("invalid index header")),
+        }
+
+        for line in lines {
+            let line = line?;
+            let mut parts = line.splitn(4, |b| *b == b'\t');
+
+            match (parts.next(), parts.next(), parts.next()) {
+                (Some(b"file"), Some(kind), Some(path)) => {
+                    let kind = kind.to_str_lossy().parse()?;
+                    let path =
+                        PathBuf::from(path.to_str_lossy().as_ref());
+                    index.files.push((kind, path));
+                }
+                (Some(b"entry"), Some(file), Some(offset)) => {
+                    let file = parse_number::<usize>(file)?;
+                    let offset = parse_number::<u64>(offset)?;
+                    let Some(key) = parts.next() else {
+                        return Err(invalid_data("missing key"));
+                    };
+
+                    if file >= index.files.len() {
+                        return Err(invalid_data(format!(
+                            "unknown file {file}"
+                        )));
+                    }
+
+                    index.insert(key, file, offset);
+                }
+                _ => {
+                    return Err(invalid_data(format!(
+                        "invalid index line '{}'",
+                        line.to_str_lossy()
+                    )));
+                }
+            }
+        }
+
+        Ok(index)
+    }
+
+    /// Writes the index.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::io::Index;
+    ///
+    /// let mut index = Index::default();
+    /// let file = index.add_file("tests/data/ada.mrc")?;
+    /// index.insert("119232022", file, 0);
+    ///
+    /// let mut data = Vec::new();
+    /// index.write_to(&mut data)?;
+    /// assert_eq!(Index::read_from(&data[..])?, index);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn write_to<W: Write>(&self, out: &mut W) -> io::Result<()> {
+        writeln!(out, "{MAGIC}")?;
+
+        for (kind, path) in self.files.iter() {
+            let Some(path) = path.to_str() else {
+                return Err(invalid_data(format!(
+                    "invalid path {}",
+                    path.display()
+                )));
+            };
+
+            writeln!(out, "file\t{kind}\t{path}")?;
+        }
+
+        for (key, locations) in self.entries.iter() {
+            if key.contains(&b'\n') {
+                return Err(invalid_data(format!(
+                    "invalid key '{}'",
+                    key.to_str_lossy()
+                )));
+            }
+
+            for (file, offset) in locations {
+                write!(out, "entry\t{file}\t{offset}\t")?;
+                out.write_all(key)?;
+                writeln!(out)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Adds a file to the index and returns its number. The storage
+    /// format of the file is detected by its content and the path is
+    /// stored as an absolute path, so that the index can be used from
+    /// any working directory.
+    pub fn add_file<P: AsRef<Path>>(
+        &mut self,
+        path: P,
+    ) -> io::Result<usize> {
+        let path = std::fs::canonicalize(path)?;
+        self.files.push((FileKind::detect(&path)?, path));
+        Ok(self.files.len() - 1)
+    }
+
+    /// Returns the paths of the indexed files.
+    pub fn files(&self) -> impl Iterator<Item = &Path> {
+        self.files.iter().map(|(_, path)| path.as_path())
+    }
+
+    /// Inserts a key, which refers to the record at the given offset of
+    /// the given file. A key may refer to more than one record.
+    pub fn insert<K: AsRef<[u8]>>(
+        &mut self,
+        key: K,
+        file: usize,
+        offset: u64,
+    ) {
+        let locations =
+            self.entries.entry(key.as_ref().to_vec()).or_default();
+
+        if !locations.contains(&(file, offset)) {
+            locations.push((file, offset));
+        }
+    }
+
+    /// Returns the locations (file number and offset) of the records,
+    /// that belong to the given key.
+    pub fn get<K: AsRef<[u8]>>(&self, key: K) -> &[(usize, u64)] {
+        self.entries
+            .get(key.as_ref())
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Returns the number of keys.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the index doesn't contain any keys.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Creates a reader over the records of the given file, which
+    /// provides the offset of each record.
+    pub fn reader(
+        &self,
+        file: usize,
+        options: &MarcReadOptions,
+    ) -> io::Result<IndexReader> {
+        let (kind, path) = self.file(file)?;
+
+        Ok(match kind {
+            FileKind::Bgzf => IndexReader::Bgzf(MarcReader::new(
+                BgzfReader::new(File::open(path)?),
+                options.clone(),
+            )),
+            _ => IndexReader::Plain(MarcReader::new(
+                open(path)?,
+                options.clone(),
+            )),
+        })
+    }
+
+    /// Reads the records, that belong to the given key. An uncompressed
+    /// or BGZF compressed file is positioned directly at the offset of
+    /// a record.
+    pub fn lookup<K: AsRef<[u8]>>(
+        &self,
+        key: K,
+        options: &MarcReadOptions,
+    ) -> io::Result<Vec<RecordBuf>> {
+        let mut records = vec![];
+
+        // The record at an indexed offset must not be skipped silently.
+        let mut options = options.clone();
+        options.prefilter = None;
+
+        for data in self.read_raw(self.get(key))? {
+            let mut reader =
+                MarcReader::new(&data[..], options.clone());
+            match reader.next_byte_record() {
+                Some(Ok(record)) => {
+                    records.push(RecordBuf::from(record))
+                }
+                Some(Err(e)) => {
+                    return Err(invalid_data(e.to_string()));
+                }
+                None => {
+                    return Err(invalid_data(
+                        "index entry does not point to a record",
+                    ));
+                }
+            }
+        }
+
+        Ok(records)
+    }
+
+    /// Reads the raw data of the records at the given locations (see
+    /// [`get`](Self::get)), without parsing them. The data is returned
+    /// in the order of the locations.
+    ///
+    /// Each file is opened only once and its records are read in the
+    /// order of their offsets, so that a compressed file, which isn't
+    /// BGZF compressed, is decompressed at most once.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::io::Index;
+    ///
+    /// let mut index = Index::default();
+    /// let file = index.add_file("tests/data/ada.mrc")?;
+    /// index.insert("119232022", file, 0);
+    ///
+    /// let data = index.read_raw(index.get("119232022"))?;
+    /// assert_eq!(data, [include_bytes!("../../tests/data/ada.mrc")]);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn read_raw(
+        &self,
+        locations: &[(usize, u64)],
+    ) -> io::Result<Vec<Vec<u8>>> {
+        let mut order: Vec<usize> = (0..locations.len()).collect();
+        order.sort_by_key(|i| locations[*i]);
+
+        let mut result = vec![vec![]; locations.len()];
+        let mut reader: Option<(usize, RawReader)> = None;
+        let mut last: Option<usize> = None;
+
+        for i in order {
+            let (file, offset) = locations[i];
+
+            // A key may be looked up more than once, but a compressed
+            // file can't be read backwards.
+            if let Some(j) =
+                last.filter(|j| locations[*j] == locations[i])
+            {
+                result[i] = result[j].clone();
+                continue;
+            }
+
+            let (kind, path) = self.file(file)?;
+            let rdr = match reader.take() {
+                Some((f, rdr)) if f == file => rdr,
+                _ => RawReader::new(kind, path)?,
+            };
+
+            let (_, rdr) = reader.insert((file, rdr));
+            if rdr.read_record(offset, &mut result[i])? == 0 {
+                return Err(invalid_data(format!(
+                    "no record at offset {offset} of {}",
+                    path.display()
+                )));
+            }
+
+            last = Some(i);
+        }
+
+        Ok(result)
+    }
+
+    fn file(&self, file: usize) -> io::Result<(FileKind, &Path)> {
+        self.files
+            .get(file)
+            .map(|(kind, path)| (*kind, path.as_path()))
+            .ok_or_else(|| invalid_data(format!("unknown file {file}")))
+    }
+}
+
+/// A reader over the raw data of an indexed file, which is positioned
+/// at the offset of a record (see [`Index::read_raw`]).
+enum RawReader {
+    Plain(BufReader<File>),
+    Bgzf(BgzfReader<File>),
+    Compressed(BufReader<Box<dyn Read>>, u64),
+}
+
+impl RawReader {
+    fn new(kind: FileKind, path: &Path) -> io::Result<Self> {
+        Ok(match kind {
+            FileKind::Plain => {
+                Self::Plain(BufReader::new(File::open(path)?))
+            }
+            FileKind::Bgzf => {
+                Self::Bgzf(BgzfReader::new(File::open(path)?))
+            }
+            FileKind::Compressed => {
+                Self::Compressed(BufReader::new(open(path)?), 0)
+            }
+        })
+    }
+
+    /// Reads the raw data of the record at the given offset and returns
+    /// the number of bytes read. The offsets of a compressed file must
+    /// be increasing.
+    fn read_record(
+        &mut self,
+        offset: u64,
+        buf: &mut Vec<u8>,
+    ) -> io::Result<usize> {
+        match self {
+            Self::Plain(reader) => {
+                reader.seek(SeekFrom::Start(offset))?;
+                reader.read_until(b'\x1d', buf)
+            }
+            Self::Bgzf(reader) => {
+                reader.seek_virtual(offset)?;
+                reader.read_until(b'\x1d', buf)
+            }
+            Self::Compressed(reader, pos) => {
+                // The offset lies within the previous record, if the
+                // index is stale or corrupt.
+                if offset < *pos {
+                    return Err(invalid_data(format!(
+                        "invalid offset {offset} (expected at least {pos})"
+                    )));
+                }
+
+                io::copy(
+                    &mut reader.by_ref().take(offset - *pos),
+                    &mut io::sink(),
+                )?;
+
+                let n = reader.read_until(b'\x1d', buf)?;
+                *pos = offset + n as u64;
+                Ok(n)
+            }
+        }
+    }
+}
+
+/// A reader over the records of an indexed file (see
+/// [`Index::reader`]).
+pub enum IndexReader {
+    Plain(MarcReader<Box<dyn Read>>),
+    Bgzf(MarcReader<BgzfReader<File>>),
+}
+
+impl fmt::Debug for IndexReader {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Plain(_) => f.write_str("IndexReader::Plain"),
+            Self::Bgzf(_) => f.write_str("IndexReader::Bgzf"),
+        }
+    }
+}
+
+impl IndexReader {
+    /// Returns the offset of the record, that was returned by the last
+    /// call of [`next_byte_record`](ByteRecordsIter::next_byte_record).
+    /// The offset of a BGZF compressed file is a virtual offset.
+    pub fn offset(&self) -> Option<u64> {
+        match self {
+            Self::Plain(reader) => Some(reader.position() as u64),
+            Self::Bgzf(reader) => reader
+                .get_ref()
+                .virtual_offset(reader.position() as u64),
+        }
+    }
+}
+
+impl ByteRecordsIter for IndexReader {
+    type ByteRecordItem<'a>
+        = Result<ByteRecord<'a>, ReadMarcError<'a>>
+    where
+        Self: 'a;
+
+    fn next_byte_record(&mut self) -> Option<Self::ByteRecordItem<'_>> {
+        match self {
+            Self::Plain(reader) => reader.next_byte_record(),
+            Self::Bgzf(reader) => reader.next_byte_record(),
+        }
+    }
+}
+
+fn parse_number<T: FromStr>(value: &[u8]) -> io::Result<T> {
+    value.to_str_lossy().parse::<T>().map_err(|_| {
+        invalid_data(format!(
+            "invalid number '{}'",
+            value.to_str_lossy()
+        ))
+    })
+}
+
+fn invalid_data<E>(message: E) -> io::Error
+where
+    E: Into<Box<dyn std::error::Error + Send + Sync>>,
+{
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::TestResult;
+    use crate::io::BgzfWriter;
+
+    fn build(path: &Path) -> io::Result<Index> {
+        let mut index = Index::default();
+        let file = index.add_file(path)?;
+        let mut rdr = index.reader(file, &Default::default())?;
+
+        while let Some(result) = rdr.next_byte_record() {
+            let Ok(record) = result else {
+                continue;
+            };
+
+            let key = record.control_number().unwrap().to_vec();
+            let offset = rdr.offset().unwrap();
+            index.insert(key, file, offset);
+        }
+
+        Ok(index)
+    }
+
+    #[test]
+    fn test_index_lookup() -> TestResult {
+        let dir = std::env::temp_dir()
+            .join(format!("marc21-index-{}", std::process::id()));
+        std::fs::create_dir_all(&dir)?;
+
+        let mut data = vec![];
+        open("tests/data/DUMP.mrc.gz")?.read_to_end(&mut data)?;
+
+        let plain = dir.join("DUMP.mrc");
+        std::fs::write(&plain, &data)?;
+
+        let bgzf = dir.join("DUMP.mrc.bgz");
+        let mut wtr =
+            BgzfWriter::new(File::create(&bgzf)?, Default::default());
+        wtr.write_all(&data)?;
+        wtr.finish()?;
+
+        let mut expected: Option<Vec<Vec<u8>>> = None;
+
+        for path in [
+            plain.as_path(),
+            &bgzf,
+            Path::new("tests/data/DUMP.mrc.gz"),
+        ] {
+            let index = build(path)?;
+            assert!(!index.is_empty());
+            assert!(index.files().all(Path::is_absolute));
+
+            // The locations are deliberately out of order and contain
+            // a duplicate, which must be read from a compressed file.
+            let mut locations: Vec<_> = index
+                .entries
+                .keys()
+                .rev()
+                .flat_map(|key| index.get(key))
+                .copied()
+                .collect();
+            locations.push(locations[0]);
+
+            let raw = index.read_raw(&locations)?;
+            assert_eq!(raw.len(), locations.len());
+            assert_eq!(raw.first(), raw.last());
+            assert!(raw.iter().all(|data| data.ends_with(b"\x1d")));
+            if let Some(ref expected) = expected {
+                assert_eq!(&raw, expected);
+            } else {
+                for ((_, offset), record) in locations.iter().zip(&raw)
+                {
+                    let start = *offset as usize;
+                    assert_eq!(
+                        &data[start..start + record.len()],
+                        record
+                    );
+                }
+
+                expected = Some(raw);
+            }
+
+            let mut out = vec![];
+            index.write_to(&mut out)?;
+            assert_eq!(Index::read_from(&out[..])?, index);
+
+            let records =
+                index.lookup("040992918", &Default::default())?;
+            assert_eq!(records.len(), 1);
+            assert_eq!(
+                records[0].as_byte_record().control_number().unwrap(),
+                "040992918"
+            );
+
+            assert!(
+                index
+                    .lookup("04099291X", &Default::default())?
+                    .is_empty()
+            );
+        }
+
+        std::fs::remove_dir_all(dir)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_index_from_path_relative() -> TestResult {
+        let dir = std::env::temp_dir()
+            .join(format!("marc21-index-rel-{}", std::process::id()));
+        std::fs::create_dir_all(&dir)?;
+        std::fs::copy("tests/data/ada.mrc", dir.join("ada.mrc"))?;
+        std::fs::write(
+            dir.join("index.txt"),
+            "marc21-index\t1\nfile\tplain\tada.mrc\n\
+             entry\t0\t0\t119232022\n",
+        )?;
+
+        let index = Index::from_path(dir.join("index.txt"))?;
+        assert_eq!(
+            index.files().next(),
+            Some(dir.join("ada.mrc").as_path())
+        );
+        assert_eq!(
+            index.read_raw(index.get("119232022"))?,
+            [include_bytes!("../../tests/data/ada.mrc")]
+        );
+
+        std::fs::remove_dir_all(dir)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_index_lookup_invalid_entry() -> TestResult {
+        use crate::matcher::{MatchOptions, RecordMatcher};
+
+        // The prefilter of the options doesn't apply to the record of
+        // an index entry.
+        let mut index = Index::default();
+        let file = index.add_file("tests/data/ada.mrc")?;
+        index.insert("119232022", file, 0);
+
+        let matcher = RecordMatcher::new("001 == '118540238'")?;
+        let prefilter = matcher.prefilter(&MatchOptions::default());
+        let options =
+            MarcReadOptions::default().prefilter(prefilter.unwrap());
+        assert_eq!(index.lookup("119232022", &options)?.len(), 1);
+
+        // An offset within a previous record of a compressed file.
+        let mut index = Index::default();
+        let file = index.add_file("tests/data/ada.mrc.gz")?;
+        index.insert("119232022", file, 0);
+        index.insert("119232022", file, 10);
+
+        let e = index.read_raw(index.get("119232022")).unwrap_err();
+        assert_eq!(e.kind(), io::ErrorKind::InvalidData);
+        assert!(e.to_string().starts_with("invalid offset 10"));
+
+        // An offset, which points to trailing line breaks.
+        let dir = std::env::temp_dir()
+            .join(format!("marc21-index-ws-{}", std::process::id()));
+        std::fs::create_dir_all(&dir)?;
+
+        let path = dir.join("ada.mrc");
+        let data = include_bytes!("../../tests/data/ada.mrc");
+        std::fs::write(&path, [&data[..], b"\n\n"].concat())?;
+
+        let mut index = Index::default();
+        let file = index.add_file(&path)?;
+        index.insert("119232022", file, data.len() as u64);
+
+        let options = MarcReadOptions::default().lenient(true);
+        let e = index.lookup("119232022", &options).unwrap_err();
+        assert_eq!(e.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(
+            e.to_string(),
+            "index entry does not point to a record"
+        );
+
+        std::fs::remove_dir_all(dir)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_index_read_invalid() {
+        for data in [
+            "",
+            "marc21-index\t2\n",
+            "marc21-index\t1\nfile\tzip\tfoo.mrc\n",
+            "marc21-index\t1\nentry\t0\t0\t123\n",
+            "marc21-index\t1\nfile\tplain\tfoo.mrc\nentry\t0\tx\t123\n",
+        ] {
+            let e = Index::read_from(data.as_bytes()).unwrap_err();
+            assert_eq!(e.kind(), io::ErrorKind::InvalidData);
+        }
+    }
+}
@@ -0,0 +1,12 @@
+//! Utilities to read and write MARC-in-JSON documents.
+//!
+//! The format follows the code4lib MARC-in-JSON proposal: a record is
+//! an object with a `leader` and a list of `fields`, each field is an
+//! object with the tag as single key. Records are either written as a
+//! JSON array or as JSON Lines (one record per line).
+
+mod reader;
+mod writer;
+
+pub use reader::MarcJsonReader;
+pub use writer::{MarcJsonWriteOptions, MarcJsonWriter};
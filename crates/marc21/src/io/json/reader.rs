@@ -0,0 +1,463 @@
+use std::io::{self, BufRead, BufReader, Read};
+
+use serde_json::Value;
+
+use crate::buf::{
+    ControlFieldBuf, DataFieldBuf, FieldBuf, RecordBuf, SubfieldBuf,
+};
+use crate::error::{ParseRecordError, ParseRecordErrorKind};
+use crate::io::{
+    ByteRecordsIter, MarcReadOptions, ReadMarcError, indicator, leader,
+};
+use crate::{ByteRecord, ControlFieldRule};
+
+/// A MARC-in-JSON reader.
+///
+/// The reader accepts a JSON array of records as well as JSON Lines
+/// (one record per line); the layout is detected by the first
+/// non-whitespace character of the input.
+///
+/// A record, which is valid JSON but not a valid MARC record (e.g. a
+/// record with an invalid tag) is reported as [ReadMarcError::Parse]
+/// and the reader continues with the next record. Invalid JSON can't be
+/// read any further and is reported as [ReadMarcError::IO].
+#[derive(Debug)]
+pub struct MarcJsonReader<R: Read> {
+    reader: BufReader<R>,
+    layout: Layout,
+    pos: usize,
+    record: Option<RecordBuf>,
+    control_fields: ControlFieldRule,
+    count: usize,
+}
+
+#[derive(Debug, PartialEq)]
+enum Layout {
+    Unknown,
+    Array { first: bool },
+    Lines,
+    Done,
+}
+
+impl<R: Read> MarcJsonReader<R> {
+    pub fn new(reader: R, options: MarcReadOptions) -> Self {
+        Self {
+            reader: BufReader::new(reader),
+            layout: Layout::Unknown,
+            pos: 0,
+            record: None,
+            control_fields: options.control_fields,
+            count: 0,
+        }
+    }
+
+    /// Reads the next record. The outer result indicates whether the
+    /// input could be read, the inner result whether the record is
+    /// valid.
+    fn read_record(
+        &mut self,
+    ) -> io::Result<Option<Result<RecordBuf, (String, usize)>>> {
+        if self.layout == Layout::Unknown {
+            match self.peek()? {
+                None => return Ok(None),
+                Some(b'[') => {
+                    self.consume();
+                    self.layout = Layout::Array { first: true };
+                }
+                Some(_) => self.layout = Layout::Lines,
+            }
+        }
+
+        match self.layout {
+            Layout::Array { first } => match self.peek()? {
+                Some(b']') => {
+                    self.consume();
+                    self.layout = Layout::Done;
+                    return Ok(None);
+                }
+                Some(b',') if !first => {
+                    self.consume();
+                    if matches!(self.peek()?, Some(b']')) {
+                        return Err(invalid_data(
+                            "trailing comma",
+                            self.pos,
+                        ));
+                    }
+                }
+                Some(_) if first => {
+                    self.layout = Layout::Array { first: false };
+                }
+                Some(_) => {
+                    return Err(invalid_data(
+                        "expected ',' or ']'",
+                        self.pos,
+                    ));
+                }
+                None => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::UnexpectedEof,
+                        "unexpected end of JSON array",
+                    ));
+                }
+            },
+            Layout::Lines => {
+                if self.peek()?.is_none() {
+                    self.layout = Layout::Done;
+                    return Ok(None);
+                }
+            }
+            Layout::Done | Layout::Unknown => return Ok(None),
+        }
+
+        let start = self.pos;
+        let reader = Counted {
+            reader: &mut self.reader,
+            pos: &mut self.pos,
+        };
+
+        let value = serde_json::Deserializer::from_reader(reader)
+            .into_iter::<Value>()
+            .next()
+            .transpose()?
+            .ok_or_else(|| invalid_data("expected a record", start))?;
+        Ok(Some(
+            record(value, &self.control_fields)
+                .map_err(|message| (message, start)),
+        ))
+    }
+
+    /// Skips whitespace and returns the next byte without consuming it.
+    fn peek(&mut self) -> io::Result<Option<u8>> {
+        loop {
+            let buf = self.reader.fill_buf()?;
+            if buf.is_empty() {
+                return Ok(None);
+            }
+
+            let len = buf.len();
+            let n = buf
+                .iter()
+                .take_while(|b| b.is_ascii_whitespace())
+                .count();
+            let next = buf.get(n).copied();
+
+            self.reader.consume(n);
+            self.pos += n;
+
+            if n < len {
+                return Ok(next);
+            }
+        }
+    }
+
+    fn consume(&mut self) {
+        self.reader.consume(1);
+        self.pos += 1;
+    }
+}
+
+impl<R: Read> ByteRecordsIter for MarcJsonReader<R> {
+    type ByteRecordItem<'a>
+        = Result<ByteRecord<'a>, ReadMarcError<'a>>
+    where
+        Self: 'a;
+
+    /// Advance the iterator and return the next record.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::prelude::*;
+    ///
+    /// let mut rdr = MarcReadOptions::default()
+    ///     .try_into_json_reader_from_path("tests/data/ada.json")?;
+    ///
+    /// let mut cnt = 0;
+    /// while let Some(result) = rdr.next_byte_record() {
+    ///     let record = result.expect("valid record");
+    ///     assert_eq!(record.control_number().unwrap(), "119232022");
+    ///     cnt += 1;
+    /// }
+    ///
+    /// assert_eq!(cnt, 1);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    fn next_byte_record(&mut self) -> Option<Self::ByteRecordItem<'_>> {
+        match self.read_record() {
+            Err(e) => Some(Err(ReadMarcError::IO(e))),
+            Ok(None) => None,
+            Ok(Some(Err((message, pos)))) => {
+                self.count += 1;
+                Some(Err(ReadMarcError::Parse(
+                    ParseRecordError::new(
+                        ParseRecordErrorKind::Invalid(message),
+                        pos,
+                        &[],
+                    )
+                    .with_location(self.count, 0),
+                )))
+            }
+            Ok(Some(Ok(record))) => {
+                self.count += 1;
+                let record = self.record.insert(record);
+                Some(Ok(record.as_byte_record()))
+            }
+        }
+    }
+}
+
+/// A reader, which counts the number of bytes read.
+struct Counted<'a, R> {
+    reader: &'a mut R,
+    pos: &'a mut usize,
+}
+
+impl<R: Read> Read for Counted<'_, R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.reader.read(buf)?;
+        *self.pos += n;
+        Ok(n)
+    }
+}
+
+fn invalid_data(message: &str, pos: usize) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("invalid MARC-in-JSON at position {pos}: {message}"),
+    )
+}
+
+/// Returns the key and the value of an object with a single key.
+fn entry(value: Value, name: &str) -> Result<(String, Value), String> {
+    if let Value::Object(object) = value
+        && object.len() == 1
+    {
+        return Ok(object.into_iter().next().unwrap());
+    }
+
+    Err(format!(
+        "invalid {name}, expected an object with a single key"
+    ))
+}
+
+fn record(
+    value: Value,
+    rule: &ControlFieldRule,
+) -> Result<RecordBuf, String> {
+    let Value::Object(mut record) = value else {
+        return Err("invalid record, expected an object".into());
+    };
+
+    let leader = match record.remove("leader") {
+        Some(Value::String(value)) => leader(value.as_bytes())?,
+        Some(_) => {
+            return Err("invalid leader, expected a string".into());
+        }
+        None => return Err("missing leader".into()),
+    };
+
+    let fields = match record.remove("fields") {
+        Some(Value::Array(fields)) => fields
+            .into_iter()
+            .map(|value| field(value, rule))
+            .collect::<Result<Vec<_>, _>>()?,
+        Some(_) => {
+            return Err("invalid fields, expected an array".into());
+        }
+        None => vec![],
+    };
+
+    Ok(RecordBuf { leader, fields })
+}
+
+fn field(
+    value: Value,
+    rule: &ControlFieldRule,
+) -> Result<FieldBuf, String> {
+    match entry(value, "field")? {
+        (tag, Value::String(value)) => {
+            ControlFieldBuf::new_with(tag, value, rule)
+                .map(FieldBuf::from)
+                .map_err(|e| e.to_string())
+        }
+        (tag, Value::Object(mut object)) => {
+            let mut ind = [b' '; 2];
+            for (i, key) in ["ind1", "ind2"].into_iter().enumerate() {
+                ind[i] = match object.remove(key) {
+                    Some(Value::String(value)) => {
+                        indicator(value.as_bytes())?
+                    }
+                    Some(_) => {
+                        return Err(format!(
+                            "invalid {key}, expected a string"
+                        ));
+                    }
+                    None => b' ',
+                };
+            }
+
+            let mut df =
+                DataFieldBuf::new_with(tag, ind[0], ind[1], rule)
+                    .map_err(|e| e.to_string())?;
+
+            let subfields = match object.remove("subfields") {
+                Some(Value::Array(subfields)) => subfields,
+                Some(_) => {
+                    return Err(
+                        "invalid subfields, expected an array".into()
+                    );
+                }
+                None => vec![],
+            };
+
+            for subfield in subfields {
+                let (code, Value::String(value)) =
+                    entry(subfield, "subfield")?
+                else {
+                    return Err(
+                        "invalid subfield, expected a string value"
+                            .into(),
+                    );
+                };
+
+                let [code] = code.as_bytes() else {
+                    return Err(format!(
+                        "invalid subfield code '{code}'"
+                    ));
+                };
+
+                df.push(
+                    SubfieldBuf::new(*code, value)
+                        .map_err(|e| e.to_string())?,
+                );
+            }
+
+            Ok(df.into())
+        }
+        (tag, _) => Err(format!("invalid field '{tag}'")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::TestResult;
+
+    fn read_all(data: &[u8]) -> Vec<Result<RecordBuf, String>> {
+        let mut reader =
+            MarcJsonReader::new(data, MarcReadOptions::default());
+        let mut result = vec![];
+
+        while let Some(item) = reader.next_byte_record() {
+            result.push(
+                item.map(|record| RecordBuf::from(&record))
+                    .map_err(|e| e.to_string()),
+            );
+        }
+
+        result
+    }
+
+    #[test]
+    fn test_read_ada() -> TestResult {
+        let json = include_bytes!("../../../../../tests/data/ada.json");
+        let mrc = include_bytes!("../../../../../tests/data/ada.mrc");
+
+        let records = read_all(json);
+        assert_eq!(records.len(), 1);
+
+        let expected = RecordBuf::from(ByteRecord::from_bytes(mrc)?);
+        assert_eq!(records[0].as_ref().unwrap(), &expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_layouts() {
+        let record = r#"{"leader":"00000nz  a2200000nc 4500",
+            "fields":[{"001":"123"},{"100":{"ind1":"1","ind2":" ",
+            "subfields":[{"a":"Tom \"&\" Jerry"},{"b":"☺"}]}}]}"#;
+        let expected = "LDR 00000nz  a2200000nc 4500\n\
+            001 123\n\
+            100/1# $a Tom \"&\" Jerry $b \u{263A}\n";
+
+        for data in [
+            format!("[{record}, {record}]"),
+            format!(" [\n{record},\n{record}\n]\n"),
+            format!("{record}\n{record}\n"),
+            format!("{record}{record}"),
+        ] {
+            let records = read_all(data.as_bytes());
+            assert_eq!(records.len(), 2);
+
+            for record in records {
+                assert_eq!(record.unwrap().to_string(), expected);
+            }
+        }
+
+        assert!(read_all(b"").is_empty());
+        assert!(read_all(b"[]").is_empty());
+        assert!(read_all(b" \n").is_empty());
+    }
+
+    #[test]
+    fn test_read_invalid_records() {
+        let records = read_all(
+            br##"[
+            {"leader":"00000nz  a2200000nc 4500","fields":[{"01":"1"}]},
+            {"fields":[{"001":"123"}]},
+            {"leader":"00000nz  a2200000nc 4500","fields":[{"100":{
+                "ind1":"#","subfields":[]}}]},
+            {"leader":"00000nz  a2200000nc 4500","fields":[{"100":{
+                "subfields":[{"ab":"1"}]}}]},
+            {"leader":"00000nz  a2200000nc 4500","fields":[
+                {"001":"1","002":"2"}]},
+            {"leader":"00000nz  a2200000nc 4500","fields":[{"001":"1"}]}
+            ]"##,
+        );
+
+        assert_eq!(records.len(), 6);
+        assert!(records[0..5].iter().all(Result::is_err));
+        assert!(records[5].is_ok());
+    }
+
+    #[test]
+    fn test_read_control_fields() -> TestResult {
+        let data = br#"{"leader":"00000nz  a2200000nc 4500",
+            "fields":[{"001":"123"},{"FMT":"BK"}]}"#;
+        assert!(read_all(data)[0].is_err());
+
+        let options = MarcReadOptions::default()
+            .control_fields("00., FMT".parse()?);
+        let mut reader = MarcJsonReader::new(&data[..], options);
+        let record = reader.next_byte_record().unwrap().unwrap();
+        assert_eq!(
+            RecordBuf::from(&record).to_string(),
+            "LDR 00000nz  a2200000nc 4500\n001 123\nFMT BK\n"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_malformed_document() {
+        for data in [
+            &b"[{\"leader\":"[..],
+            &b"[{\"leader\":\"00000nz  a2200000nc 4500\"},]"[..],
+            &b"[{\"leader\":\"00000nz  a2200000nc 4500\"} {}]"[..],
+            &b"{\"leader\":\"00000nz  a2200000nc 4500\"} xyz"[..],
+        ] {
+            let mut reader =
+                MarcJsonReader::new(data, MarcReadOptions::default());
+
+            loop {
+                match reader.next_byte_record() {
+                    Some(Err(ReadMarcError::IO(_))) => break,
+                    Some(_) => continue,
+                    None => panic!("expected an error"),
+                }
+            }
+        }
+    }
+}
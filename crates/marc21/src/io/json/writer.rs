@@ -0,0 +1,279 @@
+use std::io::{self, Write};
+
+use bstr::ByteSlice;
+
+use crate::{ByteRecord, Field};
+
+/// Configures a MARC-in-JSON writer.
+#[derive(Debug, Clone, Default)]
+pub struct MarcJsonWriteOptions {
+    pub(crate) lines: bool,
+}
+
+impl MarcJsonWriteOptions {
+    /// Whether to write JSON Lines (one record per line) or a JSON
+    /// array (default: `false`).
+    pub fn lines(mut self, yes: bool) -> Self {
+        self.lines = yes;
+        self
+    }
+}
+
+/// A streaming MARC-in-JSON writer.
+///
+/// # Example
+///
+/// ```rust
+/// use marc21::io::{MarcJsonWriteOptions, MarcJsonWriter};
+/// use marc21::prelude::*;
+///
+/// let data = include_bytes!("../../../tests/data/ada.mrc");
+/// let record = ByteRecord::from_bytes(data)?;
+///
+/// let options = MarcJsonWriteOptions::default().lines(true);
+/// let mut wtr = MarcJsonWriter::new(Vec::new(), options);
+/// wtr.write_record(&record)?;
+///
+/// let out = String::from_utf8(wtr.finish()?)?;
+/// assert!(
+///     out.starts_with("{\"leader\":\"03612nz  a2200589nc 4500\"")
+/// );
+/// assert_eq!(out.lines().count(), 1);
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug)]
+pub struct MarcJsonWriter<W: Write> {
+    writer: W,
+    options: MarcJsonWriteOptions,
+    count: usize,
+}
+
+impl<W: Write> MarcJsonWriter<W> {
+    pub fn new(writer: W, options: MarcJsonWriteOptions) -> Self {
+        Self {
+            writer,
+            options,
+            count: 0,
+        }
+    }
+
+    /// Writes a record as JSON object.
+    ///
+    /// # Errors
+    ///
+    /// An error of kind [`io::ErrorKind::InvalidData`] is returned, if
+    /// a value isn't valid UTF-8. In this case, nothing is written.
+    pub fn write_record(
+        &mut self,
+        record: &ByteRecord,
+    ) -> io::Result<()> {
+        for field in record.fields() {
+            match field {
+                Field::Control(cf) => {
+                    utf8(cf.value())?;
+                }
+                Field::Data(df) => {
+                    for subfield in df.subfields() {
+                        utf8(subfield.value())?;
+                    }
+                }
+            }
+        }
+
+        if !self.options.lines {
+            if self.count == 0 {
+                self.writer.write_all(b"[\n")?;
+            } else {
+                self.writer.write_all(b",\n")?;
+            }
+        }
+
+        let mut leader = Vec::with_capacity(24);
+        record.leader().write_to(&mut leader)?;
+
+        self.writer.write_all(b"{\"leader\":")?;
+        self.string(&leader)?;
+        self.writer.write_all(b",\"fields\":[")?;
+
+        for (i, field) in record.fields().enumerate() {
+            if i > 0 {
+                self.writer.write_all(b",")?;
+            }
+
+            self.writer.write_all(b"{")?;
+            self.string(field.tag().0)?;
+            self.writer.write_all(b":")?;
+
+            match field {
+                Field::Control(cf) => self.string(cf.value())?,
+                Field::Data(df) => {
+                    self.writer.write_all(b"{\"ind1\":")?;
+                    self.string(&[*df.indicator1()])?;
+                    self.writer.write_all(b",\"ind2\":")?;
+                    self.string(&[*df.indicator2()])?;
+                    self.writer.write_all(b",\"subfields\":[")?;
+
+                    for (j, subfield) in df.subfields().enumerate() {
+                        if j > 0 {
+                            self.writer.write_all(b",")?;
+                        }
+
+                        self.writer.write_all(b"{")?;
+                        self.string(&[*subfield.code()])?;
+                        self.writer.write_all(b":")?;
+                        self.string(subfield.value())?;
+                        self.writer.write_all(b"}")?;
+                    }
+
+                    self.writer.write_all(b"]}")?;
+                }
+            }
+
+            self.writer.write_all(b"}")?;
+        }
+
+        self.writer.write_all(b"]}")?;
+        if self.options.lines {
+            self.writer.write_all(b"\n")?;
+        }
+
+        self.count += 1;
+        Ok(())
+    }
+
+    /// Closes the JSON array (if any), flushes the writer and returns
+    /// the underlying writer.
+    pub fn finish(mut self) -> io::Result<W> {
+        if !self.options.lines {
+            if self.count == 0 {
+                self.writer.write_all(b"[]\n")?;
+            } else {
+                self.writer.write_all(b"\n]\n")?;
+            }
+        }
+
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+
+    /// Writes a value as JSON string.
+    #[inline]
+    fn string(&mut self, value: &[u8]) -> io::Result<()> {
+        serde_json::to_writer(&mut self.writer, utf8(value)?)
+            .map_err(io::Error::from)
+    }
+}
+
+/// Returns the value as string slice. A value, which isn't valid UTF-8,
+/// is rejected, because it can't be represented in JSON.
+#[inline]
+fn utf8(value: &[u8]) -> io::Result<&str> {
+    value.to_str().map_err(|e| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("invalid UTF-8 in MARC-in-JSON value: {e}"),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::TestResult;
+    use crate::io::{ByteRecordsIter, MarcJsonReader, MarcReadOptions};
+    use crate::{Leader, RecordBuf};
+
+    fn record() -> Result<RecordBuf, Box<dyn std::error::Error>> {
+        Ok(RecordBuf::builder()
+            .leader(Leader::new(b"00000nz  a2200000nc 4500")?)
+            .control_field("001", "123")
+            .data_field("100", b'1', b' ', [(b'a', "Tom \"&\" Jerry")])
+            .build()?)
+    }
+
+    #[test]
+    fn test_write_array() -> TestResult {
+        let record = record()?;
+        let mut wtr = MarcJsonWriter::new(vec![], Default::default());
+        wtr.write_record(&record.as_byte_record())?;
+        wtr.write_record(&record.as_byte_record())?;
+
+        let expected = r#"{"leader":"00000nz  a2200000nc 4500","fields":[{"001":"123"},{"100":{"ind1":"1","ind2":" ","subfields":[{"a":"Tom \"&\" Jerry"}]}}]}"#;
+
+        assert_eq!(
+            String::from_utf8(wtr.finish()?)?,
+            format!("[\n{expected},\n{expected}\n]\n")
+        );
+
+        let wtr = MarcJsonWriter::new(vec![], Default::default());
+        assert_eq!(wtr.finish()?, b"[]\n");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_lines() -> TestResult {
+        let record = record()?;
+        let options = MarcJsonWriteOptions::default().lines(true);
+        let mut wtr = MarcJsonWriter::new(vec![], options.clone());
+        wtr.write_record(&record.as_byte_record())?;
+        wtr.write_record(&record.as_byte_record())?;
+
+        let out = String::from_utf8(wtr.finish()?)?;
+        assert_eq!(out.lines().count(), 2);
+        assert!(out.ends_with("}]}}]}\n"));
+
+        let wtr = MarcJsonWriter::new(vec![], options);
+        assert!(wtr.finish()?.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_invalid_utf8() -> TestResult {
+        let record = RecordBuf::builder()
+            .leader(Leader::new(b"00000nz  a2200000nc 4500")?)
+            .data_field("100", b'1', b' ', [(b'a', &b"Ada\xff"[..])])
+            .build()?;
+
+        let options = MarcJsonWriteOptions::default().lines(true);
+        let mut wtr = MarcJsonWriter::new(vec![], options);
+        let err =
+            wtr.write_record(&record.as_byte_record()).unwrap_err();
+
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert!(wtr.finish()?.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_read_roundtrip() -> TestResult {
+        let data = include_bytes!("../../../../../tests/data/ada.mrc");
+        let record = ByteRecord::from_bytes(data)?;
+
+        for lines in [false, true] {
+            let options = MarcJsonWriteOptions::default().lines(lines);
+            let mut wtr = MarcJsonWriter::new(vec![], options);
+            wtr.write_record(&record)?;
+            wtr.write_record(&record)?;
+            let out = wtr.finish()?;
+
+            let mut rdr = MarcJsonReader::new(
+                &out[..],
+                MarcReadOptions::default(),
+            );
+            for _ in 0..2 {
+                let result = RecordBuf::from(
+                    rdr.next_byte_record().unwrap().unwrap(),
+                );
+                assert_eq!(result, RecordBuf::from(&record));
+            }
+
+            assert!(rdr.next_byte_record().is_none());
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,485 @@
+//! Transcoding of MARC-8 encoded records into UTF-8.
+//!
+//! The decoder supports the default character sets Basic Latin (ASCII)
+//! and Extended Latin (ANSEL), the subscript, superscript and Greek
+//! symbol sets (technique 1) and Basic Cyrillic. The remaining
+//! alternate graphic character sets (e.g. EACC) aren't supported and a
+//! value that switches to one of these sets can't be decoded.
+//!
+//! MARC-8 places combining diacritics _before_ the base character,
+//! whereas Unicode places them _after_ the base character. The decoder
+//! reorders them accordingly, but doesn't apply any further
+//! normalization.
+
+use crate::buf::{
+    ControlFieldBuf, DataFieldBuf, FieldBuf, SubfieldBuf,
+};
+use crate::{ByteRecord, Field, RecordBuf};
+
+const ESC: u8 = 0x1b;
+
+/// A MARC-8 graphic character set.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Charset {
+    BasicLatin,
+    ExtendedLatin,
+    Subscripts,
+    Superscripts,
+    GreekSymbols,
+    BasicCyrillic,
+    Unsupported(u8),
+}
+
+impl Charset {
+    /// Returns the character set of a final character of an escape
+    /// sequence, that designates a G0 or G1 set.
+    fn from_final(b: u8) -> Self {
+        match b {
+            b'B' | b's' => Self::BasicLatin,
+            b'E' => Self::ExtendedLatin,
+            b'b' => Self::Subscripts,
+            b'p' => Self::Superscripts,
+            b'g' => Self::GreekSymbols,
+            b'N' => Self::BasicCyrillic,
+            _ => Self::Unsupported(b),
+        }
+    }
+
+    /// Looks up a byte in the range `0x21..=0x7e`. Returns the
+    /// character and whether it is a combining character or not.
+    fn lookup(self, b: u8) -> Option<(char, bool)> {
+        match self {
+            Self::BasicLatin => Some((b as char, false)),
+            Self::ExtendedLatin => ansel(b + 0x80),
+            Self::Subscripts => subscript(b).map(|c| (c, false)),
+            Self::Superscripts => superscript(b).map(|c| (c, false)),
+            Self::GreekSymbols => greek_symbol(b).map(|c| (c, false)),
+            Self::BasicCyrillic => cyrillic(b).map(|c| (c, false)),
+            Self::Unsupported(_) => None,
+        }
+    }
+}
+
+/// An error that can occur when decoding a MARC-8 value.
+#[derive(Debug, PartialEq)]
+pub(crate) struct DecodeError {
+    pub(crate) message: String,
+    pub(crate) offset: usize,
+}
+
+impl DecodeError {
+    fn new<S: Into<String>>(message: S, offset: usize) -> Self {
+        Self {
+            message: message.into(),
+            offset,
+        }
+    }
+}
+
+/// Decodes a MARC-8 encoded value into UTF-8.
+///
+/// Each value starts with Basic Latin as G0 and Extended Latin as G1
+/// set.
+pub(crate) fn decode(value: &[u8]) -> Result<String, DecodeError> {
+    let mut g0 = Charset::BasicLatin;
+    let mut g1 = Charset::ExtendedLatin;
+    let mut combining: Vec<char> = vec![];
+    let mut out = String::with_capacity(value.len());
+    let mut i = 0;
+
+    while i < value.len() {
+        let b = value[i];
+        let (c, is_combining) = match b {
+            ESC => {
+                i += escape(&value[i..], &mut g0, &mut g1).ok_or_else(
+                    || DecodeError::new("invalid escape sequence", i),
+                )?;
+                continue;
+            }
+            0x00..=0x20 | 0x7f => (b as char, false),
+            0x21..=0x7e => lookup(g0, b, i)?,
+            // non-sort begin and end
+            0x88 => ('\u{98}', false),
+            0x89 => ('\u{9c}', false),
+            // joiner and non-joiner
+            0x8d => ('\u{200d}', false),
+            0x8e => ('\u{200c}', false),
+            0xa1..=0xfe => lookup(g1, b - 0x80, i)?,
+            _ => {
+                return Err(DecodeError::new(
+                    format!("invalid MARC-8 byte 0x{b:02x}"),
+                    i,
+                ));
+            }
+        };
+
+        if is_combining {
+            combining.push(c);
+        } else {
+            out.push(c);
+            out.extend(combining.drain(..));
+        }
+
+        i += 1;
+    }
+
+    out.extend(combining);
+    Ok(out)
+}
+
+/// Transcodes a MARC-8 encoded record into an owned UTF-8 record and
+/// sets the character coding scheme (leader position 09) to `a`.
+///
+/// The offset of an error refers to the raw data of the record.
+pub(crate) fn transcode(
+    record: &ByteRecord,
+) -> Result<RecordBuf, DecodeError> {
+    let decode = |value: &[u8]| {
+        decode(value).map(String::into_bytes).map_err(|e| {
+            let start = record
+                .raw_data()
+                .map(|data| {
+                    value.as_ptr() as usize - data.as_ptr() as usize
+                })
+                .unwrap_or_default();
+
+            DecodeError::new(e.message, start + e.offset)
+        })
+    };
+
+    let mut leader = record.leader().clone();
+    leader.encoding = b'a';
+
+    let mut fields = vec![];
+    for field in record.fields() {
+        fields.push(match field {
+            Field::Control(cf) => FieldBuf::Control(ControlFieldBuf {
+                tag: [cf.tag[0], cf.tag[1], cf.tag[2]],
+                value: decode(cf.value())?,
+            }),
+            Field::Data(df) => FieldBuf::Data(DataFieldBuf {
+                tag: [df.tag[0], df.tag[1], df.tag[2]],
+                indicator1: df.indicator1,
+                indicator2: df.indicator2,
+                subfields: df
+                    .subfields()
+                    .map(|subfield| {
+                        Ok(SubfieldBuf {
+                            code: subfield.code,
+                            value: decode(subfield.value())?,
+                        })
+                    })
+                    .collect::<Result<_, _>>()?,
+            }),
+        });
+    }
+
+    Ok(RecordBuf { leader, fields })
+}
+
+/// Processes an escape sequence and returns its length. If the escape
+/// sequence is invalid, `None` is returned.
+fn escape(
+    seq: &[u8],
+    g0: &mut Charset,
+    g1: &mut Charset,
+) -> Option<usize> {
+    match seq.get(1..)? {
+        // technique 1: greek symbols, subscripts, superscripts and
+        // ASCII default.
+        [b @ (b'g' | b'b' | b'p' | b's'), ..] => {
+            *g0 = Charset::from_final(*b);
+            Some(2)
+        }
+        // technique 2: designation of a single-byte G0 or G1 set. The
+        // second intermediate character `!` is optional.
+        [i @ (b'(' | b',' | b')' | b'-'), rest @ ..] => {
+            let (len, f) = match rest {
+                [b'!', f, ..] => (4, *f),
+                [f, ..] => (3, *f),
+                [] => return None,
+            };
+
+            if matches!(i, b'(' | b',') {
+                *g0 = Charset::from_final(f);
+            } else {
+                *g1 = Charset::from_final(f);
+            }
+
+            Some(len)
+        }
+        // technique 2: designation of a multibyte G0 or G1 set (EACC).
+        [b'$', rest @ ..] => match rest {
+            [b'(' | b',', f, ..] => {
+                *g0 = Charset::Unsupported(*f);
+                Some(4)
+            }
+            [b')' | b'-', f, ..] => {
+                *g1 = Charset::Unsupported(*f);
+                Some(4)
+            }
+            [f, ..] => {
+                *g0 = Charset::Unsupported(*f);
+                Some(3)
+            }
+            [] => None,
+        },
+        _ => None,
+    }
+}
+
+#[inline]
+fn lookup(
+    charset: Charset,
+    b: u8,
+    offset: usize,
+) -> Result<(char, bool), DecodeError> {
+    charset.lookup(b).ok_or_else(|| match charset {
+        Charset::Unsupported(f) => DecodeError::new(
+            format!(
+                "unsupported MARC-8 character set '{}'",
+                f.escape_ascii()
+            ),
+            offset,
+        ),
+        _ => DecodeError::new(
+            format!("invalid MARC-8 byte 0x{b:02x}"),
+            offset,
+        ),
+    })
+}
+
+/// Extended Latin (ANSEL).
+fn ansel(b: u8) -> Option<(char, bool)> {
+    let c = match b {
+        0xa1 => 'Ł',
+        0xa2 => 'Ø',
+        0xa3 => 'Đ',
+        0xa4 => 'Þ',
+        0xa5 => 'Æ',
+        0xa6 => 'Œ',
+        0xa7 => 'ʹ',
+        0xa8 => '·',
+        0xa9 => '♭',
+        0xaa => '®',
+        0xab => '±',
+        0xac => 'Ơ',
+        0xad => 'Ư',
+        0xae => 'ʼ',
+        0xb0 => 'ʻ',
+        0xb1 => 'ł',
+        0xb2 => 'ø',
+        0xb3 => 'đ',
+        0xb4 => 'þ',
+        0xb5 => 'æ',
+        0xb6 => 'œ',
+        0xb7 => 'ʺ',
+        0xb8 => 'ı',
+        0xb9 => '£',
+        0xba => 'ð',
+        0xbc => 'ơ',
+        0xbd => 'ư',
+        0xc0 => '°',
+        0xc1 => 'ℓ',
+        0xc2 => '℗',
+        0xc3 => '©',
+        0xc4 => '♯',
+        0xc5 => '¿',
+        0xc6 => '¡',
+        0xc7 => 'ß',
+        0xc8 => '€',
+        _ => return ansel_combining(b).map(|c| (c, true)),
+    };
+
+    Some((c, false))
+}
+
+/// The combining diacritics of Extended Latin (ANSEL).
+fn ansel_combining(b: u8) -> Option<char> {
+    Some(match b {
+        0xe0 => '\u{0309}', // hook above
+        0xe1 => '\u{0300}', // grave
+        0xe2 => '\u{0301}', // acute
+        0xe3 => '\u{0302}', // circumflex
+        0xe4 => '\u{0303}', // tilde
+        0xe5 => '\u{0304}', // macron
+        0xe6 => '\u{0306}', // breve
+        0xe7 => '\u{0307}', // dot above
+        0xe8 => '\u{0308}', // diaeresis
+        0xe9 => '\u{030c}', // caron
+        0xea => '\u{030a}', // ring above
+        0xeb => '\u{fe20}', // ligature, left half
+        0xec => '\u{fe21}', // ligature, right half
+        0xed => '\u{0315}', // comma above right
+        0xee => '\u{030b}', // double acute
+        0xef => '\u{0310}', // candrabindu
+        0xf0 => '\u{0327}', // cedilla
+        0xf1 => '\u{0328}', // ogonek
+        0xf2 => '\u{0323}', // dot below
+        0xf3 => '\u{0324}', // double dot below
+        0xf4 => '\u{0325}', // ring below
+        0xf5 => '\u{0333}', // double underscore
+        0xf6 => '\u{0332}', // underscore
+        0xf7 => '\u{0326}', // left hook (comma below)
+        0xf8 => '\u{031c}', // right cedilla
+        0xf9 => '\u{032e}', // upadhmaniya
+        0xfa => '\u{fe22}', // double tilde, left half
+        0xfb => '\u{fe23}', // double tilde, right half
+        0xfe => '\u{0313}', // high comma, centered
+        _ => return None,
+    })
+}
+
+fn subscript(b: u8) -> Option<char> {
+    Some(match b {
+        b'0'..=b'9' => char::from_u32(0x2080 + (b - b'0') as u32)?,
+        b'(' => '₍',
+        b')' => '₎',
+        b'+' => '₊',
+        b'-' => '₋',
+        _ => return None,
+    })
+}
+
+fn superscript(b: u8) -> Option<char> {
+    Some(match b {
+        b'0' => '⁰',
+        b'1' => '¹',
+        b'2' => '²',
+        b'3' => '³',
+        b'4'..=b'9' => char::from_u32(0x2070 + (b - b'0') as u32)?,
+        b'(' => '⁽',
+        b')' => '⁾',
+        b'+' => '⁺',
+        b'-' => '⁻',
+        _ => return None,
+    })
+}
+
+fn greek_symbol(b: u8) -> Option<char> {
+    Some(match b {
+        b'a' => 'α',
+        b'b' => 'β',
+        b'c' => 'γ',
+        _ => return None,
+    })
+}
+
+/// Basic Cyrillic (ISO 5427).
+fn cyrillic(b: u8) -> Option<char> {
+    const LETTERS: &[char; 32] = &[
+        'ю', 'а', 'б', 'ц', 'д', 'е', 'ф', 'г', 'х', 'и', 'й', 'к',
+        'л', 'м', 'н', 'о', 'п', 'я', 'р', 'с', 'т', 'у', 'ж', 'в',
+        'ь', 'ы', 'з', 'ш', 'э', 'щ', 'ч', 'ъ',
+    ];
+
+    match b {
+        0x21..=0x3f => Some(b as char),
+        0x40..=0x5f => Some(LETTERS[(b - 0x40) as usize]),
+        0x60..=0x7e => {
+            LETTERS[(b - 0x60) as usize].to_uppercase().next()
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::TestResult;
+
+    #[test]
+    fn test_decode_ascii() {
+        assert_eq!(decode(b"Lovelace, Ada").unwrap(), "Lovelace, Ada");
+        assert_eq!(decode(b"").unwrap(), "");
+    }
+
+    #[test]
+    fn test_decode_extended_latin() {
+        assert_eq!(decode(b"\xa1od\xbd").unwrap(), "Łodư");
+        assert_eq!(decode(b"\xc3 2024").unwrap(), "© 2024");
+    }
+
+    #[test]
+    fn test_decode_combining() {
+        // The diacritic precedes the base character in MARC-8.
+        assert_eq!(decode(b"G\xe8odel").unwrap(), "Go\u{308}del");
+        assert_eq!(
+            decode(b"Dvo\xe9r\xe2ak").unwrap(),
+            "Dvor\u{30c}a\u{301}k"
+        );
+
+        // Multiple diacritics keep their order.
+        assert_eq!(
+            decode(b"Vi\xe3\xe2et").unwrap(),
+            "Vie\u{302}\u{301}t"
+        );
+
+        // A trailing diacritic isn't dropped.
+        assert_eq!(decode(b"a\xe8").unwrap(), "a\u{308}");
+    }
+
+    #[test]
+    fn test_decode_escape_sequences() {
+        assert_eq!(decode(b"H\x1bb2\x1bsO").unwrap(), "H₂O");
+        assert_eq!(decode(b"m\x1bp2\x1bs").unwrap(), "m²");
+        assert_eq!(decode(b"\x1bgabc\x1bs").unwrap(), "αβγ");
+        assert_eq!(
+            decode(b"\x1b(NRUSSKIJ\x1b(B!").unwrap(),
+            "русский!"
+        );
+        assert_eq!(decode(b"\x1b(Nmir\x1b(B").unwrap(), "МИР");
+    }
+
+    #[test]
+    fn test_decode_invalid() {
+        assert_eq!(
+            decode(b"abc\xff").unwrap_err(),
+            DecodeError::new("invalid MARC-8 byte 0xff", 3)
+        );
+        assert_eq!(
+            decode(b"abc\x1b").unwrap_err(),
+            DecodeError::new("invalid escape sequence", 3)
+        );
+        assert_eq!(
+            decode(b"a\x1b$1!!!").unwrap_err(),
+            DecodeError::new("unsupported MARC-8 character set '1'", 4)
+        );
+    }
+
+    #[test]
+    fn test_transcode() -> TestResult {
+        let record = RecordBuf::builder()
+            .leader(crate::Leader::new(b"00000nam  2200000   4500")?)
+            .control_field("001", "123")
+            .data_field(
+                "100",
+                b'1',
+                b' ',
+                [(b'a', b"G\xe8odel".to_vec())],
+            )
+            .build()?;
+
+        let mut data = vec![];
+        record.write_to(&mut data)?;
+
+        let record = ByteRecord::from_bytes(&data).unwrap();
+        let result = transcode(&record).unwrap();
+        assert_eq!(result.leader().encoding(), b'a');
+        assert_eq!(
+            result.to_string(),
+            "LDR 00065nam a2200049   4500\n\
+             001 123\n\
+             100/1# $a Go\u{308}del\n"
+        );
+
+        let mut data = data.clone();
+        let pos = data.iter().position(|b| *b == 0xe8).unwrap();
+        data[pos] = 0xff;
+
+        let record = ByteRecord::from_bytes(&data).unwrap();
+        assert_eq!(transcode(&record).unwrap_err().offset, pos);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,227 @@
+use std::fs::File;
+use std::io;
+use std::path::Path;
+
+use bstr::ByteSlice;
+use memmap2::Mmap;
+
+use crate::ByteRecord;
+use crate::io::compression::Compression;
+use crate::io::reader::parse;
+use crate::io::{MarcReadOptions, ReadMarcError};
+
+/// A reader over a memory-mapped file.
+///
+/// In contrast to [`MarcReader`](crate::io::MarcReader), the records
+/// aren't copied into an internal buffer, but borrow directly from
+/// the mapping. Thus, any number of records can be alive at the same
+/// time, e.g. to sort them or to share them across threads.
+///
+/// The file must be an uncompressed ISO 2709 file. If the file is
+/// modified while it is mapped, the records may change or the process
+/// may crash.
+#[derive(Debug)]
+pub struct MmapReader {
+    mmap: Mmap,
+    options: MarcReadOptions,
+}
+
+impl MmapReader {
+    /// Maps the file at the given path into memory.
+    ///
+    /// The path `-` (stdin) and compressed files can't be mapped.
+    /// MARC-8 transcoding isn't supported, because a transcoded
+    /// record can't borrow from the mapping.
+    pub fn from_path<P: AsRef<Path>>(
+        path: P,
+        options: MarcReadOptions,
+    ) -> io::Result<Self> {
+        let path = path.as_ref();
+
+        let unsupported = |message: &str| {
+            Err(io::Error::new(io::ErrorKind::Unsupported, message))
+        };
+
+        if options.marc8 {
+            return unsupported("MARC-8 transcoding of a mapped file");
+        }
+
+        if matches!(path.to_str(), Some("-") | None) {
+            return unsupported("mapping of stdin");
+        }
+
+        let file = File::open(path)?;
+
+        // SAFETY: The mapping is read-only. Modifying the file while
+        // it's mapped is undefined behavior, which is documented at
+        // the type level.
+        let mmap = unsafe { Mmap::map(&file)? };
+
+        if Compression::detect(&mmap).is_some() {
+            return unsupported("mapping of a compressed file");
+        }
+
+        Ok(Self { mmap, options })
+    }
+
+    /// Returns the raw data of the file.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.mmap
+    }
+
+    /// Returns an iterator over the records of the file.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::prelude::*;
+    ///
+    /// let mmap = MarcReadOptions::default()
+    ///     .try_into_mmap_reader_from_path("tests/data/ada.mrc")?;
+    ///
+    /// let mut records: Vec<ByteRecord> = mmap
+    ///     .records()
+    ///     .chain(mmap.records())
+    ///     .collect::<Result<_, _>>()
+    ///     .expect("records");
+    ///
+    /// records.sort_by_key(|record| record.fields().count());
+    /// assert_eq!(records.len(), 2);
+    /// assert_eq!(records[0], records[1]);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn records(&self) -> MmapRecords<'_> {
+        MmapRecords {
+            data: &self.mmap,
+            options: &self.options,
+            count: 0,
+            offset: 0,
+        }
+    }
+}
+
+/// An iterator over the records of a [`MmapReader`].
+#[derive(Debug)]
+pub struct MmapRecords<'a> {
+    data: &'a [u8],
+    options: &'a MarcReadOptions,
+    count: usize,
+    offset: usize,
+}
+
+impl<'a> Iterator for MmapRecords<'a> {
+    type Item = Result<ByteRecord<'a>, ReadMarcError<'a>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (start, data) = loop {
+            if self.offset >= self.data.len() {
+                return None;
+            }
+
+            let start = self.offset;
+            let end = self.data[start..]
+                .find_byte(b'\x1d')
+                .map(|pos| start + pos + 1)
+                .unwrap_or(self.data.len());
+
+            self.offset = end;
+
+            let data = &self.data[start..end];
+
+            // In lenient mode, trailing line breaks are skipped.
+            if self.options.lenient
+                && data.iter().all(u8::is_ascii_whitespace)
+            {
+                continue;
+            }
+
+            self.count += 1;
+
+            if self
+                .options
+                .prefilter
+                .as_ref()
+                .is_some_and(|p| !p.is_match(data))
+            {
+                continue;
+            }
+
+            break (start, data);
+        };
+
+        Some(parse(data, self.options, self.count).map_err(|e| {
+            ReadMarcError::Parse(e.with_location(self.count, start))
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use super::*;
+    use crate::common::TestResult;
+    use crate::io::{ByteRecordsIter, MarcReader};
+
+    #[test]
+    fn test_mmap_reader() -> TestResult {
+        let mmap = MmapReader::from_path(
+            "tests/data/invalid.mrc",
+            MarcReadOptions::default(),
+        )?;
+
+        let mut rdr = MarcReader::new(
+            mmap.as_bytes(),
+            MarcReadOptions::default(),
+        );
+
+        let mut records = mmap.records();
+        while let Some(expected) = rdr.next_byte_record() {
+            match (expected, records.next().unwrap()) {
+                (Ok(expected), Ok(actual)) => {
+                    assert_eq!(expected, actual)
+                }
+                (
+                    Err(ReadMarcError::Parse(expected)),
+                    Err(ReadMarcError::Parse(actual)),
+                ) => {
+                    assert_eq!(expected.kind(), actual.kind());
+                    assert_eq!(expected.record(), actual.record());
+                    assert_eq!(expected.offset(), actual.offset());
+                }
+                _ => panic!("expected the same result"),
+            }
+        }
+
+        assert!(records.next().is_none());
+
+        let mmap = MmapReader::from_path(
+            "tests/data/ada.mrc",
+            MarcReadOptions::default(),
+        )?;
+
+        let records: Vec<_> =
+            mmap.records().chain(mmap.records()).collect();
+        thread::scope(|scope| {
+            for record in records.iter() {
+                scope.spawn(move || assert!(record.is_ok()));
+            }
+        });
+
+        for (path, options) in [
+            ("tests/data/ada.mrc.gz", MarcReadOptions::default()),
+            ("tests/data/ada.mrc.zst", MarcReadOptions::default()),
+            ("-", MarcReadOptions::default()),
+            (
+                "tests/data/ada.mrc",
+                MarcReadOptions::default().marc8(true),
+            ),
+        ] {
+            let e = MmapReader::from_path(path, options).unwrap_err();
+            assert_eq!(e.kind(), io::ErrorKind::Unsupported);
+        }
+
+        Ok(())
+    }
+}
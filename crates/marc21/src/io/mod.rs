@@ -1,7 +1,65 @@
 //! Utilities to read and write MARC-Records.
 
+mod bgzf;
+mod chunk;
+mod compression;
+mod index;
+#[cfg(feature = "json")]
+mod json;
+mod marc8;
+#[cfg(feature = "mmap")]
+mod mmap;
 mod reader;
+#[cfg(feature = "xml")]
+mod xml;
 
+pub use bgzf::{BgzfReader, BgzfWriter};
+pub use chunk::{Chunk, ChunkReader};
+pub use index::{Index, IndexReader};
+#[cfg(feature = "json")]
+pub use json::{MarcJsonReader, MarcJsonWriteOptions, MarcJsonWriter};
+#[cfg(feature = "mmap")]
+pub use mmap::{MmapReader, MmapRecords};
 pub use reader::{
-    ByteRecordsIter, MarcReadOptions, MarcReader, ReadMarcError,
+    ByteRecordsIter, MarcReadOptions, MarcReader, ParseWarning,
+    ReadMarcError, open,
 };
+#[cfg(feature = "xml")]
+pub use xml::{MarcXmlReader, MarcXmlWriteOptions, MarcXmlWriter};
+
+#[cfg(any(feature = "json", feature = "xml"))]
+use crate::Leader;
+
+/// Returns the indicator of a text-based format. An empty value is
+/// treated as a blank.
+#[cfg(any(feature = "json", feature = "xml"))]
+fn indicator(value: &[u8]) -> Result<u8, String> {
+    match value {
+        [] => Ok(b' '),
+        [value] => Ok(*value),
+        value => Err(format!(
+            "invalid indicator '{}'",
+            String::from_utf8_lossy(value)
+        )),
+    }
+}
+
+/// Parses the leader of a text-based format. Blanks in the record
+/// length and the base address of data are accepted, because both
+/// values are only meaningful in ISO 2709 and are recomputed when the
+/// record is written.
+#[cfg(any(feature = "json", feature = "xml"))]
+fn leader(text: &[u8]) -> Result<Leader, String> {
+    let mut text = text.to_vec();
+    if text.len() == 24 {
+        for i in (0..5).chain(12..17) {
+            if text[i] == b' ' {
+                text[i] = b'0';
+            }
+        }
+    }
+
+    Leader::new(&text).map_err(|_| {
+        format!("invalid leader '{}'", String::from_utf8_lossy(&text))
+    })
+}
@@ -2,11 +2,20 @@ use std::fmt::{self, Display};
 use std::fs::File;
 use std::io::{self, BufRead, BufReader, Read, stdin};
 use std::path::Path;
+use std::sync::mpsc::Sender;
 
-use flate2::read::GzDecoder;
-
-use crate::ByteRecord;
-use crate::error::ParseRecordError;
+use crate::error::{ParseRecordError, ParseRecordErrorKind};
+#[cfg(feature = "json")]
+use crate::io::MarcJsonReader;
+#[cfg(feature = "xml")]
+use crate::io::MarcXmlReader;
+#[cfg(feature = "mmap")]
+use crate::io::MmapReader;
+use crate::io::compression::decompress;
+use crate::io::{ChunkReader, marc8};
+use crate::matcher::Prefilter;
+use crate::record::parse_record_lenient;
+use crate::{ByteRecord, ControlFieldRule, RecordBuf};
 
 /// An error that can occur when reading records.
 #[derive(Debug)]
@@ -26,11 +35,207 @@ impl Display for ReadMarcError<'_> {
 
 impl std::error::Error for ReadMarcError<'_> {}
 
+/// A repair, that was applied to a record in lenient mode.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseWarning {
+    message: String,
+    position: usize,
+    record: usize,
+}
+
+impl ParseWarning {
+    /// Creates a warning with a description and the byte offset within
+    /// the record. The number of the record is set by the reader.
+    pub fn new<S: Into<String>>(message: S, position: usize) -> Self {
+        Self {
+            message: message.into(),
+            position,
+            record: 0,
+        }
+    }
+
+    /// Sets the (one-based) number of the record within the input of
+    /// the reader.
+    #[cfg(feature = "xml")]
+    pub(crate) fn with_record(mut self, record: usize) -> Self {
+        self.record = record;
+        self
+    }
+
+    /// Returns a description of the repair.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Returns the byte offset of the repair within the record.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Returns the (one-based) number of the record within the input
+    /// of the reader.
+    pub fn record(&self) -> usize {
+        self.record
+    }
+}
+
+impl Display for ParseWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at position {}", self.message, self.position)
+    }
+}
+
 /// Configures and builds a MARC reader.
-#[derive(Debug, Default)]
-pub struct MarcReadOptions();
+#[derive(Debug, Default, Clone)]
+pub struct MarcReadOptions {
+    pub(crate) marc8: bool,
+    pub(crate) lenient: bool,
+    pub(crate) warnings: Option<Sender<ParseWarning>>,
+    pub(crate) prefilter: Option<Prefilter>,
+    pub(crate) control_fields: ControlFieldRule,
+}
 
 impl MarcReadOptions {
+    /// Whether to transcode MARC-8 encoded records into UTF-8 or not
+    /// (default: `false`).
+    ///
+    /// A record is MARC-8 encoded, if the character coding scheme
+    /// (leader position 09) is blank. A transcoded record is a UTF-8
+    /// record, whose character coding scheme is set to `a`. Records,
+    /// that are already UTF-8 encoded, are left untouched. Since
+    /// MARCXML and MARC-in-JSON are always Unicode encoded, the
+    /// MARCXML reader only sets the character coding scheme of these
+    /// records to `a` and the MARC-in-JSON reader ignores the option.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::prelude::*;
+    ///
+    /// let mut rdr = MarcReadOptions::default()
+    ///     .marc8(true)
+    ///     .try_into_reader_from_path("tests/data/marc8.mrc")?;
+    ///
+    /// let record = rdr.next_byte_record().unwrap().expect("record");
+    /// assert_eq!(record.leader().encoding(), b'a');
+    /// assert!(record.validate().is_ok());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn marc8(mut self, yes: bool) -> Self {
+        self.marc8 = yes;
+        self
+    }
+
+    /// Whether to repair malformed records instead of rejecting them
+    /// (default: `false`).
+    ///
+    /// In lenient mode, a record that can't be parsed is parsed again
+    /// and the fields are located by their terminators, if the
+    /// directory, the record length or the base address of data is
+    /// off. Uppercase indicators are converted to lowercase and other
+    /// invalid indicators are replaced by blanks. The option applies
+    /// to ISO 2709 and MARCXML records; the MARCXML reader repairs
+    /// only the indicators. Each repair is sent to the channel set by
+    /// [`warnings`](Self::warnings).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::sync::mpsc::channel;
+    ///
+    /// use marc21::io::MarcReader;
+    /// use marc21::prelude::*;
+    ///
+    /// let (tx, rx) = channel();
+    /// let data = b"00000nz  a2200000nc 4500\
+    ///     001000400000100001300004\x1e123\x1e1A\x1faAda\x1e\x1d";
+    ///
+    /// let options = MarcReadOptions::default().lenient(true).warnings(tx);
+    /// let mut rdr = MarcReader::new(&data[..], options);
+    /// let record = rdr.next_byte_record().unwrap().expect("record");
+    /// assert_eq!(record.fields().count(), 2);
+    ///
+    /// let warnings: Vec<_> = rx.try_iter().collect();
+    /// assert_eq!(warnings.len(), 4);
+    /// assert_eq!(
+    ///     warnings[3].message(),
+    ///     "uppercase indicator 'A' of field 100 converted to lowercase"
+    /// );
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn lenient(mut self, yes: bool) -> Self {
+        self.lenient = yes;
+        self
+    }
+
+    /// Sets the channel, which receives the repairs that were applied
+    /// in lenient mode. Without a channel, the repairs are applied
+    /// silently.
+    pub fn warnings(mut self, sender: Sender<ParseWarning>) -> Self {
+        self.warnings = Some(sender);
+        self
+    }
+
+    /// Sets a prefilter, which is checked on the raw bytes of each
+    /// record before the record is parsed (see [`Prefilter`]). Records,
+    /// which are rejected by the prefilter, are skipped silently, even
+    /// if they are invalid. The prefilter isn't applied to MARC-8
+    /// encoded records, which are transcoded (see
+    /// [`marc8`](Self::marc8)), and applies only to ISO 2709 records.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::matcher::{MatchOptions, RecordMatcher};
+    /// use marc21::prelude::*;
+    ///
+    /// let matcher = RecordMatcher::new("001 == '118540238'")?;
+    /// let prefilter = matcher.prefilter(&MatchOptions::default());
+    ///
+    /// let mut rdr = MarcReadOptions::default()
+    ///     .prefilter(prefilter.expect("prefilter"))
+    ///     .try_into_reader_from_path("tests/data/ada.mrc")?;
+    ///
+    /// assert!(rdr.next_byte_record().is_none());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn prefilter(mut self, prefilter: Prefilter) -> Self {
+        self.prefilter = Some(prefilter);
+        self
+    }
+
+    /// Sets the rule, which decides whether a field of an ISO 2709
+    /// record is a control field or a data field (default: all tags
+    /// starting with `00`). The rule doesn't apply to MARCXML and
+    /// MARC-in-JSON records, which distinguish control fields by their
+    /// markup.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::ControlFieldRule;
+    /// use marc21::io::MarcReader;
+    /// use marc21::prelude::*;
+    ///
+    /// let data = b"00057nz  a2200049nc 4500\
+    ///     001000400000FMT000300004\x1e123\x1eAU\x1e\x1d";
+    ///
+    /// let rule = "00., FMT".parse::<ControlFieldRule>()?;
+    /// let options = MarcReadOptions::default().control_fields(rule);
+    /// let mut rdr = MarcReader::new(&data[..], options);
+    /// let record = rdr.next_byte_record().unwrap().expect("record");
+    /// assert!(record.fields().all(|field| field.is_control_field()));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn control_fields(mut self, rule: ControlFieldRule) -> Self {
+        self.control_fields = rule;
+        self
+    }
+
     /// Create a new reader from a path.
     ///
     /// # Example
@@ -47,18 +252,116 @@ impl MarcReadOptions {
         self,
         path: P,
     ) -> io::Result<MarcReader<Box<dyn Read>>> {
-        let path = path.as_ref();
+        Ok(MarcReader::new(open(path)?, self))
+    }
 
-        let reader: Box<dyn Read> = match path.to_str() {
-            Some("-") | None => Box::new(stdin().lock()),
-            Some(path_str) if path_str.ends_with(".gz") => {
-                Box::new(GzDecoder::new(File::open(path)?))
-            }
-            Some(_) => Box::new(File::open(path)?),
-        };
+    /// Create a new chunk reader from a path.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::prelude::*;
+    ///
+    /// MarcReadOptions::default()
+    ///     .try_into_chunk_reader_from_path("tests/data/ada.mrc")?;
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn try_into_chunk_reader_from_path<P: AsRef<Path>>(
+        self,
+        path: P,
+    ) -> io::Result<ChunkReader<Box<dyn Read>>> {
+        Ok(ChunkReader::new(open(path)?, self))
+    }
 
-        Ok(MarcReader::new(reader, self))
+    /// Create a new memory-mapped reader from a path. The records
+    /// borrow directly from the mapping (see [`MmapReader`]).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::prelude::*;
+    ///
+    /// let mmap = MarcReadOptions::default()
+    ///     .try_into_mmap_reader_from_path("tests/data/ada.mrc")?;
+    ///
+    /// for result in mmap.records() {
+    ///     assert!(result.is_ok());
+    /// }
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[cfg(feature = "mmap")]
+    pub fn try_into_mmap_reader_from_path<P: AsRef<Path>>(
+        self,
+        path: P,
+    ) -> io::Result<MmapReader> {
+        MmapReader::from_path(path, self)
     }
+
+    /// Create a new MARCXML reader from a path.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::prelude::*;
+    ///
+    /// MarcReadOptions::default()
+    ///     .try_into_xml_reader_from_path("tests/data/ada.xml")?;
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[cfg(feature = "xml")]
+    pub fn try_into_xml_reader_from_path<P: AsRef<Path>>(
+        self,
+        path: P,
+    ) -> io::Result<MarcXmlReader<Box<dyn Read>>> {
+        Ok(MarcXmlReader::new(open(path)?, self))
+    }
+
+    /// Create a new MARC-in-JSON reader from a path.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::prelude::*;
+    ///
+    /// MarcReadOptions::default()
+    ///     .try_into_json_reader_from_path("tests/data/ada.json")?;
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[cfg(feature = "json")]
+    pub fn try_into_json_reader_from_path<P: AsRef<Path>>(
+        self,
+        path: P,
+    ) -> io::Result<MarcJsonReader<Box<dyn Read>>> {
+        Ok(MarcJsonReader::new(open(path)?, self))
+    }
+}
+
+/// Opens the given path for reading. The path `-` refers to stdin.
+/// Gzip compressed data is detected by its magic bytes and
+/// decompressed on the fly, regardless of the file extension. The
+/// `compression` feature adds Zstandard, bzip2 and xz compressed data.
+///
+/// # Example
+///
+/// ```rust
+/// use std::io::Read;
+///
+/// let mut data = Vec::new();
+/// marc21::io::open("tests/data/ada.mrc.gz")?.read_to_end(&mut data)?;
+/// assert_eq!(data, include_bytes!("../../tests/data/ada.mrc"));
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn open<P: AsRef<Path>>(path: P) -> io::Result<Box<dyn Read>> {
+    let path = path.as_ref();
+    decompress(match path.to_str() {
+        Some("-") | None => Box::new(stdin().lock()),
+        Some(_) => Box::new(File::open(path)?),
+    })
 }
 
 /// A MARC Reader.
@@ -66,14 +369,257 @@ impl MarcReadOptions {
 pub struct MarcReader<R: Read> {
     reader: BufReader<R>,
     buffer: Vec<u8>,
+    options: MarcReadOptions,
+    record: Option<RecordBuf>,
+    count: usize,
+    offset: usize,
+    position: usize,
 }
 
 impl<R: Read> MarcReader<R> {
-    pub fn new(reader: R, _options: MarcReadOptions) -> Self {
+    pub fn new(reader: R, options: MarcReadOptions) -> Self {
         let reader = BufReader::new(reader);
         let buffer = Vec::new();
 
-        Self { reader, buffer }
+        Self {
+            reader,
+            buffer,
+            options,
+            record: None,
+            count: 0,
+            offset: 0,
+            position: 0,
+        }
+    }
+
+    /// Sets the number of records and bytes, that precede the input of
+    /// the reader. This is useful, if the input is a chunk of a larger
+    /// stream, because the record numbers and byte offsets of errors
+    /// and warnings then refer to the whole stream.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::io::{MarcReader, ReadMarcError};
+    /// use marc21::prelude::*;
+    ///
+    /// let data = b"00000nz  a2200000nc 45x0\x1d";
+    /// let mut rdr = MarcReader::new(&data[..], Default::default())
+    ///     .with_position(41, 8192);
+    ///
+    /// let Some(Err(ReadMarcError::Parse(e))) = rdr.next_byte_record()
+    /// else {
+    ///     panic!("expected parse error");
+    /// };
+    ///
+    /// assert_eq!(e.record(), Some(42));
+    /// assert_eq!(e.offset(), Some(8192));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn with_position(
+        mut self,
+        count: usize,
+        offset: usize,
+    ) -> Self {
+        self.count = count;
+        self.offset = offset;
+        self
+    }
+
+    /// Returns the byte offset of the record, that was returned by the
+    /// last call of
+    /// [`next_byte_record`](ByteRecordsIter::next_byte_record).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::io::MarcReader;
+    /// use marc21::prelude::*;
+    ///
+    /// let data = include_bytes!("../../tests/data/ada.mrc").repeat(2);
+    /// let mut rdr = MarcReader::new(&data[..], Default::default());
+    ///
+    /// rdr.next_byte_record();
+    /// assert_eq!(rdr.position(), 0);
+    ///
+    /// rdr.next_byte_record();
+    /// assert_eq!(rdr.position(), 3612);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Returns the number of records read so far, including the
+    /// records which were rejected by the prefilter and the records
+    /// preceding the input (see [`with_position`](Self::with_position)).
+    /// After a call of
+    /// [`next_byte_record`](ByteRecordsIter::next_byte_record), this is
+    /// the (one-based) number of the returned record.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::io::MarcReader;
+    /// use marc21::matcher::{MatchOptions, RecordMatcher};
+    /// use marc21::prelude::*;
+    ///
+    /// let data = include_bytes!("../../tests/data/ada.mrc").repeat(2);
+    /// let matcher = RecordMatcher::new("001 == '118540238'")?;
+    /// let prefilter = matcher.prefilter(&MatchOptions::default());
+    /// let options = MarcReadOptions::default()
+    ///     .prefilter(prefilter.expect("prefilter"));
+    ///
+    /// let mut rdr = MarcReader::new(&data[..], options)
+    ///     .with_position(1, 0);
+    /// assert_eq!(rdr.records_read(), 1);
+    ///
+    /// assert!(rdr.next_byte_record().is_none());
+    /// assert_eq!(rdr.records_read(), 3);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn records_read(&self) -> usize {
+        self.count
+    }
+
+    /// Returns a reference to the underlying reader.
+    pub fn get_ref(&self) -> &R {
+        self.reader.get_ref()
+    }
+
+    /// Advances the reader and returns the next record together with
+    /// its (one-based) number within the input. The number also counts
+    /// the records, which were rejected by the prefilter (see
+    /// [`MarcReadOptions::prefilter`]).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::io::MarcReader;
+    /// use marc21::matcher::{MatchOptions, RecordMatcher};
+    /// use marc21::prelude::*;
+    ///
+    /// let ada = include_bytes!("../../tests/data/ada.mrc");
+    /// let data = [&b"00000nz  a2200000nc 45x0\x1d"[..], ada].concat();
+    ///
+    /// let matcher = RecordMatcher::new("001 == '119232022'")?;
+    /// let prefilter = matcher.prefilter(&MatchOptions::default());
+    /// let options = MarcReadOptions::default()
+    ///     .prefilter(prefilter.expect("prefilter"));
+    ///
+    /// let mut rdr = MarcReader::new(&data[..], options);
+    /// let (number, result) = rdr.next_numbered_record().unwrap();
+    /// assert_eq!(number, 2);
+    /// assert!(result.is_ok());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn next_numbered_record(
+        &mut self,
+    ) -> Option<(usize, Result<ByteRecord<'_>, ReadMarcError<'_>>)>
+    {
+        let start = loop {
+            self.buffer.clear();
+
+            match self.reader.read_until(b'\x1d', &mut self.buffer) {
+                Err(e) => {
+                    return Some((
+                        self.count + 1,
+                        Err(ReadMarcError::IO(e)),
+                    ));
+                }
+                Ok(0) => return None,
+                Ok(n) => {
+                    self.offset += n;
+
+                    // In lenient mode, trailing line breaks are
+                    // skipped.
+                    if self.options.lenient
+                        && self
+                            .buffer
+                            .iter()
+                            .all(u8::is_ascii_whitespace)
+                    {
+                        continue;
+                    }
+
+                    self.count += 1;
+                    self.position = self.offset - n;
+
+                    if !self.options.marc8
+                        && self
+                            .options
+                            .prefilter
+                            .as_ref()
+                            .is_some_and(|p| !p.is_match(&self.buffer))
+                    {
+                        continue;
+                    }
+
+                    break self.position;
+                }
+            }
+        };
+
+        let number = self.count;
+        let result = match parse(&self.buffer, &self.options, number) {
+            Err(e) => Err(ReadMarcError::Parse(
+                e.with_location(number, start),
+            )),
+            Ok(record)
+                if self.options.marc8
+                    && record.leader().encoding() == b' ' =>
+            {
+                match marc8::transcode(&record) {
+                    Err(e) => Err(ReadMarcError::Parse(
+                        ParseRecordError::new(
+                            ParseRecordErrorKind::Invalid(e.message),
+                            e.offset,
+                            &self.buffer,
+                        )
+                        .with_location(number, start),
+                    )),
+                    Ok(record) => {
+                        let record = self.record.insert(record);
+                        Ok(record.as_byte_record())
+                    }
+                }
+            }
+            Ok(record) => Ok(record),
+        };
+
+        Some((number, result))
+    }
+}
+
+/// Parses a record. In lenient mode, a record that can't be parsed is
+/// repaired and the repairs are sent to the warning channel.
+pub(crate) fn parse<'a>(
+    data: &'a [u8],
+    options: &MarcReadOptions,
+    count: usize,
+) -> Result<ByteRecord<'a>, ParseRecordError<'a>> {
+    let rule = &options.control_fields;
+
+    match ByteRecord::from_bytes_with(data, rule) {
+        Err(_) if options.lenient => {
+            let mut warnings = vec![];
+            let result =
+                parse_record_lenient(data, rule, &mut warnings);
+
+            if let Some(ref sender) = options.warnings {
+                for mut warning in warnings {
+                    warning.record = count;
+                    let _ = sender.send(warning);
+                }
+            }
+
+            result
+        }
+        result => result,
     }
 }
 
@@ -113,15 +659,6 @@ impl<R: Read> ByteRecordsIter for MarcReader<R> {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     fn next_byte_record(&mut self) -> Option<Self::ByteRecordItem<'_>> {
-        self.buffer.clear();
-
-        match self.reader.read_until(b'\x1d', &mut self.buffer) {
-            Err(e) => Some(Err(ReadMarcError::IO(e))),
-            Ok(0) => None,
-            Ok(_) => match ByteRecord::from_bytes(&self.buffer) {
-                Err(e) => Some(Err(ReadMarcError::Parse(e))),
-                Ok(record) => Some(Ok(record)),
-            },
-        }
+        self.next_numbered_record().map(|(_, result)| result)
     }
 }
@@ -0,0 +1,10 @@
+//! Utilities to read and write MARCXML (MARC 21 slim) documents.
+
+mod reader;
+mod writer;
+
+pub use reader::MarcXmlReader;
+pub use writer::{MarcXmlWriteOptions, MarcXmlWriter};
+
+/// The namespace of the MARC 21 slim schema.
+pub(crate) const NAMESPACE: &str = "http://www.loc.gov/MARC21/slim";
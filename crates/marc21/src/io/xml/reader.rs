@@ -0,0 +1,628 @@
+use std::io::{self, BufReader, Read};
+use std::mem;
+
+use quick_xml::escape::resolve_predefined_entity;
+use quick_xml::events::{BytesStart, Event};
+
+use crate::buf::{
+    ControlFieldBuf, DataFieldBuf, FieldBuf, RecordBuf, SubfieldBuf,
+};
+use crate::common::is_indicator;
+use crate::error::{ParseRecordError, ParseRecordErrorKind};
+use crate::io::{
+    ByteRecordsIter, MarcReadOptions, ParseWarning, ReadMarcError,
+    indicator, leader,
+};
+use crate::{ByteRecord, ControlFieldRule, Leader};
+
+/// A MARCXML reader.
+///
+/// The reader streams the `record` elements of a MARC 21 slim XML
+/// document (MARCXML) and yields the same record model as the binary
+/// [MarcReader](crate::io::MarcReader). Namespace prefixes (e.g.
+/// `marc:record`) are ignored and the `collection` element is
+/// optional.
+///
+/// An invalid record (e.g. a record with an invalid tag) is reported as
+/// [ReadMarcError::Parse] and the reader continues with the next
+/// record. A document that isn't well-formed XML can't be read any
+/// further and is reported as [ReadMarcError::IO].
+///
+/// In lenient mode (see [`MarcReadOptions::lenient`]), uppercase
+/// indicators are converted to lowercase and other invalid indicators
+/// are replaced by blanks. Since a MARCXML document is always Unicode
+/// encoded, the [`marc8`](MarcReadOptions::marc8) option only sets the
+/// character coding scheme of records with a blank coding scheme to
+/// `a`.
+#[derive(Debug)]
+pub struct MarcXmlReader<R: Read> {
+    reader: quick_xml::Reader<BufReader<R>>,
+    options: MarcReadOptions,
+    buffer: Vec<u8>,
+    text: Vec<u8>,
+    record: Option<RecordBuf>,
+    count: usize,
+}
+
+impl<R: Read> MarcXmlReader<R> {
+    pub fn new(reader: R, options: MarcReadOptions) -> Self {
+        let reader =
+            quick_xml::Reader::from_reader(BufReader::new(reader));
+
+        Self {
+            reader,
+            options,
+            buffer: Vec::new(),
+            text: Vec::new(),
+            record: None,
+            count: 0,
+        }
+    }
+
+    /// Reads the next `record` element and returns its state and the
+    /// byte offset of the end of the element.
+    fn read_record(&mut self) -> io::Result<Option<(State, usize)>> {
+        let mut state: Option<State> = None;
+
+        loop {
+            self.buffer.clear();
+
+            let start = self.reader.buffer_position() as usize;
+            let event = self
+                .reader
+                .read_event_into(&mut self.buffer)
+                .map_err(io::Error::other)?;
+            let pos = self.reader.buffer_position() as usize;
+
+            match event {
+                Event::Start(e)
+                    if e.local_name().as_ref() == b"record" =>
+                {
+                    state = Some(State::new(start, &self.options));
+                }
+                Event::Empty(e)
+                    if e.local_name().as_ref() == b"record" =>
+                {
+                    return Ok(Some((
+                        State::new(start, &self.options),
+                        pos,
+                    )));
+                }
+                Event::End(e)
+                    if e.local_name().as_ref() == b"record" =>
+                {
+                    if let Some(state) = state.take() {
+                        return Ok(Some((state, pos)));
+                    }
+                }
+                Event::Start(e) => {
+                    if let Some(ref mut state) = state {
+                        self.text.clear();
+                        state.start(
+                            &e,
+                            pos,
+                            &self.options.control_fields,
+                        );
+                    }
+                }
+                Event::Empty(e) => {
+                    if let Some(ref mut state) = state {
+                        self.text.clear();
+                        state.start(
+                            &e,
+                            pos,
+                            &self.options.control_fields,
+                        );
+                        state.end(
+                            e.local_name().as_ref(),
+                            &self.text,
+                            pos,
+                            &self.options.control_fields,
+                        );
+                    }
+                }
+                Event::End(e) => {
+                    if let Some(ref mut state) = state {
+                        state.end(
+                            e.local_name().as_ref(),
+                            &self.text,
+                            pos,
+                            &self.options.control_fields,
+                        );
+                    }
+                }
+                Event::Text(e) if state.is_some() => {
+                    self.text.extend_from_slice(&e);
+                }
+                Event::CData(e) if state.is_some() => {
+                    self.text.extend_from_slice(&e);
+                }
+                Event::GeneralRef(e) => {
+                    let Some(ref mut state) = state else {
+                        continue;
+                    };
+
+                    if e.is_char_ref() {
+                        match e.resolve_char_ref() {
+                            Ok(Some(c)) => {
+                                let mut buf = [0; 4];
+                                self.text.extend_from_slice(
+                                    c.encode_utf8(&mut buf).as_bytes(),
+                                );
+                            }
+                            _ => state.error(
+                                "invalid character reference",
+                                pos,
+                            ),
+                        }
+                    } else {
+                        match e.decode().ok().and_then(|name| {
+                            resolve_predefined_entity(&name)
+                        }) {
+                            Some(value) => self
+                                .text
+                                .extend_from_slice(value.as_bytes()),
+                            None => state.error("unknown entity", pos),
+                        }
+                    }
+                }
+                Event::Eof => {
+                    if state.is_some() {
+                        return Err(io::Error::new(
+                            io::ErrorKind::UnexpectedEof,
+                            "unexpected end of MARCXML document",
+                        ));
+                    }
+
+                    return Ok(None);
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+impl<R: Read> ByteRecordsIter for MarcXmlReader<R> {
+    type ByteRecordItem<'a>
+        = Result<ByteRecord<'a>, ReadMarcError<'a>>
+    where
+        Self: 'a;
+
+    /// Advance the iterator and return the next record.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::prelude::*;
+    ///
+    /// let mut rdr = MarcReadOptions::default()
+    ///     .try_into_xml_reader_from_path("tests/data/ada.xml")?;
+    ///
+    /// let mut cnt = 0;
+    /// while let Some(result) = rdr.next_byte_record() {
+    ///     let record = result.expect("valid record");
+    ///     assert_eq!(record.control_number().unwrap(), "119232022");
+    ///     cnt += 1;
+    /// }
+    ///
+    /// assert_eq!(cnt, 1);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    fn next_byte_record(&mut self) -> Option<Self::ByteRecordItem<'_>> {
+        let (mut state, pos) = match self.read_record() {
+            Err(e) => return Some(Err(ReadMarcError::IO(e))),
+            Ok(None) => return None,
+            Ok(Some(result)) => result,
+        };
+
+        self.count += 1;
+
+        if let Some(ref sender) = self.options.warnings {
+            for warning in mem::take(&mut state.warnings) {
+                let _ = sender.send(warning.with_record(self.count));
+            }
+        }
+
+        let start = state.start;
+        match state.finish(pos) {
+            Err((message, pos)) => Some(Err(ReadMarcError::Parse(
+                ParseRecordError::new(
+                    ParseRecordErrorKind::Invalid(message),
+                    pos - start,
+                    &[],
+                )
+                .with_location(self.count, start),
+            ))),
+            Ok(mut record) => {
+                if self.options.marc8 && record.leader.encoding == b' '
+                {
+                    record.leader.encoding = b'a';
+                }
+
+                let record = self.record.insert(record);
+                Some(Ok(record.as_byte_record()))
+            }
+        }
+    }
+}
+
+/// The element whose text content is currently collected.
+#[derive(Debug, Default)]
+enum Target {
+    #[default]
+    None,
+    Leader,
+    Control(Vec<u8>),
+    Subfield(u8),
+}
+
+/// The state of the record, which is currently read.
+#[derive(Debug, Default)]
+struct State {
+    /// The byte offset of the `record` element.
+    start: usize,
+    lenient: bool,
+    leader: Option<Leader>,
+    fields: Vec<FieldBuf>,
+    field: Option<DataFieldBuf>,
+    target: Target,
+    error: Option<(String, usize)>,
+    warnings: Vec<ParseWarning>,
+}
+
+impl State {
+    fn new(start: usize, options: &MarcReadOptions) -> Self {
+        Self {
+            start,
+            lenient: options.lenient,
+            ..Default::default()
+        }
+    }
+
+    fn start(
+        &mut self,
+        e: &BytesStart,
+        pos: usize,
+        rule: &ControlFieldRule,
+    ) {
+        let result = match e.local_name().as_ref() {
+            b"leader" => Ok(Target::Leader),
+            b"controlfield" => attribute(e, "tag").map(Target::Control),
+            b"datafield" => (|| {
+                let tag = attribute(e, "tag")?;
+                let ind1 = self.indicator(&tag, "ind1", e, pos)?;
+                let ind2 = self.indicator(&tag, "ind2", e, pos)?;
+
+                self.field = Some(
+                    DataFieldBuf::new_with(tag, ind1, ind2, rule)
+                        .map_err(|e| e.to_string())?,
+                );
+
+                Ok(Target::None)
+            })(),
+            b"subfield" => {
+                attribute(e, "code").and_then(|code| match *code {
+                    [code] => Ok(Target::Subfield(code)),
+                    _ => Err(format!(
+                        "invalid subfield code '{}'",
+                        String::from_utf8_lossy(&code)
+                    )),
+                })
+            }
+            _ => Ok(Target::None),
+        };
+
+        match result {
+            Ok(target) => self.target = target,
+            Err(message) => {
+                self.target = Target::None;
+                self.error(message, pos);
+            }
+        }
+    }
+
+    fn end(
+        &mut self,
+        name: &[u8],
+        text: &[u8],
+        pos: usize,
+        rule: &ControlFieldRule,
+    ) {
+        let result = match (name, mem::take(&mut self.target)) {
+            (b"leader", Target::Leader) => leader(text).map(|leader| {
+                self.leader = Some(leader);
+            }),
+            (b"controlfield", Target::Control(tag)) => {
+                ControlFieldBuf::new_with(tag, text, rule)
+                    .map(|cf| self.fields.push(cf.into()))
+                    .map_err(|e| e.to_string())
+            }
+            (b"subfield", Target::Subfield(code)) => {
+                SubfieldBuf::new(code, text)
+                    .map(|subfield| {
+                        if let Some(ref mut df) = self.field {
+                            df.push(subfield);
+                        }
+                    })
+                    .map_err(|e| e.to_string())
+            }
+            (b"datafield", _) => {
+                if let Some(df) = self.field.take() {
+                    self.fields.push(df.into());
+                }
+
+                Ok(())
+            }
+            _ => Ok(()),
+        };
+
+        if let Err(message) = result {
+            self.error(message, pos);
+        }
+    }
+
+    /// Returns an indicator of a data field. In lenient mode, an
+    /// uppercase indicator is converted to lowercase and other invalid
+    /// indicators are replaced by a blank.
+    fn indicator(
+        &mut self,
+        tag: &[u8],
+        name: &str,
+        e: &BytesStart,
+        pos: usize,
+    ) -> Result<u8, String> {
+        let value = attribute(e, name)?;
+        let result = indicator(&value);
+
+        if !self.lenient {
+            return result;
+        }
+
+        let tag = String::from_utf8_lossy(tag);
+        let (ind, message) = match result {
+            Ok(ind) if is_indicator(ind) => return Ok(ind),
+            Ok(ind) if ind.is_ascii_uppercase() => (
+                ind.to_ascii_lowercase(),
+                format!(
+                    "uppercase indicator '{}' of field {tag} converted \
+                     to lowercase",
+                    ind as char
+                ),
+            ),
+            _ => (
+                b' ',
+                format!(
+                    "invalid indicator '{}' of field {tag} replaced by \
+                     a blank",
+                    value.escape_ascii()
+                ),
+            ),
+        };
+
+        self.warnings
+            .push(ParseWarning::new(message, pos - self.start));
+        Ok(ind)
+    }
+
+    fn error<S: Into<String>>(&mut self, message: S, pos: usize) {
+        self.error.get_or_insert((message.into(), pos));
+    }
+
+    fn finish(self, pos: usize) -> Result<RecordBuf, (String, usize)> {
+        if let Some(err) = self.error {
+            return Err(err);
+        }
+
+        let Some(leader) = self.leader else {
+            return Err(("missing leader".into(), pos));
+        };
+
+        Ok(RecordBuf {
+            leader,
+            fields: self.fields,
+        })
+    }
+}
+
+/// Returns the unescaped value of an attribute.
+fn attribute(e: &BytesStart, name: &str) -> Result<Vec<u8>, String> {
+    match e.try_get_attribute(name) {
+        Ok(Some(attr)) => attr
+            .unescape_value()
+            .map(|value| value.as_bytes().to_vec())
+            .map_err(|e| e.to_string()),
+        Ok(None) => Err(format!("missing attribute '{name}'")),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::TestResult;
+
+    fn read_all(data: &[u8]) -> Vec<Result<RecordBuf, String>> {
+        let mut reader =
+            MarcXmlReader::new(data, MarcReadOptions::default());
+        let mut result = vec![];
+
+        while let Some(item) = reader.next_byte_record() {
+            result.push(
+                item.map(|record| RecordBuf::from(&record))
+                    .map_err(|e| e.to_string()),
+            );
+        }
+
+        result
+    }
+
+    #[test]
+    fn test_read_ada() -> TestResult {
+        let xml = include_bytes!("../../../../../tests/data/ada.xml");
+        let mrc = include_bytes!("../../../../../tests/data/ada.mrc");
+
+        let records = read_all(xml);
+        assert_eq!(records.len(), 1);
+
+        let expected = RecordBuf::from(ByteRecord::from_bytes(mrc)?);
+        assert_eq!(records[0].as_ref().unwrap(), &expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_namespaces_and_entities() {
+        let records = read_all(
+            br#"<?xml version="1.0"?>
+            <marc:collection xmlns:marc="http://www.loc.gov/MARC21/slim">
+              <marc:record>
+                <marc:leader>     nz  a22     nc 4500</marc:leader>
+                <marc:controlfield tag="001">123</marc:controlfield>
+                <marc:datafield tag="100" ind1="1" ind2="">
+                  <marc:subfield code="a">Tom &amp; Jerry &#x263A;</marc:subfield>
+                  <marc:subfield code="b"><![CDATA[<b>]]></marc:subfield>
+                  <marc:subfield code="c"/>
+                </marc:datafield>
+              </marc:record>
+            </marc:collection>"#,
+        );
+
+        assert_eq!(records.len(), 1);
+        assert_eq!(
+            records[0].as_ref().unwrap().to_string(),
+            "LDR 00000nz  a2200000nc 4500\n\
+             001 123\n\
+             100/1# $a Tom & Jerry \u{263A} $b <b> $c\n"
+        );
+    }
+
+    #[test]
+    fn test_read_invalid_records() {
+        let records = read_all(
+            br##"<collection>
+              <record>
+                <leader>00000nz  a2200000nc 4500</leader>
+                <controlfield tag="01">123</controlfield>
+              </record>
+              <record>
+                <controlfield tag="001">123</controlfield>
+              </record>
+              <record>
+                <leader>00000nz  a2200000nc 4500</leader>
+                <datafield tag="100" ind1="#" ind2=" "/>
+              </record>
+              <record>
+                <leader>00000nz  a2200000nc 4500</leader>
+                <controlfield tag="001">123</controlfield>
+              </record>
+            </collection>"##,
+        );
+
+        assert_eq!(records.len(), 4);
+        assert!(records[0].is_err());
+        assert!(records[1].is_err());
+        assert!(records[2].is_err());
+        assert!(records[3].is_ok());
+    }
+
+    #[test]
+    fn test_read_control_fields() -> TestResult {
+        let data = br#"<record>
+              <leader>00000nz  a2200000nc 4500</leader>
+              <controlfield tag="001">123</controlfield>
+              <controlfield tag="FMT">BK</controlfield>
+            </record>"#;
+        assert!(read_all(data)[0].is_err());
+
+        let options = MarcReadOptions::default()
+            .control_fields("00., FMT".parse()?);
+        let mut reader = MarcXmlReader::new(&data[..], options);
+        let record = reader.next_byte_record().unwrap().unwrap();
+        assert_eq!(
+            RecordBuf::from(&record).to_string(),
+            "LDR 00000nz  a2200000nc 4500\n001 123\nFMT BK\n"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_error_location() {
+        let data = b"<collection>\n<record>\
+            <controlfield tag=\"01\">123</controlfield></record>\n\
+            </collection>";
+
+        let mut reader =
+            MarcXmlReader::new(&data[..], MarcReadOptions::default());
+
+        let Some(Err(ReadMarcError::Parse(e))) =
+            reader.next_byte_record()
+        else {
+            panic!("expected parse error");
+        };
+
+        assert_eq!(e.record(), Some(1));
+        assert_eq!(e.position(), 49);
+        assert_eq!(e.offset(), Some(62));
+    }
+
+    #[test]
+    fn test_read_lenient() {
+        use std::sync::mpsc::channel;
+
+        let data = br##"<collection>
+              <record>
+                <leader>00000nz   2200000nc 4500</leader>
+                <datafield tag="100" ind1="A" ind2="#">
+                  <subfield code="a">Ada</subfield>
+                </datafield>
+              </record>
+            </collection>"##;
+
+        let records = read_all(data);
+        assert!(records[0].is_err());
+
+        let (tx, rx) = channel();
+        let options = MarcReadOptions::default()
+            .lenient(true)
+            .marc8(true)
+            .warnings(tx);
+
+        let mut reader = MarcXmlReader::new(&data[..], options);
+        let Some(Ok(record)) = reader.next_byte_record() else {
+            panic!("expected record");
+        };
+
+        assert_eq!(record.leader().encoding(), b'a');
+        assert_eq!(
+            record.to_string(),
+            "LDR 00000nz  a2200000nc 4500\n100/a# $a Ada\n"
+        );
+
+        let warnings: Vec<_> = rx.try_iter().collect();
+        assert_eq!(warnings.len(), 2);
+        assert_eq!(warnings[0].record(), 1);
+        assert_eq!(
+            warnings[0].message(),
+            "uppercase indicator 'A' of field 100 converted to lowercase"
+        );
+        assert_eq!(
+            warnings[1].message(),
+            "invalid indicator '#' of field 100 replaced by a blank"
+        );
+    }
+
+    #[test]
+    fn test_read_malformed_document() {
+        let mut reader = MarcXmlReader::new(
+            &b"<collection><record><leader>"[..],
+            MarcReadOptions::default(),
+        );
+
+        assert!(matches!(
+            reader.next_byte_record(),
+            Some(Err(ReadMarcError::IO(_)))
+        ));
+    }
+}
@@ -0,0 +1,351 @@
+use std::io::{self, Write};
+
+use bstr::ByteSlice;
+use quick_xml::escape::escape;
+
+use crate::io::xml::NAMESPACE;
+use crate::{ByteRecord, Field};
+
+/// Configures a MARCXML writer.
+#[derive(Debug, Clone)]
+pub struct MarcXmlWriteOptions {
+    pub(crate) pretty: bool,
+    pub(crate) collection: bool,
+}
+
+impl Default for MarcXmlWriteOptions {
+    fn default() -> Self {
+        Self {
+            pretty: false,
+            collection: true,
+        }
+    }
+}
+
+impl MarcXmlWriteOptions {
+    /// Whether to indent the elements or not (default: `false`).
+    pub fn pretty(mut self, yes: bool) -> Self {
+        self.pretty = yes;
+        self
+    }
+
+    /// Whether to wrap the records into a `collection` element or not
+    /// (default: `true`).
+    ///
+    /// Without a `collection` element, each record is written as a
+    /// standalone `record` element followed by a newline.
+    pub fn collection(mut self, yes: bool) -> Self {
+        self.collection = yes;
+        self
+    }
+}
+
+/// A streaming MARCXML writer.
+///
+/// # Example
+///
+/// ```rust
+/// use marc21::io::{MarcXmlWriteOptions, MarcXmlWriter};
+/// use marc21::prelude::*;
+///
+/// let data = include_bytes!("../../../tests/data/ada.mrc");
+/// let record = ByteRecord::from_bytes(data)?;
+///
+/// let mut wtr =
+///     MarcXmlWriter::new(Vec::new(), MarcXmlWriteOptions::default());
+/// wtr.write_record(&record)?;
+///
+/// let out = String::from_utf8(wtr.finish()?)?;
+/// assert!(
+///     out.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>")
+/// );
+/// assert!(out.contains("<controlfield tag=\"001\">119232022</"));
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug)]
+pub struct MarcXmlWriter<W: Write> {
+    writer: W,
+    options: MarcXmlWriteOptions,
+    started: bool,
+}
+
+impl<W: Write> MarcXmlWriter<W> {
+    pub fn new(writer: W, options: MarcXmlWriteOptions) -> Self {
+        Self {
+            writer,
+            options,
+            started: false,
+        }
+    }
+
+    /// Writes a record as `record` element.
+    ///
+    /// # Errors
+    ///
+    /// An error of kind [`io::ErrorKind::InvalidData`] is returned, if
+    /// a value isn't valid UTF-8. In this case, nothing is written.
+    pub fn write_record(
+        &mut self,
+        record: &ByteRecord,
+    ) -> io::Result<()> {
+        if !self.started {
+            self.start()?;
+        }
+
+        let (record_indent, field_indent, subfield_indent, nl) =
+            if !self.options.pretty {
+                ("", "", "", "")
+            } else if self.options.collection {
+                ("  ", "    ", "      ", "\n")
+            } else {
+                ("", "  ", "    ", "\n")
+            };
+
+        let mut out = Vec::new();
+
+        if self.options.collection {
+            write!(out, "{record_indent}<record>{nl}")?;
+        } else {
+            write!(out, "<record xmlns=\"{NAMESPACE}\">{nl}")?;
+        }
+
+        let mut leader = Vec::with_capacity(24);
+        record.leader().write_to(&mut leader)?;
+        write!(
+            out,
+            "{field_indent}<leader>{}</leader>{nl}",
+            text(&leader)?
+        )?;
+
+        for field in record.fields() {
+            match field {
+                Field::Control(cf) => {
+                    write!(
+                        out,
+                        "{field_indent}<controlfield tag=\"{}\">{}\
+                         </controlfield>{nl}",
+                        cf.tag(),
+                        text(cf.value())?
+                    )?;
+                }
+                Field::Data(df) => {
+                    write!(
+                        out,
+                        "{field_indent}<datafield tag=\"{}\" \
+                         ind1=\"{}\" ind2=\"{}\">{nl}",
+                        df.tag(),
+                        text(&[*df.indicator1()])?,
+                        text(&[*df.indicator2()])?,
+                    )?;
+
+                    for subfield in df.subfields() {
+                        write!(
+                            out,
+                            "{subfield_indent}<subfield code=\"{}\">{}\
+                             </subfield>{nl}",
+                            text(&[*subfield.code()])?,
+                            text(subfield.value())?
+                        )?;
+                    }
+
+                    write!(out, "{field_indent}</datafield>{nl}")?;
+                }
+            }
+        }
+
+        write!(out, "{record_indent}</record>")?;
+        if self.options.pretty || !self.options.collection {
+            writeln!(out)?;
+        }
+
+        self.writer.write_all(&out)
+    }
+
+    /// Closes the `collection` element (if any), flushes the writer and
+    /// returns the underlying writer.
+    pub fn finish(mut self) -> io::Result<W> {
+        if self.options.collection {
+            if !self.started {
+                self.start()?;
+            }
+
+            writeln!(self.writer, "</collection>")?;
+        }
+
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+
+    fn start(&mut self) -> io::Result<()> {
+        self.started = true;
+
+        if self.options.collection {
+            writeln!(
+                self.writer,
+                "<?xml version=\"1.0\" encoding=\"UTF-8\"?>"
+            )?;
+            write!(self.writer, "<collection xmlns=\"{NAMESPACE}\">")?;
+            if self.options.pretty {
+                writeln!(self.writer)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the escaped text of a value. A value, which isn't valid
+/// UTF-8, is rejected, because it can't be represented in XML.
+#[inline]
+fn text(value: &[u8]) -> io::Result<String> {
+    let value = value.to_str().map_err(|e| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("invalid UTF-8 in MARCXML value: {e}"),
+        )
+    })?;
+
+    Ok(escape(value).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::TestResult;
+    use crate::io::{ByteRecordsIter, MarcReadOptions, MarcXmlReader};
+    use crate::{Leader, RecordBuf};
+
+    fn record() -> Result<RecordBuf, Box<dyn std::error::Error>> {
+        Ok(RecordBuf::builder()
+            .leader(Leader::new(b"00000nz  a2200000nc 4500")?)
+            .control_field("001", "123")
+            .data_field("100", b'1', b' ', [(b'a', "Tom & Jerry <3")])
+            .build()?)
+    }
+
+    #[test]
+    fn test_write_compact() -> TestResult {
+        let record = record()?;
+        let mut wtr =
+            MarcXmlWriter::new(vec![], MarcXmlWriteOptions::default());
+        wtr.write_record(&record.as_byte_record())?;
+        wtr.write_record(&record.as_byte_record())?;
+
+        let expected = "<record><leader>00000nz  a2200000nc 4500</leader>\
+            <controlfield tag=\"001\">123</controlfield>\
+            <datafield tag=\"100\" ind1=\"1\" ind2=\" \">\
+            <subfield code=\"a\">Tom &amp; Jerry &lt;3</subfield>\
+            </datafield></record>";
+
+        assert_eq!(
+            String::from_utf8(wtr.finish()?)?,
+            format!(
+                "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+                 <collection xmlns=\"{NAMESPACE}\">\
+                 {expected}{expected}</collection>\n"
+            )
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_pretty() -> TestResult {
+        let record = record()?;
+        let options = MarcXmlWriteOptions::default().pretty(true);
+        let mut wtr = MarcXmlWriter::new(vec![], options);
+        wtr.write_record(&record.as_byte_record())?;
+
+        assert_eq!(
+            String::from_utf8(wtr.finish()?)?,
+            format!(
+                "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+                 <collection xmlns=\"{NAMESPACE}\">\n  \
+                   <record>\n    \
+                     <leader>00000nz  a2200000nc 4500</leader>\n    \
+                     <controlfield tag=\"001\">123</controlfield>\n    \
+                     <datafield tag=\"100\" ind1=\"1\" ind2=\" \">\n      \
+                       <subfield code=\"a\">Tom &amp; Jerry &lt;3</subfield>\n    \
+                     </datafield>\n  \
+                   </record>\n\
+                 </collection>\n"
+            )
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_without_collection() -> TestResult {
+        let record = record()?;
+        let options = MarcXmlWriteOptions::default().collection(false);
+        let mut wtr = MarcXmlWriter::new(vec![], options);
+        wtr.write_record(&record.as_byte_record())?;
+
+        let out = String::from_utf8(wtr.finish()?)?;
+        assert!(
+            out.starts_with(&format!("<record xmlns=\"{NAMESPACE}\">"))
+        );
+        assert!(out.ends_with("</record>\n"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_invalid_utf8() -> TestResult {
+        let record = RecordBuf::builder()
+            .leader(Leader::new(b"00000nz  a2200000nc 4500")?)
+            .data_field("100", b'1', b' ', [(b'a', &b"Ada\xff"[..])])
+            .build()?;
+
+        let options = MarcXmlWriteOptions::default().collection(false);
+        let mut wtr = MarcXmlWriter::new(vec![], options);
+        let err =
+            wtr.write_record(&record.as_byte_record()).unwrap_err();
+
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert!(wtr.finish()?.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_empty_collection() -> TestResult {
+        let wtr = MarcXmlWriter::new(vec![], Default::default());
+        assert_eq!(
+            String::from_utf8(wtr.finish()?)?,
+            format!(
+                "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+                 <collection xmlns=\"{NAMESPACE}\"></collection>\n"
+            )
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_read_roundtrip() -> TestResult {
+        let data = include_bytes!("../../../../../tests/data/ada.mrc");
+        let record = ByteRecord::from_bytes(data)?;
+
+        for pretty in [false, true] {
+            let options = MarcXmlWriteOptions::default().pretty(pretty);
+            let mut wtr = MarcXmlWriter::new(vec![], options);
+            wtr.write_record(&record)?;
+            let out = wtr.finish()?;
+
+            let mut rdr = MarcXmlReader::new(
+                &out[..],
+                MarcReadOptions::default(),
+            );
+            let result = RecordBuf::from(
+                rdr.next_byte_record().unwrap().unwrap(),
+            );
+            assert_eq!(result, RecordBuf::from(&record));
+            assert!(rdr.next_byte_record().is_none());
+        }
+
+        Ok(())
+    }
+}
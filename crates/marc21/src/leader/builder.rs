@@ -0,0 +1,232 @@
+use crate::Leader;
+use crate::error::BuildRecordError;
+use crate::leader::code::{RecordFormat, validate};
+
+/// A builder to create a leader, whose values are valid for the
+/// record format.
+///
+/// The values can be given either as a byte or as a code enum (e.g.
+/// [`RecordStatus`](crate::leader::RecordStatus)). The type of record
+/// is mandatory; the record status defaults to `n` (new), the
+/// character coding scheme to `a` (Unicode) and all other positions
+/// to a blank. The record length and the base address of data are
+/// calculated when a record is written.
+///
+/// # Example
+///
+/// ```rust
+/// use marc21::LeaderBuilder;
+/// use marc21::leader::*;
+///
+/// let leader = LeaderBuilder::default()
+///     .r#type(RecordType::LanguageMaterial)
+///     .bibliographic_level(BibliographicLevel::Monograph)
+///     .encoding_level(EncodingLevel::Minimal)
+///     .descriptive_cataloging_form(DescriptiveCatalogingForm::Aacr2)
+///     .build()?;
+///
+/// assert_eq!(leader.to_string(), "LDR 00000nam a22000007a 4500");
+///
+/// let result = LeaderBuilder::default()
+///     .r#type(RecordType::AuthorityData)
+///     .status(RecordStatus::IncreaseFromPrepublication)
+///     .build();
+/// assert!(result.is_err());
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct LeaderBuilder {
+    leader: Leader,
+}
+
+impl Default for LeaderBuilder {
+    fn default() -> Self {
+        Self {
+            leader: Leader {
+                length: 0,
+                status: b'n',
+                r#type: b' ',
+                pos07: b' ',
+                pos08: b' ',
+                encoding: b'a',
+                base_addr: 0,
+                pos17: b' ',
+                pos18: b' ',
+                pos19: b' ',
+                indicator_count: 2,
+                subfield_code_length: 2,
+                length_of_field_length: 4,
+                length_of_starting_position: 5,
+                length_of_implementation_defined: 0,
+                pos23: b'0',
+            },
+        }
+    }
+}
+
+impl LeaderBuilder {
+    /// Sets the record status (position 05).
+    pub fn status<T: Into<u8>>(mut self, value: T) -> Self {
+        self.leader.status = value.into();
+        self
+    }
+
+    /// Sets the type of record (position 06), which determines the
+    /// record format.
+    pub fn r#type<T: Into<u8>>(mut self, value: T) -> Self {
+        self.leader.r#type = value.into();
+        self
+    }
+
+    /// Sets the bibliographic level (position 07) of a bibliographic
+    /// record.
+    pub fn bibliographic_level<T: Into<u8>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.leader.pos07 = value.into();
+        self
+    }
+
+    /// Sets the kind of data (position 07) of a community information
+    /// record.
+    pub fn kind_of_data<T: Into<u8>>(mut self, value: T) -> Self {
+        self.leader.pos07 = value.into();
+        self
+    }
+
+    /// Sets the type of control (position 08) of a bibliographic
+    /// record.
+    pub fn type_of_control<T: Into<u8>>(mut self, value: T) -> Self {
+        self.leader.pos08 = value.into();
+        self
+    }
+
+    /// Sets the character coding scheme (position 09).
+    pub fn encoding<T: Into<u8>>(mut self, value: T) -> Self {
+        self.leader.encoding = value.into();
+        self
+    }
+
+    /// Sets the encoding level (position 17).
+    pub fn encoding_level<T: Into<u8>>(mut self, value: T) -> Self {
+        self.leader.pos17 = value.into();
+        self
+    }
+
+    /// Sets the descriptive cataloging form (position 18) of a
+    /// bibliographic record.
+    pub fn descriptive_cataloging_form<T: Into<u8>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.leader.pos18 = value.into();
+        self
+    }
+
+    /// Sets the punctuation policy (position 18) of an authority
+    /// record.
+    pub fn punctuation_policy<T: Into<u8>>(mut self, value: T) -> Self {
+        self.leader.pos18 = value.into();
+        self
+    }
+
+    /// Sets the item information (position 18) of a holdings record.
+    pub fn item_information<T: Into<u8>>(mut self, value: T) -> Self {
+        self.leader.pos18 = value.into();
+        self
+    }
+
+    /// Sets the multipart resource record level (position 19) of a
+    /// bibliographic record.
+    pub fn multipart_level<T: Into<u8>>(mut self, value: T) -> Self {
+        self.leader.pos19 = value.into();
+        self
+    }
+
+    /// Builds the leader. An error is returned, if the type of record
+    /// is missing or invalid or if a value isn't allowed in the record
+    /// format.
+    pub fn build(self) -> Result<Leader, BuildRecordError> {
+        let ldr = self.leader;
+        ldr.check_supported()?;
+
+        let Some(format) = RecordFormat::from_type(ldr.r#type) else {
+            return Err(BuildRecordError::InvalidLeaderValue(
+                6, ldr.r#type,
+            ));
+        };
+
+        for (position, code) in [
+            (5, ldr.status),
+            (7, ldr.pos07),
+            (8, ldr.pos08),
+            (9, ldr.encoding),
+            (17, ldr.pos17),
+            (18, ldr.pos18),
+            (19, ldr.pos19),
+        ] {
+            validate(format, position, code)?;
+        }
+
+        Ok(ldr)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::leader::*;
+
+    #[test]
+    fn test_leader_builder() -> TestResult {
+        let ldr = LeaderBuilder::default()
+            .r#type(RecordType::AuthorityData)
+            .encoding_level(Completeness::Complete)
+            .punctuation_policy(PunctuationPolicy::Omitted)
+            .build()?;
+        assert_eq!(ldr, Leader::new(b"00000nz  a2200000nc 4500")?);
+
+        let ldr = LeaderBuilder::default()
+            .r#type(b'y')
+            .status(b'c')
+            .encoding_level(HoldingsLevel::Level4)
+            .item_information(ItemInformation::NotIncluded)
+            .build()?;
+        assert_eq!(ldr, Leader::new(b"00000cy  a22000004n 4500")?);
+
+        let ldr = LeaderBuilder::default()
+            .r#type(RecordType::CommunityInformation)
+            .kind_of_data(KindOfData::Organization)
+            .encoding(CharacterCoding::Marc8)
+            .build()?;
+        assert_eq!(ldr.kind_of_data(), Some(b'o'));
+        assert_eq!(ldr.encoding(), b' ');
+
+        assert_eq!(
+            LeaderBuilder::default().build().unwrap_err(),
+            BuildRecordError::InvalidLeaderValue(6, b' ')
+        );
+
+        assert_eq!(
+            LeaderBuilder::default()
+                .r#type(RecordType::ClassificationData)
+                .encoding_level(EncodingLevel::Minimal)
+                .build()
+                .unwrap_err(),
+            BuildRecordError::InvalidLeaderValue(17, b'7')
+        );
+
+        assert_eq!(
+            LeaderBuilder::default()
+                .r#type(RecordType::Kit)
+                .bibliographic_level(b'x')
+                .build()
+                .unwrap_err(),
+            BuildRecordError::InvalidLeaderValue(7, b'x')
+        );
+
+        Ok(())
+    }
+}
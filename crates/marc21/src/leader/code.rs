@@ -0,0 +1,457 @@
+use crate::error::BuildRecordError;
+
+/// Defines an enum of the codes of a leader position, which can be
+/// converted from and into the corresponding byte.
+macro_rules! leader_code {
+    (
+        $(#[$meta:meta])*
+        $name:ident at $pos:literal $(for [$($format:ident),+])? {
+            $($(#[$vmeta:meta])* $variant:ident = $code:literal,)+
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $($(#[$vmeta])* $variant,)+
+        }
+
+        impl $name {
+            /// The position of the code in the leader.
+            pub const POSITION: usize = $pos;
+        }
+
+        impl LeaderCode for $name {
+            const POSITION: usize = $pos;
+            const FORMATS: &'static [RecordFormat] =
+                &[$($(RecordFormat::$format),+)?];
+        }
+
+        impl TryFrom<u8> for $name {
+            type Error = BuildRecordError;
+
+            fn try_from(code: u8) -> Result<Self, Self::Error> {
+                match code {
+                    $($code => Ok(Self::$variant),)+
+                    _ => Err(BuildRecordError::InvalidLeaderValue(
+                        $pos, code,
+                    )),
+                }
+            }
+        }
+
+        impl From<$name> for u8 {
+            fn from(value: $name) -> Self {
+                match value {
+                    $($name::$variant => $code,)+
+                }
+            }
+        }
+    };
+}
+
+/// A code of a leader position, which can be read by
+/// [`Leader::code`](crate::Leader::code).
+pub trait LeaderCode: TryFrom<u8> + Into<u8> + Copy {
+    /// The position of the code in the leader.
+    const POSITION: usize;
+
+    /// The record formats, in which the position is defined by this
+    /// code. An empty list means, that the code applies to all record
+    /// formats.
+    const FORMATS: &'static [RecordFormat];
+}
+
+/// The record format, which is determined by the type of record
+/// (leader position 06).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RecordFormat {
+    Bibliographic,
+    Authority,
+    Holdings,
+    Classification,
+    CommunityInformation,
+}
+
+impl RecordFormat {
+    /// Returns the format of a record with the given type of record.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::leader::RecordFormat;
+    ///
+    /// assert_eq!(
+    ///     RecordFormat::from_type(b'z'),
+    ///     Some(RecordFormat::Authority)
+    /// );
+    /// assert_eq!(RecordFormat::from_type(b'b'), None);
+    /// ```
+    pub fn from_type(r#type: u8) -> Option<Self> {
+        match r#type {
+            b'a'
+            | b'c'..=b'g'
+            | b'i'..=b'k'
+            | b'm'
+            | b'o'
+            | b'p'
+            | b'r'
+            | b't' => Some(Self::Bibliographic),
+            b'z' => Some(Self::Authority),
+            b'u' | b'v' | b'x' | b'y' => Some(Self::Holdings),
+            b'w' => Some(Self::Classification),
+            b'q' => Some(Self::CommunityInformation),
+            _ => None,
+        }
+    }
+}
+
+leader_code! {
+    /// Record status (position 05).
+    ///
+    /// Not every status is defined for every record format: `p` is
+    /// only defined for bibliographic records and `o`, `s` and `x`
+    /// only for authority records.
+    RecordStatus at 5 {
+        /// Increase in encoding level (`a`).
+        IncreaseInEncodingLevel = b'a',
+        /// Corrected or revised (`c`).
+        Corrected = b'c',
+        /// Deleted (`d`).
+        Deleted = b'd',
+        /// New (`n`).
+        New = b'n',
+        /// Obsolete (`o`).
+        Obsolete = b'o',
+        /// Increase in encoding level from prepublication (`p`).
+        IncreaseFromPrepublication = b'p',
+        /// Deleted; heading split into two or more headings (`s`).
+        DeletedSplit = b's',
+        /// Deleted; heading replaced by another heading (`x`).
+        DeletedReplaced = b'x',
+    }
+}
+
+leader_code! {
+    /// Type of record (position 06).
+    RecordType at 6 {
+        /// Language material (`a`).
+        LanguageMaterial = b'a',
+        /// Notated music (`c`).
+        NotatedMusic = b'c',
+        /// Manuscript notated music (`d`).
+        ManuscriptNotatedMusic = b'd',
+        /// Cartographic material (`e`).
+        CartographicMaterial = b'e',
+        /// Manuscript cartographic material (`f`).
+        ManuscriptCartographicMaterial = b'f',
+        /// Projected medium (`g`).
+        ProjectedMedium = b'g',
+        /// Nonmusical sound recording (`i`).
+        NonmusicalSoundRecording = b'i',
+        /// Musical sound recording (`j`).
+        MusicalSoundRecording = b'j',
+        /// Two-dimensional nonprojectable graphic (`k`).
+        NonprojectableGraphic = b'k',
+        /// Computer file (`m`).
+        ComputerFile = b'm',
+        /// Kit (`o`).
+        Kit = b'o',
+        /// Mixed materials (`p`).
+        MixedMaterials = b'p',
+        /// Community information (`q`).
+        CommunityInformation = b'q',
+        /// Three-dimensional artifact or naturally occurring object
+        /// (`r`).
+        ThreeDimensionalArtifact = b'r',
+        /// Manuscript language material (`t`).
+        ManuscriptLanguageMaterial = b't',
+        /// Unknown holdings (`u`).
+        UnknownHoldings = b'u',
+        /// Multipart item holdings (`v`).
+        MultipartItemHoldings = b'v',
+        /// Classification data (`w`).
+        ClassificationData = b'w',
+        /// Single-part item holdings (`x`).
+        SinglePartItemHoldings = b'x',
+        /// Serial item holdings (`y`).
+        SerialItemHoldings = b'y',
+        /// Authority data (`z`).
+        AuthorityData = b'z',
+    }
+}
+
+impl RecordType {
+    /// Returns the record format of this type of record.
+    pub fn format(&self) -> RecordFormat {
+        // Every type of record belongs to a record format.
+        RecordFormat::from_type(u8::from(*self)).unwrap()
+    }
+}
+
+leader_code! {
+    /// Bibliographic level (position 07) of a bibliographic record.
+    BibliographicLevel at 7 for [Bibliographic] {
+        /// Monographic component part (`a`).
+        MonographicComponentPart = b'a',
+        /// Serial component part (`b`).
+        SerialComponentPart = b'b',
+        /// Collection (`c`).
+        Collection = b'c',
+        /// Subunit (`d`).
+        Subunit = b'd',
+        /// Integrating resource (`i`).
+        IntegratingResource = b'i',
+        /// Monograph/Item (`m`).
+        Monograph = b'm',
+        /// Serial (`s`).
+        Serial = b's',
+    }
+}
+
+leader_code! {
+    /// Kind of data (position 07) of a community information record.
+    KindOfData at 7 for [CommunityInformation] {
+        /// Individual (`n`).
+        Individual = b'n',
+        /// Organization (`o`).
+        Organization = b'o',
+        /// Program or service (`p`).
+        ProgramOrService = b'p',
+        /// Event (`z`).
+        Event = b'z',
+    }
+}
+
+leader_code! {
+    /// Type of control (position 08) of a bibliographic record.
+    TypeOfControl at 8 for [Bibliographic] {
+        /// No specified type (blank).
+        Unspecified = b' ',
+        /// Archival (`a`).
+        Archival = b'a',
+    }
+}
+
+leader_code! {
+    /// Character coding scheme (position 09).
+    CharacterCoding at 9 {
+        /// MARC-8 (blank).
+        Marc8 = b' ',
+        /// UCS/Unicode (`a`).
+        Unicode = b'a',
+    }
+}
+
+leader_code! {
+    /// Encoding level (position 17) of a bibliographic record.
+    EncodingLevel at 17 for [Bibliographic] {
+        /// Full level (blank).
+        Full = b' ',
+        /// Full level, material not examined (`1`).
+        FullNotExamined = b'1',
+        /// Less-than-full level, material not examined (`2`).
+        LessThanFullNotExamined = b'2',
+        /// Abbreviated level (`3`).
+        Abbreviated = b'3',
+        /// Core level (`4`).
+        Core = b'4',
+        /// Partial (preliminary) level (`5`).
+        Partial = b'5',
+        /// Minimal level (`7`).
+        Minimal = b'7',
+        /// Prepublication level (`8`).
+        Prepublication = b'8',
+        /// Unknown (`u`).
+        Unknown = b'u',
+        /// Not applicable (`z`).
+        NotApplicable = b'z',
+    }
+}
+
+leader_code! {
+    /// Encoding level (position 17) of an authority or a
+    /// classification record.
+    Completeness at 17 for [Authority, Classification] {
+        /// Complete record (`n`).
+        Complete = b'n',
+        /// Incomplete record (`o`).
+        Incomplete = b'o',
+    }
+}
+
+leader_code! {
+    /// Encoding level (position 17) of a holdings record.
+    HoldingsLevel at 17 for [Holdings] {
+        /// Holdings level 1 (`1`).
+        Level1 = b'1',
+        /// Holdings level 2 (`2`).
+        Level2 = b'2',
+        /// Holdings level 3 (`3`).
+        Level3 = b'3',
+        /// Holdings level 4 (`4`).
+        Level4 = b'4',
+        /// Holdings level 4 with piece designation (`5`).
+        Level4WithPieceDesignation = b'5',
+        /// Mixed level (`m`).
+        Mixed = b'm',
+        /// Unknown (`u`).
+        Unknown = b'u',
+        /// Other level (`z`).
+        Other = b'z',
+    }
+}
+
+leader_code! {
+    /// Descriptive cataloging form (position 18) of a bibliographic
+    /// record.
+    DescriptiveCatalogingForm at 18 for [Bibliographic] {
+        /// Non-ISBD (blank).
+        NonIsbd = b' ',
+        /// AACR 2 (`a`).
+        Aacr2 = b'a',
+        /// ISBD punctuation omitted (`c`).
+        IsbdPunctuationOmitted = b'c',
+        /// ISBD punctuation included (`i`).
+        IsbdPunctuationIncluded = b'i',
+        /// Non-ISBD punctuation omitted (`n`).
+        NonIsbdPunctuationOmitted = b'n',
+        /// Unknown (`u`).
+        Unknown = b'u',
+    }
+}
+
+leader_code! {
+    /// Punctuation policy (position 18) of an authority record.
+    PunctuationPolicy at 18 for [Authority] {
+        /// No information provided (blank).
+        NoInformation = b' ',
+        /// Punctuation omitted (`c`).
+        Omitted = b'c',
+        /// Punctuation included (`i`).
+        Included = b'i',
+        /// Unknown (`u`).
+        Unknown = b'u',
+    }
+}
+
+leader_code! {
+    /// Item information in record (position 18) of a holdings record.
+    ItemInformation at 18 for [Holdings] {
+        /// Item information (`i`).
+        Included = b'i',
+        /// No item information (`n`).
+        NotIncluded = b'n',
+    }
+}
+
+leader_code! {
+    /// Multipart resource record level (position 19) of a
+    /// bibliographic record.
+    MultipartLevel at 19 for [Bibliographic] {
+        /// Not specified or not applicable (blank).
+        Unspecified = b' ',
+        /// Set (`a`).
+        Set = b'a',
+        /// Part with independent title (`b`).
+        PartWithIndependentTitle = b'b',
+        /// Part with dependent title (`c`).
+        PartWithDependentTitle = b'c',
+    }
+}
+
+/// Checks whether the byte at the given leader position is allowed in
+/// a record of the given format.
+pub(crate) fn validate(
+    format: RecordFormat,
+    position: usize,
+    code: u8,
+) -> Result<(), BuildRecordError> {
+    use RecordFormat::*;
+
+    let valid = match (position, format) {
+        (5, Bibliographic) => {
+            matches!(code, b'a' | b'c' | b'd' | b'n' | b'p')
+        }
+        (5, Authority) => matches!(
+            code,
+            b'a' | b'c' | b'd' | b'n' | b'o' | b's' | b'x'
+        ),
+        (5, Classification) => {
+            matches!(code, b'a' | b'c' | b'd' | b'n')
+        }
+        (5, Holdings | CommunityInformation) => {
+            matches!(code, b'c' | b'd' | b'n')
+        }
+        (7, Bibliographic) => {
+            BibliographicLevel::try_from(code).is_ok()
+        }
+        (7, CommunityInformation) => KindOfData::try_from(code).is_ok(),
+        (8, Bibliographic) => TypeOfControl::try_from(code).is_ok(),
+        (9, _) => CharacterCoding::try_from(code).is_ok(),
+        (17, Bibliographic) => EncodingLevel::try_from(code).is_ok(),
+        (17, Authority | Classification) => {
+            Completeness::try_from(code).is_ok()
+        }
+        (17, Holdings) => HoldingsLevel::try_from(code).is_ok(),
+        // The encoding level of community information records isn't
+        // restricted to a list of codes.
+        (17, CommunityInformation) => {
+            code == b' ' || code.is_ascii_graphic()
+        }
+        (18, Bibliographic) => {
+            DescriptiveCatalogingForm::try_from(code).is_ok()
+        }
+        (18, Authority) => PunctuationPolicy::try_from(code).is_ok(),
+        (18, Holdings) => ItemInformation::try_from(code).is_ok(),
+        (19, Bibliographic) => MultipartLevel::try_from(code).is_ok(),
+        // All other positions are undefined and contain a blank.
+        _ => code == b' ',
+    };
+
+    if valid {
+        Ok(())
+    } else {
+        Err(BuildRecordError::InvalidLeaderValue(position, code))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_leader_code() {
+        assert_eq!(
+            BibliographicLevel::try_from(b'm'),
+            Ok(BibliographicLevel::Monograph)
+        );
+        assert_eq!(
+            BibliographicLevel::try_from(b'x'),
+            Err(BuildRecordError::InvalidLeaderValue(7, b'x'))
+        );
+        assert_eq!(u8::from(CharacterCoding::Marc8), b' ');
+        assert_eq!(HoldingsLevel::POSITION, 17);
+        assert_eq!(
+            RecordType::SerialItemHoldings.format(),
+            RecordFormat::Holdings
+        );
+    }
+
+    #[test]
+    fn test_validate() {
+        use RecordFormat::*;
+
+        assert!(validate(Bibliographic, 5, b'p').is_ok());
+        assert!(validate(Authority, 5, b'p').is_err());
+        assert!(validate(Authority, 5, b'x').is_ok());
+        assert!(validate(Authority, 7, b' ').is_ok());
+        assert!(validate(Authority, 7, b'm').is_err());
+        assert!(validate(Authority, 17, b'n').is_ok());
+        assert!(validate(Bibliographic, 17, b'n').is_err());
+        assert!(validate(Holdings, 17, b'm').is_ok());
+        assert!(validate(Holdings, 18, b'i').is_ok());
+        assert!(validate(Classification, 18, b'i').is_err());
+        assert!(validate(CommunityInformation, 7, b'z').is_ok());
+        assert!(validate(Bibliographic, 19, b'c').is_ok());
+    }
+}
@@ -0,0 +1,1056 @@
+//! The leader of a record and the codes of its positions.
+
+use std::fmt::{self, Display};
+use std::io::{self, Write};
+
+use bstr::ByteSlice;
+use winnow::combinator::seq;
+use winnow::{ModalResult, Parser};
+
+pub use self::builder::LeaderBuilder;
+pub use self::code::{
+    BibliographicLevel, CharacterCoding, Completeness,
+    DescriptiveCatalogingForm, EncodingLevel, HoldingsLevel,
+    ItemInformation, KindOfData, LeaderCode, MultipartLevel,
+    PunctuationPolicy, RecordFormat, RecordStatus, RecordType,
+    TypeOfControl,
+};
+use crate::common::*;
+use crate::error::{
+    BuildRecordError, ParseRecordError, ParseRecordErrorKind,
+};
+
+mod builder;
+mod code;
+
+/// The leader contains essential metadata about the record.
+///
+/// The meaning of the positions 07, 08 and 17-19 depends on the
+/// record format; they are accessible through the named accessors of
+/// the corresponding format (e.g. [`Leader::bibliographic_level`]).
+/// The codes of each position are modeled by the enums of the
+/// [`leader`](crate::leader) module and can be read with
+/// [`Leader::code`].
+///
+/// The indicator count (position 10), the subfield code length
+/// (position 11) and the entry map (positions 20-23) describe the
+/// structure of the record according to ISO 2709. They default to the
+/// values of MARC 21 (`22` and `4500`), but records of other ISO 2709
+/// formats (e.g. UNIMARC or MAB2) may use other values. Records with
+/// up to two indicators and a subfield code length of two (i.e. a
+/// single-byte subfield code) are supported. A leader with other
+/// values is rejected: a record with such a leader can't be parsed
+/// ([`ParseRecordErrorKind::InvalidLeader`]), built
+/// ([`BuildRecordError::InvalidLeaderValue`](crate::BuildRecordError))
+/// or written (an error of kind
+/// [`InvalidInput`](std::io::ErrorKind::InvalidInput)).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Leader {
+    pub(crate) length: u32,
+    pub(crate) status: u8,
+    pub(crate) r#type: u8,
+    pub(crate) pos07: u8,
+    pub(crate) pos08: u8,
+    pub(crate) encoding: u8,
+    pub(crate) base_addr: u32,
+    pub(crate) pos17: u8,
+    pub(crate) pos18: u8,
+    pub(crate) pos19: u8,
+    pub(crate) indicator_count: u8,
+    pub(crate) subfield_code_length: u8,
+    pub(crate) length_of_field_length: u8,
+    pub(crate) length_of_starting_position: u8,
+    pub(crate) length_of_implementation_defined: u8,
+    pub(crate) pos23: u8,
+}
+
+impl Leader {
+    /// Create a new leader from a byte slice.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Leader;
+    ///
+    /// let leader = Leader::new(b"00000nz  a2200000oc 4500")?;
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn new<'a, B: AsRef<[u8]> + ?Sized>(
+        bytes: &'a B,
+    ) -> Result<Self, ParseRecordError<'a>> {
+        parse_leader.parse(bytes.as_ref()).map_err(|e| {
+            ParseRecordError::from_parse(e)
+                .with_kind(ParseRecordErrorKind::InvalidLeader)
+        })
+    }
+
+    /// Returns a builder to create a leader, whose values are valid
+    /// for the record format (see [`LeaderBuilder`]).
+    #[inline]
+    pub fn builder() -> LeaderBuilder {
+        LeaderBuilder::default()
+    }
+
+    /// Returns the length of the entire record including the leader and
+    /// the record terminator.
+    ///
+    /// The length is limited to five digits. The record length of an
+    /// oversize record, which exceeds 99999 bytes, isn't reliable
+    /// (e.g. `99999`); such records are parsed by scanning for the
+    /// record terminator.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Leader;
+    ///
+    /// let leader = Leader::new(b"00000nz  a2200000oc 4500")?;
+    /// assert_eq!(leader.length(), 0);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline(always)]
+    pub fn length(&self) -> u32 {
+        self.length
+    }
+
+    /// Returns the record status (new, updated, etc.)
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Leader;
+    ///
+    /// let leader = Leader::new(b"00000nz  a2200000oc 4500")?;
+    /// assert_eq!(leader.status(), b'n');
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline(always)]
+    pub fn status(&self) -> u8 {
+        self.status
+    }
+
+    /// Returns the type of record
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Leader;
+    ///
+    /// let leader = Leader::new(b"00000nz  a2200000oc 4500")?;
+    /// assert_eq!(leader.r#type(), b'z');
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline(always)]
+    pub fn r#type(&self) -> u8 {
+        self.r#type
+    }
+
+    /// Returns true if and only if the underlying record is a
+    /// bibliographic record.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Leader;
+    ///
+    /// let leader = Leader::new(b"00000nz  a2200000oc 4500")?;
+    /// assert!(!leader.is_bibliographic());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline(always)]
+    pub fn is_bibliographic(&self) -> bool {
+        self.format() == Some(RecordFormat::Bibliographic)
+    }
+
+    /// Returns true if and only if the underlying record is a community
+    /// information record.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Leader;
+    ///
+    /// let leader = Leader::new(b"00000nz  a2200000oc 4500")?;
+    /// assert!(!leader.is_community_information());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline(always)]
+    pub fn is_community_information(&self) -> bool {
+        self.r#type == b'q'
+    }
+
+    /// Returns true if and only if the underlying record is an
+    /// authority record.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Leader;
+    ///
+    /// let leader = Leader::new(b"00000nz  a2200000oc 4500")?;
+    /// assert!(leader.is_authority());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline(always)]
+    pub fn is_authority(&self) -> bool {
+        self.r#type == b'z'
+    }
+
+    /// Returns true if and only if the underlying record is a holdings
+    /// record.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Leader;
+    ///
+    /// let leader = Leader::new(b"00000ny  a22000001n 4500")?;
+    /// assert!(leader.is_holdings());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline(always)]
+    pub fn is_holdings(&self) -> bool {
+        matches!(self.r#type, b'u' | b'v' | b'x' | b'y')
+    }
+
+    /// Returns true if and only if the underlying record is a
+    /// classification record.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Leader;
+    ///
+    /// let leader = Leader::new(b"00000nw  a2200000n  4500")?;
+    /// assert!(leader.is_classification());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline(always)]
+    pub fn is_classification(&self) -> bool {
+        self.r#type == b'w'
+    }
+
+    /// Returns the record format, which is determined by the type of
+    /// record, or `None` if the type of record is unknown.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Leader;
+    /// use marc21::leader::RecordFormat;
+    ///
+    /// let leader = Leader::new(b"00000nz  a2200000oc 4500")?;
+    /// assert_eq!(leader.format(), Some(RecordFormat::Authority));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn format(&self) -> Option<RecordFormat> {
+        RecordFormat::from_type(self.r#type)
+    }
+
+    /// Returns the bibliographic level if the underlying record is a
+    /// bibliographic record, otherwise `None`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Leader;
+    ///
+    /// let leader = Leader::new(b"00000nz  a2200000oc 4500")?;
+    /// assert!(leader.bibliographic_level().is_none());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn bibliographic_level(&self) -> Option<u8> {
+        if self.is_bibliographic() && self.pos07.is_ascii_graphic() {
+            Some(self.pos07)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the kind of data if the underlying record is a community
+    /// information record.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Leader;
+    ///
+    /// let leader = Leader::new(b"00000nz  a2200000oc 4500")?;
+    /// assert!(leader.kind_of_data().is_none());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn kind_of_data(&self) -> Option<u8> {
+        if self.is_community_information() && self.pos07 != b' ' {
+            Some(self.pos07)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the type of control if the underlying record is a
+    /// bibliographic record, otherwise `None`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Leader;
+    ///
+    /// let leader = Leader::new(b"00000nz  a2200000oc 4500")?;
+    /// assert!(leader.type_of_control().is_none());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn type_of_control(&self) -> Option<u8> {
+        if self.is_bibliographic() && self.pos08 != b' ' {
+            Some(self.pos08)
+        } else {
+            None
+        }
+    }
+
+    /// Returns a code, that identifies the character encoding scheme
+    /// used in this record.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Leader;
+    ///
+    /// let leader = Leader::new(b"00000nz  a2200000oc 4500")?;
+    /// assert_eq!(leader.encoding(), b'a');
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline(always)]
+    pub fn encoding(&self) -> u8 {
+        self.encoding
+    }
+
+    /// Returns the base address, that specifies the first character
+    /// position of the first variable field in the record. The position
+    /// is equal to the sum of the lengths of the leader and the
+    /// directory, including the field terminator.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Leader;
+    ///
+    /// let leader = Leader::new(b"00000nz  a2200000oc 4500")?;
+    /// assert_eq!(leader.base_addr(), 0);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline(always)]
+    pub fn base_addr(&self) -> u32 {
+        self.base_addr
+    }
+
+    /// Returns the number of indicators of a data field (position 10).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Leader;
+    ///
+    /// let leader = Leader::new(b"00000nz  a2200000oc 4500")?;
+    /// assert_eq!(leader.indicator_count(), 2);
+    ///
+    /// let leader = Leader::new(b"00000nz  a1200000oc 4500")?;
+    /// assert_eq!(leader.indicator_count(), 1);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline(always)]
+    pub fn indicator_count(&self) -> u8 {
+        self.indicator_count
+    }
+
+    /// Returns the length of a subfield code including the delimiter
+    /// (position 11).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Leader;
+    ///
+    /// let leader = Leader::new(b"00000nz  a2200000oc 4500")?;
+    /// assert_eq!(leader.subfield_code_length(), 2);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline(always)]
+    pub fn subfield_code_length(&self) -> u8 {
+        self.subfield_code_length
+    }
+
+    /// Returns the encoding level (position 17). The codes depend on
+    /// the record format (see [`EncodingLevel`], [`Completeness`] and
+    /// [`HoldingsLevel`]).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Leader;
+    ///
+    /// let leader = Leader::new(b"00000nz  a2200000oc 4500")?;
+    /// assert_eq!(leader.encoding_level(), b'o');
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline(always)]
+    pub fn encoding_level(&self) -> u8 {
+        self.pos17
+    }
+
+    /// Returns the descriptive cataloging form (position 18) if the
+    /// underlying record is a bibliographic record, otherwise `None`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Leader;
+    ///
+    /// let leader = Leader::new(b"00000nam a2200000 a 4500")?;
+    /// assert_eq!(leader.descriptive_cataloging_form(), Some(b'a'));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn descriptive_cataloging_form(&self) -> Option<u8> {
+        self.is_bibliographic().then_some(self.pos18)
+    }
+
+    /// Returns the punctuation policy (position 18) if the underlying
+    /// record is an authority record, otherwise `None`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Leader;
+    ///
+    /// let leader = Leader::new(b"00000nz  a2200000oc 4500")?;
+    /// assert_eq!(leader.punctuation_policy(), Some(b'c'));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn punctuation_policy(&self) -> Option<u8> {
+        self.is_authority().then_some(self.pos18)
+    }
+
+    /// Returns the item information in record (position 18) if the
+    /// underlying record is a holdings record, otherwise `None`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Leader;
+    ///
+    /// let leader = Leader::new(b"00000ny  a22000001n 4500")?;
+    /// assert_eq!(leader.item_information(), Some(b'n'));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn item_information(&self) -> Option<u8> {
+        self.is_holdings().then_some(self.pos18)
+    }
+
+    /// Returns the multipart resource record level (position 19) if
+    /// the underlying record is a bibliographic record, otherwise
+    /// `None`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Leader;
+    ///
+    /// let leader = Leader::new(b"00000nam a2200000 aa4500")?;
+    /// assert_eq!(leader.multipart_level(), Some(b'a'));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn multipart_level(&self) -> Option<u8> {
+        self.is_bibliographic().then_some(self.pos19)
+    }
+
+    /// Returns the code of a leader position as a typed enum (see
+    /// [`LeaderCode`]), or `None` if the position isn't defined by the
+    /// code for the record format or contains an invalid value.
+    ///
+    /// The raw byte of a position is returned by the corresponding
+    /// accessor (e.g. [`Leader::bibliographic_level`]).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Leader;
+    /// use marc21::leader::*;
+    ///
+    /// let leader = Leader::new(b"00000nam a2200000 a 4500")?;
+    /// assert_eq!(leader.code(), Some(RecordStatus::New));
+    /// assert_eq!(leader.code(), Some(BibliographicLevel::Monograph));
+    /// assert_eq!(leader.code(), Some(EncodingLevel::Full));
+    /// assert_eq!(leader.code::<Completeness>(), None);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn code<T: LeaderCode>(&self) -> Option<T> {
+        if !T::FORMATS.is_empty()
+            && !self.format().is_some_and(|f| T::FORMATS.contains(&f))
+        {
+            return None;
+        }
+
+        let value = match T::POSITION {
+            5 => self.status,
+            6 => self.r#type,
+            7 => self.pos07,
+            8 => self.pos08,
+            9 => self.encoding,
+            17 => self.pos17,
+            18 => self.pos18,
+            19 => self.pos19,
+            _ => return None,
+        };
+
+        T::try_from(value).ok()
+    }
+
+    /// Returns the length of the length-of-field portion of a
+    /// directory entry (position 20).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Leader;
+    ///
+    /// let leader = Leader::new(b"00000nz  a2200000oc 4500")?;
+    /// assert_eq!(leader.length_of_field_length(), 4);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline(always)]
+    pub fn length_of_field_length(&self) -> u8 {
+        self.length_of_field_length
+    }
+
+    /// Returns the length of the starting-character-position portion
+    /// of a directory entry (position 21).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Leader;
+    ///
+    /// let leader = Leader::new(b"00000nz  a2200000oc 4500")?;
+    /// assert_eq!(leader.length_of_starting_position(), 5);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline(always)]
+    pub fn length_of_starting_position(&self) -> u8 {
+        self.length_of_starting_position
+    }
+
+    /// Returns the length of the implementation-defined portion of a
+    /// directory entry (position 22).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Leader;
+    ///
+    /// let leader = Leader::new(b"00000nz  a2200000oc 4500")?;
+    /// assert_eq!(leader.length_of_implementation_defined(), 0);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline(always)]
+    pub fn length_of_implementation_defined(&self) -> u8 {
+        self.length_of_implementation_defined
+    }
+
+    /// Returns the length of a directory entry, which is determined by
+    /// the entry map (positions 20-22).
+    #[inline(always)]
+    pub(crate) fn entry_length(&self) -> usize {
+        3 + self.length_of_field_length as usize
+            + self.length_of_starting_position as usize
+            + self.length_of_implementation_defined as usize
+    }
+
+    /// Returns true if the structure of the record, which is described
+    /// by the indicator count and the subfield code length, can be
+    /// represented by a [`DataField`](crate::DataField).
+    #[inline(always)]
+    pub(crate) fn is_supported(&self) -> bool {
+        self.indicator_count <= 2 && self.subfield_code_length == 2
+    }
+
+    /// Returns an error, if the structure of the record isn't supported
+    /// (see [`is_supported`](Self::is_supported)).
+    pub(crate) fn check_supported(
+        &self,
+    ) -> Result<(), BuildRecordError> {
+        if self.indicator_count > 2 {
+            return Err(BuildRecordError::InvalidLeaderValue(
+                10,
+                b'0' + self.indicator_count,
+            ));
+        }
+
+        if self.subfield_code_length != 2 {
+            return Err(BuildRecordError::InvalidLeaderValue(
+                11,
+                b'0' + self.subfield_code_length,
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Write the leader into the given writer
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::io::{Cursor, Write};
+    ///
+    /// use marc21::prelude::*;
+    ///
+    /// let ldr = Leader::new(b"00000nz  a2200000oc 4500")?;
+    ///
+    /// let mut wrt = Cursor::new(Vec::<u8>::new());
+    /// ldr.write_to(&mut wrt)?;
+    ///
+    /// assert_eq!(wrt.into_inner(), b"00000nz  a2200000oc 4500");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn write_to<W: Write>(&self, out: &mut W) -> io::Result<()> {
+        write!(
+            out,
+            "{:0>5}{}{}{}{}{}{}{}{:0>5}{}{}{}{}{}{}{}",
+            self.length,
+            self.status as char,
+            self.r#type as char,
+            self.pos07 as char,
+            self.pos08 as char,
+            self.encoding as char,
+            self.indicator_count,
+            self.subfield_code_length,
+            self.base_addr,
+            self.pos17 as char,
+            self.pos18 as char,
+            self.pos19 as char,
+            self.length_of_field_length,
+            self.length_of_starting_position,
+            self.length_of_implementation_defined,
+            self.pos23 as char,
+        )
+    }
+}
+
+impl Display for Leader {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut out = Vec::<u8>::with_capacity(24);
+        self.write_to(&mut out).unwrap();
+
+        // SAFETY: It is guaranteed that the leader consists only of
+        // valid ascii characters and can be converted to a string slice
+        // without validation.
+        write!(f, "LDR {}", unsafe { out.to_str_unchecked() })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Leader {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut out = Vec::<u8>::with_capacity(24);
+        self.write_to(&mut out).map_err(serde::ser::Error::custom)?;
+        serializer.serialize_str(&out.to_str_lossy())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Leader {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s: String = serde::Deserialize::deserialize(deserializer)?;
+        Self::new(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg_attr(feature = "perf-inline", inline(always))]
+pub(crate) fn parse_leader(i: &mut &[u8]) -> ModalResult<Leader> {
+    seq! {Leader {
+        length: parse_digits_u32,
+        status: parse_ascii_graphic,
+        r#type: parse_ascii_graphic,
+        pos07: parse_space_or_ascii_graphic,
+        pos08: parse_space_or_ascii_graphic,
+        encoding: parse_space_or_ascii_graphic,
+        indicator_count: parse_digit,
+        subfield_code_length: parse_digit,
+        base_addr: parse_digits_u32,
+        pos17: parse_space_or_ascii_graphic,
+        pos18: parse_space_or_ascii_graphic,
+        pos19: parse_space_or_ascii_graphic,
+        length_of_field_length: parse_digit.verify(|n| *n > 0),
+        length_of_starting_position: parse_digit.verify(|n| *n > 0),
+        length_of_implementation_defined: parse_digit,
+        pos23: parse_space_or_ascii_graphic,
+    }}
+    .parse_next(i)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_leader() {
+        assert_eq!(
+            parse_leader.parse(b"03612nz  a2200589nc 4500").unwrap(),
+            Leader {
+                length: 3612,
+                status: b'n',
+                r#type: b'z',
+                pos07: b' ',
+                pos08: b' ',
+                encoding: b'a',
+                base_addr: 589,
+                pos17: b'n',
+                pos18: b'c',
+                pos19: b' ',
+                indicator_count: 2,
+                subfield_code_length: 2,
+                length_of_field_length: 4,
+                length_of_starting_position: 5,
+                length_of_implementation_defined: 0,
+                pos23: b'0',
+            }
+        )
+    }
+
+    #[test]
+    fn test_leader_new() -> TestResult {
+        assert_eq!(
+            Leader::new(b"03612nz  a2200589nc 4500")?,
+            Leader {
+                length: 3612,
+                status: b'n',
+                r#type: b'z',
+                pos07: b' ',
+                pos08: b' ',
+                encoding: b'a',
+                base_addr: 589,
+                pos17: b'n',
+                pos18: b'c',
+                pos19: b' ',
+                indicator_count: 2,
+                subfield_code_length: 2,
+                length_of_field_length: 4,
+                length_of_starting_position: 5,
+                length_of_implementation_defined: 0,
+                pos23: b'0',
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_leader_code_roundtrip() -> TestResult {
+        let leader = Leader::builder()
+            .status(RecordStatus::Corrected)
+            .r#type(RecordType::LanguageMaterial)
+            .bibliographic_level(BibliographicLevel::Serial)
+            .type_of_control(TypeOfControl::Archival)
+            .encoding(CharacterCoding::Marc8)
+            .encoding_level(EncodingLevel::Minimal)
+            .descriptive_cataloging_form(
+                DescriptiveCatalogingForm::Aacr2,
+            )
+            .multipart_level(MultipartLevel::Set)
+            .build()?;
+
+        let mut data = Vec::new();
+        leader.write_to(&mut data)?;
+        let leader = Leader::new(&data).unwrap();
+
+        assert_eq!(leader.code(), Some(RecordStatus::Corrected));
+        assert_eq!(leader.code(), Some(RecordType::LanguageMaterial));
+        assert_eq!(leader.code(), Some(BibliographicLevel::Serial));
+        assert_eq!(leader.code(), Some(TypeOfControl::Archival));
+        assert_eq!(leader.code(), Some(CharacterCoding::Marc8));
+        assert_eq!(leader.code(), Some(EncodingLevel::Minimal));
+        assert_eq!(
+            leader.code(),
+            Some(DescriptiveCatalogingForm::Aacr2)
+        );
+        assert_eq!(leader.code(), Some(MultipartLevel::Set));
+        assert_eq!(leader.code::<KindOfData>(), None);
+        assert_eq!(leader.code::<Completeness>(), None);
+        assert_eq!(leader.code::<PunctuationPolicy>(), None);
+
+        let leader = Leader::builder()
+            .r#type(RecordType::AuthorityData)
+            .status(RecordStatus::DeletedReplaced)
+            .encoding_level(Completeness::Incomplete)
+            .punctuation_policy(PunctuationPolicy::Omitted)
+            .build()?;
+
+        let mut data = Vec::new();
+        leader.write_to(&mut data)?;
+        let leader = Leader::new(&data).unwrap();
+
+        assert_eq!(leader.code(), Some(RecordStatus::DeletedReplaced));
+        assert_eq!(leader.code(), Some(Completeness::Incomplete));
+        assert_eq!(leader.code(), Some(PunctuationPolicy::Omitted));
+        assert_eq!(leader.code::<EncodingLevel>(), None);
+        assert_eq!(leader.code::<BibliographicLevel>(), None);
+
+        let leader = Leader::new(b"00000nzx a2200000oc 4500")?;
+        assert_eq!(
+            leader.code::<RecordType>(),
+            Some(RecordType::AuthorityData)
+        );
+        assert_eq!(leader.code::<BibliographicLevel>(), None);
+
+        let leader = Leader::new(b"00000nam a2200000#a 4500")?;
+        assert_eq!(leader.code::<EncodingLevel>(), None);
+        assert_eq!(leader.encoding_level(), b'#');
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_leader_length() -> TestResult {
+        let ldr = Leader::new(b"03612nz  a2200589nc 4500")?;
+        assert_eq!(ldr.length(), 3612);
+        Ok(())
+    }
+
+    #[test]
+    fn test_leader_status() -> TestResult {
+        let ldr = Leader::new(b"03612nz  a2200589nc 4500")?;
+        assert_eq!(ldr.status(), b'n');
+        Ok(())
+    }
+
+    #[test]
+    fn test_leader_type() -> TestResult {
+        let ldr = Leader::new(b"03612nz  a2200589nc 4500")?;
+        assert_eq!(ldr.r#type(), b'z');
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_bibliographic() -> TestResult {
+        let ldr = Leader::new(b"00000nam a2200000 c 4500")?;
+        assert!(ldr.is_bibliographic());
+
+        let ldr = Leader::new(b"00000nbm a2200000 c 4500")?;
+        assert!(!ldr.is_bibliographic());
+
+        let ldr = Leader::new(b"00000ncm a2200000 c 4500")?;
+        assert!(ldr.is_bibliographic());
+
+        let ldr = Leader::new(b"00000ndm a2200000 c 4500")?;
+        assert!(ldr.is_bibliographic());
+
+        let ldr = Leader::new(b"00000nhm a2200000 c 4500")?;
+        assert!(!ldr.is_bibliographic());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_community_information() -> TestResult {
+        let ldr = Leader::new(b"00000nqo a2200000 c 4500")?;
+        assert!(ldr.is_community_information());
+
+        let ldr = Leader::new(b"00000namaa2200000 c 4500")?;
+        assert!(!ldr.is_community_information());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bibliographic_level() -> TestResult {
+        let ldr = Leader::new(b"00000nam a2200000 c 4500")?;
+        assert_eq!(ldr.bibliographic_level(), Some(b'm'));
+
+        let ldr = Leader::new(b"00000nqo a2200000 c 4500")?;
+        assert!(ldr.bibliographic_level().is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_kind_of_data() -> TestResult {
+        let ldr = Leader::new(b"00000nqo a2200000 c 4500")?;
+        assert_eq!(ldr.kind_of_data(), Some(b'o'));
+
+        let ldr = Leader::new(b"00000nq  a2200000 c 4500")?;
+        assert!(ldr.kind_of_data().is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_type_of_control() -> TestResult {
+        let ldr = Leader::new(b"00000namaa2200000 c 4500")?;
+        assert_eq!(ldr.type_of_control(), Some(b'a'));
+
+        let ldr = Leader::new(b"00000nam a2200000 c 4500")?;
+        assert!(ldr.type_of_control().is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_format() -> TestResult {
+        use RecordFormat::*;
+
+        for (ldr, format) in [
+            ("00000nam a2200000 c 4500", Some(Bibliographic)),
+            ("00000nz  a2200000nc 4500", Some(Authority)),
+            ("00000nv  a22000001i 4500", Some(Holdings)),
+            ("00000nw  a2200000n  4500", Some(Classification)),
+            ("00000nqn a2200000   4500", Some(CommunityInformation)),
+            ("00000nb  a2200000   4500", None),
+        ] {
+            assert_eq!(Leader::new(ldr)?.format(), format);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_format_specific_positions() -> TestResult {
+        let ldr = Leader::new(b"00000nam a2200000 i 4500")?;
+        assert_eq!(ldr.encoding_level(), b' ');
+        assert_eq!(ldr.descriptive_cataloging_form(), Some(b'i'));
+        assert_eq!(ldr.multipart_level(), Some(b' '));
+        assert!(ldr.punctuation_policy().is_none());
+        assert!(ldr.item_information().is_none());
+
+        let ldr = Leader::new(b"00000nz  a2200000nc 4500")?;
+        assert_eq!(ldr.encoding_level(), b'n');
+        assert_eq!(ldr.punctuation_policy(), Some(b'c'));
+        assert!(ldr.descriptive_cataloging_form().is_none());
+        assert!(ldr.multipart_level().is_none());
+
+        let ldr = Leader::new(b"00000nx  a22000003i 4500")?;
+        assert_eq!(ldr.encoding_level(), b'3');
+        assert_eq!(ldr.item_information(), Some(b'i'));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_encoding() -> TestResult {
+        let ldr = Leader::new(b"03612nz  a2200589nc 4500")?;
+        assert_eq!(ldr.encoding(), b'a');
+        Ok(())
+    }
+
+    #[test]
+    fn test_base_address() -> TestResult {
+        let ldr = Leader::new(b"03612nz  a2200589nc 4500")?;
+        assert_eq!(ldr.base_addr(), 589u32);
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_to() -> TestResult {
+        let ldr = Leader::new(b"03612nz  a2200000 c 4500")?;
+        let mut wrt = Cursor::new(Vec::<u8>::new());
+        ldr.write_to(&mut wrt)?;
+
+        assert_eq!(wrt.into_inner(), b"03612nz  a2200000 c 4500");
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_string() -> TestResult {
+        let ldr = Leader::new("01234nz  a2200000 c 4500")?;
+        assert_eq!(ldr.to_string(), "LDR 01234nz  a2200000 c 4500");
+
+        let ldr = Leader::new(b"01234nz   2200000 c 4500")?;
+        assert_eq!(ldr.to_string(), "LDR 01234nz   2200000 c 4500");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_leader_iso2709() -> TestResult {
+        let ldr = Leader::new(b"01234nam0 2200000   450 ")?;
+        assert_eq!(ldr.indicator_count(), 2);
+        assert_eq!(ldr.length_of_field_length(), 4);
+        assert_eq!(ldr.to_string(), "LDR 01234nam0 2200000   450 ");
+
+        let ldr = Leader::new(b"01234nM2.01200000   3410")?;
+        assert_eq!(ldr.indicator_count(), 1);
+        assert_eq!(ldr.subfield_code_length(), 2);
+        assert_eq!(ldr.length_of_field_length(), 3);
+        assert_eq!(ldr.length_of_starting_position(), 4);
+        assert_eq!(ldr.length_of_implementation_defined(), 1);
+        assert_eq!(ldr.entry_length(), 11);
+        assert_eq!(ldr.to_string(), "LDR 01234nM2.01200000   3410");
+
+        assert!(Leader::new(b"01234nz  a2200000 c 0500").is_err());
+        assert!(Leader::new(b"01234nz  a2200000 c 4000").is_err());
+        assert!(Leader::new(b"01234nz  a#200000 c 4500").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_leader_serde() -> TestResult {
+        use serde_test::{
+            Token, assert_de_tokens_error, assert_tokens,
+        };
+
+        assert_tokens(
+            &Leader::new(b"03612nz  a2200589nc 4500")?,
+            &[Token::Str("03612nz  a2200589nc 4500")],
+        );
+
+        assert_de_tokens_error::<Leader>(
+            &[Token::Str("03612nz  a2200589nc")],
+            "invalid leader at position 19",
+        );
+
+        Ok(())
+    }
+}
@@ -1,9 +1,11 @@
+mod buf;
 mod common;
 mod directory;
 mod error;
 mod field;
+pub mod fixed;
 pub mod io;
-mod leader;
+pub mod leader;
 pub mod matcher;
 mod path;
 mod query;
@@ -12,22 +14,36 @@ mod subfield;
 mod tag;
 mod value;
 
+pub use buf::{
+    ControlFieldBuf, DataFieldBuf, FieldBuf, RecordBuf, RecordBuilder,
+    SubfieldBuf,
+};
 pub use directory::{Directory, Entry};
-pub use error::Error;
+pub use error::{
+    BuildRecordError, Error, ParseRecordError, ParseRecordErrorKind,
+};
 pub use field::{ControlField, DataField, Field};
-pub use leader::Leader;
+pub use leader::{Leader, LeaderBuilder};
 pub use path::{ParsePathError, Path};
 pub use query::{DataType, ParseQueryError, Query};
-pub use record::{ByteRecord, StringRecord};
+pub use record::{ByteRecord, OversizePolicy, StringRecord};
 pub use subfield::Subfield;
-pub use tag::Tag;
+pub use tag::{ControlFieldRule, Tag};
 pub use value::Value;
 
 /// Core types available for glob import.
 pub mod prelude {
-    pub use super::io::{ByteRecordsIter, MarcReadOptions, MarcReader};
+    #[cfg(feature = "json")]
+    pub use super::io::MarcJsonReader;
+    #[cfg(feature = "xml")]
+    pub use super::io::MarcXmlReader;
+    #[cfg(feature = "mmap")]
+    pub use super::io::MmapReader;
+    pub use super::io::{
+        ByteRecordsIter, ChunkReader, MarcReadOptions, MarcReader,
+    };
     pub use super::{
-        ByteRecord, Directory, Entry, Leader, Path, Query,
+        ByteRecord, Directory, Entry, Leader, Path, Query, RecordBuf,
         StringRecord, Subfield, Tag,
     };
 }
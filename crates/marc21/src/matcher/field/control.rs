@@ -1,4 +1,6 @@
-use crate::matcher::shared::{ComparisonOperator, Value};
+use crate::matcher::shared::{
+    ComparisonOperator, Folding, Value, ValueList,
+};
 use crate::matcher::{MatchOptions, TagMatcher};
 use crate::{ControlField, Field};
 
@@ -26,6 +28,7 @@ pub struct ComparisonMatcher {
     pub(crate) tag_matcher: TagMatcher,
     pub(crate) range: Option<(Option<usize>, Option<usize>)>,
     pub(crate) operator: ComparisonOperator,
+    pub(crate) folding: Folding,
     pub(crate) value: Value,
 }
 
@@ -48,8 +51,9 @@ impl ComparisonMatcher {
     pub fn is_match<'a, F: Iterator<Item = &'a Field<'a>>>(
         &self,
         fields: F,
-        _options: &MatchOptions,
+        options: &MatchOptions,
     ) -> bool {
+        let folding = self.folding | options.folding;
         let mut iter = fields;
 
         // The control fields always precede the data fields. This means
@@ -76,16 +80,11 @@ impl ComparisonMatcher {
                     value
                 };
 
-                let result = match self.operator {
-                    ComparisonOperator::Eq => lhs == self.value,
-                    ComparisonOperator::Ne => lhs != self.value,
-                    ComparisonOperator::Ge => lhs >= self.value,
-                    ComparisonOperator::Gt => lhs > self.value,
-                    ComparisonOperator::Le => lhs <= self.value,
-                    ComparisonOperator::Lt => lhs < self.value,
-                };
-
-                if result {
+                if self.operator.compare_folded(
+                    lhs,
+                    &self.value,
+                    folding,
+                ) {
                     return true;
                 }
             }
@@ -99,7 +98,8 @@ impl ComparisonMatcher {
 pub struct InMatcher {
     pub(crate) tag_matcher: TagMatcher,
     pub(crate) range: Option<(Option<usize>, Option<usize>)>,
-    pub(crate) values: Vec<Vec<u8>>,
+    pub(crate) values: ValueList,
+    pub(crate) folding: Folding,
     pub(crate) negated: bool,
 }
 
@@ -134,8 +134,9 @@ impl InMatcher {
     pub fn is_match<'a, F: Iterator<Item = &'a Field<'a>>>(
         &self,
         fields: F,
-        _options: &MatchOptions,
+        options: &MatchOptions,
     ) -> bool {
+        let folding = self.folding | options.folding;
         let mut iter = fields;
 
         // The control fields always precede the data fields. This means
@@ -162,11 +163,9 @@ impl InMatcher {
                     value
                 };
 
-                let result = if self.negated {
-                    !self.values.iter().any(|rhs| lhs == rhs)
-                } else {
-                    self.values.iter().any(|rhs| lhs == rhs)
-                };
+                let result = self.values.contains(lhs, folding);
+                let result =
+                    if self.negated { !result } else { result };
 
                 if result {
                     return true;
@@ -6,6 +6,7 @@ use crate::matcher::field::count::CountMatcher;
 use crate::matcher::field::data::DataFieldMatcher;
 use crate::matcher::field::exists::ExistsMatcher;
 use crate::matcher::field::parse::parse_field_matcher;
+use crate::matcher::shared::clear_value_files;
 use crate::matcher::{MatchOptions, ParseMatcherError};
 
 pub(crate) mod control;
@@ -37,9 +38,12 @@ impl FieldMatcher {
     pub fn new<B: AsRef<[u8]>>(
         matcher: B,
     ) -> Result<Self, ParseMatcherError> {
-        parse_field_matcher
+        let result = parse_field_matcher
             .parse(matcher.as_ref())
-            .map_err(ParseMatcherError::from_parse)
+            .map_err(ParseMatcherError::from_parse);
+
+        clear_value_files();
+        result
     }
 
     /// Returns true if and only if the given field(s) match against the
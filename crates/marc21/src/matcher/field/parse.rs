@@ -1,17 +1,18 @@
-use winnow::ascii::multispace0;
+use winnow::ascii::{multispace0, multispace1};
 use winnow::combinator::{
     alt, delimited, opt, preceded, separated, seq, terminated,
 };
 use winnow::prelude::*;
 
+use crate::matcher::TagMatcher;
 use crate::matcher::field::control::{self, ControlFieldMatcher};
 use crate::matcher::field::count::parse_count_matcher;
 use crate::matcher::field::data::DataFieldMatcher;
 use crate::matcher::field::{ExistsMatcher, FieldMatcher};
 use crate::matcher::indicator::parse::parse_indicator_matcher_opt;
 use crate::matcher::shared::{
-    parse_byte_string, parse_comparison_operator, parse_quantifier_opt,
-    parse_range, parse_string_value, ws0, ws1,
+    ValueList, parse_byte_string, parse_comparison, parse_folding,
+    parse_quantifier_opt, parse_range, parse_value_file, ws0,
 };
 use crate::matcher::subfield::parse::{
     parse_subfield_matcher_long, parse_subfield_matcher_short,
@@ -58,13 +59,28 @@ fn parse_control_field_matcher(
 fn parse_control_field_comparison_matcher(
     i: &mut &[u8],
 ) -> ModalResult<control::ComparisonMatcher> {
-    seq! { control::ComparisonMatcher{
-        tag_matcher: parse_tag_matcher,
-        range: opt(parse_range),
-        operator: ws1(parse_comparison_operator),
-        value: parse_string_value,
-    }}
-    .parse_next(i)
+    parse_comparison(parse_control_field_lhs)
+        .map(|((tag_matcher, range), operator, folding, value)| {
+            control::ComparisonMatcher {
+                tag_matcher,
+                range,
+                operator,
+                folding,
+                value,
+            }
+        })
+        .parse_next(i)
+}
+
+/// The tag matcher and the optional range of a control field
+/// comparison.
+type ControlFieldLhs =
+    (TagMatcher, Option<(Option<usize>, Option<usize>)>);
+
+fn parse_control_field_lhs(
+    i: &mut &[u8],
+) -> ModalResult<ControlFieldLhs> {
+    (parse_tag_matcher, opt(parse_range)).parse_next(i)
 }
 
 fn parse_control_field_in_matcher(
@@ -73,11 +89,13 @@ fn parse_control_field_in_matcher(
     seq! { control::InMatcher{
         tag_matcher: parse_tag_matcher,
         range: opt(parse_range),
-        negated: alt((
-            ws1("not in").value(true),
-            ws1("in").value(false),
-            )),
-        values:
+        negated: preceded(multispace1, alt((
+            "not in".value(true),
+            "in".value(false),
+            ))),
+        folding: terminated(parse_folding, multispace1),
+        values: alt((
+            parse_value_file.map(ValueList::File),
             delimited(
                 ws0('['),
                 terminated(
@@ -85,7 +103,8 @@ fn parse_control_field_in_matcher(
                     opt(ws0(',')),
                 ),
                 ws0(']'),
-            ),
+            ).map(ValueList::inline),
+        )),
     }}
     .parse_next(i)
 }
@@ -133,8 +152,8 @@ fn parse_data_field_matcher_long(
 mod tests {
 
     use super::*;
-    use crate::matcher::TagMatcher;
     use crate::matcher::field::control::InMatcher;
+    use crate::matcher::shared::Folding;
 
     #[test]
     fn test_parse_control_field_in_matcher() {
@@ -154,7 +173,11 @@ mod tests {
             InMatcher {
                 tag_matcher: TagMatcher::new("001").unwrap(),
                 range: None,
-                values: vec![b"A".to_vec(), b"B".to_vec()],
+                values: ValueList::inline(vec![
+                    b"A".to_vec(),
+                    b"B".to_vec()
+                ]),
+                folding: Folding::default(),
                 negated: false,
             }
         );
@@ -164,7 +187,11 @@ mod tests {
             InMatcher {
                 tag_matcher: TagMatcher::new("001").unwrap(),
                 range: None,
-                values: vec![b"A".to_vec(), b"B".to_vec()],
+                values: ValueList::inline(vec![
+                    b"A".to_vec(),
+                    b"B".to_vec()
+                ]),
+                folding: Folding::default(),
                 negated: true,
             }
         );
@@ -174,7 +201,8 @@ mod tests {
             InMatcher {
                 tag_matcher: TagMatcher::new("001").unwrap(),
                 range: None,
-                values: vec![b"A".to_vec()],
+                values: ValueList::inline(vec![b"A".to_vec()]),
+                folding: Folding::default(),
                 negated: false,
             }
         );
@@ -184,7 +212,11 @@ mod tests {
             InMatcher {
                 tag_matcher: TagMatcher::new("001").unwrap(),
                 range: None,
-                values: vec![b"A".to_vec(), b"B".to_vec()],
+                values: ValueList::inline(vec![
+                    b"A".to_vec(),
+                    b"B".to_vec()
+                ]),
+                folding: Folding::default(),
                 negated: false,
             }
         );
@@ -194,7 +226,11 @@ mod tests {
             InMatcher {
                 tag_matcher: TagMatcher::new("001").unwrap(),
                 range: Some((Some(0usize), Some(5usize))),
-                values: vec![b"A".to_vec(), b"B".to_vec()],
+                values: ValueList::inline(vec![
+                    b"A".to_vec(),
+                    b"B".to_vec()
+                ]),
+                folding: Folding::default(),
                 negated: false,
             }
         );
@@ -204,7 +240,11 @@ mod tests {
             InMatcher {
                 tag_matcher: TagMatcher::new("001").unwrap(),
                 range: Some((None, Some(5usize))),
-                values: vec![b"A".to_vec(), b"B".to_vec()],
+                values: ValueList::inline(vec![
+                    b"A".to_vec(),
+                    b"B".to_vec()
+                ]),
+                folding: Folding::default(),
                 negated: false,
             }
         );
@@ -214,7 +254,11 @@ mod tests {
             InMatcher {
                 tag_matcher: TagMatcher::new("001").unwrap(),
                 range: Some((Some(5usize), None)),
-                values: vec![b"A".to_vec(), b"B".to_vec()],
+                values: ValueList::inline(vec![
+                    b"A".to_vec(),
+                    b"B".to_vec()
+                ]),
+                folding: Folding::default(),
                 negated: false,
             }
         );
@@ -0,0 +1,117 @@
+use winnow::prelude::*;
+
+use crate::ByteRecord;
+use crate::matcher::fixed::parse::parse_fixed_field_matcher;
+use crate::matcher::shared::{ComparisonOperator, Folding, Value};
+use crate::matcher::{MatchOptions, ParseMatcherError};
+
+pub(crate) mod parse;
+
+#[derive(Debug, PartialEq, Clone)]
+pub(crate) enum Condition {
+    Comparison(ComparisonOperator, Folding, Value),
+    In {
+        values: Vec<Vec<u8>>,
+        negated: bool,
+        folding: Folding,
+    },
+}
+
+/// A matcher that checks a named data element of the fixed-length
+/// control fields 006, 007 and 008.
+///
+/// The element is decoded with respect to the record format and the
+/// type of material (see [`FixedField`](crate::fixed::FixedField)),
+/// which is why the matcher must be applied on the whole record. A
+/// field, whose definition doesn't contain the element (e.g. the
+/// language of an authority record), never matches.
+///
+/// ```rust
+/// use marc21::matcher::{FixedFieldMatcher, MatchOptions};
+/// use marc21::prelude::*;
+///
+/// # let data = include_bytes!("../../../tests/data/ada.mrc");
+/// let record = ByteRecord::from_bytes(data)?;
+/// let options = MatchOptions::default();
+///
+/// let matcher = FixedFieldMatcher::new("008.kind_of_record == 'a'")?;
+/// assert!(matcher.is_match(&record, &options));
+///
+/// let matcher =
+///     FixedFieldMatcher::new("008.date_entered < '960000'")?;
+/// assert!(matcher.is_match(&record, &options));
+///
+/// let matcher =
+///     FixedFieldMatcher::new("008.cataloging_source in ['c', 'd']")?;
+/// assert!(matcher.is_match(&record, &options));
+///
+/// let matcher = FixedFieldMatcher::new("008.lang == 'ger'")?;
+/// assert!(!matcher.is_match(&record, &options));
+///
+/// let matcher =
+///     FixedFieldMatcher::new("int(008.date_entered) > 950000")?;
+/// assert!(matcher.is_match(&record, &options));
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+pub struct FixedFieldMatcher {
+    pub(crate) tag: Vec<u8>,
+    pub(crate) element: String,
+    pub(crate) condition: Condition,
+}
+
+impl FixedFieldMatcher {
+    /// Creates a new fixed field matcher from a byte slice.
+    ///
+    /// ```rust
+    /// # use marc21::matcher::FixedFieldMatcher;
+    ///
+    /// let _matcher = FixedFieldMatcher::new("008.lang == 'ger'")?;
+    /// let _matcher = FixedFieldMatcher::new("008.date1 >= '2000'")?;
+    /// let _matcher = FixedFieldMatcher::new("007.color not in ['c']")?;
+    ///
+    /// assert!(FixedFieldMatcher::new("008.foo == 'ger'").is_err());
+    /// assert!(FixedFieldMatcher::new("001.lang == 'ger'").is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn new<B: AsRef<[u8]>>(
+        matcher: B,
+    ) -> Result<Self, ParseMatcherError> {
+        parse_fixed_field_matcher
+            .parse(matcher.as_ref())
+            .map_err(ParseMatcherError::from_parse)
+    }
+
+    /// Returns true, if the element of any field with the given tag
+    /// matches the condition.
+    pub fn is_match(
+        &self,
+        record: &ByteRecord,
+        options: &MatchOptions,
+    ) -> bool {
+        record
+            .fixed_fields()
+            .filter(|field| *field.tag() == self.tag)
+            .filter_map(|field| field.get(&self.element))
+            .any(|lhs| match &self.condition {
+                Condition::Comparison(op, folding, value) => op
+                    .compare_folded(
+                        lhs,
+                        value,
+                        *folding | options.folding,
+                    ),
+                Condition::In {
+                    values,
+                    negated,
+                    folding,
+                } => {
+                    let folding = *folding | options.folding;
+                    let lhs = folding.apply(lhs);
+                    values.iter().any(|rhs| lhs == folding.apply(rhs))
+                        != *negated
+                }
+            })
+    }
+}
@@ -0,0 +1,163 @@
+use winnow::combinator::{
+    alt, delimited, opt, separated, separated_pair, terminated,
+};
+use winnow::prelude::*;
+use winnow::token::take_while;
+
+use crate::fixed::is_element;
+use crate::matcher::FixedFieldMatcher;
+use crate::matcher::fixed::Condition;
+use crate::matcher::shared::{
+    parse_byte_string, parse_comparison, parse_folding, ws0, ws1,
+};
+
+/// Parses the tag of a fixed-length control field and the name of one
+/// of its elements (e.g. `008.lang`).
+pub(crate) fn parse_fixed_element(
+    i: &mut &[u8],
+) -> ModalResult<(Vec<u8>, String)> {
+    separated_pair(
+        alt(("006", "007", "008")),
+        '.',
+        take_while(1.., |b: u8| {
+            b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'_'
+        }),
+    )
+    .verify(|(tag, name): &(&[u8], &[u8])| is_element(tag, name))
+    .map(|(tag, name): (&[u8], &[u8])| {
+        (tag.to_vec(), String::from_utf8_lossy(name).to_string())
+    })
+    .parse_next(i)
+}
+
+fn parse_in_condition(i: &mut &[u8]) -> ModalResult<Condition> {
+    (
+        ws1((
+            alt(("not in".value(true), "in".value(false))),
+            parse_folding,
+        )),
+        delimited(
+            ws0('['),
+            terminated(
+                separated(1.., parse_byte_string, ws0(',')),
+                opt(ws0(',')),
+            ),
+            ws0(']'),
+        ),
+    )
+        .map(|((negated, folding), values)| Condition::In {
+            values,
+            negated,
+            folding,
+        })
+        .parse_next(i)
+}
+
+pub(crate) fn parse_fixed_field_matcher(
+    i: &mut &[u8],
+) -> ModalResult<FixedFieldMatcher> {
+    alt((
+        parse_comparison(parse_fixed_element).map(
+            |((tag, element), op, folding, value)| FixedFieldMatcher {
+                tag,
+                element,
+                condition: Condition::Comparison(op, folding, value),
+            },
+        ),
+        (parse_fixed_element, parse_in_condition).map(
+            |((tag, element), condition)| FixedFieldMatcher {
+                tag,
+                element,
+                condition,
+            },
+        ),
+    ))
+    .parse_next(i)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matcher::shared::{ComparisonOperator, Folding, Value};
+
+    #[test]
+    fn test_parse_fixed_field_matcher() {
+        macro_rules! parse_success {
+            ($i:expr, $o:expr) => {
+                assert_eq!(
+                    parse_fixed_field_matcher
+                        .parse($i.as_bytes())
+                        .unwrap(),
+                    $o
+                );
+            };
+        }
+
+        parse_success!(
+            "008.lang == 'ger'",
+            FixedFieldMatcher {
+                tag: b"008".to_vec(),
+                element: "lang".into(),
+                condition: Condition::Comparison(
+                    ComparisonOperator::Eq,
+                    Folding::default(),
+                    "ger".into()
+                ),
+            }
+        );
+
+        parse_success!(
+            "006.form_of_item not in ['o', 'q']",
+            FixedFieldMatcher {
+                tag: b"006".to_vec(),
+                element: "form_of_item".into(),
+                condition: Condition::In {
+                    values: vec![b"o".to_vec(), b"q".to_vec()],
+                    negated: true,
+                    folding: Folding::default(),
+                },
+            }
+        );
+
+        parse_success!(
+            "int(008.date1) >= '1990'",
+            FixedFieldMatcher {
+                tag: b"008".to_vec(),
+                element: "date1".into(),
+                condition: Condition::Comparison(
+                    ComparisonOperator::Ge,
+                    Folding::default(),
+                    Value::Int(1990)
+                ),
+            }
+        );
+
+        parse_success!(
+            "008.date1 < 2000",
+            FixedFieldMatcher {
+                tag: b"008".to_vec(),
+                element: "date1".into(),
+                condition: Condition::Comparison(
+                    ComparisonOperator::Lt,
+                    Folding::default(),
+                    Value::Int(2000)
+                ),
+            }
+        );
+
+        assert!(parse_fixed_field_matcher.parse(b"008.lang").is_err());
+        assert!(
+            parse_fixed_field_matcher.parse(b"008.foo == 'a'").is_err()
+        );
+        assert!(
+            parse_fixed_field_matcher
+                .parse(b"007.lang == 'a'")
+                .is_err()
+        );
+        assert!(
+            parse_fixed_field_matcher
+                .parse(b"008.lang=='ger'")
+                .is_err()
+        );
+    }
+}
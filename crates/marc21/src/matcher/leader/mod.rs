@@ -10,10 +10,79 @@ pub(crate) mod parse;
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub(crate) enum LeaderField {
     BaseAddr,
+    BibliographicLevel,
+    DescriptiveCatalogingForm,
     Encoding,
+    EncodingLevel,
+    IndicatorCount,
+    ItemInformation,
+    KindOfData,
     Length,
+    LengthOfFieldLength,
+    LengthOfImplementationDefined,
+    LengthOfStartingPosition,
+    MultipartLevel,
+    PunctuationPolicy,
     Status,
+    SubfieldCodeLength,
     Type,
+    TypeOfControl,
+}
+
+impl LeaderField {
+    /// Returns true if the field is a number; otherwise the field is a
+    /// single character.
+    pub(crate) fn is_numeric(&self) -> bool {
+        matches!(
+            self,
+            Self::BaseAddr
+                | Self::IndicatorCount
+                | Self::Length
+                | Self::LengthOfFieldLength
+                | Self::LengthOfImplementationDefined
+                | Self::LengthOfStartingPosition
+                | Self::SubfieldCodeLength
+        )
+    }
+
+    /// Returns the value of the field or `None`, if the field isn't
+    /// defined in the record format.
+    pub(crate) fn value(&self, ldr: &Leader) -> Option<Value> {
+        Some(match self {
+            Self::BaseAddr => ldr.base_addr().into(),
+            Self::BibliographicLevel => {
+                ldr.bibliographic_level()?.into()
+            }
+            Self::DescriptiveCatalogingForm => {
+                ldr.descriptive_cataloging_form()?.into()
+            }
+            Self::Encoding => ldr.encoding().into(),
+            Self::EncodingLevel => ldr.encoding_level().into(),
+            Self::IndicatorCount => {
+                u32::from(ldr.indicator_count()).into()
+            }
+            Self::ItemInformation => ldr.item_information()?.into(),
+            Self::KindOfData => ldr.kind_of_data()?.into(),
+            Self::Length => ldr.length().into(),
+            Self::LengthOfFieldLength => {
+                u32::from(ldr.length_of_field_length()).into()
+            }
+            Self::LengthOfImplementationDefined => {
+                u32::from(ldr.length_of_implementation_defined()).into()
+            }
+            Self::LengthOfStartingPosition => {
+                u32::from(ldr.length_of_starting_position()).into()
+            }
+            Self::MultipartLevel => ldr.multipart_level()?.into(),
+            Self::PunctuationPolicy => ldr.punctuation_policy()?.into(),
+            Self::Status => ldr.status().into(),
+            Self::SubfieldCodeLength => {
+                u32::from(ldr.subfield_code_length()).into()
+            }
+            Self::Type => ldr.r#type().into(),
+            Self::TypeOfControl => ldr.type_of_control()?.into(),
+        })
+    }
 }
 
 /// A matcher that can be applied on a [Leader].
@@ -21,16 +90,36 @@ pub(crate) enum LeaderField {
 /// The LeaderMatcher can be used to check the leader fields. The
 /// following fields can be checked:
 ///
-/// - Base Address `ldr.base_addr`,
-/// - Encoding `ldr.encoding`,
-/// - Length `ldr.length`,
-/// - Status`ldr.status`,
-/// - and Type `ldr.type`.
+/// - Length `ldr.length` (00-04),
+/// - Status `ldr.status` (05),
+/// - Type `ldr.type` (06),
+/// - Bibliographic level `ldr.bibliographic_level` (07),
+/// - Kind of data `ldr.kind_of_data` (07),
+/// - Type of control `ldr.type_of_control` (08),
+/// - Encoding `ldr.encoding` (09),
+/// - Indicator count `ldr.indicator_count` (10),
+/// - Subfield code length `ldr.subfield_code_length` (11),
+/// - Base Address `ldr.base_addr` (12-16),
+/// - Encoding level `ldr.encoding_level` (17),
+/// - Descriptive cataloging form `ldr.descriptive_cataloging_form`
+///   (18),
+/// - Punctuation policy `ldr.punctuation_policy` (18),
+/// - Item information `ldr.item_information` (18),
+/// - Multipart resource record level `ldr.multipart_level` (19),
+/// - Length of the length-of-field portion `ldr.length_of_field_length`
+///   (20),
+/// - Length of the starting-character-position portion
+///   `ldr.length_of_starting_position` (21),
+/// - and Length of the implementation-defined portion
+///   `ldr.length_of_implementation_defined` (22).
 ///
 /// The data type of the comparison value must match the data type of
-/// the corresponding leader field; i.e., the base address and length
-/// can only be compared with u32 values, and the remaining fields can
-/// only be compared with a single character.
+/// the corresponding leader field; i.e., the base address, the length
+/// and the lengths of the positions 10, 11 and 20-22 can only be
+/// compared with u32 values, and the remaining fields can only be
+/// compared with a single character. A field, which isn't defined in
+/// the record format (e.g. the bibliographic level of an authority
+/// record), never matches.
 ///
 /// The comparison operators `==`, `=!`, `>=`, `>`, `<=` and `<` ca be
 /// used in a comparisopn expression.
@@ -57,6 +146,12 @@ pub(crate) enum LeaderField {
 /// let matcher = LeaderMatcher::new("ldr.type == 'z'")?;
 /// assert!(matcher.is_match(&leader, &options));
 ///
+/// let matcher = LeaderMatcher::new("ldr.punctuation_policy == 'c'")?;
+/// assert!(matcher.is_match(&leader, &options));
+///
+/// let matcher = LeaderMatcher::new("ldr.bibliographic_level != 'm'")?;
+/// assert!(!matcher.is_match(&leader, &options));
+///
 /// # Ok::<(), Box<dyn std::error::Error>>(())
 /// ```
 #[derive(Debug, PartialEq, Clone)]
@@ -114,12 +209,8 @@ impl LeaderMatcher {
         ldr: &Leader,
         _options: &MatchOptions,
     ) -> bool {
-        let lhs: Value = match self.field {
-            LeaderField::BaseAddr => ldr.base_addr().into(),
-            LeaderField::Status => ldr.status().into(),
-            LeaderField::Encoding => ldr.encoding().into(),
-            LeaderField::Length => ldr.length().into(),
-            LeaderField::Type => ldr.r#type().into(),
+        let Some(lhs) = self.field.value(ldr) else {
+            return false;
         };
 
         match self.operator {
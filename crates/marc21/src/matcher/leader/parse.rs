@@ -13,11 +13,11 @@ pub(crate) fn parse_leader_matcher(
     let _prefix = "ldr.".parse_next(i)?;
     let field = parse_leader_field.parse_next(i)?;
     let operator = ws1(parse_comparison_operator).parse_next(i)?;
-    let value = match field {
-        LeaderField::BaseAddr | LeaderField::Length => parse_u32_value,
-        _ => parse_char_value,
-    }
-    .parse_next(i)?;
+    let value = if field.is_numeric() {
+        parse_u32_value.parse_next(i)?
+    } else {
+        parse_char_value.parse_next(i)?
+    };
 
     Ok(LeaderMatcher {
         field,
@@ -29,12 +29,38 @@ pub(crate) fn parse_leader_matcher(
 pub(crate) fn parse_leader_field(
     i: &mut &[u8],
 ) -> ModalResult<LeaderField> {
+    use LeaderField::*;
+
+    // The alternatives are tried in order, so a field name must come
+    // before any other field name, which is a prefix of it.
     alt((
-        "base_addr".value(LeaderField::BaseAddr),
-        "encoding".value(LeaderField::Encoding),
-        "length".value(LeaderField::Length),
-        "status".value(LeaderField::Status),
-        "type".value(LeaderField::Type),
+        alt((
+            "base_addr".value(BaseAddr),
+            "bibliographic_level".value(BibliographicLevel),
+            "descriptive_cataloging_form"
+                .value(DescriptiveCatalogingForm),
+            "encoding_level".value(EncodingLevel),
+            "encoding".value(Encoding),
+            "indicator_count".value(IndicatorCount),
+            "item_information".value(ItemInformation),
+        )),
+        alt((
+            "kind_of_data".value(KindOfData),
+            "length_of_field_length".value(LengthOfFieldLength),
+            "length_of_implementation_defined"
+                .value(LengthOfImplementationDefined),
+            "length_of_starting_position"
+                .value(LengthOfStartingPosition),
+            "length".value(Length),
+            "multipart_level".value(MultipartLevel),
+        )),
+        alt((
+            "punctuation_policy".value(PunctuationPolicy),
+            "status".value(Status),
+            "subfield_code_length".value(SubfieldCodeLength),
+            "type_of_control".value(TypeOfControl),
+            "type".value(Type),
+        )),
     ))
     .parse_next(i)
 }
@@ -60,6 +86,17 @@ mod tests {
         parse_success!("length", LeaderField::Length);
         parse_success!("status", LeaderField::Status);
         parse_success!("type", LeaderField::Type);
+        parse_success!(
+            "bibliographic_level",
+            LeaderField::BibliographicLevel
+        );
+        parse_success!("encoding_level", LeaderField::EncodingLevel);
+        parse_success!("type_of_control", LeaderField::TypeOfControl);
+        parse_success!(
+            "length_of_field_length",
+            LeaderField::LengthOfFieldLength
+        );
+        parse_success!("multipart_level", LeaderField::MultipartLevel);
     }
 
     #[test]
@@ -118,6 +155,29 @@ mod tests {
             }
         );
 
+        parse_success!(
+            "ldr.encoding_level == 'n'",
+            LeaderMatcher {
+                field: LeaderField::EncodingLevel,
+                operator: ComparisonOperator::Eq,
+                value: b'n'.into(),
+            }
+        );
+
+        parse_success!(
+            "ldr.indicator_count == 2",
+            LeaderMatcher {
+                field: LeaderField::IndicatorCount,
+                operator: ComparisonOperator::Eq,
+                value: 2u32.into(),
+            }
+        );
+
+        assert!(
+            parse_leader_matcher
+                .parse(b"ldr.encoding_level == 2")
+                .is_err()
+        );
         assert!(parse_leader_matcher.parse(b"ldr.type=='z'").is_err());
         assert!(parse_leader_matcher.parse(b"ldr.type== 'z'").is_err());
         assert!(parse_leader_matcher.parse(b"ldr.type =='z'").is_err());
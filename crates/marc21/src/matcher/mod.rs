@@ -19,10 +19,14 @@
 //! * [`IndicatorMatcher`] --- checks the indicator fields of a field
 //! * [`FieldMatcher`] --- check a field and its subfields for specific
 //!   properties
+//! * [`FixedFieldMatcher`] --- check a named element of the fields 006,
+//!   007 and 008
 //! * [`SubfieldMatcher`]
 //! * ...
 //!
 //! The behavior of some matchers can be influenced by [`MatchOptions`].
+//! A [`Prefilter`] rejects records, that can't match a record matcher,
+//! before they are parsed.
 //!
 //! # Errors
 //!
@@ -31,19 +35,24 @@
 //! [`ByteRecord`]: crate::ByteRecord
 
 pub use field::FieldMatcher;
+pub use fixed::FixedFieldMatcher;
 pub use indicator::IndicatorMatcher;
 pub use leader::LeaderMatcher;
 pub use options::MatchOptions;
+pub use prefilter::Prefilter;
 pub use record::RecordMatcher;
+pub use shared::StrsimAlgorithm;
 pub use subfield::SubfieldMatcher;
 pub use tag::TagMatcher;
 
 pub use crate::error::ParseMatcherError;
 
 pub(crate) mod field;
+pub(crate) mod fixed;
 pub(crate) mod indicator;
 pub(crate) mod leader;
 pub(crate) mod options;
+pub(crate) mod prefilter;
 pub(crate) mod record;
 pub(crate) mod shared;
 pub(crate) mod subfield;
@@ -1,14 +1,59 @@
+use crate::matcher::shared::{Folding, StrsimAlgorithm};
+
 /// Options and flags which can be used to configure a matcher.
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Default, PartialEq)]
 pub struct MatchOptions {
-    /// The threshold for string similarity comparisons.
-    pub(crate) strsim_threshold: f64,
+    /// The algorithm for string similarity comparisons.
+    pub(crate) strsim_algorithm: StrsimAlgorithm,
+
+    /// The thresholds for string similarity comparisons.
+    pub(crate) strsim_thresholds: StrsimThresholds,
+
+    /// The flags, which apply to all string comparisons in addition to
+    /// the flags of the operators (e.g. `==i`).
+    pub(crate) folding: Folding,
 }
 
-impl Default for MatchOptions {
+/// The thresholds for string similarity comparisons, one per
+/// algorithm.
+#[derive(Debug, PartialEq)]
+pub(crate) struct StrsimThresholds {
+    levenshtein: f64,
+    jaro_winkler: f64,
+    sorensen_dice: f64,
+    token_set: f64,
+}
+
+impl Default for StrsimThresholds {
     fn default() -> Self {
+        use StrsimAlgorithm::*;
+
         Self {
-            strsim_threshold: 0.8,
+            levenshtein: Levenshtein.default_threshold(),
+            jaro_winkler: JaroWinkler.default_threshold(),
+            sorensen_dice: SorensenDice.default_threshold(),
+            token_set: TokenSet.default_threshold(),
+        }
+    }
+}
+
+impl StrsimThresholds {
+    #[inline(always)]
+    fn get(&self, algorithm: StrsimAlgorithm) -> f64 {
+        match algorithm {
+            StrsimAlgorithm::Levenshtein => self.levenshtein,
+            StrsimAlgorithm::JaroWinkler => self.jaro_winkler,
+            StrsimAlgorithm::SorensenDice => self.sorensen_dice,
+            StrsimAlgorithm::TokenSet => self.token_set,
+        }
+    }
+
+    fn get_mut(&mut self, algorithm: StrsimAlgorithm) -> &mut f64 {
+        match algorithm {
+            StrsimAlgorithm::Levenshtein => &mut self.levenshtein,
+            StrsimAlgorithm::JaroWinkler => &mut self.jaro_winkler,
+            StrsimAlgorithm::SorensenDice => &mut self.sorensen_dice,
+            StrsimAlgorithm::TokenSet => &mut self.token_set,
         }
     }
 }
@@ -18,8 +63,107 @@ impl MatchOptions {
         Self::default()
     }
 
+    /// Sets the threshold for string similarity comparisons of all
+    /// algorithms.
     pub fn strsim_threshold(mut self, threshold: f64) -> Self {
-        self.strsim_threshold = threshold;
+        self.strsim_thresholds = StrsimThresholds {
+            levenshtein: threshold,
+            jaro_winkler: threshold,
+            sorensen_dice: threshold,
+            token_set: threshold,
+        };
+        self
+    }
+
+    /// Sets the algorithm for string similarity comparisons (`=*`),
+    /// which don't specify an algorithm of their own.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::matcher::{MatchOptions, RecordMatcher, StrsimAlgorithm};
+    /// use marc21::prelude::*;
+    ///
+    /// # let data = include_bytes!("../../tests/data/ada.mrc");
+    /// let record = ByteRecord::from_bytes(data)?;
+    /// let matcher = RecordMatcher::new("100/1#.a =* 'Ada Lovelace'")?;
+    ///
+    /// let options = MatchOptions::new();
+    /// assert!(!matcher.is_match(&record, &options));
+    ///
+    /// let options =
+    ///     MatchOptions::new().strsim_algorithm(StrsimAlgorithm::TokenSet);
+    /// assert!(matcher.is_match(&record, &options));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn strsim_algorithm(
+        mut self,
+        algorithm: StrsimAlgorithm,
+    ) -> Self {
+        self.strsim_algorithm = algorithm;
+        self
+    }
+
+    /// Sets the threshold for string similarity comparisons of a
+    /// single algorithm.
+    pub fn strsim_algorithm_threshold(
+        mut self,
+        algorithm: StrsimAlgorithm,
+        threshold: f64,
+    ) -> Self {
+        *self.strsim_thresholds.get_mut(algorithm) = threshold;
+        self
+    }
+
+    /// Returns the threshold of the given algorithm.
+    #[inline(always)]
+    pub(crate) fn strsim_threshold_of(
+        &self,
+        algorithm: StrsimAlgorithm,
+    ) -> f64 {
+        self.strsim_thresholds.get(algorithm)
+    }
+
+    /// Whether string comparisons should ignore the case of the values
+    /// or not. This corresponds to the operator flag `i` (e.g. `==i`).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::matcher::{MatchOptions, RecordMatcher};
+    /// use marc21::prelude::*;
+    ///
+    /// # let data = include_bytes!("../../tests/data/ada.mrc");
+    /// let record = ByteRecord::from_bytes(data)?;
+    /// let matcher = RecordMatcher::new("042.a == 'GND1'")?;
+    ///
+    /// let options = MatchOptions::new();
+    /// assert!(!matcher.is_match(&record, &options));
+    ///
+    /// let options = MatchOptions::new().case_insensitive(true);
+    /// assert!(matcher.is_match(&record, &options));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn case_insensitive(mut self, yes: bool) -> Self {
+        self.folding.case = yes;
+        self
+    }
+
+    /// Whether string comparisons should ignore diacritics (e.g. `ü`
+    /// and `u` are equal) or not. This corresponds to the operator
+    /// flag `a` (e.g. `==a`).
+    pub fn diacritic_insensitive(mut self, yes: bool) -> Self {
+        self.folding.diacritics = yes;
+        self
+    }
+
+    /// Whether string comparisons should ignore the Unicode normal
+    /// form (e.g. NFC and NFD) of the values or not. This corresponds
+    /// to the operator flag `n` (e.g. `==n`).
+    pub fn normalization_insensitive(mut self, yes: bool) -> Self {
+        self.folding.normalization = yes;
         self
     }
 }
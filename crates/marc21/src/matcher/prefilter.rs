@@ -0,0 +1,347 @@
+use aho_corasick::AhoCorasick;
+
+use crate::matcher::field::control::ControlFieldMatcher;
+use crate::matcher::record::MatcherKind;
+use crate::matcher::shared::{
+    BooleanOp, ComparisonOperator, Folding, Quantifier, Value,
+};
+use crate::matcher::{
+    FieldMatcher, MatchOptions, RecordMatcher, SubfieldMatcher,
+};
+
+/// A disjunction of literals, of which at least one must occur in the
+/// raw data of a matching record. The literals are borrowed from the
+/// matcher, so that large value lists (e.g. `in @ids.txt`) aren't
+/// copied before the automaton is built.
+type Clause<'a> = Vec<&'a [u8]>;
+
+/// A necessary condition of a [`RecordMatcher`], which can be checked
+/// on the raw bytes of a record before the record is parsed.
+///
+/// The prefilter consists of a conjunction of clauses, each of which
+/// is a set of literals. A record can only match, if it contains at
+/// least one literal of each clause. Thus, a record that is rejected by
+/// the prefilter doesn't match the record matcher, but a record that
+/// passes the prefilter doesn't necessarily match.
+#[derive(Debug, Clone)]
+pub struct Prefilter {
+    clauses: Vec<AhoCorasick>,
+}
+
+impl Prefilter {
+    fn from_clauses(clauses: Vec<Clause<'_>>) -> Option<Self> {
+        let clauses: Vec<AhoCorasick> = clauses
+            .into_iter()
+            .filter(|clause| clause.iter().all(|lit| !lit.is_empty()))
+            .filter_map(|clause| AhoCorasick::new(clause).ok())
+            .collect();
+
+        if clauses.is_empty() {
+            return None;
+        }
+
+        Some(Self { clauses })
+    }
+
+    /// Returns true, if the raw data of a record contains at least one
+    /// literal of each clause.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::matcher::{MatchOptions, RecordMatcher};
+    ///
+    /// # let data = include_bytes!("../../tests/data/ada.mrc");
+    /// let matcher = RecordMatcher::new("100/1#.a == 'Lovelace, Ada'")?;
+    /// let prefilter = matcher.prefilter(&MatchOptions::default());
+    /// let prefilter = prefilter.expect("prefilter");
+    ///
+    /// assert!(prefilter.is_match(data));
+    /// assert!(!prefilter.is_match(b"Lessing, Gotthold Ephraim"));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[cfg_attr(feature = "perf-inline", inline(always))]
+    pub fn is_match(&self, data: &[u8]) -> bool {
+        self.clauses.iter().all(|ac| ac.is_match(data))
+    }
+}
+
+impl RecordMatcher {
+    /// Returns a prefilter, which rejects records that can't match
+    /// without parsing them, or `None` if the matcher doesn't require
+    /// any literal.
+    ///
+    /// Only exact comparisons (`==`), prefix (`=^`), suffix (`=$`),
+    /// substring (`=?`) and membership (`in`) tests contribute
+    /// literals. Negated tests, regular expressions, similarity
+    /// comparisons, `ALL` quantifiers and tests, which fold the
+    /// strings (e.g. `==i`), don't contribute any literal.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::matcher::{MatchOptions, RecordMatcher};
+    ///
+    /// let options = MatchOptions::default();
+    ///
+    /// let matcher = RecordMatcher::new("001 == '119232022'")?;
+    /// assert!(matcher.prefilter(&options).is_some());
+    ///
+    /// let matcher = RecordMatcher::new("!(001 == '119232022')")?;
+    /// assert!(matcher.prefilter(&options).is_none());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn prefilter(
+        &self,
+        options: &MatchOptions,
+    ) -> Option<Prefilter> {
+        Prefilter::from_clauses(record_clauses(&self.kind, options))
+    }
+}
+
+/// Combines the clauses of both operands of a disjunction. Since only
+/// one of the operands must match, the best clause of each operand is
+/// chosen and both clauses are merged into a single clause. If one of
+/// the operands doesn't require any literal, neither does the
+/// disjunction.
+fn or_clauses<'a>(
+    lhs: Vec<Clause<'a>>,
+    rhs: Vec<Clause<'a>>,
+) -> Vec<Clause<'a>> {
+    let best = |clauses: Vec<Clause<'a>>| -> Option<Clause<'a>> {
+        clauses.into_iter().max_by_key(|clause| {
+            clause.iter().map(|lit| lit.len()).min().unwrap_or_default()
+        })
+    };
+
+    match (best(lhs), best(rhs)) {
+        (Some(mut lhs), Some(rhs)) => {
+            lhs.extend(rhs);
+            vec![lhs]
+        }
+        _ => vec![],
+    }
+}
+
+fn bool_clauses<'a>(
+    lhs: Vec<Clause<'a>>,
+    op: &BooleanOp,
+    rhs: Vec<Clause<'a>>,
+) -> Vec<Clause<'a>> {
+    match op {
+        BooleanOp::And => lhs.into_iter().chain(rhs).collect(),
+        BooleanOp::Or => or_clauses(lhs, rhs),
+    }
+}
+
+/// Returns the literals of a test, if the strings aren't folded and the
+/// test isn't negated.
+fn literals<'a, I, T>(
+    literals: I,
+    negated: bool,
+    folding: Folding,
+    options: &MatchOptions,
+) -> Vec<Clause<'a>>
+where
+    I: IntoIterator<Item = &'a T>,
+    T: AsRef<[u8]> + ?Sized + 'a,
+{
+    if negated || !(folding | options.folding).is_empty() {
+        return vec![];
+    }
+
+    vec![literals.into_iter().map(AsRef::as_ref).collect()]
+}
+
+fn record_clauses<'a>(
+    kind: &'a MatcherKind,
+    options: &MatchOptions,
+) -> Vec<Clause<'a>> {
+    match kind {
+        MatcherKind::Field(m) => field_clauses(m, options),
+        MatcherKind::Group(m) => record_clauses(m, options),
+        MatcherKind::Composite { lhs, op, rhs } => bool_clauses(
+            record_clauses(lhs, options),
+            op,
+            record_clauses(rhs, options),
+        ),
+        MatcherKind::Leader(_)
+        | MatcherKind::Fixed(_)
+        | MatcherKind::Not(_) => vec![],
+    }
+}
+
+fn field_clauses<'a>(
+    m: &'a FieldMatcher,
+    options: &MatchOptions,
+) -> Vec<Clause<'a>> {
+    match m {
+        FieldMatcher::Data(m) if m.quantifier == Quantifier::Any => {
+            subfield_clauses(&m.matcher, options)
+        }
+        FieldMatcher::Control(ControlFieldMatcher::Comparison(m)) => {
+            match (&m.operator, &m.value) {
+                (ComparisonOperator::Eq, Value::String(value)) => {
+                    literals(
+                        [value.as_slice()],
+                        false,
+                        m.folding,
+                        options,
+                    )
+                }
+                _ => vec![],
+            }
+        }
+        FieldMatcher::Control(ControlFieldMatcher::In(m)) => {
+            literals(m.values.iter(), m.negated, m.folding, options)
+        }
+        _ => vec![],
+    }
+}
+
+fn subfield_clauses<'a>(
+    m: &'a SubfieldMatcher,
+    options: &MatchOptions,
+) -> Vec<Clause<'a>> {
+    use SubfieldMatcher::*;
+
+    match m {
+        Comparison(m) if m.quantifier == Quantifier::Any => {
+            match (&m.operator, &m.value) {
+                (ComparisonOperator::Eq, Value::String(value)) => {
+                    literals(
+                        [value.as_slice()],
+                        false,
+                        m.folding,
+                        options,
+                    )
+                }
+                _ => vec![],
+            }
+        }
+        Prefix(m) if m.quantifier == Quantifier::Any => {
+            literals(&m.prefixes, m.negated, m.folding, options)
+        }
+        Suffix(m) if m.quantifier == Quantifier::Any => {
+            literals(&m.suffixes, m.negated, m.folding, options)
+        }
+        Substr(m) if m.quantifier == Quantifier::Any => {
+            literals(&m.patterns, m.negated, m.folding, options)
+        }
+        Member(m) if m.quantifier == Quantifier::Any => {
+            literals(m.values.iter(), m.negated, m.folding, options)
+        }
+        Group(m) => subfield_clauses(m, options),
+        Composite { lhs, op, rhs } => bool_clauses(
+            subfield_clauses(lhs, options),
+            op,
+            subfield_clauses(rhs, options),
+        ),
+        _ => vec![],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn clauses(
+        matcher: &str,
+        options: &MatchOptions,
+    ) -> Vec<Vec<Vec<u8>>> {
+        let matcher = RecordMatcher::new(matcher).unwrap();
+        record_clauses(&matcher.kind, options)
+            .into_iter()
+            .map(|clause| {
+                clause.into_iter().map(<[u8]>::to_vec).collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_record_clauses() {
+        let options = MatchOptions::default();
+
+        macro_rules! assert_clauses {
+            ($matcher:expr, $clauses:expr) => {
+                let expected: Vec<Vec<Vec<u8>>> = $clauses
+                    .iter()
+                    .map(|clause: &Vec<&str>| {
+                        clause.iter().map(|lit| lit.as_bytes().to_vec())
+                    })
+                    .map(Iterator::collect)
+                    .collect();
+
+                assert_eq!(clauses($matcher, &options), expected);
+            };
+        }
+
+        assert_clauses!("001 == '123'", [vec!["123"]]);
+        assert_clauses!("001 in ['123', '456']", [vec!["123", "456"]]);
+        assert_clauses!("001 not in ['123', '456']", []);
+        assert_clauses!("001 != '123'", []);
+        assert_clauses!("001 ==i '123'", []);
+        assert_clauses!("100.a == 'abc'", [vec!["abc"]]);
+        assert_clauses!(
+            "100.a =^ ['abc', 'def']",
+            [vec!["abc", "def"]]
+        );
+        assert_clauses!("100.a =$ 'abc'", [vec!["abc"]]);
+        assert_clauses!("100.a =? 'abc'", [vec!["abc"]]);
+        assert_clauses!(
+            "100.a in ['abc', 'def']",
+            [vec!["abc", "def"]]
+        );
+        assert_clauses!("100.a !^ 'abc'", []);
+        assert_clauses!("100.a =~ '^abc'", []);
+        assert_clauses!("100.a =* 'abc'", []);
+        assert_clauses!("100{ ALL a == 'abc' }", []);
+        assert_clauses!("ALL 100.a == 'abc'", []);
+        assert_clauses!("!(100.a == 'abc')", []);
+        assert_clauses!("100{ !(a == 'abc') }", []);
+        assert_clauses!("ldr.status == 'z'", []);
+        assert_clauses!(
+            "100{ a == 'abc' && b == 'def' }",
+            [vec!["abc"], vec!["def"]]
+        );
+        assert_clauses!(
+            "100.a == 'abc' && 001 == '123'",
+            [vec!["abc"], vec!["123"]]
+        );
+        assert_clauses!(
+            "100.a == 'abc' || 001 == '123'",
+            [vec!["abc", "123"]]
+        );
+        assert_clauses!("100.a == 'abc' || 100.a =~ 'def'", []);
+        assert_clauses!(
+            "100.a == 'a' && 100.b == 'bcd' || 001 == '123'",
+            [vec!["bcd", "123"]]
+        );
+    }
+
+    #[test]
+    fn test_record_clauses_folding() {
+        let options = MatchOptions::default().case_insensitive(true);
+        assert!(clauses("100.a == 'abc'", &options).is_empty());
+        assert!(clauses("001 == '123'", &options).is_empty());
+    }
+
+    #[test]
+    fn test_prefilter_is_match() {
+        let options = MatchOptions::default();
+        let matcher =
+            RecordMatcher::new("100.a == 'abc' && 001 in ['1', '2']")
+                .unwrap();
+        let prefilter = matcher.prefilter(&options).unwrap();
+
+        assert!(prefilter.is_match(b"1abc"));
+        assert!(prefilter.is_match(b"abc2"));
+        assert!(!prefilter.is_match(b"abc3"));
+        assert!(!prefilter.is_match(b"ab1"));
+
+        let matcher = RecordMatcher::new("100.a == ''").unwrap();
+        assert!(matcher.prefilter(&options).is_none());
+    }
+}
@@ -5,9 +5,10 @@ use winnow::Parser;
 
 use crate::ByteRecord;
 use crate::matcher::record::parse::parse_record_matcher;
-use crate::matcher::shared::BooleanOp;
+use crate::matcher::shared::{BooleanOp, clear_value_files};
 use crate::matcher::{
-    FieldMatcher, LeaderMatcher, MatchOptions, ParseMatcherError,
+    FieldMatcher, FixedFieldMatcher, LeaderMatcher, MatchOptions,
+    ParseMatcherError,
 };
 
 pub(crate) mod parse;
@@ -39,9 +40,12 @@ impl RecordMatcher {
     pub fn new<B: AsRef<[u8]>>(
         matcher: B,
     ) -> Result<Self, ParseMatcherError> {
-        parse_record_matcher
+        let result = parse_record_matcher
             .parse(matcher.as_ref())
-            .map_err(ParseMatcherError::from_parse)
+            .map_err(ParseMatcherError::from_parse);
+
+        clear_value_files();
+        result
     }
 
     /// Returns true if and only if the given record matches against the
@@ -66,6 +70,9 @@ impl RecordMatcher {
     /// let matcher = RecordMatcher::new("042.a == 'gnd1'")?;
     /// assert!(matcher.is_match(&record, &options));
     ///
+    /// let matcher = RecordMatcher::new("008.kind_of_record == 'a'")?;
+    /// assert!(matcher.is_match(&record, &options));
+    ///
     /// let matcher = RecordMatcher::new("065{ ALL a == '28p' }")?;
     /// assert!(matcher.is_match(&record, &options));
     ///
@@ -131,6 +138,7 @@ impl<'de> serde::Deserialize<'de> for RecordMatcher {
 pub(crate) enum MatcherKind {
     Leader(LeaderMatcher),
     Field(FieldMatcher),
+    Fixed(FixedFieldMatcher),
     Group(Box<MatcherKind>),
     Not(Box<MatcherKind>),
     Composite {
@@ -149,6 +157,7 @@ impl MatcherKind {
         match self {
             Self::Leader(m) => m.is_match(record.leader(), options),
             Self::Field(m) => m.is_match(record.fields(), options),
+            Self::Fixed(m) => m.is_match(record, options),
             Self::Group(m) => m.is_match(record, options),
             Self::Not(m) => !m.is_match(record, options),
             Self::Composite { lhs, op, rhs } => match *op {
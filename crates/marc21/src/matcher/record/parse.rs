@@ -8,6 +8,7 @@ use winnow::prelude::*;
 
 use crate::matcher::RecordMatcher;
 use crate::matcher::field::parse::parse_field_matcher;
+use crate::matcher::fixed::parse::parse_fixed_field_matcher;
 use crate::matcher::leader::parse::parse_leader_matcher;
 use crate::matcher::record::MatcherKind;
 use crate::matcher::shared::ws0;
@@ -18,6 +19,7 @@ pub(crate) fn parse_record_matcher(
     ws0(alt((
         parse_composite_matcher,
         parse_leader_matcher.map(MatcherKind::Leader),
+        parse_fixed_field_matcher.map(MatcherKind::Fixed),
         parse_field_matcher.map(MatcherKind::Field),
         parse_group_matcher,
         parse_not_matcher,
@@ -57,6 +59,7 @@ pub(crate) fn parse_group_matcher(
         terminated(ws0('('), group_level_incr),
         alt((
             parse_leader_matcher.map(MatcherKind::Leader),
+            parse_fixed_field_matcher.map(MatcherKind::Fixed),
             parse_field_matcher.map(MatcherKind::Field),
         )),
         ws0(')').map(|_| group_level_decr),
@@ -87,6 +90,7 @@ pub(crate) fn parse_composite_and_matcher(
     let atom = |i: &mut &[u8]| -> ModalResult<MatcherKind> {
         ws0(alt((
             parse_leader_matcher.map(MatcherKind::Leader),
+            parse_fixed_field_matcher.map(MatcherKind::Fixed),
             parse_field_matcher.map(MatcherKind::Field),
             parse_group_matcher,
             parse_not_matcher,
@@ -108,6 +112,7 @@ pub(crate) fn parse_composite_or_matcher(
         ws0(alt((
             parse_composite_and_matcher,
             parse_leader_matcher.map(MatcherKind::Leader),
+            parse_fixed_field_matcher.map(MatcherKind::Fixed),
             parse_field_matcher.map(MatcherKind::Field),
             parse_group_matcher,
             parse_not_matcher,
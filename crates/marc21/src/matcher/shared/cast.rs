@@ -0,0 +1,435 @@
+use bstr::ByteSlice;
+use winnow::ascii::digit1;
+use winnow::combinator::{alt, empty, opt, terminated};
+use winnow::prelude::*;
+use winnow::token::take_while;
+
+use crate::matcher::shared::{
+    ComparisonOperator, Folding, Value, parse_byte_string,
+    parse_comparison_operator, parse_folding, parse_string_value, ws1,
+};
+
+/// The type into which the values of a typed comparison are converted
+/// (e.g. `int(a) > 1990`).
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) enum Cast {
+    Int,
+    Decimal,
+    Date,
+    Timestamp,
+}
+
+impl Cast {
+    /// Returns the cast, which corresponds to the type of the given
+    /// reference value, or `None` if the value isn't typed.
+    pub(crate) fn of(value: &Value) -> Option<Self> {
+        match value {
+            Value::Int(_) => Some(Self::Int),
+            Value::Decimal(_) => Some(Self::Decimal),
+            Value::Date(_) => Some(Self::Date),
+            Value::Timestamp(_) => Some(Self::Timestamp),
+            _ => None,
+        }
+    }
+
+    /// Converts a value into the type of the cast. Leading and
+    /// trailing whitespace is ignored. Returns `None`, if the value
+    /// can't be converted.
+    pub(crate) fn convert(&self, value: &[u8]) -> Option<Value> {
+        let value = value.trim_ascii();
+
+        match self {
+            Self::Int => to_int(value).map(Value::Int),
+            Self::Decimal => to_decimal(value).map(Value::Decimal),
+            Self::Date => to_date(value).map(Value::Date),
+            Self::Timestamp => {
+                to_timestamp(value).map(Value::Timestamp)
+            }
+        }
+    }
+}
+
+/// Splits off an optional sign and checks that the remainder consists
+/// of digits and at most one decimal point, which is followed by a
+/// digit.
+fn is_number(value: &[u8], fraction: bool) -> bool {
+    let value = value.strip_prefix(b"+").unwrap_or(value);
+    let value = value.strip_prefix(b"-").unwrap_or(value);
+
+    let (int, frac) = match value.iter().position(|b| *b == b'.') {
+        Some(pos) if fraction => {
+            (&value[..pos], Some(&value[pos + 1..]))
+        }
+        _ => (value, None),
+    };
+
+    !int.is_empty()
+        && int.iter().all(u8::is_ascii_digit)
+        && frac.is_none_or(|frac| {
+            !frac.is_empty() && frac.iter().all(u8::is_ascii_digit)
+        })
+}
+
+fn to_int(value: &[u8]) -> Option<i64> {
+    if !is_number(value, false) {
+        return None;
+    }
+
+    value.to_str().ok()?.parse().ok()
+}
+
+fn to_decimal(value: &[u8]) -> Option<f64> {
+    if !is_number(value, true) {
+        return None;
+    }
+
+    value.to_str().ok()?.parse().ok()
+}
+
+fn to_digits(value: &[u8]) -> Option<u32> {
+    if value.is_empty() || !value.iter().all(u8::is_ascii_digit) {
+        return None;
+    }
+
+    Some(value.iter().fold(0, |acc, b| acc * 10 + (b - b'0') as u32))
+}
+
+fn days_in_month(year: u32, month: u32) -> u32 {
+    match month {
+        2 if year.is_multiple_of(4)
+            && (!year.is_multiple_of(100)
+                || year.is_multiple_of(400)) =>
+        {
+            29
+        }
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Converts a date in the form `YYYY-MM-DD` or `YYYYMMDD` into the
+/// number `YYYYMMDD`.
+fn to_date(value: &[u8]) -> Option<u32> {
+    let (year, month, day) = match value.len() {
+        8 => (&value[0..4], &value[4..6], &value[6..8]),
+        10 if value[4] == b'-' && value[7] == b'-' => {
+            (&value[0..4], &value[5..7], &value[8..10])
+        }
+        _ => return None,
+    };
+
+    let year = to_digits(year)?;
+    let month = to_digits(month)?;
+    let day = to_digits(day)?;
+
+    if !(1..=12).contains(&month)
+        || day == 0
+        || day > days_in_month(year, month)
+    {
+        return None;
+    }
+
+    Some(year * 10_000 + month * 100 + day)
+}
+
+/// Converts a timestamp into the number `YYYYMMDDhhmmssf`. The
+/// timestamp is either given in the form of the field 005
+/// (`YYYYMMDDhhmmss.f`) or in the ISO 8601 form
+/// (`YYYY-MM-DDThh:mm:ss`). The time (or its fraction of a second) can
+/// be omitted.
+fn to_timestamp(value: &[u8]) -> Option<u64> {
+    let (date, time) = if value.get(4) == Some(&b'-') {
+        let (date, time) = value.split_at_checked(10)?;
+        let time = match time {
+            [] => vec![],
+            [
+                b'T' | b' ',
+                hh1,
+                hh2,
+                b':',
+                mm1,
+                mm2,
+                b':',
+                ss1,
+                ss2,
+                rest @ ..,
+            ] => {
+                let mut time = vec![*hh1, *hh2, *mm1, *mm2, *ss1, *ss2];
+                time.extend_from_slice(rest);
+                time
+            }
+            _ => return None,
+        };
+
+        (date, time)
+    } else {
+        let (date, time) = value.split_at_checked(8)?;
+        (date, time.to_vec())
+    };
+
+    let date = to_date(date)? as u64;
+    let (time, frac) = match time.iter().position(|b| *b == b'.') {
+        Some(pos) => (&time[..pos], Some(&time[pos + 1..])),
+        None => (time.as_slice(), None),
+    };
+
+    let time = match time.len() {
+        0 if frac.is_none() => 0,
+        6 => to_hms(time)?,
+        _ => return None,
+    };
+
+    let tenth = match frac {
+        Some(frac) if frac.iter().all(u8::is_ascii_digit) => {
+            (frac.first()? - b'0') as u64
+        }
+        Some(_) => return None,
+        None => 0,
+    };
+
+    Some((date * 1_000_000 + time) * 10 + tenth)
+}
+
+fn to_hms(value: &[u8]) -> Option<u64> {
+    let (hh, mm, ss) = (
+        to_digits(&value[0..2])?,
+        to_digits(&value[2..4])?,
+        to_digits(&value[4..6])?,
+    );
+
+    if hh > 23 || mm > 59 || ss > 59 {
+        return None;
+    }
+
+    Some((hh * 10_000 + mm * 100 + ss) as u64)
+}
+
+fn parse_cast(i: &mut &[u8]) -> ModalResult<Cast> {
+    terminated(
+        alt((
+            "int".value(Cast::Int),
+            "decimal".value(Cast::Decimal),
+            "date".value(Cast::Date),
+            "timestamp".value(Cast::Timestamp),
+        )),
+        '(',
+    )
+    .parse_next(i)
+}
+
+/// Parses the reference value of a typed comparison, which is either
+/// a quoted string or an unquoted literal (e.g. `1990` or
+/// `2024-01-01`), and converts it into the type of the cast.
+fn parse_typed_value(
+    cast: Cast,
+) -> impl FnMut(&mut &[u8]) -> ModalResult<Value> {
+    move |i: &mut &[u8]| {
+        alt((
+            parse_byte_string,
+            take_while(1.., |b: u8| {
+                b.is_ascii_alphanumeric() || b"+-.:".contains(&b)
+            })
+            .map(<[u8]>::to_vec),
+        ))
+        .verify_map(|value| cast.convert(&value))
+        .parse_next(i)
+    }
+}
+
+/// Parses an unquoted numeric literal (e.g. `1990` or `-2.5`). A
+/// literal with a decimal point is a decimal, otherwise an integer.
+pub(crate) fn parse_number_value(i: &mut &[u8]) -> ModalResult<Value> {
+    (opt('-'), digit1, opt(('.', digit1)))
+        .take()
+        .verify_map(|value: &[u8]| {
+            if value.contains(&b'.') {
+                Cast::Decimal.convert(value)
+            } else {
+                Cast::Int.convert(value)
+            }
+        })
+        .parse_next(i)
+}
+
+/// Parses a comparison consisting of a left-hand side, a comparison
+/// operator, its flags and a reference value. The left-hand side can
+/// be wrapped in a cast (e.g. `int(a)` or `date(005[0:8])`), in which
+/// case the reference value is converted into the type of the cast.
+/// Otherwise the reference value is either a string or a numeric
+/// literal.
+pub(crate) fn parse_comparison<O>(
+    lhs: fn(&mut &[u8]) -> ModalResult<O>,
+) -> impl FnMut(
+    &mut &[u8],
+) -> ModalResult<(O, ComparisonOperator, Folding, Value)> {
+    move |i: &mut &[u8]| {
+        alt((
+            parse_cast.flat_map(|cast| {
+                (
+                    terminated(lhs, ')'),
+                    ws1(parse_comparison_operator),
+                    empty.value(Folding::default()),
+                    parse_typed_value(cast),
+                )
+            }),
+            (
+                lhs,
+                ws1((parse_comparison_operator, parse_folding)),
+                alt((parse_string_value, parse_number_value)),
+            )
+                .map(|(lhs, (op, folding), value)| {
+                    (lhs, op, folding, value)
+                }),
+        ))
+        .parse_next(i)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cast_convert() {
+        assert_eq!(Cast::Int.convert(b"1990"), Some(Value::Int(1990)));
+        assert_eq!(Cast::Int.convert(b" -12 "), Some(Value::Int(-12)));
+        assert_eq!(Cast::Int.convert(b"+7"), Some(Value::Int(7)));
+        assert_eq!(Cast::Int.convert(b"1990."), None);
+        assert_eq!(Cast::Int.convert(b"c1990"), None);
+        assert_eq!(Cast::Int.convert(b"1.5"), None);
+        assert_eq!(Cast::Int.convert(b""), None);
+
+        assert_eq!(
+            Cast::Decimal.convert(b"9.5"),
+            Some(Value::Decimal(9.5))
+        );
+        assert_eq!(
+            Cast::Decimal.convert(b"28"),
+            Some(Value::Decimal(28.0))
+        );
+        assert_eq!(Cast::Decimal.convert(b"9.5p"), None);
+        assert_eq!(Cast::Decimal.convert(b"1."), None);
+        assert_eq!(Cast::Decimal.convert(b"NaN"), None);
+        assert_eq!(Cast::Decimal.convert(b"inf"), None);
+
+        assert_eq!(
+            Cast::Date.convert(b"2024-01-01"),
+            Some(Value::Date(20240101))
+        );
+        assert_eq!(
+            Cast::Date.convert(b"20240229"),
+            Some(Value::Date(20240229))
+        );
+        assert_eq!(Cast::Date.convert(b"20230229"), None);
+        assert_eq!(Cast::Date.convert(b"2024-13-01"), None);
+        assert_eq!(Cast::Date.convert(b"2024-1-01"), None);
+        assert_eq!(Cast::Date.convert(b"19uu0101"), None);
+
+        assert_eq!(
+            Cast::Timestamp.convert(b"20250720173911.0"),
+            Some(Value::Timestamp(202507201739110))
+        );
+        assert_eq!(
+            Cast::Timestamp.convert(b"20250720173911.25"),
+            Some(Value::Timestamp(202507201739112))
+        );
+        assert_eq!(
+            Cast::Timestamp.convert(b"2025-07-20T17:39:11"),
+            Some(Value::Timestamp(202507201739110))
+        );
+        assert_eq!(
+            Cast::Timestamp.convert(b"2025-07-20 17:39:11.5"),
+            Some(Value::Timestamp(202507201739115))
+        );
+        assert_eq!(
+            Cast::Timestamp.convert(b"2025-07-20"),
+            Some(Value::Timestamp(202507200000000))
+        );
+        assert_eq!(Cast::Timestamp.convert(b"20250720246000.0"), None);
+        assert_eq!(Cast::Timestamp.convert(b"20250720173911."), None);
+        assert_eq!(Cast::Timestamp.convert(b"2025072017"), None);
+    }
+
+    #[test]
+    fn test_parse_number_value() {
+        macro_rules! parse_success {
+            ($i:expr, $o:expr) => {
+                assert_eq!(
+                    parse_number_value.parse($i.as_bytes()).unwrap(),
+                    $o
+                );
+            };
+        }
+
+        parse_success!("1990", Value::Int(1990));
+        parse_success!("-3", Value::Int(-3));
+        parse_success!("2.5", Value::Decimal(2.5));
+
+        assert!(parse_number_value.parse(b"1.").is_err());
+        assert!(
+            parse_number_value.parse(b"99999999999999999999").is_err()
+        );
+    }
+
+    #[test]
+    fn test_parse_comparison() {
+        use ComparisonOperator::*;
+
+        fn parse_lhs(i: &mut &[u8]) -> ModalResult<Vec<u8>> {
+            digit1.map(<[u8]>::to_vec).parse_next(i)
+        }
+
+        let mut parser = parse_comparison(parse_lhs);
+        macro_rules! parse_success {
+            ($i:expr, $o:expr) => {
+                assert_eq!(parser.parse($i.as_bytes()).unwrap(), $o);
+            };
+        }
+
+        let none = Folding::default();
+        let case = Folding {
+            case: true,
+            ..Default::default()
+        };
+
+        parse_success!(
+            "1 == 'foo'",
+            (b"1".to_vec(), Eq, none, "foo".into())
+        );
+        parse_success!(
+            "1 ==i 'foo'",
+            (b"1".to_vec(), Eq, case, "foo".into())
+        );
+        parse_success!(
+            "1 >= 1990",
+            (b"1".to_vec(), Ge, none, Value::Int(1990))
+        );
+        parse_success!(
+            "int(1) < '1990'",
+            (b"1".to_vec(), Lt, none, Value::Int(1990))
+        );
+        parse_success!(
+            "decimal(1) > 2",
+            (b"1".to_vec(), Gt, none, Value::Decimal(2.0))
+        );
+        parse_success!(
+            "date(1) >= 2024-01-01",
+            (b"1".to_vec(), Ge, none, Value::Date(20240101))
+        );
+        parse_success!(
+            "timestamp(1) < '2025-01-01T12:00:00'",
+            (
+                b"1".to_vec(),
+                Lt,
+                none,
+                Value::Timestamp(202501011200000)
+            )
+        );
+
+        assert!(parser.parse(b"date(1) >= '2024-02-30'").is_err());
+        assert!(parser.parse(b"int(1) >= 'abc'").is_err());
+        assert!(parser.parse(b"foo(1) >= 1").is_err());
+        assert!(parser.parse(b"int(1) >=i 1").is_err());
+    }
+}
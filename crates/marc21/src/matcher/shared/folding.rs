@@ -0,0 +1,178 @@
+use std::borrow::Cow;
+use std::ops::BitOr;
+
+use bstr::ByteSlice;
+use unicode_normalization::UnicodeNormalization;
+use unicode_normalization::char::is_combining_mark;
+use winnow::prelude::*;
+use winnow::token::take_while;
+
+/// Flags, which make string comparisons insensitive to the case, to
+/// diacritics or to the Unicode normal form of the values.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub(crate) struct Folding {
+    pub(crate) case: bool,
+    pub(crate) diacritics: bool,
+    pub(crate) normalization: bool,
+}
+
+impl BitOr for Folding {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self {
+            case: self.case || rhs.case,
+            diacritics: self.diacritics || rhs.diacritics,
+            normalization: self.normalization || rhs.normalization,
+        }
+    }
+}
+
+impl Folding {
+    /// Returns true, if no flag is set.
+    #[inline(always)]
+    pub(crate) fn is_empty(&self) -> bool {
+        !self.case && !self.diacritics && !self.normalization
+    }
+
+    /// Folds a value, so that values, which only differ in the
+    /// selected properties, are equal. The value is decomposed (NFD),
+    /// if it should be compared regardless of its normal form or its
+    /// diacritics, in which case the combining marks are removed.
+    /// Finally, the case is folded (lowercase and `ß` → `ss`).
+    pub(crate) fn apply<'a>(&self, value: &'a [u8]) -> Cow<'a, [u8]> {
+        if self.is_empty() {
+            return Cow::Borrowed(value);
+        }
+
+        if value.is_ascii() {
+            return if self.case {
+                Cow::Owned(value.to_ascii_lowercase())
+            } else {
+                Cow::Borrowed(value)
+            };
+        }
+
+        let mut value = value.to_str_lossy().into_owned();
+        if self.diacritics || self.normalization {
+            value = value
+                .nfd()
+                .filter(|c| !self.diacritics || !is_combining_mark(*c))
+                .collect();
+        }
+
+        if self.case {
+            value = value
+                .chars()
+                .flat_map(|c| match c {
+                    'ß' | 'ẞ' => "ss".chars().collect::<Vec<_>>(),
+                    c => c.to_lowercase().collect(),
+                })
+                .collect();
+        }
+
+        Cow::Owned(value.into_bytes())
+    }
+}
+
+/// Parses the flags of an operator (e.g. `==i` or `=^ia`): `i` stands
+/// for case-insensitive, `a` for diacritic-insensitive (accents) and
+/// `n` for normalization-insensitive comparisons.
+pub(crate) fn parse_folding(i: &mut &[u8]) -> ModalResult<Folding> {
+    take_while(0..=3, b"ian")
+        .verify(|flags: &[u8]| {
+            flags
+                .iter()
+                .enumerate()
+                .all(|(pos, flag)| !flags[pos + 1..].contains(flag))
+        })
+        .map(|flags: &[u8]| Folding {
+            case: flags.contains(&b'i'),
+            diacritics: flags.contains(&b'a'),
+            normalization: flags.contains(&b'n'),
+        })
+        .parse_next(i)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_folding_apply() {
+        let folding = Folding {
+            case: true,
+            ..Default::default()
+        };
+
+        assert_eq!(folding.apply(b"FoO"), b"foo".as_slice());
+        assert_eq!(
+            folding.apply("MÜLLER".as_bytes()),
+            "müller".as_bytes()
+        );
+        assert_eq!(
+            folding.apply("Straße".as_bytes()),
+            b"strasse".as_slice()
+        );
+
+        let folding = Folding {
+            diacritics: true,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            folding.apply("Müller".as_bytes()),
+            b"Muller".as_slice()
+        );
+        assert_eq!(
+            folding.apply("Mu\u{0308}ller".as_bytes()),
+            b"Muller".as_slice()
+        );
+
+        let folding = Folding {
+            normalization: true,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            folding.apply("M\u{00fc}ller".as_bytes()),
+            folding.apply("Mu\u{0308}ller".as_bytes())
+        );
+        assert_ne!(
+            folding.apply("Müller".as_bytes()),
+            folding.apply(b"Muller")
+        );
+
+        let folding = Folding::default();
+        assert_eq!(
+            folding.apply("Müller".as_bytes()),
+            "Müller".as_bytes()
+        );
+    }
+
+    #[test]
+    fn test_parse_folding() {
+        macro_rules! parse_success {
+            ($i:expr, $case:expr, $diacritics:expr, $normalization:expr) => {
+                assert_eq!(
+                    parse_folding.parse($i.as_bytes()).unwrap(),
+                    Folding {
+                        case: $case,
+                        diacritics: $diacritics,
+                        normalization: $normalization
+                    }
+                );
+            };
+        }
+
+        parse_success!("", false, false, false);
+        parse_success!("i", true, false, false);
+        parse_success!("a", false, true, false);
+        parse_success!("n", false, false, true);
+        parse_success!("ni", true, false, true);
+        parse_success!("ian", true, true, true);
+
+        assert!(parse_folding.parse(b"ii").is_err());
+        assert!(parse_folding.parse(b"x").is_err());
+    }
+}
@@ -0,0 +1,281 @@
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::fmt::{self, Debug};
+use std::io::{self, BufRead, BufReader};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, OnceLock};
+
+use winnow::combinator::{alt, cut_err, preceded};
+use winnow::prelude::*;
+use winnow::token::take_while;
+
+use crate::matcher::shared::{
+    Folding, parse_byte_string_list, parse_string,
+};
+
+thread_local! {
+    /// The files, which have been read while parsing a matcher
+    /// expression. Since the parser backtracks, the same file would
+    /// otherwise be read several times.
+    static VALUE_FILES: RefCell<HashMap<PathBuf, Arc<ValueFile>>> =
+        RefCell::new(HashMap::new());
+}
+
+/// Removes the files from the cache, which have been read while parsing
+/// a matcher expression. The function must be called once the parsing
+/// is finished, so that a subsequent parse reads the current contents of
+/// the files.
+pub(crate) fn clear_value_files() {
+    VALUE_FILES.with(|files| files.borrow_mut().clear());
+}
+
+/// The values of a membership test (`in` or `not in`), which are either
+/// listed in the expression or read from a file.
+#[derive(Debug, PartialEq, Clone)]
+pub(crate) enum ValueList {
+    Inline(Arc<InlineValues>),
+    File(Arc<ValueFile>),
+}
+
+impl ValueList {
+    /// Creates a list from the values listed in the expression.
+    pub(crate) fn inline(values: Vec<Vec<u8>>) -> Self {
+        Self::Inline(Arc::new(InlineValues {
+            values,
+            folded: Default::default(),
+        }))
+    }
+
+    /// Returns true, if the list contains the given value. Both the
+    /// value and the values of the list are folded (see [`Folding`]).
+    #[cfg_attr(feature = "perf-inline", inline(always))]
+    pub(crate) fn contains(
+        &self,
+        value: &[u8],
+        folding: Folding,
+    ) -> bool {
+        match self {
+            Self::Inline(values) => values.contains(value, folding),
+            Self::File(file) => file.contains(value, folding),
+        }
+    }
+
+    /// Returns an iterator over the (unfolded) values of the list.
+    pub(crate) fn iter(
+        &self,
+    ) -> Box<dyn Iterator<Item = &Vec<u8>> + '_> {
+        match self {
+            Self::Inline(inline) => Box::new(inline.values.iter()),
+            Self::File(file) => Box::new(file.values.iter()),
+        }
+    }
+}
+
+/// Returns the index of the folded values of a list, which is derived
+/// from the flags of the folding.
+#[inline]
+fn folded_index(folding: Folding) -> usize {
+    folding.case as usize
+        | (folding.diacritics as usize) << 1
+        | (folding.normalization as usize) << 2
+}
+
+/// The values, which are listed in the expression (e.g. `['foo',
+/// 'bar']`).
+pub(crate) struct InlineValues {
+    values: Vec<Vec<u8>>,
+    /// The folded values, indexed by the flags of the folding. The
+    /// lists are built on first use.
+    folded: [OnceLock<Vec<Vec<u8>>>; 8],
+}
+
+impl InlineValues {
+    fn contains(&self, value: &[u8], folding: Folding) -> bool {
+        if folding.is_empty() {
+            return self.values.iter().any(|rhs| rhs == value);
+        }
+
+        let value = folding.apply(value);
+        self.folded[folded_index(folding)]
+            .get_or_init(|| {
+                self.values
+                    .iter()
+                    .map(|value| folding.apply(value).into_owned())
+                    .collect()
+            })
+            .iter()
+            .any(|rhs| *rhs == *value)
+    }
+}
+
+impl Debug for InlineValues {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(&self.values).finish()
+    }
+}
+
+impl PartialEq for InlineValues {
+    fn eq(&self, other: &Self) -> bool {
+        self.values == other.values
+    }
+}
+
+/// A set of values, which is read from a file with one value per line.
+/// The file may be compressed (e.g. gzip).
+pub(crate) struct ValueFile {
+    path: PathBuf,
+    values: HashSet<Vec<u8>>,
+    /// The folded values, indexed by the flags of the folding. The sets
+    /// are built on first use.
+    folded: [OnceLock<HashSet<Vec<u8>>>; 8],
+}
+
+impl ValueFile {
+    /// Reads the values from a file. Empty lines are skipped and a
+    /// trailing carriage return is removed.
+    pub(crate) fn from_path<P: AsRef<Path>>(
+        path: P,
+    ) -> io::Result<Self> {
+        let path = path.as_ref();
+        let reader =
+            BufReader::new(crate::io::open(path).map_err(|e| {
+                io::Error::new(
+                    e.kind(),
+                    format!("{}: {e}", path.display()),
+                )
+            })?);
+
+        let mut values = HashSet::new();
+        for line in reader.split(b'\n') {
+            let mut line = line?;
+            if line.last() == Some(&b'\r') {
+                line.pop();
+            }
+
+            if !line.is_empty() {
+                values.insert(line);
+            }
+        }
+
+        Ok(Self {
+            path: path.into(),
+            values,
+            folded: Default::default(),
+        })
+    }
+
+    fn contains(&self, value: &[u8], folding: Folding) -> bool {
+        if folding.is_empty() {
+            return self.values.contains(value);
+        }
+
+        self.folded[folded_index(folding)]
+            .get_or_init(|| {
+                self.values
+                    .iter()
+                    .map(|value| folding.apply(value).into_owned())
+                    .collect()
+            })
+            .contains(folding.apply(value).as_ref())
+    }
+}
+
+impl Debug for ValueFile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ValueFile")
+            .field("path", &self.path)
+            .field("len", &self.values.len())
+            .finish()
+    }
+}
+
+impl PartialEq for ValueFile {
+    fn eq(&self, other: &Self) -> bool {
+        self.path == other.path && self.values == other.values
+    }
+}
+
+pub(crate) fn parse_value_file(
+    i: &mut &[u8],
+) -> ModalResult<Arc<ValueFile>> {
+    preceded(
+        '@',
+        cut_err(
+            alt((
+                parse_string,
+                take_while(1.., |b: u8| {
+                    !b.is_ascii_whitespace() && !b")]}|&,".contains(&b)
+                })
+                .try_map(|path: &[u8]| {
+                    String::from_utf8(path.to_vec())
+                }),
+            ))
+            .try_map(|path| {
+                let path = PathBuf::from(path);
+                VALUE_FILES.with(|files| {
+                    if let Some(file) = files.borrow().get(&path) {
+                        return Ok(file.clone());
+                    }
+
+                    let file = Arc::new(ValueFile::from_path(&path)?);
+                    files.borrow_mut().insert(path, file.clone());
+                    Ok::<_, io::Error>(file)
+                })
+            }),
+        ),
+    )
+    .parse_next(i)
+}
+
+/// Parses the values of a membership test, either a string or a list
+/// of strings (e.g. `['foo', 'bar']`) or a reference to a file (e.g.
+/// `@ids.txt` or `@'ids.txt.gz'`).
+pub(crate) fn parse_value_list(
+    i: &mut &[u8],
+) -> ModalResult<ValueList> {
+    alt((
+        parse_value_file.map(ValueList::File),
+        parse_byte_string_list.map(ValueList::inline),
+    ))
+    .parse_next(i)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_value_list() {
+        assert_eq!(
+            parse_value_list.parse(b"['foo', 'bar']").unwrap(),
+            ValueList::inline(vec![b"foo".to_vec(), b"bar".to_vec()])
+        );
+
+        let list =
+            parse_value_list.parse(b"@tests/data/ids.txt").unwrap();
+        assert!(list.contains(b"119232022", Folding::default()));
+        assert!(
+            list.contains(b"(DE-588)118540238", Folding::default())
+        );
+        assert!(!list.contains(b"", Folding::default()));
+        assert!(
+            !list.contains(b"(de-588)118540238", Folding::default())
+        );
+
+        let folding = Folding {
+            case: true,
+            ..Default::default()
+        };
+        assert!(list.contains(b"(de-588)118540238", folding));
+
+        let list = parse_value_list
+            .parse(b"@'tests/data/ids.txt.gz'")
+            .unwrap();
+        assert!(list.contains(b"040992918", Folding::default()));
+
+        assert!(parse_value_list.parse(b"@").is_err());
+        assert!(
+            parse_value_list.parse(b"@tests/data/missing.txt").is_err()
+        );
+    }
+}
@@ -1,5 +1,10 @@
+pub(crate) use cast::*;
+pub(crate) use folding::*;
+pub(crate) use list::*;
 pub(crate) use operator::*;
 pub(crate) use quantifier::*;
+pub use similarity::StrsimAlgorithm;
+pub(crate) use similarity::*;
 pub(crate) use value::*;
 use winnow::ascii::{multispace0, multispace1};
 use winnow::combinator::{
@@ -10,8 +15,12 @@ use winnow::prelude::*;
 use winnow::stream::{AsChar, Stream, StreamIsPartial};
 use winnow::token::one_of;
 
+mod cast;
+mod folding;
+mod list;
 mod operator;
 mod quantifier;
+mod similarity;
 mod value;
 
 pub(crate) fn ws0<I, O, E: ParserError<I>, F>(
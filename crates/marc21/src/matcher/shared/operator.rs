@@ -1,6 +1,8 @@
 use winnow::combinator::alt;
 use winnow::prelude::*;
 
+use crate::matcher::shared::{Folding, Value};
+
 #[derive(Debug, PartialEq, Clone)]
 pub enum BooleanOp {
     And,
@@ -17,6 +19,49 @@ pub(crate) enum ComparisonOperator {
     Lt,
 }
 
+impl ComparisonOperator {
+    /// Compares the left-hand side with the right-hand side. Values,
+    /// which can't be compared (e.g. a value, which isn't a number,
+    /// with a numeric literal), never match, not even with `!=`.
+    #[cfg_attr(feature = "perf-inline", inline(always))]
+    pub(crate) fn compare<L: PartialOrd<R>, R>(
+        &self,
+        lhs: &L,
+        rhs: &R,
+    ) -> bool {
+        let Some(ordering) = lhs.partial_cmp(rhs) else {
+            return false;
+        };
+
+        match self {
+            Self::Eq => ordering.is_eq(),
+            Self::Ne => ordering.is_ne(),
+            Self::Ge => ordering.is_ge(),
+            Self::Gt => ordering.is_gt(),
+            Self::Le => ordering.is_le(),
+            Self::Lt => ordering.is_lt(),
+        }
+    }
+
+    /// Compares a byte string with a reference value. A string value
+    /// and the byte string are folded (see [`Folding`]) before they
+    /// are compared.
+    #[cfg_attr(feature = "perf-inline", inline(always))]
+    pub(crate) fn compare_folded(
+        &self,
+        lhs: &[u8],
+        rhs: &Value,
+        folding: Folding,
+    ) -> bool {
+        match rhs {
+            Value::String(rhs) if !folding.is_empty() => {
+                self.compare(&folding.apply(lhs), &folding.apply(rhs))
+            }
+            _ => self.compare(&lhs, rhs),
+        }
+    }
+}
+
 pub(crate) fn parse_comparison_operator(
     i: &mut &[u8],
 ) -> ModalResult<ComparisonOperator> {
@@ -0,0 +1,189 @@
+use std::collections::BTreeSet;
+
+use strsim::{jaro_winkler, normalized_levenshtein, sorensen_dice};
+use winnow::combinator::{alt, delimited, opt, preceded};
+use winnow::prelude::*;
+
+use crate::matcher::shared::{parse_usize, ws0};
+
+/// The algorithm to compute the similarity of two strings. All
+/// algorithms compute a score between `0.0` and `1.0` (inclusive),
+/// where `1.0` indicates that the two strings are equal.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum StrsimAlgorithm {
+    /// The normalized Levenshtein distance (default).
+    #[default]
+    Levenshtein,
+    /// The Jaro-Winkler similarity, which favors strings with a common
+    /// prefix.
+    JaroWinkler,
+    /// The Sørensen-Dice coefficient of the character bigrams.
+    SorensenDice,
+    /// The similarity of the sets of words, which ignores the order of
+    /// the words (e.g. "Lovelace, Ada" and "Ada Lovelace" are equal).
+    TokenSet,
+}
+
+impl StrsimAlgorithm {
+    /// Returns the threshold, which is used if no other threshold is
+    /// configured. The scores of the Jaro-Winkler similarity are
+    /// generally higher than those of the other algorithms, which is
+    /// why a higher threshold is used.
+    pub fn default_threshold(&self) -> f64 {
+        match self {
+            Self::JaroWinkler => 0.9,
+            _ => 0.8,
+        }
+    }
+
+    /// Computes the similarity score of two strings.
+    pub fn score(&self, lhs: &str, rhs: &str) -> f64 {
+        match self {
+            Self::Levenshtein => normalized_levenshtein(lhs, rhs),
+            Self::JaroWinkler => jaro_winkler(lhs, rhs),
+            Self::SorensenDice => sorensen_dice(lhs, rhs),
+            Self::TokenSet => token_set(lhs, rhs),
+        }
+    }
+}
+
+/// Computes the token set similarity of two strings. Both strings are
+/// split into a set of words and the common words (intersection) are
+/// compared with each string. The maximum of the normalized Levenshtein
+/// distances is the score.
+fn token_set(lhs: &str, rhs: &str) -> f64 {
+    let tokens = |value: &'_ str| -> BTreeSet<String> {
+        value
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|token| !token.is_empty())
+            .map(ToString::to_string)
+            .collect()
+    };
+
+    let (lhs, rhs) = (tokens(lhs), tokens(rhs));
+    let join = |tokens: Vec<&String>| -> String {
+        tokens
+            .into_iter()
+            .map(String::as_str)
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+
+    let common = join(lhs.intersection(&rhs).collect());
+    let with_diff = |diff: Vec<&String>| -> String {
+        match (common.is_empty(), diff.is_empty()) {
+            (true, _) => join(diff),
+            (false, true) => common.clone(),
+            (false, false) => format!("{common} {}", join(diff)),
+        }
+    };
+
+    let lhs_all = with_diff(lhs.difference(&rhs).collect());
+    let rhs_all = with_diff(rhs.difference(&lhs).collect());
+
+    let mut score = normalized_levenshtein(&lhs_all, &rhs_all);
+    if !common.is_empty() {
+        score = score
+            .max(normalized_levenshtein(&common, &lhs_all))
+            .max(normalized_levenshtein(&common, &rhs_all));
+    }
+
+    score
+}
+
+/// The algorithm and the optional threshold of a similarity operator.
+pub(crate) type StrsimSpec = (StrsimAlgorithm, Option<f64>);
+
+fn parse_strsim_algorithm(
+    i: &mut &[u8],
+) -> ModalResult<StrsimAlgorithm> {
+    alt((
+        "levenshtein".value(StrsimAlgorithm::Levenshtein),
+        "jaro-winkler".value(StrsimAlgorithm::JaroWinkler),
+        "sorensen-dice".value(StrsimAlgorithm::SorensenDice),
+        "token-set".value(StrsimAlgorithm::TokenSet),
+    ))
+    .parse_next(i)
+}
+
+/// Parses the algorithm and an optional threshold of a similarity
+/// operator (e.g. `=*(jaro-winkler)` or `=*(token-set, 90)`). The
+/// threshold must be between 0 and 100.
+pub(crate) fn parse_strsim_spec(
+    i: &mut &[u8],
+) -> ModalResult<StrsimSpec> {
+    delimited(
+        '(',
+        (
+            ws0(parse_strsim_algorithm),
+            opt(preceded(
+                ',',
+                ws0(parse_usize)
+                    .verify(|threshold| *threshold <= 100)
+                    .map(|threshold| threshold as f64 / 100f64),
+            )),
+        ),
+        ')',
+    )
+    .parse_next(i)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strsim_algorithm_score() {
+        use StrsimAlgorithm::*;
+
+        for algorithm in
+            [Levenshtein, JaroWinkler, SorensenDice, TokenSet]
+        {
+            assert_eq!(algorithm.score("Ada", "Ada"), 1.0);
+        }
+
+        assert_eq!(
+            TokenSet.score("Lovelace, Ada", "Ada Lovelace"),
+            1.0
+        );
+        assert_eq!(TokenSet.score("Ada Lovelace", "Lovelace"), 1.0);
+        assert!(TokenSet.score("Ada", "Lovelace") < 0.5);
+        assert!(
+            Levenshtein.score("Lovelace, Ada", "Ada Lovelace") < 0.8
+        );
+        assert!(
+            JaroWinkler.score("Lovelace, Ada", "Lovelace, A.") > 0.9
+        );
+        assert!(
+            SorensenDice.score("Lovelace, Ada", "Lovelace, Bda") > 0.8
+        );
+    }
+
+    #[test]
+    fn test_parse_strsim_spec() {
+        use StrsimAlgorithm::*;
+
+        macro_rules! parse_success {
+            ($i:expr, $o:expr) => {
+                assert_eq!(
+                    parse_strsim_spec.parse($i.as_bytes()).unwrap(),
+                    $o
+                );
+            };
+        }
+
+        parse_success!("(levenshtein)", (Levenshtein, None));
+        parse_success!("(jaro-winkler)", (JaroWinkler, None));
+        parse_success!("(sorensen-dice)", (SorensenDice, None));
+        parse_success!("(token-set)", (TokenSet, None));
+        parse_success!("(token-set, 90)", (TokenSet, Some(0.9)));
+        parse_success!(
+            "( jaro-winkler,95 )",
+            (JaroWinkler, Some(0.95))
+        );
+
+        assert!(parse_strsim_spec.parse(b"(foo)").is_err());
+        assert!(parse_strsim_spec.parse(b"(token-set, 101)").is_err());
+        assert!(parse_strsim_spec.parse(b"(token-set,)").is_err());
+    }
+}
@@ -11,13 +11,19 @@ use winnow::prelude::*;
 use winnow::stream::{AsChar, Compare, Stream, StreamIsPartial};
 use winnow::token::{one_of, take, take_till};
 
-use crate::matcher::shared::ws0;
+use crate::matcher::shared::{Cast, ws0};
 
 #[derive(Debug, PartialEq, PartialOrd, Clone)]
 pub(crate) enum Value {
     String(BString),
     Char(u8),
     U32(u32),
+    Int(i64),
+    Decimal(f64),
+    /// A date in the form `YYYYMMDD`.
+    Date(u32),
+    /// A timestamp in the form `YYYYMMDDhhmmssf`.
+    Timestamp(u64),
 }
 
 impl From<u32> for Value {
@@ -66,7 +72,9 @@ impl PartialEq<Value> for &[u8] {
     fn eq(&self, other: &Value) -> bool {
         match other {
             Value::String(value) => self == value,
-            _ => false,
+            _ => Cast::of(other)
+                .and_then(|cast| cast.convert(self))
+                .is_some_and(|value| value == *other),
         }
     }
 }
@@ -92,12 +100,14 @@ impl PartialOrd<Value> for u32 {
 }
 
 impl PartialOrd<Value> for &[u8] {
+    /// Compares a byte string with a string value or, after converting
+    /// the byte string into the type of the value, with a typed value.
+    /// Returns `None`, if the byte string can't be converted.
     fn partial_cmp(&self, other: &Value) -> Option<Ordering> {
-        let Value::String(other) = other else {
-            return None;
-        };
-
-        self.partial_cmp(other)
+        match other {
+            Value::String(value) => self.partial_cmp(value),
+            _ => Cast::of(other)?.convert(self)?.partial_cmp(other),
+        }
     }
 }
 
@@ -1,12 +1,10 @@
 use smallvec::SmallVec;
-use winnow::combinator::{empty, seq};
 use winnow::prelude::*;
 
 use crate::Subfield;
 use crate::matcher::shared::{
-    ComparisonOperator, Quantifier, Value, parse_codes,
-    parse_comparison_operator, parse_quantifier_opt,
-    parse_string_value, ws1,
+    ComparisonOperator, Folding, Quantifier, Value, parse_codes,
+    parse_comparison, parse_quantifier_opt,
 };
 use crate::matcher::{MatchOptions, SubfieldMatcher};
 
@@ -15,6 +13,7 @@ pub struct ComparisonMatcher {
     pub(crate) quantifier: Quantifier,
     pub(crate) codes: SmallVec<[u8; 4]>,
     pub(crate) operator: ComparisonOperator,
+    pub(crate) folding: Folding,
     pub(crate) value: Value,
 }
 
@@ -22,22 +21,19 @@ impl ComparisonMatcher {
     pub fn is_match<'a, S: IntoIterator<Item = &'a Subfield<'a>>>(
         &self,
         subfields: S,
-        _options: &MatchOptions,
+        options: &MatchOptions,
     ) -> bool {
+        let folding = self.folding | options.folding;
         let mut subfields = subfields
             .into_iter()
             .filter(|subfield| self.codes.contains(subfield.code()));
 
         let r#fn = |subfield: &Subfield| -> bool {
-            let value = subfield.value();
-            match self.operator {
-                ComparisonOperator::Eq => value == self.value,
-                ComparisonOperator::Ne => value != self.value,
-                ComparisonOperator::Ge => value >= self.value,
-                ComparisonOperator::Gt => value > self.value,
-                ComparisonOperator::Le => value <= self.value,
-                ComparisonOperator::Lt => value < self.value,
-            }
+            self.operator.compare_folded(
+                subfield.value(),
+                &self.value,
+                folding,
+            )
         };
 
         match self.quantifier {
@@ -50,27 +46,33 @@ impl ComparisonMatcher {
 pub(crate) fn parse_comparison_matcher_short(
     i: &mut &[u8],
 ) -> ModalResult<SubfieldMatcher> {
-    seq! { ComparisonMatcher {
-        quantifier: empty.value(Quantifier::Any),
-        codes: parse_codes.map(SmallVec::from),
-        operator: ws1( parse_comparison_operator ),
-        value: parse_string_value,
-    }}
-    .map(|m| SubfieldMatcher::Comparison(Box::new(m)))
-    .parse_next(i)
+    parse_comparison(parse_codes)
+        .map(|(codes, operator, folding, value)| ComparisonMatcher {
+            quantifier: Quantifier::Any,
+            codes: SmallVec::from(codes),
+            operator,
+            folding,
+            value,
+        })
+        .map(|m| SubfieldMatcher::Comparison(Box::new(m)))
+        .parse_next(i)
 }
 
 pub(crate) fn parse_comparison_matcher_long(
     i: &mut &[u8],
 ) -> ModalResult<SubfieldMatcher> {
-    seq! { ComparisonMatcher {
-        quantifier: parse_quantifier_opt,
-        codes: parse_codes.map(SmallVec::from),
-        operator: ws1( parse_comparison_operator ),
-        value: parse_string_value,
-    }}
-    .map(|m| SubfieldMatcher::Comparison(Box::new(m)))
-    .parse_next(i)
+    (parse_quantifier_opt, parse_comparison(parse_codes))
+        .map(|(quantifier, (codes, operator, folding, value))| {
+            ComparisonMatcher {
+                quantifier,
+                codes: SmallVec::from(codes),
+                operator,
+                folding,
+                value,
+            }
+        })
+        .map(|m| SubfieldMatcher::Comparison(Box::new(m)))
+        .parse_next(i)
 }
 
 #[cfg(test)]
@@ -104,6 +106,21 @@ mod tests {
         let matcher = RecordMatcher::new("079.u <= 'w'")?;
         assert!(matcher.is_match(&record, &options));
 
+        let matcher = RecordMatcher::new("042.a ==i 'GND1'")?;
+        assert!(matcher.is_match(&record, &options));
+
+        let matcher = RecordMatcher::new("042.a !=i 'GND1'")?;
+        assert!(!matcher.is_match(&record, &options));
+
+        let matcher = RecordMatcher::new("040.d < 1900")?;
+        assert!(matcher.is_match(&record, &options));
+
+        let matcher = RecordMatcher::new("040{ decimal(d) == 1841 }")?;
+        assert!(matcher.is_match(&record, &options));
+
+        let matcher = RecordMatcher::new("065{ ALL decimal(a) > 0 }")?;
+        assert!(!matcher.is_match(&record, &options));
+
         Ok(())
     }
 
@@ -124,12 +141,16 @@ mod tests {
             };
         }
 
-        parse_success!("a == 'foo'", ComparisonMatcher { quantifier: Any, codes: SmallVec::from(vec![b'a']), operator: Eq, value: "foo".into() });
-        parse_success!("a != 'foo'", ComparisonMatcher { quantifier: Any, codes: SmallVec::from(vec![b'a']), operator: Ne, value: "foo".into() });
-        parse_success!("a >= 'foo'", ComparisonMatcher { quantifier: Any, codes: SmallVec::from(vec![b'a']), operator: Ge, value: "foo".into() });
-        parse_success!("a > 'foo'", ComparisonMatcher { quantifier: Any,  codes: SmallVec::from(vec![b'a']), operator: Gt, value: "foo".into() });
-        parse_success!("a <= 'foo'", ComparisonMatcher { quantifier: Any, codes: SmallVec::from(vec![b'a']), operator: Le, value: "foo".into() });
-        parse_success!("a < 'foo'", ComparisonMatcher { quantifier: Any,  codes: SmallVec::from(vec![b'a']), operator: Lt, value: "foo".into() });
+        parse_success!("a == 'foo'", ComparisonMatcher { quantifier: Any, codes: SmallVec::from(vec![b'a']), operator: Eq, folding: Folding::default(), value: "foo".into() });
+        parse_success!("a != 'foo'", ComparisonMatcher { quantifier: Any, codes: SmallVec::from(vec![b'a']), operator: Ne, folding: Folding::default(), value: "foo".into() });
+        parse_success!("a >= 'foo'", ComparisonMatcher { quantifier: Any, codes: SmallVec::from(vec![b'a']), operator: Ge, folding: Folding::default(), value: "foo".into() });
+        parse_success!("a > 'foo'", ComparisonMatcher { quantifier: Any,  codes: SmallVec::from(vec![b'a']), operator: Gt, folding: Folding::default(), value: "foo".into() });
+        parse_success!("a <= 'foo'", ComparisonMatcher { quantifier: Any, codes: SmallVec::from(vec![b'a']), operator: Le, folding: Folding::default(), value: "foo".into() });
+        parse_success!("a < 'foo'", ComparisonMatcher { quantifier: Any,  codes: SmallVec::from(vec![b'a']), operator: Lt, folding: Folding::default(), value: "foo".into() });
+        parse_success!("a ==i 'foo'", ComparisonMatcher { quantifier: Any, codes: SmallVec::from(vec![b'a']), operator: Eq, folding: Folding { case: true, ..Default::default() }, value: "foo".into() });
+        parse_success!("a >= 1990", ComparisonMatcher { quantifier: Any, codes: SmallVec::from(vec![b'a']), operator: Ge, folding: Folding::default(), value: Value::Int(1990) });
+        parse_success!("int(a) > '1990'", ComparisonMatcher { quantifier: Any, codes: SmallVec::from(vec![b'a']), operator: Gt, folding: Folding::default(), value: Value::Int(1990) });
+        parse_success!("date([ab]) < 2024-01-01", ComparisonMatcher { quantifier: Any, codes: SmallVec::from(vec![b'a', b'b']), operator: Lt, folding: Folding::default(), value: Value::Date(20240101) });
     }
 
     #[test]
@@ -149,25 +170,27 @@ mod tests {
             };
         }
 
-        parse_success!("a == 'foo'", ComparisonMatcher { quantifier: Any, codes: SmallVec::from(vec![b'a']), operator: Eq, value: "foo".into() });
-        parse_success!("a != 'foo'", ComparisonMatcher { quantifier: Any, codes: SmallVec::from(vec![b'a']), operator: Ne, value: "foo".into() });
-        parse_success!("a >= 'foo'", ComparisonMatcher { quantifier: Any, codes: SmallVec::from(vec![b'a']), operator: Ge, value: "foo".into() });
-        parse_success!("a > 'foo'", ComparisonMatcher { quantifier: Any,  codes: SmallVec::from(vec![b'a']), operator: Gt, value: "foo".into() });
-        parse_success!("a <= 'foo'", ComparisonMatcher { quantifier: Any, codes: SmallVec::from(vec![b'a']), operator: Le, value: "foo".into() });
-        parse_success!("a < 'foo'", ComparisonMatcher { quantifier: Any,  codes: SmallVec::from(vec![b'a']), operator: Lt, value: "foo".into() });
-
-        parse_success!("ANY a == 'foo'", ComparisonMatcher { quantifier: Any, codes: SmallVec::from(vec![b'a']), operator: Eq, value: "foo".into() });
-        parse_success!("ANY a != 'foo'", ComparisonMatcher { quantifier: Any, codes: SmallVec::from(vec![b'a']), operator: Ne, value: "foo".into() });
-        parse_success!("ANY a >= 'foo'", ComparisonMatcher { quantifier: Any, codes: SmallVec::from(vec![b'a']), operator: Ge, value: "foo".into() });
-        parse_success!("ANY a > 'foo'", ComparisonMatcher { quantifier: Any,  codes: SmallVec::from(vec![b'a']), operator: Gt, value: "foo".into() });
-        parse_success!("ANY a <= 'foo'", ComparisonMatcher { quantifier: Any, codes: SmallVec::from(vec![b'a']), operator: Le, value: "foo".into() });
-        parse_success!("ANY a < 'foo'", ComparisonMatcher { quantifier: Any,  codes: SmallVec::from(vec![b'a']), operator: Lt, value: "foo".into() });
-
-        parse_success!("ALL a == 'foo'", ComparisonMatcher { quantifier: All, codes: SmallVec::from(vec![b'a']), operator: Eq, value: "foo".into() });
-        parse_success!("ALL a != 'foo'", ComparisonMatcher { quantifier: All, codes: SmallVec::from(vec![b'a']), operator: Ne, value: "foo".into() });
-        parse_success!("ALL a >= 'foo'", ComparisonMatcher { quantifier: All, codes: SmallVec::from(vec![b'a']), operator: Ge, value: "foo".into() });
-        parse_success!("ALL a > 'foo'", ComparisonMatcher { quantifier: All,  codes: SmallVec::from(vec![b'a']), operator: Gt, value: "foo".into() });
-        parse_success!("ALL a <= 'foo'", ComparisonMatcher { quantifier: All, codes: SmallVec::from(vec![b'a']), operator: Le, value: "foo".into() });
-        parse_success!("ALL a < 'foo'", ComparisonMatcher { quantifier: All,  codes: SmallVec::from(vec![b'a']), operator: Lt, value: "foo".into() });
+        parse_success!("a == 'foo'", ComparisonMatcher { quantifier: Any, codes: SmallVec::from(vec![b'a']), operator: Eq, folding: Folding::default(), value: "foo".into() });
+        parse_success!("a != 'foo'", ComparisonMatcher { quantifier: Any, codes: SmallVec::from(vec![b'a']), operator: Ne, folding: Folding::default(), value: "foo".into() });
+        parse_success!("a >= 'foo'", ComparisonMatcher { quantifier: Any, codes: SmallVec::from(vec![b'a']), operator: Ge, folding: Folding::default(), value: "foo".into() });
+        parse_success!("a > 'foo'", ComparisonMatcher { quantifier: Any,  codes: SmallVec::from(vec![b'a']), operator: Gt, folding: Folding::default(), value: "foo".into() });
+        parse_success!("a <= 'foo'", ComparisonMatcher { quantifier: Any, codes: SmallVec::from(vec![b'a']), operator: Le, folding: Folding::default(), value: "foo".into() });
+        parse_success!("a < 'foo'", ComparisonMatcher { quantifier: Any,  codes: SmallVec::from(vec![b'a']), operator: Lt, folding: Folding::default(), value: "foo".into() });
+
+        parse_success!("ANY a == 'foo'", ComparisonMatcher { quantifier: Any, codes: SmallVec::from(vec![b'a']), operator: Eq, folding: Folding::default(), value: "foo".into() });
+        parse_success!("ANY a != 'foo'", ComparisonMatcher { quantifier: Any, codes: SmallVec::from(vec![b'a']), operator: Ne, folding: Folding::default(), value: "foo".into() });
+        parse_success!("ANY a >= 'foo'", ComparisonMatcher { quantifier: Any, codes: SmallVec::from(vec![b'a']), operator: Ge, folding: Folding::default(), value: "foo".into() });
+        parse_success!("ANY a > 'foo'", ComparisonMatcher { quantifier: Any,  codes: SmallVec::from(vec![b'a']), operator: Gt, folding: Folding::default(), value: "foo".into() });
+        parse_success!("ANY a <= 'foo'", ComparisonMatcher { quantifier: Any, codes: SmallVec::from(vec![b'a']), operator: Le, folding: Folding::default(), value: "foo".into() });
+        parse_success!("ANY a < 'foo'", ComparisonMatcher { quantifier: Any,  codes: SmallVec::from(vec![b'a']), operator: Lt, folding: Folding::default(), value: "foo".into() });
+
+        parse_success!("ALL a == 'foo'", ComparisonMatcher { quantifier: All, codes: SmallVec::from(vec![b'a']), operator: Eq, folding: Folding::default(), value: "foo".into() });
+        parse_success!("ALL a != 'foo'", ComparisonMatcher { quantifier: All, codes: SmallVec::from(vec![b'a']), operator: Ne, folding: Folding::default(), value: "foo".into() });
+        parse_success!("ALL a >= 'foo'", ComparisonMatcher { quantifier: All, codes: SmallVec::from(vec![b'a']), operator: Ge, folding: Folding::default(), value: "foo".into() });
+        parse_success!("ALL a > 'foo'", ComparisonMatcher { quantifier: All,  codes: SmallVec::from(vec![b'a']), operator: Gt, folding: Folding::default(), value: "foo".into() });
+        parse_success!("ALL a <= 'foo'", ComparisonMatcher { quantifier: All, codes: SmallVec::from(vec![b'a']), operator: Le, folding: Folding::default(), value: "foo".into() });
+        parse_success!("ALL a < 'foo'", ComparisonMatcher { quantifier: All,  codes: SmallVec::from(vec![b'a']), operator: Lt, folding: Folding::default(), value: "foo".into() });
+
+        parse_success!("ALL decimal(a) <= 9.5", ComparisonMatcher { quantifier: All, codes: SmallVec::from(vec![b'a']), operator: Le, folding: Folding::default(), value: Value::Decimal(9.5) });
     }
 }
@@ -1,5 +1,6 @@
 use smallvec::SmallVec;
-use winnow::combinator::{alt, empty, seq};
+use winnow::ascii::multispace1;
+use winnow::combinator::{alt, empty, preceded, seq, terminated};
 use winnow::prelude::*;
 
 use crate::Subfield;
@@ -10,7 +11,8 @@ use crate::matcher::{MatchOptions, SubfieldMatcher};
 pub struct MemberMatcher {
     pub(crate) quantifier: Quantifier,
     pub(crate) codes: SmallVec<[u8; 4]>,
-    pub(crate) values: Vec<Vec<u8>>,
+    pub(crate) folding: Folding,
+    pub(crate) values: ValueList,
     pub(crate) negated: bool,
 }
 
@@ -18,17 +20,20 @@ impl MemberMatcher {
     pub fn is_match<'a, S: IntoIterator<Item = &'a Subfield<'a>>>(
         &self,
         subfields: S,
-        _options: &MatchOptions,
+        options: &MatchOptions,
     ) -> bool {
+        let folding = self.folding | options.folding;
         let mut subfields = subfields
             .into_iter()
             .filter(|subfield| self.codes.contains(subfield.code()));
 
         let r#fn = |subfield: &Subfield| -> bool {
-            let lhs = subfield.value();
+            let result =
+                self.values.contains(subfield.value(), folding);
+
             match self.negated {
-                true => !self.values.iter().any(|value| lhs == value),
-                false => self.values.iter().any(|value| lhs == value),
+                true => !result,
+                false => result,
             }
         };
 
@@ -45,8 +50,9 @@ pub(crate) fn parse_member_matcher_short(
     seq! { MemberMatcher {
         quantifier: empty.value(Quantifier::Any),
         codes: parse_codes.map(SmallVec::from),
-        negated: ws1(alt(("in".value(false), "not in".value(true)))),
-        values: parse_byte_string_list,
+        negated: preceded(multispace1, alt(("in".value(false), "not in".value(true)))),
+        folding: terminated(parse_folding, multispace1),
+        values: parse_value_list,
     }}
     .map(|m| SubfieldMatcher::Member(Box::new(m)))
     .parse_next(i)
@@ -58,8 +64,9 @@ pub(crate) fn parse_member_matcher_long(
     seq! { MemberMatcher {
         quantifier: parse_quantifier_opt,
         codes: parse_codes.map(SmallVec::from),
-        negated: ws1(alt(("in".value(false), "not in".value(true)))),
-        values: parse_byte_string_list,
+        negated: preceded(multispace1, alt(("in".value(false), "not in".value(true)))),
+        folding: terminated(parse_folding, multispace1),
+        values: parse_value_list,
     }}
     .map(|m| SubfieldMatcher::Member(Box::new(m)))
     .parse_next(i)
@@ -73,7 +80,9 @@ mod tests {
     #[rustfmt::skip]
     fn test_parse_member_matcher_short() {
         macro_rules! parse_success {
-            ($i:expr, $codes:expr, $negated:expr, $values:expr) => {
+            (
+                $i:expr, $codes:expr, $negated:expr, $values:expr $(,)?
+            ) => {
                 assert_eq!(
                     parse_member_matcher_short
                         .parse($i.as_bytes())
@@ -81,26 +90,50 @@ mod tests {
                     SubfieldMatcher::Member(Box::new(MemberMatcher {
                         quantifier: Quantifier::Any,
                         codes: SmallVec::from($codes),
+                        folding: Folding::default(),
                         negated: $negated,
-                        values: $values,
+                        values: ValueList::inline($values),
                     }))
                 )
             };
         }
 
-        parse_success!("a in ['A']", vec![b'a'], false, vec![b"A".into()]);
-        parse_success!("a not in ['A']", vec![b'a'], true, vec![b"A".into()]);
-        parse_success!("a in ['A', 'B']", vec![b'a'], false, vec![b"A".into(), b"B".into()]);
-        parse_success!("a in ['A', 'B', ]", vec![b'a'], false, vec![b"A".into(), b"B".into()]);
+        parse_success!(
+            "a in ['A']",
+            vec![b'a'],
+            false,
+            vec![b"A".into()],
+        );
+        parse_success!(
+            "a not in ['A']",
+            vec![b'a'],
+            true,
+            vec![b"A".into()],
+        );
+        parse_success!(
+            "a in ['A', 'B']",
+            vec![b'a'],
+            false,
+            vec![b"A".into(), b"B".into()],
+        );
+        parse_success!(
+            "a in ['A', 'B', ]",
+            vec![b'a'],
+            false,
+            vec![b"A".into(), b"B".into()],
+        );
     }
 
     #[test]
     #[rustfmt::skip]
     fn test_parse_member_matcher_long() {
         use Quantifier::*;
-        
+
         macro_rules! parse_success {
-            ($i:expr, $quantifier:expr, $codes:expr, $negated:expr, $values:expr) => {
+            (
+                $i:expr, $quantifier:expr, $codes:expr, $negated:expr,
+                $values:expr $(,)?
+            ) => {
                 assert_eq!(
                     parse_member_matcher_long
                         .parse($i.as_bytes())
@@ -108,26 +141,99 @@ mod tests {
                     SubfieldMatcher::Member(Box::new(MemberMatcher {
                         quantifier: $quantifier,
                         codes: SmallVec::from($codes),
+                        folding: Folding::default(),
                         negated: $negated,
-                        values: $values,
+                        values: ValueList::inline($values),
                     }))
                 )
             };
         }
 
-        parse_success!("a in ['A']", Any,vec![b'a'], false, vec![b"A".into()]);
-        parse_success!("a not in ['A']", Any, vec![b'a'], true, vec![b"A".into()]);
-        parse_success!("a in ['A', 'B']", Any, vec![b'a'], false, vec![b"A".into(), b"B".into()]);
-        parse_success!("a in ['A', 'B', ]", Any, vec![b'a'], false, vec![b"A".into(), b"B".into()]);
+        parse_success!(
+            "a in ['A']",
+            Any,
+            vec![b'a'],
+            false,
+            vec![b"A".into()],
+        );
+        parse_success!(
+            "a not in ['A']",
+            Any,
+            vec![b'a'],
+            true,
+            vec![b"A".into()],
+        );
+        parse_success!(
+            "a in ['A', 'B']",
+            Any,
+            vec![b'a'],
+            false,
+            vec![b"A".into(), b"B".into()],
+        );
+        parse_success!(
+            "a in ['A', 'B', ]",
+            Any,
+            vec![b'a'],
+            false,
+            vec![b"A".into(), b"B".into()],
+        );
 
-        parse_success!("ANY a in ['A']", Any, vec![b'a'], false, vec![b"A".into()]);
-        parse_success!("ANY a not in ['A']", Any, vec![b'a'], true, vec![b"A".into()]);
-        parse_success!("ANY a in ['A', 'B']", Any, vec![b'a'], false, vec![b"A".into(), b"B".into()]);
-        parse_success!("ANY a in ['A', 'B', ]", Any, vec![b'a'], false, vec![b"A".into(), b"B".into()]);
+        parse_success!(
+            "ANY a in ['A']",
+            Any,
+            vec![b'a'],
+            false,
+            vec![b"A".into()],
+        );
+        parse_success!(
+            "ANY a not in ['A']",
+            Any,
+            vec![b'a'],
+            true,
+            vec![b"A".into()],
+        );
+        parse_success!(
+            "ANY a in ['A', 'B']",
+            Any,
+            vec![b'a'],
+            false,
+            vec![b"A".into(), b"B".into()],
+        );
+        parse_success!(
+            "ANY a in ['A', 'B', ]",
+            Any,
+            vec![b'a'],
+            false,
+            vec![b"A".into(), b"B".into()],
+        );
 
-        parse_success!("ALL a in ['A']", All, vec![b'a'], false, vec![b"A".into()]);
-        parse_success!("ALL a not in ['A']", All, vec![b'a'], true, vec![b"A".into()]);
-        parse_success!("ALL a in ['A', 'B']", All, vec![b'a'], false, vec![b"A".into(), b"B".into()]);
-        parse_success!("ALL a in ['A', 'B', ]", All, vec![b'a'], false, vec![b"A".into(), b"B".into()]);
+        parse_success!(
+            "ALL a in ['A']",
+            All,
+            vec![b'a'],
+            false,
+            vec![b"A".into()],
+        );
+        parse_success!(
+            "ALL a not in ['A']",
+            All,
+            vec![b'a'],
+            true,
+            vec![b"A".into()],
+        );
+        parse_success!(
+            "ALL a in ['A', 'B']",
+            All,
+            vec![b'a'],
+            false,
+            vec![b"A".into(), b"B".into()],
+        );
+        parse_success!(
+            "ALL a in ['A', 'B', ]",
+            All,
+            vec![b'a'],
+            false,
+            vec![b"A".into(), b"B".into()],
+        );
     }
 }
@@ -13,7 +13,7 @@ mod substr;
 mod suffix;
 
 use crate::Subfield;
-use crate::matcher::shared::BooleanOp;
+use crate::matcher::shared::{BooleanOp, clear_value_files};
 use crate::matcher::subfield::comparison::ComparisonMatcher;
 use crate::matcher::subfield::count::CountMatcher;
 use crate::matcher::subfield::exists::ExistsMatcher;
@@ -94,9 +94,12 @@ impl SubfieldMatcher {
     pub fn new<B: AsRef<[u8]>>(
         bytes: B,
     ) -> Result<Self, ParseMatcherError> {
-        parse_subfield_matcher_long
+        let result = parse_subfield_matcher_long
             .parse(bytes.as_ref())
-            .map_err(ParseMatcherError::from_parse)
+            .map_err(ParseMatcherError::from_parse);
+
+        clear_value_files();
+        result
     }
 
     /// Whether the given subfields matches against the matcher or not.
@@ -182,7 +182,7 @@ mod test {
 
     use super::*;
     use crate::matcher::shared::{
-        BooleanOp, ComparisonOperator, Quantifier,
+        BooleanOp, ComparisonOperator, Folding, Quantifier, ValueList,
     };
     use crate::matcher::subfield::comparison::ComparisonMatcher;
     use crate::matcher::subfield::count::CountMatcher;
@@ -211,6 +211,7 @@ mod test {
             SubfieldMatcher::Comparison(Box::new(ComparisonMatcher {
                 quantifier: Quantifier::Any,
                 codes: SmallVec::from(vec![b'a']),
+                folding: Folding::default(),
                 operator: ComparisonOperator::Eq,
                 value: "foo".into()
             }))
@@ -221,6 +222,7 @@ mod test {
             SubfieldMatcher::Comparison(Box::new(ComparisonMatcher {
                 quantifier: Quantifier::Any,
                 codes: SmallVec::from(vec![b'b', b'c']),
+                folding: Folding::default(),
                 operator: ComparisonOperator::Ne,
                 value: "foo".into()
             }))
@@ -241,6 +243,7 @@ mod test {
             SubfieldMatcher::Prefix(Box::new(PrefixMatcher {
                 quantifier: Quantifier::Any,
                 codes: SmallVec::from(vec![b'a']),
+                folding: Folding::default(),
                 negated: false,
                 prefixes: vec![b"foo".into()],
             }))
@@ -252,6 +255,7 @@ mod test {
             SubfieldMatcher::Suffix(Box::new(SuffixMatcher {
                 quantifier: Quantifier::Any,
                 codes: SmallVec::from(vec![b'a']),
+                folding: Folding::default(),
                 negated: false,
                 suffixes: vec![b"foo".into()],
             }))
@@ -263,8 +267,12 @@ mod test {
             SubfieldMatcher::Member(Box::new(MemberMatcher {
                 quantifier: Quantifier::Any,
                 codes: SmallVec::from(vec![b'a']),
+                folding: Folding::default(),
                 negated: false,
-                values: vec![b"foo".into(), b"bar".into()],
+                values: ValueList::inline(vec![
+                    b"foo".into(),
+                    b"bar".into()
+                ]),
             }))
         );
 
@@ -273,8 +281,12 @@ mod test {
             SubfieldMatcher::Member(Box::new(MemberMatcher {
                 quantifier: Quantifier::Any,
                 codes: SmallVec::from(vec![b'a']),
+                folding: Folding::default(),
                 negated: true,
-                values: vec![b"foo".into(), b"bar".into()],
+                values: ValueList::inline(vec![
+                    b"foo".into(),
+                    b"bar".into()
+                ]),
             }))
         );
 
@@ -284,6 +296,7 @@ mod test {
             SubfieldMatcher::Substr(Box::new(SubstrMatcher {
                 quantifier: Quantifier::Any,
                 codes: SmallVec::from(vec![b'a']),
+                folding: Folding::default(),
                 negated: false,
                 patterns: vec![b"foo".into()],
                 ac: AhoCorasick::new([b"foo"]).unwrap(),
@@ -308,6 +321,9 @@ mod test {
             SubfieldMatcher::Similarity(Box::new(SimilarityMatcher {
                 quantifier: Quantifier::Any,
                 codes: SmallVec::from(vec![b'a']),
+                folding: Folding::default(),
+                algorithm: None,
+                threshold: None,
                 negated: false,
                 patterns: vec!["foo".into()],
             }))
@@ -352,6 +368,7 @@ mod test {
             SubfieldMatcher::Comparison(Box::new(ComparisonMatcher {
                 quantifier: Quantifier::Any,
                 codes: SmallVec::from(vec![b'a']),
+                folding: Folding::default(),
                 operator: ComparisonOperator::Eq,
                 value: "foo".into()
             }))
@@ -362,6 +379,7 @@ mod test {
             SubfieldMatcher::Comparison(Box::new(ComparisonMatcher {
                 quantifier: Quantifier::Any,
                 codes: SmallVec::from(vec![b'b', b'c']),
+                folding: Folding::default(),
                 operator: ComparisonOperator::Ne,
                 value: "foo".into()
             }))
@@ -373,6 +391,7 @@ mod test {
             SubfieldMatcher::Prefix(Box::new(PrefixMatcher {
                 quantifier: Quantifier::Any,
                 codes: SmallVec::from(vec![b'a']),
+                folding: Folding::default(),
                 negated: false,
                 prefixes: vec![b"foo".into()],
             }))
@@ -384,6 +403,7 @@ mod test {
             SubfieldMatcher::Suffix(Box::new(SuffixMatcher {
                 quantifier: Quantifier::Any,
                 codes: SmallVec::from(vec![b'a']),
+                folding: Folding::default(),
                 negated: false,
                 suffixes: vec![b"foo".into()],
             }))
@@ -395,8 +415,12 @@ mod test {
             SubfieldMatcher::Member(Box::new(MemberMatcher {
                 quantifier: Quantifier::Any,
                 codes: SmallVec::from(vec![b'a']),
+                folding: Folding::default(),
                 negated: false,
-                values: vec![b"foo".into(), b"bar".into()],
+                values: ValueList::inline(vec![
+                    b"foo".into(),
+                    b"bar".into()
+                ]),
             }))
         );
 
@@ -405,8 +429,12 @@ mod test {
             SubfieldMatcher::Member(Box::new(MemberMatcher {
                 quantifier: Quantifier::Any,
                 codes: SmallVec::from(vec![b'a']),
+                folding: Folding::default(),
                 negated: true,
-                values: vec![b"foo".into(), b"bar".into()],
+                values: ValueList::inline(vec![
+                    b"foo".into(),
+                    b"bar".into()
+                ]),
             }))
         );
 
@@ -416,6 +444,7 @@ mod test {
             SubfieldMatcher::Substr(Box::new(SubstrMatcher {
                 quantifier: Quantifier::Any,
                 codes: SmallVec::from(vec![b'a']),
+                folding: Folding::default(),
                 negated: false,
                 patterns: vec![b"foo".into(), b"bar".into()],
                 ac: AhoCorasick::new([b"foo", b"bar"]).unwrap(),
@@ -440,6 +469,9 @@ mod test {
             SubfieldMatcher::Similarity(Box::new(SimilarityMatcher {
                 quantifier: Quantifier::Any,
                 codes: SmallVec::from(vec![b'a']),
+                folding: Folding::default(),
+                algorithm: None,
+                threshold: None,
                 negated: false,
                 patterns: vec!["foo".into()],
             }))
@@ -564,6 +596,7 @@ mod test {
             SubfieldMatcher::Comparison(Box::new(ComparisonMatcher {
                 quantifier: Quantifier::Any,
                 codes: SmallVec::from(vec![b'x']),
+                folding: Folding::default(),
                 operator: ComparisonOperator::Ne,
                 value: "".into(),
             }))
@@ -574,6 +607,7 @@ mod test {
             SubfieldMatcher::Comparison(Box::new(ComparisonMatcher {
                 quantifier: Quantifier::All,
                 codes: SmallVec::from(vec![b'a']),
+                folding: Folding::default(),
                 operator: ComparisonOperator::Ge,
                 value: "bar".into(),
             }))
@@ -604,6 +638,7 @@ mod test {
             SubfieldMatcher::Prefix(Box::new(PrefixMatcher {
                 quantifier: Quantifier::Any,
                 codes: SmallVec::from(vec![b'a']),
+                folding: Folding::default(),
                 negated: false,
                 prefixes: vec![b"foo".into()],
             }))
@@ -615,6 +650,7 @@ mod test {
             SubfieldMatcher::Suffix(Box::new(SuffixMatcher {
                 quantifier: Quantifier::Any,
                 codes: SmallVec::from(vec![b'a']),
+                folding: Folding::default(),
                 negated: false,
                 suffixes: vec![b"foo".into(), b"bar".into()],
             }))
@@ -626,8 +662,12 @@ mod test {
             SubfieldMatcher::Member(Box::new(MemberMatcher {
                 quantifier: Quantifier::All,
                 codes: SmallVec::from(vec![b'a']),
+                folding: Folding::default(),
                 negated: false,
-                values: vec![b"foo".into(), b"bar".into()],
+                values: ValueList::inline(vec![
+                    b"foo".into(),
+                    b"bar".into()
+                ]),
             }))
         );
 
@@ -637,6 +677,7 @@ mod test {
             SubfieldMatcher::Substr(Box::new(SubstrMatcher {
                 quantifier: Quantifier::Any,
                 codes: SmallVec::from(vec![b'a']),
+                folding: Folding::default(),
                 negated: false,
                 patterns: vec![b"foo".into()],
                 ac: AhoCorasick::new([b"foo"]).unwrap(),
@@ -661,6 +702,9 @@ mod test {
             SubfieldMatcher::Similarity(Box::new(SimilarityMatcher {
                 quantifier: Quantifier::Any,
                 codes: SmallVec::from(vec![b'a']),
+                folding: Folding::default(),
+                algorithm: None,
+                threshold: None,
                 negated: false,
                 patterns: vec!["foo".into()],
             }))
@@ -748,6 +792,7 @@ mod test {
                     ComparisonMatcher {
                         quantifier: Quantifier::Any,
                         codes: SmallVec::from(vec![b'x', b'y', b'z']),
+                        folding: Folding::default(),
                         operator: ComparisonOperator::Eq,
                         value: "baz".into(),
                     }
@@ -785,6 +830,7 @@ mod test {
                 Box::new(PrefixMatcher {
                     quantifier: Quantifier::Any,
                     codes: SmallVec::from(vec![b'a']),
+                    folding: Folding::default(),
                     negated: false,
                     prefixes: vec![b"foo".into()],
                 })
@@ -798,6 +844,7 @@ mod test {
                 Box::new(SuffixMatcher {
                     quantifier: Quantifier::Any,
                     codes: SmallVec::from(vec![b'a']),
+                    folding: Folding::default(),
                     negated: false,
                     suffixes: vec![b"foo".into()],
                 })
@@ -811,8 +858,12 @@ mod test {
                 Box::new(MemberMatcher {
                     quantifier: Quantifier::Any,
                     codes: SmallVec::from(vec![b'a']),
+                    folding: Folding::default(),
                     negated: true,
-                    values: vec![b"foo".into(), b"bar".into()],
+                    values: ValueList::inline(vec![
+                        b"foo".into(),
+                        b"bar".into()
+                    ]),
                 })
             )))
         );
@@ -824,6 +875,7 @@ mod test {
                 Box::new(SubstrMatcher {
                     quantifier: Quantifier::Any,
                     codes: SmallVec::from(vec![b'a']),
+                    folding: Folding::default(),
                     negated: false,
                     patterns: vec![b"foo".into()],
                     ac: AhoCorasick::new([b"foo"]).unwrap(),
@@ -853,6 +905,9 @@ mod test {
                     SimilarityMatcher {
                         quantifier: Quantifier::Any,
                         codes: SmallVec::from(vec![b'a']),
+                        folding: Folding::default(),
+                        algorithm: None,
+                        threshold: None,
                         negated: false,
                         patterns: vec!["foo".into()],
                     }
@@ -909,6 +964,7 @@ mod test {
             SubfieldMatcher::Comparison(Box::new(ComparisonMatcher {
                 quantifier: Quantifier::Any,
                 codes: SmallVec::from(vec![b'b']),
+                folding: Folding::default(),
                 operator: ComparisonOperator::Eq,
                 value: "p".into(),
             })),
@@ -916,6 +972,7 @@ mod test {
             SubfieldMatcher::Comparison(Box::new(ComparisonMatcher {
                 quantifier: Quantifier::Any,
                 codes: SmallVec::from(vec![b'2']),
+                folding: Folding::default(),
                 operator: ComparisonOperator::Eq,
                 value: "gndgen".into(),
             }))
@@ -1066,12 +1123,14 @@ mod test {
             SubfieldMatcher::Comparison(Box::new(ComparisonMatcher {
                 quantifier: Quantifier::Any,
                 codes: SmallVec::from(vec![b'a']),
+                folding: Folding::default(),
                 operator: ComparisonOperator::Eq,
                 value: "foo".into(),
             })),
             SubfieldMatcher::Comparison(Box::new(ComparisonMatcher {
                 quantifier: Quantifier::Any,
                 codes: SmallVec::from(vec![b'a']),
+                folding: Folding::default(),
                 operator: ComparisonOperator::Eq,
                 value: "bar".into(),
             }))
@@ -1082,12 +1141,14 @@ mod test {
             SubfieldMatcher::Prefix(Box::new(PrefixMatcher {
                 quantifier: Quantifier::Any,
                 codes: SmallVec::from(vec![b'a']),
+                folding: Folding::default(),
                 negated: false,
                 prefixes: vec!["foo".into()],
             })),
             SubfieldMatcher::Prefix(Box::new(PrefixMatcher {
                 quantifier: Quantifier::Any,
                 codes: SmallVec::from(vec![b'a']),
+                folding: Folding::default(),
                 negated: false,
                 prefixes: vec!["bar".into()],
             }))
@@ -1099,12 +1160,14 @@ mod test {
             SubfieldMatcher::Suffix(Box::new(SuffixMatcher {
                 quantifier: Quantifier::Any,
                 codes: SmallVec::from(vec![b'a']),
+                folding: Folding::default(),
                 negated: false,
                 suffixes: vec![".pdf".into()],
             })),
             SubfieldMatcher::Suffix(Box::new(SuffixMatcher {
                 quantifier: Quantifier::Any,
                 codes: SmallVec::from(vec![b'a']),
+                folding: Folding::default(),
                 negated: false,
                 suffixes: vec![".PDF".into()],
             }))
@@ -1116,14 +1179,16 @@ mod test {
             SubfieldMatcher::Member(Box::new(MemberMatcher {
                 quantifier: Quantifier::Any,
                 codes: SmallVec::from(vec![b'a']),
+                folding: Folding::default(),
                 negated: false,
-                values: vec!["A".into(), "B".into()],
+                values: ValueList::inline(vec!["A".into(), "B".into()]),
             })),
             SubfieldMatcher::Member(Box::new(MemberMatcher {
                 quantifier: Quantifier::Any,
                 codes: SmallVec::from(vec![b'b']),
+                folding: Folding::default(),
                 negated: true,
-                values: vec!["C".into(), "D".into()],
+                values: ValueList::inline(vec!["C".into(), "D".into()]),
             }))
         );
 
@@ -1133,6 +1198,7 @@ mod test {
             SubfieldMatcher::Substr(Box::new(SubstrMatcher {
                 quantifier: Quantifier::Any,
                 codes: SmallVec::from(vec![b'a']),
+                folding: Folding::default(),
                 negated: false,
                 patterns: vec![b"foo".into()],
                 ac: AhoCorasick::new([b"foo"]).unwrap(),
@@ -1140,6 +1206,7 @@ mod test {
             SubfieldMatcher::Substr(Box::new(SubstrMatcher {
                 quantifier: Quantifier::Any,
                 codes: SmallVec::from(vec![b'a']),
+                folding: Folding::default(),
                 negated: false,
                 patterns: vec![b"bar".into()],
                 ac: AhoCorasick::new([b"bar"]).unwrap(),
@@ -1171,12 +1238,18 @@ mod test {
             SubfieldMatcher::Similarity(Box::new(SimilarityMatcher {
                 quantifier: Quantifier::Any,
                 codes: SmallVec::from(vec![b'a']),
+                folding: Folding::default(),
+                algorithm: None,
+                threshold: None,
                 negated: false,
                 patterns: vec!["foo".into()],
             })),
             SubfieldMatcher::Similarity(Box::new(SimilarityMatcher {
                 quantifier: Quantifier::All,
                 codes: SmallVec::from(vec![b'b']),
+                folding: Folding::default(),
+                algorithm: None,
+                threshold: None,
                 negated: true,
                 patterns: vec!["bar".into(), "baz".into()],
             }))
@@ -1281,12 +1354,14 @@ mod test {
             SubfieldMatcher::Comparison(Box::new(ComparisonMatcher {
                 quantifier: Quantifier::Any,
                 codes: SmallVec::from(vec![b'a']),
+                folding: Folding::default(),
                 operator: ComparisonOperator::Eq,
                 value: "foo".into(),
             })),
             SubfieldMatcher::Comparison(Box::new(ComparisonMatcher {
                 quantifier: Quantifier::Any,
                 codes: SmallVec::from(vec![b'b']),
+                folding: Folding::default(),
                 operator: ComparisonOperator::Eq,
                 value: "bar".into(),
             }))
@@ -1297,12 +1372,14 @@ mod test {
             SubfieldMatcher::Prefix(Box::new(PrefixMatcher {
                 quantifier: Quantifier::Any,
                 codes: SmallVec::from(vec![b'a']),
+                folding: Folding::default(),
                 negated: false,
                 prefixes: vec!["foo".into()],
             })),
             SubfieldMatcher::Prefix(Box::new(PrefixMatcher {
                 quantifier: Quantifier::Any,
                 codes: SmallVec::from(vec![b'a']),
+                folding: Folding::default(),
                 negated: false,
                 prefixes: vec!["bar".into()],
             }))
@@ -1314,12 +1391,14 @@ mod test {
             SubfieldMatcher::Suffix(Box::new(SuffixMatcher {
                 quantifier: Quantifier::Any,
                 codes: SmallVec::from(vec![b'a']),
+                folding: Folding::default(),
                 negated: false,
                 suffixes: vec![".pdf".into()],
             })),
             SubfieldMatcher::Suffix(Box::new(SuffixMatcher {
                 quantifier: Quantifier::Any,
                 codes: SmallVec::from(vec![b'a']),
+                folding: Folding::default(),
                 negated: false,
                 suffixes: vec![".PDF".into()],
             }))
@@ -1331,14 +1410,16 @@ mod test {
             SubfieldMatcher::Member(Box::new(MemberMatcher {
                 quantifier: Quantifier::Any,
                 codes: SmallVec::from(vec![b'a']),
+                folding: Folding::default(),
                 negated: false,
-                values: vec!["A".into(), "B".into()],
+                values: ValueList::inline(vec!["A".into(), "B".into()]),
             })),
             SubfieldMatcher::Member(Box::new(MemberMatcher {
                 quantifier: Quantifier::Any,
                 codes: SmallVec::from(vec![b'b']),
+                folding: Folding::default(),
                 negated: true,
-                values: vec!["C".into(), "D".into()],
+                values: ValueList::inline(vec!["C".into(), "D".into()]),
             }))
         );
 
@@ -1348,6 +1429,7 @@ mod test {
             SubfieldMatcher::Substr(Box::new(SubstrMatcher {
                 quantifier: Quantifier::Any,
                 codes: SmallVec::from(vec![b'a']),
+                folding: Folding::default(),
                 negated: false,
                 patterns: vec![b"foo".into()],
                 ac: AhoCorasick::new([b"foo"]).unwrap(),
@@ -1355,6 +1437,7 @@ mod test {
             SubfieldMatcher::Substr(Box::new(SubstrMatcher {
                 quantifier: Quantifier::Any,
                 codes: SmallVec::from(vec![b'a']),
+                folding: Folding::default(),
                 negated: false,
                 patterns: vec![b"bar".into()],
                 ac: AhoCorasick::new([b"bar"]).unwrap(),
@@ -1386,12 +1469,18 @@ mod test {
             SubfieldMatcher::Similarity(Box::new(SimilarityMatcher {
                 quantifier: Quantifier::Any,
                 codes: SmallVec::from(vec![b'a']),
+                folding: Folding::default(),
+                algorithm: None,
+                threshold: None,
                 negated: false,
                 patterns: vec!["foo".into()],
             })),
             SubfieldMatcher::Similarity(Box::new(SimilarityMatcher {
                 quantifier: Quantifier::All,
                 codes: SmallVec::from(vec![b'b']),
+                folding: Folding::default(),
+                algorithm: None,
+                threshold: None,
                 negated: true,
                 patterns: vec!["bar".into(), "baz".into()],
             }))
@@ -1,5 +1,6 @@
 use smallvec::SmallVec;
-use winnow::combinator::{alt, empty, seq};
+use winnow::ascii::multispace1;
+use winnow::combinator::{alt, empty, preceded, seq, terminated};
 use winnow::prelude::*;
 
 use crate::Subfield;
@@ -11,6 +12,7 @@ pub struct PrefixMatcher {
     pub(crate) quantifier: Quantifier,
     pub(crate) negated: bool,
     pub(crate) codes: SmallVec<[u8; 4]>,
+    pub(crate) folding: Folding,
     pub(crate) prefixes: Vec<Vec<u8>>,
 }
 
@@ -18,17 +20,18 @@ impl PrefixMatcher {
     pub fn is_match<'a, S: IntoIterator<Item = &'a Subfield<'a>>>(
         &self,
         subfields: S,
-        _options: &MatchOptions,
+        options: &MatchOptions,
     ) -> bool {
+        let folding = self.folding | options.folding;
         let mut subfields = subfields
             .into_iter()
             .filter(|subfield| self.codes.contains(subfield.code()));
 
         let r#fn = |subfield: &Subfield| -> bool {
-            let result = self
-                .prefixes
-                .iter()
-                .any(|pattern| subfield.value().starts_with(pattern));
+            let value = folding.apply(subfield.value());
+            let result = self.prefixes.iter().any(|pattern| {
+                value.starts_with(&folding.apply(pattern))
+            });
 
             match self.negated {
                 false => result,
@@ -49,7 +52,8 @@ pub(crate) fn parse_prefix_matcher_short(
     seq! { PrefixMatcher {
         quantifier: empty.value(Quantifier::Any),
         codes: parse_codes.map(SmallVec::from),
-        negated: ws1(alt(("=^".value(false), "!^".value(true)))),
+        negated: preceded(multispace1, alt(("=^".value(false), "!^".value(true)))),
+        folding: terminated(parse_folding, multispace1),
         prefixes: parse_byte_string_list,
 
     }}
@@ -63,7 +67,8 @@ pub(crate) fn parse_prefix_matcher_long(
     seq! { PrefixMatcher {
         quantifier: parse_quantifier_opt,
         codes: parse_codes.map(SmallVec::from),
-        negated: ws1(alt(("=^".value(false), "!^".value(true)))),
+        negated: preceded(multispace1, alt(("=^".value(false), "!^".value(true)))),
+        folding: terminated(parse_folding, multispace1),
         prefixes: parse_byte_string_list,
 
     }}
@@ -86,6 +91,7 @@ mod tests {
                         PrefixMatcher {
                             quantifier: Quantifier::Any,
                             codes: SmallVec::from($codes),
+                            folding: Folding::default(),
                             negated: $negated,
                             prefixes: $prefixes,
                         }
@@ -115,6 +121,7 @@ mod tests {
                         PrefixMatcher {
                             quantifier: $quantifier,
                             codes: SmallVec::from($codes),
+                            folding: Folding::default(),
                             negated: $negated,
                             prefixes: $prefixes,
                         }
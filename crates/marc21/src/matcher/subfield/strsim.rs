@@ -1,8 +1,8 @@
 use bstr::ByteSlice;
 use smallvec::SmallVec;
-use strsim::normalized_levenshtein;
+use winnow::ascii::multispace1;
 use winnow::combinator::{
-    alt, delimited, empty, opt, separated, seq, terminated,
+    alt, delimited, opt, preceded, separated, terminated,
 };
 use winnow::prelude::*;
 
@@ -15,6 +15,9 @@ pub struct SimilarityMatcher {
     pub(crate) quantifier: Quantifier,
     pub(crate) negated: bool,
     pub(crate) codes: SmallVec<[u8; 4]>,
+    pub(crate) folding: Folding,
+    pub(crate) algorithm: Option<StrsimAlgorithm>,
+    pub(crate) threshold: Option<f64>,
     pub(crate) patterns: Vec<String>,
 }
 
@@ -24,15 +27,25 @@ impl SimilarityMatcher {
         subfields: S,
         options: &MatchOptions,
     ) -> bool {
+        let folding = self.folding | options.folding;
+        let algorithm =
+            self.algorithm.unwrap_or(options.strsim_algorithm);
+        let threshold = self
+            .threshold
+            .unwrap_or_else(|| options.strsim_threshold_of(algorithm));
+
         let mut subfields = subfields
             .into_iter()
             .filter(|subfield| self.codes.contains(subfield.code()));
 
         let r#fn = |subfield: &Subfield| -> bool {
-            let value = subfield.value().to_str_lossy();
+            let value = folding.apply(subfield.value());
+            let value = value.to_str_lossy();
             self.patterns.iter().any(|pattern| {
-                let result = normalized_levenshtein(&value, pattern)
-                    >= options.strsim_threshold;
+                let pattern = folding.apply(pattern.as_bytes());
+                let result = algorithm
+                    .score(&value, &pattern.to_str_lossy())
+                    >= threshold;
                 if self.negated { !result } else { result }
             })
         };
@@ -44,50 +57,84 @@ impl SimilarityMatcher {
     }
 }
 
-pub(crate) fn parse_strsim_matcher_short(
+fn parse_strsim_operator(
     i: &mut &[u8],
-) -> ModalResult<SubfieldMatcher> {
-    seq! { SimilarityMatcher {
-        quantifier: empty.value(Quantifier::Any),
-        codes: parse_codes.map(SmallVec::from),
-        negated: ws1(alt(("=*".value(false), "!*".value(true)))),
-        patterns: alt((
-            parse_string.map(|pattern| vec![pattern]),
-            delimited(
-                ws0('['),
-                terminated(
-                    separated(1.., parse_string, ws0(',')),
-                    opt(ws0(',')),
-                ),
-                ws0(']'),
+) -> ModalResult<(bool, Folding, Option<StrsimSpec>)> {
+    (
+        preceded(
+            multispace1,
+            alt(("=*".value(false), "!*".value(true))),
+        ),
+        parse_folding,
+        terminated(opt(parse_strsim_spec), multispace1),
+    )
+        .parse_next(i)
+}
+
+fn parse_strsim_patterns(i: &mut &[u8]) -> ModalResult<Vec<String>> {
+    alt((
+        parse_string.map(|pattern| vec![pattern]),
+        delimited(
+            ws0('['),
+            terminated(
+                separated(1.., parse_string, ws0(',')),
+                opt(ws0(',')),
             ),
-        ))
-    }}
-    .map(|m| SubfieldMatcher::Similarity(Box::new(m)))
+            ws0(']'),
+        ),
+    ))
     .parse_next(i)
 }
 
+pub(crate) fn parse_strsim_matcher_short(
+    i: &mut &[u8],
+) -> ModalResult<SubfieldMatcher> {
+    (parse_codes, parse_strsim_operator, parse_strsim_patterns)
+        .map(|(codes, (negated, folding, spec), patterns)| {
+            SimilarityMatcher {
+                quantifier: Quantifier::Any,
+                negated,
+                codes: SmallVec::from(codes),
+                folding,
+                algorithm: spec.map(|(algorithm, _)| algorithm),
+                threshold: spec.and_then(|(_, threshold)| threshold),
+                patterns,
+            }
+        })
+        .map(|m| SubfieldMatcher::Similarity(Box::new(m)))
+        .parse_next(i)
+}
+
 pub(crate) fn parse_strsim_matcher_long(
     i: &mut &[u8],
 ) -> ModalResult<SubfieldMatcher> {
-    seq! { SimilarityMatcher {
-        quantifier: parse_quantifier_opt,
-        codes: parse_codes.map(SmallVec::from),
-        negated: ws1(alt(("=*".value(false), "!*".value(true)))),
-        patterns: alt((
-            parse_string.map(|pattern| vec![pattern]),
-            delimited(
-                ws0('['),
-                terminated(
-                    separated(1.., parse_string, ws0(',')),
-                    opt(ws0(',')),
-                ),
-                ws0(']'),
-            ),
-        ))
-    }}
-    .map(|m| SubfieldMatcher::Similarity(Box::new(m)))
-    .parse_next(i)
+    (
+        parse_quantifier_opt,
+        parse_codes,
+        parse_strsim_operator,
+        parse_strsim_patterns,
+    )
+        .map(
+            |(
+                quantifier,
+                codes,
+                (negated, folding, spec),
+                patterns,
+            )| {
+                SimilarityMatcher {
+                    quantifier,
+                    negated,
+                    codes: SmallVec::from(codes),
+                    folding,
+                    algorithm: spec.map(|(algorithm, _)| algorithm),
+                    threshold: spec
+                        .and_then(|(_, threshold)| threshold),
+                    patterns,
+                }
+            },
+        )
+        .map(|m| SubfieldMatcher::Similarity(Box::new(m)))
+        .parse_next(i)
 }
 
 #[cfg(test)]
@@ -112,6 +159,9 @@ mod tests {
                         SimilarityMatcher {
                             quantifier: Quantifier::Any,
                             codes: SmallVec::from($codes),
+                            folding: Folding::default(),
+                            algorithm: None,
+                            threshold: None,
                             negated: $negated,
                             patterns,
                         }
@@ -148,6 +198,9 @@ mod tests {
                         SimilarityMatcher {
                             quantifier: $quantifier,
                             codes: SmallVec::from($codes),
+                            folding: Folding::default(),
+                            algorithm: None,
+                            threshold: None,
                             negated: $negated,
                             patterns,
                         }
@@ -177,4 +230,46 @@ mod tests {
         parse_success!("ALL a =* ['foo', 'bar',]", All, vec![b'a'], false, vec!["foo", "bar"]);
         parse_success!("ALL a !* ['foo', 'bar',]", All, vec![b'a'], true, vec!["foo", "bar"]);
     }
+
+    #[test]
+    fn test_parse_strsim_matcher_algorithm() {
+        assert_eq!(
+            parse_strsim_matcher_short
+                .parse(b"a =*i(token-set, 90) 'foo'")
+                .unwrap(),
+            SubfieldMatcher::Similarity(Box::new(SimilarityMatcher {
+                quantifier: Quantifier::Any,
+                codes: SmallVec::from(vec![b'a']),
+                folding: Folding {
+                    case: true,
+                    ..Default::default()
+                },
+                algorithm: Some(StrsimAlgorithm::TokenSet),
+                threshold: Some(0.9),
+                negated: false,
+                patterns: vec!["foo".into()],
+            }))
+        );
+
+        assert_eq!(
+            parse_strsim_matcher_long
+                .parse(b"ALL a !*(jaro-winkler) 'foo'")
+                .unwrap(),
+            SubfieldMatcher::Similarity(Box::new(SimilarityMatcher {
+                quantifier: Quantifier::All,
+                codes: SmallVec::from(vec![b'a']),
+                folding: Folding::default(),
+                algorithm: Some(StrsimAlgorithm::JaroWinkler),
+                threshold: None,
+                negated: true,
+                patterns: vec!["foo".into()],
+            }))
+        );
+
+        assert!(
+            parse_strsim_matcher_short
+                .parse(b"a =*(foo) 'foo'")
+                .is_err()
+        );
+    }
 }
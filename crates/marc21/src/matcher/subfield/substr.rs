@@ -1,6 +1,8 @@
 use aho_corasick::AhoCorasick;
+use bstr::ByteSlice;
 use smallvec::SmallVec;
-use winnow::combinator::alt;
+use winnow::ascii::multispace1;
+use winnow::combinator::{alt, preceded, terminated};
 use winnow::error::ParserError;
 use winnow::prelude::*;
 
@@ -14,6 +16,7 @@ pub struct SubstrMatcher {
     pub(crate) quantifier: Quantifier,
     pub(crate) negated: bool,
     pub(crate) codes: SmallVec<[u8; 4]>,
+    pub(crate) folding: Folding,
     pub(crate) patterns: Vec<Vec<u8>>,
 }
 
@@ -22,6 +25,7 @@ impl PartialEq for SubstrMatcher {
         self.quantifier == other.quantifier
             && self.negated == other.negated
             && self.codes == other.codes
+            && self.folding == other.folding
             && self.patterns == other.patterns
     }
 }
@@ -30,16 +34,30 @@ impl SubstrMatcher {
     pub fn is_match<'a, S: IntoIterator<Item = &'a Subfield<'a>>>(
         &self,
         subfields: S,
-        _options: &MatchOptions,
+        options: &MatchOptions,
     ) -> bool {
+        let folding = self.folding | options.folding;
         let mut subfields = subfields
             .into_iter()
             .filter(|subfield| self.codes.contains(subfield.code()));
 
         let r#fn = |subfield: &Subfield| -> bool {
+            let value = folding.apply(subfield.value());
+
+            // The automaton is built from the patterns folded by the
+            // flags of the operator. Additional flags of the options
+            // require to fold the patterns on each comparison.
+            let result = if folding == self.folding {
+                self.ac.is_match(value.as_ref())
+            } else {
+                self.patterns.iter().any(|pattern| {
+                    value.find(folding.apply(pattern)).is_some()
+                })
+            };
+
             match self.negated {
-                false => self.ac.is_match(subfield.value()),
-                true => !self.ac.is_match(subfield.value()),
+                false => result,
+                true => !result,
             }
         };
 
@@ -54,16 +72,24 @@ pub(crate) fn parse_substr_matcher_short(
     i: &mut &[u8],
 ) -> ModalResult<SubfieldMatcher> {
     let codes = parse_codes.map(SmallVec::from).parse_next(i)?;
-    let negated = ws1(alt(("=?".value(false), "!?".value(true))))
-        .parse_next(i)?;
+    let negated = preceded(
+        multispace1,
+        alt(("=?".value(false), "!?".value(true))),
+    )
+    .parse_next(i)?;
+    let folding =
+        terminated(parse_folding, multispace1).parse_next(i)?;
     let patterns = parse_byte_string_list.parse_next(i)?;
 
-    if let Ok(ac) = AhoCorasick::new(&patterns) {
+    if let Ok(ac) = AhoCorasick::new(
+        patterns.iter().map(|pattern| folding.apply(pattern)),
+    ) {
         Ok(SubfieldMatcher::Substr(Box::new(SubstrMatcher {
             ac,
             quantifier: Quantifier::Any,
             negated,
             codes,
+            folding,
             patterns,
         })))
     } else {
@@ -76,16 +102,24 @@ pub(crate) fn parse_substr_matcher_long(
 ) -> ModalResult<SubfieldMatcher> {
     let quantifier = parse_quantifier_opt.parse_next(i)?;
     let codes = parse_codes.map(SmallVec::from).parse_next(i)?;
-    let negated = ws1(alt(("=?".value(false), "!?".value(true))))
-        .parse_next(i)?;
+    let negated = preceded(
+        multispace1,
+        alt(("=?".value(false), "!?".value(true))),
+    )
+    .parse_next(i)?;
+    let folding =
+        terminated(parse_folding, multispace1).parse_next(i)?;
     let patterns = parse_byte_string_list.parse_next(i)?;
 
-    if let Ok(ac) = AhoCorasick::new(&patterns) {
+    if let Ok(ac) = AhoCorasick::new(
+        patterns.iter().map(|pattern| folding.apply(pattern)),
+    ) {
         Ok(SubfieldMatcher::Substr(Box::new(SubstrMatcher {
             ac,
             quantifier,
             negated,
             codes,
+            folding,
             patterns,
         })))
     } else {
@@ -115,6 +149,7 @@ mod tests {
                         quantifier: Quantifier::Any,
                         negated: $negated,
                         codes: SmallVec::from($codes),
+                        folding: Folding::default(),
                         ac: AhoCorasick::new(&patterns).unwrap(),
                         patterns,
                     }))
@@ -150,6 +185,7 @@ mod tests {
                         quantifier: $quantifier,
                         negated: $negated,
                         codes: SmallVec::from($codes),
+                        folding: Folding::default(),
                         ac: AhoCorasick::new(&patterns).unwrap(),
                         patterns,
                     }))
@@ -1,5 +1,6 @@
 use smallvec::SmallVec;
-use winnow::combinator::{alt, empty, seq};
+use winnow::ascii::multispace1;
+use winnow::combinator::{alt, empty, preceded, seq, terminated};
 use winnow::prelude::*;
 
 use crate::Subfield;
@@ -11,6 +12,7 @@ pub struct SuffixMatcher {
     pub(crate) quantifier: Quantifier,
     pub(crate) negated: bool,
     pub(crate) codes: SmallVec<[u8; 4]>,
+    pub(crate) folding: Folding,
     pub(crate) suffixes: Vec<Vec<u8>>,
 }
 
@@ -18,17 +20,18 @@ impl SuffixMatcher {
     pub fn is_match<'a, S: IntoIterator<Item = &'a Subfield<'a>>>(
         &self,
         subfields: S,
-        _options: &MatchOptions,
+        options: &MatchOptions,
     ) -> bool {
+        let folding = self.folding | options.folding;
         let mut subfields = subfields
             .into_iter()
             .filter(|subfield| self.codes.contains(subfield.code()));
 
         let r#fn = |subfield: &Subfield| -> bool {
-            let result = self
-                .suffixes
-                .iter()
-                .any(|pattern| subfield.value().ends_with(pattern));
+            let value = folding.apply(subfield.value());
+            let result = self.suffixes.iter().any(|pattern| {
+                value.ends_with(&folding.apply(pattern))
+            });
 
             if self.negated { !result } else { result }
         };
@@ -46,7 +49,8 @@ pub(crate) fn parse_suffix_matcher_short(
     seq! { SuffixMatcher {
         quantifier: empty.value(Quantifier::Any),
         codes: parse_codes.map(SmallVec::from),
-        negated: ws1(alt(("=$".value(false), "!$".value(true)))),
+        negated: preceded(multispace1, alt(("=$".value(false), "!$".value(true)))),
+        folding: terminated(parse_folding, multispace1),
         suffixes: parse_byte_string_list,
     }}
     .map(|m| SubfieldMatcher::Suffix(Box::new(m)))
@@ -59,7 +63,8 @@ pub(crate) fn parse_suffix_matcher_long(
     seq! { SuffixMatcher {
         quantifier: parse_quantifier_opt,
         codes: parse_codes.map(SmallVec::from),
-        negated: ws1(alt(("=$".value(false), "!$".value(true)))),
+        negated: preceded(multispace1, alt(("=$".value(false), "!$".value(true)))),
+        folding: terminated(parse_folding, multispace1),
         suffixes: parse_byte_string_list,
     }}
     .map(|m| SubfieldMatcher::Suffix(Box::new(m)))
@@ -81,6 +86,7 @@ mod tests {
                         SuffixMatcher {
                             quantifier: Quantifier::Any,
                             codes: SmallVec::from($codes),
+                            folding: Folding::default(),
                             negated: $negated,
                             suffixes: $suffixes,
                         }
@@ -110,6 +116,7 @@ mod tests {
                         SuffixMatcher {
                             quantifier: $quantifier,
                             codes: SmallVec::from($codes),
+                            folding: Folding::default(),
                             negated: $negated,
                             suffixes: $suffixes,
                         }
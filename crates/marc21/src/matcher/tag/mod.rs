@@ -11,11 +11,22 @@ pub(crate) mod parse;
 ///
 /// A [`TagMatcher`] is used to identify a field by its [`Tag`]. There
 /// are two different types: a straightforward comparison of the three
-/// numeric digits and a pattern-based comparison.
+/// characters and a pattern-based comparison.
 ///
-/// In its simplest form, only the three numerical digits of a tag are
-/// specified. A match with a tag only exists if these digits exactly
-/// match those of the tag.
+/// In its simplest form, only the three characters (ASCII digits or
+/// letters) of a tag are specified. A match with a tag only exists if
+/// these characters exactly match those of the tag.
+///
+/// ```rust
+/// # use marc21::matcher::TagMatcher;
+/// # use marc21::prelude::*;
+/// #
+/// let matcher = TagMatcher::new("CAT")?;
+/// assert!(matcher.is_match(&Tag::from_bytes(b"CAT")?));
+/// assert!(!matcher.is_match(&Tag::from_bytes(b"SYS")?));
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
 ///
 /// ```rust
 /// # use marc21::matcher::TagMatcher;
@@ -29,11 +40,11 @@ pub(crate) mod parse;
 /// ```
 ///
 /// In order to identify more than one [`Tag`], a pattern-based
-/// comparison must be performed. Each numerical digit of a [`Tag`] can
-/// be specified by one of the following variants.
+/// comparison must be performed. Each character of a [`Tag`] can be
+/// specified by one of the following variants.
 ///
-/// A digit can be represented by the wildcard character `.` that
-/// accepts all possible values from 0 to 9. For example, the following
+/// A character can be represented by the wildcard character `.` that
+/// accepts all possible values. For example, the following
 /// matcher accepts all tags that start with two zeros and end with any
 /// number.
 ///
@@ -47,10 +58,10 @@ pub(crate) mod parse;
 ///
 /// # Ok::<(), Box<dyn std::error::Error>>(())
 /// ```
-/// Furthermore, a digit can also be represented by specifying a class
-/// of possible digits. In the following example, all [`Tag`]s that
-/// start with a zero, have either a two, three, or five in the second
-/// position, and end with a nine are accepted.
+/// Furthermore, a character can also be represented by specifying a
+/// class of possible characters. In the following example, all [`Tag`]s
+/// that start with a zero, have either a two, three, or five in the
+/// second position, and end with a nine are accepted.
 ///
 /// ```rust
 /// # use marc21::matcher::TagMatcher;
@@ -66,10 +77,11 @@ pub(crate) mod parse;
 /// ```
 ///
 /// Similar to the character classes of a regular expression, several
-/// consecutive digits within a class can be combined into a range. The
-/// range is inclusive, and the upper interval limit must be greater
-/// than the lower limit. Note that a class can consist of more than one
-/// range expression.
+/// consecutive digits or letters of the same case within a class can be
+/// combined into a range. The range is inclusive, and the upper
+/// interval limit must be greater than the lower limit. Note that a
+/// class can consist of more than one range expression (e.g.
+/// `[0-9A-Z]`).
 ///
 /// ```rust
 /// # use marc21::matcher::TagMatcher;
@@ -85,8 +97,9 @@ pub(crate) mod parse;
 /// ```
 ///
 /// A class can also be specified in negated form. In this case, the
-/// matcher checks that the digit in the corresponding position of the
-/// [`Tag`] does not originate from the class digits:
+/// matcher checks that the character in the corresponding position of
+/// the [`Tag`] does not originate from the class (a class like `[^0-9]`
+/// accepts all letters):
 ///
 /// ```rust
 /// # use marc21::matcher::TagMatcher;
@@ -15,12 +15,10 @@ pub(crate) fn parse_tag_matcher(
 
 fn parse_tag(i: &mut &[u8]) -> ModalResult<TagMatcher> {
     take(3usize)
-        .verify(|digits: &[u8]| {
-            digits[0].is_ascii_digit()
-                && digits[1].is_ascii_digit()
-                && digits[2].is_ascii_digit()
+        .verify(|value: &[u8]| {
+            value.iter().all(u8::is_ascii_alphanumeric)
         })
-        .map(|digits: &[u8]| TagMatcher::Tag(digits.into()))
+        .map(|value: &[u8]| TagMatcher::Tag(value.into()))
         .parse_next(i)
 }
 
@@ -51,7 +49,7 @@ fn parse_pattern_constituent(
 fn parse_pattern_constituent_value(
     i: &mut &[u8],
 ) -> ModalResult<Constituent> {
-    one_of(AsChar::is_dec_digit)
+    one_of(AsChar::is_alphanum)
         .map(Constituent::Value)
         .parse_next(i)
 }
@@ -74,24 +72,24 @@ fn parse_pattern_constituent_class(
                 1..,
                 alt((
                     parse_pattern_constituent_class_range,
-                    parse_pattern_constituent_class_digit,
+                    parse_pattern_constituent_class_value,
                 )),
             ),
         )
             .map(|(negated, parts): (bool, Vec<_>)| {
-                let mut digits: Vec<u8> =
+                let mut values: Vec<u8> =
                     parts.into_iter().flatten().collect();
-                digits.sort_unstable();
-                digits.dedup();
+                values.sort_unstable();
+                values.dedup();
 
                 Constituent::Class(if negated {
-                    b"0123456789"
-                        .iter()
-                        .filter(|value| !digits.contains(value))
-                        .copied()
+                    (b'0'..=b'9')
+                        .chain(b'A'..=b'Z')
+                        .chain(b'a'..=b'z')
+                        .filter(|value| !values.contains(value))
                         .collect()
                 } else {
-                    digits
+                    values
                 })
             }),
         ']',
@@ -100,10 +98,10 @@ fn parse_pattern_constituent_class(
 }
 
 #[cfg_attr(feature = "perf-inline", inline(always))]
-fn parse_pattern_constituent_class_digit(
+fn parse_pattern_constituent_class_value(
     i: &mut &[u8],
 ) -> ModalResult<Vec<u8>> {
-    one_of(AsChar::is_dec_digit)
+    one_of(AsChar::is_alphanum)
         .map(|value| vec![value])
         .parse_next(i)
 }
@@ -111,11 +109,23 @@ fn parse_pattern_constituent_class_digit(
 fn parse_pattern_constituent_class_range(
     i: &mut &[u8],
 ) -> ModalResult<Vec<u8>> {
-    separated_pair(
-        one_of(AsChar::is_dec_digit),
-        b'-',
-        one_of(AsChar::is_dec_digit),
-    )
+    alt((
+        separated_pair(
+            one_of(AsChar::is_dec_digit),
+            b'-',
+            one_of(AsChar::is_dec_digit),
+        ),
+        separated_pair(
+            one_of(|b: u8| b.is_ascii_uppercase()),
+            b'-',
+            one_of(|b: u8| b.is_ascii_uppercase()),
+        ),
+        separated_pair(
+            one_of(|b: u8| b.is_ascii_lowercase()),
+            b'-',
+            one_of(|b: u8| b.is_ascii_lowercase()),
+        ),
+    ))
     .verify(|(min, max)| min < max)
     .map(|(min, max)| (min..=max).collect())
     .parse_next(i)
@@ -127,6 +137,10 @@ mod tests {
 
     use super::*;
 
+    fn letters() -> Vec<u8> {
+        (b'A'..=b'Z').chain(b'a'..=b'z').collect()
+    }
+
     #[test]
     fn test_parse_tag_matcher() {
         macro_rules! parse_success {
@@ -166,7 +180,23 @@ mod tests {
             })
         );
 
-        assert!(parse_tag_matcher.parse(b"00X").is_err());
+        parse_success!("CAT", TagMatcher::Tag(svec![b'C', b'A', b'T']));
+        parse_success!(
+            "[A-Z][^0-9].",
+            TagMatcher::Pattern(Pattern {
+                constituents: SmallVec::from_vec(vec![
+                    Constituent::Class((b'A'..=b'Z').collect()),
+                    Constituent::Class(
+                        (b'A'..=b'Z').chain(b'a'..=b'z').collect()
+                    ),
+                    Constituent::Wildcard,
+                ]),
+                input: b"[A-Z][^0-9].".into()
+            })
+        );
+
+        assert!(parse_tag_matcher.parse(b"00-").is_err());
+        assert!(parse_tag_matcher.parse(b"0[a-Z]0").is_err());
     }
 
     #[test]
@@ -181,7 +211,10 @@ mod tests {
         parse_success!("065", TagMatcher::Tag(svec![b'0', b'6', b'5']));
         parse_success!("550", TagMatcher::Tag(svec![b'5', b'5', b'0']));
 
-        assert!(parse_tag.parse(b"00X").is_err());
+        parse_success!("SYS", TagMatcher::Tag(svec![b'S', b'Y', b'S']));
+        parse_success!("00x", TagMatcher::Tag(svec![b'0', b'0', b'x']));
+
+        assert!(parse_tag.parse(b"00-").is_err());
     }
 
     #[test]
@@ -239,7 +272,13 @@ mod tests {
             SmallVec::from_vec(vec![
                 Constituent::Value(b'0'),
                 Constituent::Wildcard,
-                Constituent::Class(b"016789".to_vec())
+                Constituent::Class(
+                    b"016789"
+                        .iter()
+                        .copied()
+                        .chain(letters())
+                        .collect()
+                )
             ])
         );
     }
@@ -260,7 +299,13 @@ mod tests {
         }
 
         parse_success!("[03-59]", Class(b"03459".to_vec()));
-        parse_success!("[^1-8]", Class(b"09".to_vec()));
+        parse_success!(
+            "[^1-8]",
+            Class(b"09".iter().copied().chain(letters()).collect())
+        );
+        parse_success!("[A-C]", Class(b"ABC".to_vec()));
+        parse_success!("C", Value(b'C'));
+        parse_success!("x", Value(b'x'));
         parse_success!(".", Wildcard);
 
         parse_success!("0", Value(b'0'));
@@ -301,7 +346,10 @@ mod tests {
         parse_success!("8", b'8');
         parse_success!("9", b'9');
 
-        assert!(parse_pattern_constituent_value.parse(b"A").is_err());
+        parse_success!("A", b'A');
+        parse_success!("z", b'z');
+
+        assert!(parse_pattern_constituent_value.parse(b"-").is_err());
     }
 
     #[test]
@@ -319,7 +367,15 @@ mod tests {
         macro_rules! parse_success {
             ($i:expr, $o:expr) => {
                 assert_eq!(
-                    Constituent::Class($o.as_bytes().to_vec()),
+                    Constituent::Class(
+                        $o.bytes()
+                            .chain(if $i.starts_with("[^") {
+                                letters()
+                            } else {
+                                vec![]
+                            })
+                            .collect()
+                    ),
                     parse_pattern_constituent_class
                         .parse($i.as_bytes())
                         .unwrap()
@@ -335,14 +391,15 @@ mod tests {
         parse_success!("[7-960-345]", "0123456789");
         parse_success!("[^45]", "01236789");
         parse_success!("[^457-9]", "01236");
+        parse_success!("[0A-CZ]", "0ABCZ");
     }
 
     #[test]
-    fn test_parse_constituent_class_digit() {
+    fn test_parse_constituent_class_value() {
         macro_rules! parse_success {
             ($i:expr, $o:expr) => {
                 assert_eq!(
-                    parse_pattern_constituent_class_digit
+                    parse_pattern_constituent_class_value
                         .parse($i.as_bytes())
                         .unwrap(),
                     vec![$o]
@@ -361,8 +418,11 @@ mod tests {
         parse_success!("8", b'8');
         parse_success!("9", b'9');
 
+        parse_success!("A", b'A');
+        parse_success!("z", b'z');
+
         assert!(
-            parse_pattern_constituent_class_digit.parse(b"A").is_err()
+            parse_pattern_constituent_class_value.parse(b"-").is_err()
         );
     }
 
@@ -381,6 +441,8 @@ mod tests {
 
         parse_success!("0-2", "012");
         parse_success!("3-9", "3456789");
+        parse_success!("A-C", "ABC");
+        parse_success!("x-z", "xyz");
 
         assert!(
             parse_pattern_constituent_class_range
@@ -153,6 +153,12 @@ impl Path {
     ///     vec!["2025".as_bytes()]
     /// );
     ///
+    /// let path = Path::from_bytes("008.date_entered")?;
+    /// assert_eq!(
+    ///     path.project(&record, &Default::default()),
+    ///     vec!["950316".as_bytes()]
+    /// );
+    ///
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn project<'a>(
@@ -175,6 +181,9 @@ impl Path {
                 field.is_control_field()
                     && expr.tag_matcher.is_match(field.tag())
             }
+            Kind::FixedField(ref expr) => {
+                field.is_control_field() && *field.tag() == expr.tag
+            }
             Kind::DataField(ref expr) => {
                 field.is_data_field()
                     && expr.tag_matcher.is_match(field.tag())
@@ -0,0 +1,53 @@
+use winnow::prelude::*;
+
+use crate::matcher::MatchOptions;
+use crate::matcher::fixed::parse::parse_fixed_element;
+use crate::query::EMPTY_BYTE_STRING;
+use crate::{ByteRecord, DataType, Value};
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct FixedFieldExpr {
+    pub(crate) tag: Vec<u8>,
+    pub(crate) element: String,
+}
+
+impl FixedFieldExpr {
+    /// Returns the data types of all columns generated by this
+    /// expression.
+    #[inline]
+    pub(crate) fn dtypes(&self) -> Vec<DataType> {
+        vec![DataType::String]
+    }
+
+    /// Performs the projection on the given record and return a list of
+    /// columns.
+    pub(crate) fn project<'a>(
+        &self,
+        record: &ByteRecord<'a>,
+        _options: &MatchOptions,
+    ) -> Vec<Vec<Value<'a>>> {
+        let mut rows: Vec<_> = record
+            .fixed_fields()
+            .filter(|field| *field.tag() == self.tag)
+            .filter_map(|field| field.get(&self.element))
+            .map(|value| vec![value.into()])
+            .collect();
+
+        if rows.is_empty() {
+            // If no field defines the element, the result will be a
+            // column with a single row containing an empty value (see
+            // `ControlFieldExpr`).
+            rows.push(vec![Value::from(&EMPTY_BYTE_STRING)]);
+        }
+
+        rows
+    }
+}
+
+pub(crate) fn parse_fixed_field_expr(
+    i: &mut &[u8],
+) -> ModalResult<FixedFieldExpr> {
+    parse_fixed_element
+        .map(|(tag, element)| FixedFieldExpr { tag, element })
+        .parse_next(i)
+}
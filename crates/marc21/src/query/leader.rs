@@ -4,7 +4,8 @@ use winnow::prelude::*;
 use crate::matcher::MatchOptions;
 use crate::matcher::leader::LeaderField;
 use crate::matcher::leader::parse::parse_leader_field;
-use crate::query::DataType;
+use crate::matcher::shared::Value as FieldValue;
+use crate::query::{DataType, EMPTY_BYTE_STRING};
 use crate::{ByteRecord, Value};
 
 #[derive(Debug, Clone, PartialEq)]
@@ -14,12 +15,10 @@ impl LeaderExpr {
     /// Returns the data types of all columns generated by this
     /// expression.
     pub(crate) fn dtypes(&self) -> Vec<DataType> {
-        match self.0 {
-            LeaderField::BaseAddr => vec![DataType::UInt32],
-            LeaderField::Encoding => vec![DataType::Char],
-            LeaderField::Length => vec![DataType::UInt32],
-            LeaderField::Status => vec![DataType::Char],
-            LeaderField::Type => vec![DataType::Char],
+        if self.0.is_numeric() {
+            vec![DataType::UInt32]
+        } else {
+            vec![DataType::Char]
         }
     }
 
@@ -30,19 +29,17 @@ impl LeaderExpr {
         record: &ByteRecord<'a>,
         _options: &MatchOptions,
     ) -> Vec<Vec<Value<'a>>> {
-        use LeaderField::*;
-
-        let leader = record.leader();
-
-        let value = match self.0 {
-            BaseAddr => leader.base_addr().to_string(),
-            Encoding => char::from(leader.encoding()).to_string(),
-            Length => leader.length().to_string(),
-            Status => char::from(leader.status()).to_string(),
-            Type => char::from(leader.r#type()).to_string(),
+        let value = match self.0.value(record.leader()) {
+            Some(FieldValue::U32(value)) => value.to_string().into(),
+            Some(FieldValue::Char(value)) => {
+                char::from(value).to_string().into()
+            }
+            // A field, which isn't defined in the record format,
+            // results in an empty value.
+            _ => Value::from(&EMPTY_BYTE_STRING),
         };
 
-        vec![vec![value.into()]]
+        vec![vec![value]]
     }
 }
 
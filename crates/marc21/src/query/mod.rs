@@ -9,6 +9,7 @@ use winnow::Parser;
 use crate::matcher::MatchOptions;
 use crate::query::control_field::ControlFieldExpr;
 use crate::query::data_field::DataFieldExpr;
+use crate::query::fixed_field::FixedFieldExpr;
 use crate::query::leader::LeaderExpr;
 use crate::query::parse::parse_query;
 use crate::{ByteRecord, Value};
@@ -17,6 +18,7 @@ mod control_field;
 pub(crate) mod data_field;
 mod dtype;
 mod error;
+mod fixed_field;
 mod leader;
 pub(crate) mod parse;
 
@@ -44,6 +46,7 @@ impl Query {
     /// let _query = Query::new("ldr.length")?;
     /// let _query = Query::new("001")?;
     /// let _query = Query::new("005[0:4]")?;
+    /// let _query = Query::new("008.lang")?;
     /// let _query = Query::new("075{ b | 2 == 'gndspec' }")?;
     /// let _query = Query::new("075{ _ | 2 == 'gndspec' }")?;
     /// let _query = Query::new("075{ b, 'gndspec' | 2 == 'gndspec' }")?;
@@ -216,6 +219,7 @@ impl Constituent {
         match self.kind {
             Kind::ControlField(ref cf) => cf.dtypes(),
             Kind::DataField(ref df) => df.dtypes(),
+            Kind::FixedField(ref ff) => ff.dtypes(),
             Kind::Leader(ref ldr) => ldr.dtypes(),
             Kind::Literal(_) => vec![DataType::String],
         }
@@ -230,6 +234,7 @@ impl Constituent {
         match self.kind {
             Kind::ControlField(ref cf) => cf.project(record, options),
             Kind::DataField(ref df) => df.project(record, options),
+            Kind::FixedField(ref ff) => ff.project(record, options),
             Kind::Leader(ref ldr) => ldr.project(record, options),
             Kind::Literal(ref lit) => {
                 vec![vec![Value::from(lit.clone())]]
@@ -242,6 +247,7 @@ impl Constituent {
 pub(crate) enum Kind {
     ControlField(ControlFieldExpr),
     DataField(DataFieldExpr),
+    FixedField(FixedFieldExpr),
     Leader(LeaderExpr),
     Literal(String),
 }
@@ -4,6 +4,7 @@ use winnow::prelude::*;
 use crate::matcher::shared::{parse_string, ws0};
 use crate::query::control_field::parse_control_field_expr;
 use crate::query::data_field::parse_data_field_expr;
+use crate::query::fixed_field::parse_fixed_field_expr;
 use crate::query::leader::parse_leader_expr;
 use crate::query::{Constituent, Kind, Query};
 
@@ -31,9 +32,12 @@ fn parse_constituent(i: &mut &[u8]) -> ModalResult<Constituent> {
 
 fn parse_constituent_kind(i: &mut &[u8]) -> ModalResult<Kind> {
     alt((
+        // The leader expression must be tried first, because its
+        // prefix `ldr` is also a valid (local) tag.
+        parse_leader_expr.map(Kind::Leader),
+        parse_fixed_field_expr.map(Kind::FixedField),
         parse_data_field_expr.map(Kind::DataField),
         parse_control_field_expr.map(Kind::ControlField),
-        parse_leader_expr.map(Kind::Leader),
         parse_string.map(Kind::Literal),
     ))
     .parse_next(i)
@@ -9,22 +9,57 @@ use winnow::combinator::{empty, repeat, seq, terminated};
 use winnow::prelude::*;
 use winnow::token::{one_of, take};
 
-use crate::directory::parse_directory;
-use crate::error::ParseRecordError;
+use crate::common::{
+    GROUP_SEPARATOR, MAX_RECORD_LENGTH, RECORD_SEPARATOR,
+    UNIT_SEPARATOR, is_indicator, is_subfield_code,
+};
+use crate::directory::{parse_directory_with, parse_entry_with};
+use crate::error::{ParseRecordError, ParseRecordErrorKind};
 use crate::field::DataField;
+use crate::fixed::FixedField;
+use crate::io::ParseWarning;
 use crate::leader::parse_leader;
 use crate::matcher::MatchOptions;
 use crate::subfield::parse_subfield;
+use crate::tag::parse_tag;
 use crate::{
-    ControlField, Directory, Field, Leader, Path, Query, Subfield,
-    Value,
+    ControlField, ControlFieldRule, Directory, Field, Leader, Path,
+    Query, Subfield, Tag, Value,
 };
 
+/// The policy to write a record, whose length exceeds the maximum
+/// record length of ISO 2709 (99999 bytes).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum OversizePolicy {
+    /// Return an error.
+    Error,
+    /// Split the record into several records, that fit into the limit.
+    /// Each record contains all control fields (so all parts share the
+    /// control number 001), a part of the data fields and a host item
+    /// entry (773), which links the parts: `$g` contains the number of
+    /// the part (e.g. `part 2/3`) and `$w` the control number of the
+    /// record. The link field is inserted in tag order. A record, that
+    /// fits into the limit, isn't split, and a record, that already
+    /// contains a host item entry, can't be split.
+    Split,
+    /// Drop the fields, that don't fit into the limit, and report
+    /// their tags. A field is only dropped, if it doesn't fit into the
+    /// space left by the preceding fields, so that smaller fields
+    /// following a large field are kept.
+    Truncate,
+    /// Write the non-standard oversize form: the record length and the
+    /// values of the directory, which can't be represented, are
+    /// written as nines. Such records can be read again, because the
+    /// fields of an oversize record are located by their terminators.
+    /// This is the default, so that no data is lost.
+    #[default]
+    Oversize,
+}
+
 /// A record, that may contain invalid UTF-8 data.
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct ByteRecord<'a> {
     leader: Leader,
-    directory: Directory<'a>,
     fields: Vec<Field<'a>>,
     raw_data: Option<&'a [u8]>,
 }
@@ -32,6 +67,12 @@ pub struct ByteRecord<'a> {
 impl<'a> ByteRecord<'a> {
     /// Create a new reocord from a byte slice.
     ///
+    /// The record is parsed according to the indicator count and the
+    /// entry map of its leader. A record with more than two indicators
+    /// or a subfield code length other than two can't be represented by
+    /// a [`DataField`] and is rejected with
+    /// [`ParseRecordErrorKind::InvalidLeader`] (see [`Leader`]).
+    ///
     /// # Example
     ///
     /// ```rust
@@ -47,11 +88,83 @@ impl<'a> ByteRecord<'a> {
         bytes: &'a B,
     ) -> Result<Self, ParseRecordError<'a>>
     where
-        B: AsRef<[u8]>,
+        B: AsRef<[u8]> + ?Sized,
     {
-        parse_record
-            .parse(bytes.as_ref())
-            .map_err(ParseRecordError::from_parse)
+        Self::from_bytes_with(bytes, &ControlFieldRule::default())
+    }
+
+    /// Create a new record from a byte slice, whose control fields are
+    /// determined by the given rule.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::ControlFieldRule;
+    /// use marc21::prelude::*;
+    ///
+    /// let data = include_bytes!("../../../tests/data/aleph.mrc");
+    /// assert!(ByteRecord::from_bytes(data).is_err());
+    ///
+    /// let rule = "00., FMT".parse::<ControlFieldRule>()?;
+    /// let record = ByteRecord::from_bytes_with(data, &rule).unwrap();
+    /// let fields: Vec<_> = record
+    ///     .fields()
+    ///     .filter(|field| field.is_control_field())
+    ///     .map(|field| field.tag().to_string())
+    ///     .collect();
+    /// assert_eq!(fields, ["001", "FMT"]);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn from_bytes_with<B>(
+        bytes: &'a B,
+        rule: &ControlFieldRule,
+    ) -> Result<Self, ParseRecordError<'a>>
+    where
+        B: AsRef<[u8]> + ?Sized,
+    {
+        let bytes = bytes.as_ref();
+        parse_record_with(rule).parse(bytes).map_err(
+            |e| match diagnose(bytes, rule) {
+                Some((kind, position)) => {
+                    ParseRecordError::new(kind, position, bytes)
+                }
+                None => ParseRecordError::from_parse(e),
+            },
+        )
+    }
+
+    /// Create a new record from a leader and a list of fields.
+    ///
+    /// The record isn't backed by raw data. The record length, the base
+    /// address of data and the directory are computed when the record
+    /// is written.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::prelude::*;
+    /// use marc21::{ControlField, Field};
+    ///
+    /// let record = ByteRecord::from_fields(
+    ///     Leader::new(b"00000nz  a2200000nc 4500")?,
+    ///     vec![Field::Control(ControlField::new(
+    ///         Tag::from_bytes(b"001")?,
+    ///         b"119232022",
+    ///     ))],
+    /// );
+    ///
+    /// assert!(record.raw_data().is_none());
+    /// assert_eq!(record.fields().count(), 1);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn from_fields(leader: Leader, fields: Vec<Field<'a>>) -> Self {
+        Self {
+            leader,
+            fields,
+            raw_data: None,
+        }
     }
 
     /// Returns the leader of the record.
@@ -91,6 +204,30 @@ impl<'a> ByteRecord<'a> {
         self.fields.iter()
     }
 
+    /// Returns an iterator over the decoded fixed-length control fields
+    /// (006, 007 and 008) of the record.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::prelude::*;
+    ///
+    /// let data = include_bytes!("../tests/data/ada.mrc");
+    /// let record = ByteRecord::from_bytes(data)?;
+    /// let field = record.fixed_fields().next().unwrap();
+    ///
+    /// assert_eq!(field.tag(), "008");
+    /// assert_eq!(field.get("kind_of_record"), Some(&b"a"[..]));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn fixed_fields(&self) -> impl Iterator<Item = FixedField<'a>> {
+        self.fields.iter().filter_map(|field| match field {
+            Field::Control(cf) => FixedField::new(cf, &self.leader),
+            _ => None,
+        })
+    }
+
     /// Returns the control number of the record.
     ///
     /// If the record does not have a control field, `None` is returned.
@@ -238,7 +375,18 @@ impl<'a> ByteRecord<'a> {
         Ok(())
     }
 
-    /// Write the leader into the given writer
+    /// Write the record into the given writer.
+    ///
+    /// If the record is backed by raw data, the data is written
+    /// unchanged. Otherwise the record is serialized according to ISO
+    /// 2709; the record length and the base address of data are
+    /// recomputed and the directory is regenerated from the fields.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the record or one of its fields exceeds
+    /// the limits of the record format. Use
+    /// [`write_with`](Self::write_with) to write such records.
     ///
     /// # Example
     ///
@@ -259,9 +407,215 @@ impl<'a> ByteRecord<'a> {
     pub fn write_to<W: Write>(&self, out: &mut W) -> io::Result<()> {
         match self.raw_data {
             Some(buf) => out.write_all(buf),
-            None => todo!(),
+            None => self.write_iso2709(out, self.fields(), false),
         }
     }
+
+    /// Write the record into the given writer and apply the given
+    /// policy, if the record exceeds the maximum record length of ISO
+    /// 2709 (99999 bytes).
+    ///
+    /// A record, which doesn't exceed the limit, is written like
+    /// [`write_to`](Self::write_to) does. Returns the tags of the
+    /// fields, which were dropped by [`OversizePolicy::Truncate`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::prelude::*;
+    /// use marc21::{ControlField, Field, OversizePolicy};
+    ///
+    /// let value = vec![b'x'; 9000];
+    /// let mut fields = vec![];
+    /// for _ in 0..12 {
+    ///     fields.push(Field::Control(ControlField::new(
+    ///         Tag::from_bytes(b"009")?,
+    ///         &value,
+    ///     )));
+    /// }
+    ///
+    /// let leader = Leader::new(b"00000nz  a2200000nc 4500")?;
+    /// let record = ByteRecord::from_fields(leader, fields);
+    /// assert!(record.write_to(&mut Vec::<u8>::new()).is_err());
+    ///
+    /// let mut out = Vec::<u8>::new();
+    /// let dropped =
+    ///     record.write_with(&mut out, OversizePolicy::Truncate)?;
+    /// assert_eq!(dropped.len(), 1);
+    /// assert!(out.len() <= 99999);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn write_with<W: Write>(
+        &self,
+        out: &mut W,
+        policy: OversizePolicy,
+    ) -> io::Result<Vec<Tag<'a>>> {
+        if let Some(buf) = self.raw_data
+            && buf.len() <= MAX_RECORD_LENGTH
+        {
+            out.write_all(buf)?;
+            return Ok(vec![]);
+        }
+
+        let indicator_count = self.leader.indicator_count() as usize;
+        let entry_length = self.leader.entry_length();
+        let size = |field: &Field| {
+            entry_length + field_length(field, indicator_count)
+        };
+
+        let mut length = 24 + 1 + 1;
+        let mut dropped = vec![];
+
+        match policy {
+            OversizePolicy::Error => {
+                self.write_iso2709(out, self.fields(), false)?
+            }
+            OversizePolicy::Oversize => {
+                self.write_iso2709(out, self.fields(), true)?
+            }
+            OversizePolicy::Truncate => {
+                let mut kept = vec![];
+                for field in self.fields.iter() {
+                    if length + size(field) <= MAX_RECORD_LENGTH {
+                        length += size(field);
+                        kept.push(field);
+                    } else {
+                        dropped.push(field.tag().clone());
+                    }
+                }
+
+                self.write_iso2709(out, kept, false)?;
+            }
+            OversizePolicy::Split => {
+                let (control, data): (Vec<_>, Vec<_>) = self
+                    .fields
+                    .iter()
+                    .partition(|field| field.is_control_field());
+
+                let cn = control.iter().find_map(|field| match field {
+                    Field::Control(cf) if cf.tag() == "001" => {
+                        Some(cf.value())
+                    }
+                    _ => None,
+                });
+
+                // The space of the link field is reserved in every
+                // part, since the number of parts isn't known yet.
+                length +=
+                    control.iter().map(|f| size(f)).sum::<usize>()
+                        + size(&link_field(cn, b"part 99999/99999"));
+
+                let mut parts: Vec<Vec<&Field>> = vec![vec![]];
+                let mut part_length = length;
+                for field in data {
+                    let field_size = size(field);
+                    let part = parts.last_mut().unwrap();
+                    if part_length + field_size > MAX_RECORD_LENGTH
+                        && !part.is_empty()
+                    {
+                        parts.push(vec![]);
+                        part_length = length;
+                    }
+
+                    parts.last_mut().unwrap().push(field);
+                    part_length += field_size;
+                }
+
+                let n = parts.len();
+                if n == 1 {
+                    let fields =
+                        control.iter().chain(&parts[0]).copied();
+                    self.write_iso2709(out, fields, false)?;
+                } else if parts
+                    .iter()
+                    .flatten()
+                    .any(|f| f.tag() == "773")
+                {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        "a record with a host item entry (773) can't \
+                         be split",
+                    ));
+                } else {
+                    for (i, mut part) in parts.into_iter().enumerate() {
+                        let g = format!("part {}/{n}", i + 1);
+                        let link = link_field(cn, g.as_bytes());
+                        let pos = part
+                            .iter()
+                            .position(|f| f.tag().0 > &b"773"[..])
+                            .unwrap_or(part.len());
+                        part.insert(pos, &link);
+
+                        let fields =
+                            control.iter().copied().chain(part);
+                        self.write_iso2709(out, fields, false)?;
+                    }
+                }
+            }
+        }
+
+        Ok(dropped)
+    }
+
+    /// Serializes the leader and the given fields according to ISO
+    /// 2709. In the oversize form, the record length and the values
+    /// of the directory, which exceed the limits, are written as
+    /// nines instead of returning an error.
+    fn write_iso2709<'b, W, F>(
+        &self,
+        out: &mut W,
+        fields: F,
+        oversize: bool,
+    ) -> io::Result<()>
+    where
+        W: Write,
+        F: IntoIterator<Item = &'b Field<'a>>,
+        'a: 'b,
+    {
+        if let Err(e) = self.leader.check_supported() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "unsupported record structure: {e} (at most 2 \
+                     indicators and a subfield code length of 2 are \
+                     supported)"
+                ),
+            ));
+        }
+
+        let mut data = Vec::<u8>::new();
+        let directory = Directory::layout(
+            fields,
+            &self.leader,
+            oversize,
+            &mut data,
+        )?;
+
+        let base_addr =
+            24 + self.leader.entry_length() * directory.length() + 1;
+        let length = base_addr + data.len() + 1;
+
+        if !oversize && length > MAX_RECORD_LENGTH {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "record length {length} exceeds {MAX_RECORD_LENGTH}"
+                ),
+            ));
+        }
+
+        let leader = Leader {
+            length: length.min(MAX_RECORD_LENGTH) as u32,
+            base_addr: base_addr.min(MAX_RECORD_LENGTH) as u32,
+            ..self.leader.clone()
+        };
+
+        leader.write_to(out)?;
+        directory.write_iso2709(out, &leader)?;
+        out.write_all(&data)?;
+        out.write_all(&[GROUP_SEPARATOR])
+    }
 }
 
 impl Display for ByteRecord<'_> {
@@ -274,6 +628,21 @@ impl Display for ByteRecord<'_> {
     }
 }
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for ByteRecord<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("Record", 2)?;
+        state.serialize_field("leader", &self.leader)?;
+        state.serialize_field("fields", &self.fields)?;
+        state.end()
+    }
+}
+
 impl<'a> IntoIterator for ByteRecord<'a> {
     type Item = char;
     type IntoIter = IntoIter<Self::Item>;
@@ -323,36 +692,85 @@ impl<'a> Deref for StringRecord<'a> {
     }
 }
 
-fn parse_record<'a>(i: &mut &'a [u8]) -> ModalResult<ByteRecord<'a>> {
+#[cfg(feature = "serde")]
+impl serde::Serialize for StringRecord<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+/// Parses a record.
+///
+/// The indicator count (0-2) and the entry map of the leader are
+/// honoured; a leader with more than two indicators or a subfield code
+/// length other than two is rejected.
+///
+/// The length of an oversize record, which exceeds the maximum record
+/// length of ISO 2709 (99999 bytes), can't be represented by the
+/// leader; neither can the lengths and the starting positions of its
+/// fields be represented by the directory. Therefore, the record
+/// length and the field lengths of such a record are ignored and the
+/// fields are located by scanning for their terminators.
+fn parse_record_with<'a>(
+    rule: &ControlFieldRule,
+) -> impl FnMut(&mut &'a [u8]) -> ModalResult<ByteRecord<'a>> + '_ {
+    move |i: &mut &'a [u8]| parse_record(i, rule)
+}
+
+fn parse_record<'a>(
+    i: &mut &'a [u8],
+    rule: &ControlFieldRule,
+) -> ModalResult<ByteRecord<'a>> {
     let raw_data: Option<&[u8]> = Some(i);
+    let oversize = i.len() > MAX_RECORD_LENGTH;
     let leader = parse_leader
-        .verify(|leader| leader.length() > leader.base_addr() + 1)
+        .verify(|leader| {
+            (oversize || leader.length() > leader.base_addr() + 1)
+                && leader.is_supported()
+        })
         .parse_next(i)?;
-    let directory = parse_directory.parse_next(i)?;
+    let directory = parse_directory_with(&leader).parse_next(i)?;
     let mut fields = Vec::with_capacity(directory.length());
-    let mut payload =
-        take(leader.length() - leader.base_addr() - 1).parse_next(i)?;
+    let mut payload = if oversize {
+        take(i.len().saturating_sub(1)).parse_next(i)?
+    } else {
+        take(leader.length() - leader.base_addr() - 1).parse_next(i)?
+    };
     let _ = b'\x1d'.parse_next(i)?;
 
+    let mut indicators = parse_indicators(leader.indicator_count());
     for entry in directory.entries() {
-        let field = if entry.is_control_field() {
+        let field = if rule.is_control_field(entry.tag()) {
             Field::Control(
                 seq! { ControlField {
                     tag: empty.value(entry.tag().clone()),
-                    value: terminated(take(entry.length() - 1), b'\x1e')
+                    value: terminated(
+                        take(if oversize {
+                            payload
+                                .iter()
+                                .position(|b| *b == RECORD_SEPARATOR)
+                                .unwrap_or(payload.len())
+                        } else {
+                            entry.length() - 1
+                        }),
+                        b'\x1e'
+                    )
                 }}
                 .parse_next(&mut payload)?,
             )
         } else {
-            Field::Data(
-                seq! { DataField {
-                    tag: empty.value(entry.tag().clone()),
-                    indicator1: parse_indicator,
-                    indicator2: parse_indicator,
-                    subfields: parse_subfields,
-                }}
-                .parse_next(&mut payload)?,
-            )
+            let (indicator1, indicator2) =
+                indicators.parse_next(&mut payload)?;
+
+            Field::Data(DataField {
+                tag: entry.tag().clone(),
+                indicator1,
+                indicator2,
+                subfields: parse_subfields.parse_next(&mut payload)?,
+            })
         };
 
         fields.push(field);
@@ -360,18 +778,508 @@ fn parse_record<'a>(i: &mut &'a [u8]) -> ModalResult<ByteRecord<'a>> {
 
     Ok(ByteRecord {
         leader,
-        directory,
         fields,
         raw_data,
     })
 }
 
+/// Determines why a record can't be parsed by `parse_record_with`.
+///
+/// The checks follow the structure of the strict parser (leader,
+/// directory, record terminator and fields), so that the first
+/// violation found is the one the parser stumbled upon. Returns the
+/// kind of the error and its position within the record, or `None` if
+/// no specific cause could be determined.
+fn diagnose(
+    bytes: &[u8],
+    rule: &ControlFieldRule,
+) -> Option<(ParseRecordErrorKind, usize)> {
+    use ParseRecordErrorKind::*;
+
+    let Some(Ok(leader)) =
+        bytes.get(0..24).map(|ldr| parse_leader.parse(ldr))
+    else {
+        return Some((InvalidLeader, 0));
+    };
+
+    if !leader.is_supported() {
+        return Some((InvalidLeader, 10));
+    }
+
+    let oversize = bytes.len() > MAX_RECORD_LENGTH;
+    let length = if oversize {
+        bytes.len()
+    } else {
+        leader.length() as usize
+    };
+
+    let base_addr = leader.base_addr() as usize;
+    if length <= base_addr + 1 {
+        return Some((RecordLengthMismatch, 0));
+    }
+
+    let entry_length = leader.entry_length();
+    let mut parse_entry = parse_entry_with(
+        leader.length_of_field_length() as usize,
+        leader.length_of_starting_position() as usize,
+        leader.length_of_implementation_defined() as usize,
+    );
+
+    let mut entries = vec![];
+    let mut pos = 24;
+    while bytes.get(pos) != Some(&RECORD_SEPARATOR) {
+        let Some(mut entry) = bytes.get(pos..pos + entry_length) else {
+            return Some((MissingDirectoryTerminator, pos));
+        };
+
+        match parse_entry.parse_next(&mut entry) {
+            Ok(entry) => entries.push(entry),
+            Err(_) => {
+                return Some((
+                    InvalidDirectoryEntry(entries.len()),
+                    pos,
+                ));
+            }
+        }
+
+        pos += entry_length;
+    }
+
+    if entries.is_empty() {
+        return Some((EmptyDirectory, pos));
+    }
+
+    if pos + 1 != base_addr {
+        return Some((BaseAddressMismatch, 12));
+    }
+
+    if bytes.len() != length {
+        return if bytes.last() == Some(&GROUP_SEPARATOR) {
+            Some((RecordLengthMismatch, 0))
+        } else {
+            Some((MissingRecordTerminator, bytes.len()))
+        };
+    }
+
+    if bytes[length - 1] != GROUP_SEPARATOR {
+        return Some((MissingRecordTerminator, length - 1));
+    }
+
+    let end = length - 1;
+    let mut pos = base_addr;
+    for entry in entries {
+        let tag = entry.tag().to_string();
+
+        if rule.is_control_field(entry.tag()) {
+            let field_length = if oversize {
+                bytes[pos..end]
+                    .iter()
+                    .position(|b| *b == RECORD_SEPARATOR)
+                    .map_or(end - pos, |i| i + 1)
+            } else {
+                entry.length()
+            };
+
+            let Some(value) = bytes[..end].get(pos..pos + field_length)
+            else {
+                return Some((FieldLengthMismatch(tag), pos));
+            };
+
+            match value.iter().position(|b| *b == RECORD_SEPARATOR) {
+                Some(i) if i + 1 == value.len() => {}
+                Some(i) => {
+                    return Some((FieldLengthMismatch(tag), pos + i));
+                }
+                None => {
+                    return Some((
+                        MissingFieldTerminator(tag),
+                        pos + value.len() - 1,
+                    ));
+                }
+            }
+
+            pos += value.len();
+            continue;
+        }
+
+        let indicator_count = leader.indicator_count() as usize;
+        for i in pos..pos + indicator_count {
+            if !bytes[..end].get(i).is_some_and(|b| is_indicator(*b)) {
+                return Some((InvalidIndicator(tag), i));
+            }
+        }
+
+        pos += indicator_count;
+        let Some(field_end) = bytes[pos..end]
+            .iter()
+            .position(|b| *b == RECORD_SEPARATOR)
+            .map(|i| pos + i)
+        else {
+            return Some((MissingFieldTerminator(tag), end));
+        };
+
+        if pos < field_end && bytes[pos] != UNIT_SEPARATOR {
+            return Some((InvalidSubfield(tag), pos));
+        }
+
+        for i in pos..field_end {
+            if bytes[i] == UNIT_SEPARATOR
+                && !bytes[i + 1].is_ascii_alphanumeric()
+            {
+                return Some((InvalidSubfield(tag), i));
+            }
+        }
+
+        pos = field_end + 1;
+    }
+
+    None
+}
+
+/// Parses a record and repairs structural errors instead of failing.
+///
+/// The fields are located by their terminators; the lengths and
+/// starting positions of the directory are only used to report
+/// discrepancies. The record length and the base address of data are
+/// recomputed, uppercase indicators are converted to lowercase and
+/// other invalid indicators are replaced by blanks. Each repair is
+/// reported as a warning. Because the raw data doesn't match the
+/// repaired record, the record isn't backed by raw data.
+pub(crate) fn parse_record_lenient<'a>(
+    bytes: &'a [u8],
+    rule: &ControlFieldRule,
+    warnings: &mut Vec<ParseWarning>,
+) -> Result<ByteRecord<'a>, ParseRecordError<'a>> {
+    let mut warn = |position: usize, message: String| {
+        warnings.push(ParseWarning::new(message, position));
+    };
+
+    let skip = bytes
+        .iter()
+        .position(|b| !b.is_ascii_whitespace())
+        .unwrap_or(bytes.len());
+
+    if skip > 0 {
+        warn(0, "whitespace before the leader ignored".into());
+    }
+
+    let data = bytes;
+    let error = |kind, position| {
+        Err(ParseRecordError::new(kind, skip + position, data))
+    };
+
+    let bytes = &bytes[skip..];
+    if bytes.len() < 24 {
+        return error(ParseRecordErrorKind::InvalidLeader, 0);
+    }
+
+    let end = match bytes.last() {
+        Some(&GROUP_SEPARATOR) => bytes.len() - 1,
+        _ => {
+            warn(bytes.len(), "missing record terminator".into());
+            bytes.len()
+        }
+    };
+
+    let Some(dir_end) =
+        bytes[24..end].iter().position(|b| *b == RECORD_SEPARATOR)
+    else {
+        return error(
+            ParseRecordErrorKind::MissingDirectoryTerminator,
+            24,
+        );
+    };
+
+    let dir_end = dir_end + 24;
+    let base_addr = dir_end + 1;
+
+    let mut ldr: [u8; 24] = bytes[0..24].try_into().unwrap();
+
+    // The indicator count, the subfield code length and the entry map
+    // are kept, if they are valid; otherwise the values of MARC 21
+    // are assumed.
+    let valid_counts = ldr[10..12].iter().all(u8::is_ascii_digit);
+    let valid_entry_map = matches!(
+        ldr[20..24],
+        [b'1'..=b'9', b'1'..=b'9', b'0'..=b'9', pos23]
+            if pos23 == b' ' || pos23.is_ascii_graphic()
+    );
+
+    let mut repair =
+        |range: std::ops::Range<usize>, value: &[u8], name| {
+            if &ldr[range.clone()] != value {
+                warn(
+                    range.start,
+                    format!(
+                        "{name} '{}' corrected to '{}'",
+                        ldr[range.clone()].escape_ascii(),
+                        value.escape_ascii()
+                    ),
+                );
+
+                ldr[range].copy_from_slice(value);
+            }
+        };
+
+    let length = format!("{:05}", (end + 1).min(MAX_RECORD_LENGTH));
+    repair(0..5, length.as_bytes(), "record length");
+    if !valid_counts {
+        repair(
+            10..12,
+            b"22",
+            "indicator count and subfield code length",
+        );
+    }
+
+    let base = format!("{base_addr:05}");
+    repair(12..17, base.as_bytes(), "base address of data");
+    if !valid_entry_map {
+        repair(20..24, b"4500", "entry map");
+    }
+
+    let Ok(leader) = parse_leader.parse(&ldr) else {
+        return error(ParseRecordErrorKind::InvalidLeader, 0);
+    };
+
+    if !leader.is_supported() {
+        return error(ParseRecordErrorKind::InvalidLeader, 10);
+    }
+
+    let lf = leader.length_of_field_length() as usize;
+    let ls = leader.length_of_starting_position() as usize;
+    let entry_length = leader.entry_length();
+
+    let mut directory = &bytes[24..dir_end];
+    if !directory.len().is_multiple_of(entry_length) {
+        warn(
+            dir_end - directory.len() % entry_length,
+            "incomplete directory entry ignored".into(),
+        );
+    }
+
+    let mut entries = vec![];
+    while directory.len() >= entry_length {
+        let position = dir_end - directory.len();
+        let Ok(tag) = parse_tag.parse_next(&mut directory) else {
+            return error(
+                ParseRecordErrorKind::InvalidDirectoryEntry(
+                    entries.len(),
+                ),
+                position,
+            );
+        };
+
+        let digits = |value: &[u8]| {
+            std::str::from_utf8(value)
+                .ok()
+                .and_then(|value| value.parse::<usize>().ok())
+        };
+
+        let length = digits(&directory[0..lf]);
+        let start = digits(&directory[lf..lf + ls]);
+        entries.push((tag, length, start, position));
+        directory = &directory[entry_length - 3..];
+    }
+
+    let mut chunks = vec![];
+    let mut start = base_addr;
+    for (i, b) in bytes[base_addr..end].iter().enumerate() {
+        if *b == RECORD_SEPARATOR {
+            chunks.push((start, &bytes[start..base_addr + i]));
+            start = base_addr + i + 1;
+        }
+    }
+
+    if start < end {
+        warn(end, "missing field terminator".into());
+        chunks.push((start, &bytes[start..end]));
+    }
+
+    if chunks.len() != entries.len() {
+        return error(
+            ParseRecordErrorKind::Invalid(format!(
+                "directory has {} entries, but the data contains {} \
+                 fields",
+                entries.len(),
+                chunks.len()
+            )),
+            base_addr,
+        );
+    }
+
+    let indicator_count = leader.indicator_count() as usize;
+    let mut fields = Vec::with_capacity(entries.len());
+    for ((tag, length, start, position), (offset, value)) in
+        entries.into_iter().zip(chunks)
+    {
+        if length != Some(value.len() + 1)
+            || start != Some(offset - base_addr)
+        {
+            warn(
+                position,
+                format!("directory entry of field {tag} corrected"),
+            );
+        }
+
+        if rule.is_control_field(&tag) {
+            fields.push(Field::Control(ControlField { tag, value }));
+            continue;
+        }
+
+        let mut indicator = |ind: u8, position: usize| {
+            if is_indicator(ind) {
+                ind
+            } else if ind.is_ascii_uppercase() {
+                warn(
+                    position,
+                    format!(
+                        "uppercase indicator '{}' of field {tag} \
+                         converted to lowercase",
+                        ind as char
+                    ),
+                );
+                ind.to_ascii_lowercase()
+            } else {
+                warn(
+                    position,
+                    format!(
+                        "invalid indicator '{}' of field {tag} \
+                         replaced by a blank",
+                        ind.escape_ascii()
+                    ),
+                );
+                b' '
+            }
+        };
+
+        let mut indicators = [b' '; 2];
+        let mut rest = match value.split_at_checked(indicator_count) {
+            Some((inds, rest)) if !inds.contains(&UNIT_SEPARATOR) => {
+                for (i, ind) in inds.iter().enumerate() {
+                    indicators[i] = indicator(*ind, offset + i);
+                }
+
+                rest
+            }
+            _ => {
+                warn(
+                    offset,
+                    format!(
+                        "missing indicators of field {tag} replaced by \
+                         blanks"
+                    ),
+                );
+                value
+            }
+        };
+
+        if rest.first().is_some_and(|b| *b != UNIT_SEPARATOR) {
+            warn(
+                offset + value.len() - rest.len(),
+                format!(
+                    "data before the first subfield of field {tag} \
+                     ignored"
+                ),
+            );
+
+            rest = rest
+                .iter()
+                .position(|b| *b == UNIT_SEPARATOR)
+                .map(|i| &rest[i..])
+                .unwrap_or_default();
+        }
+
+        let mut subfields = vec![];
+        for subfield in rest.split(|b| *b == UNIT_SEPARATOR).skip(1) {
+            let position = offset + (subfield.as_ptr() as usize)
+                - (value.as_ptr() as usize);
+
+            match subfield {
+                [] => warn(
+                    position,
+                    format!("empty subfield of field {tag} ignored"),
+                ),
+                [code, value @ ..] => {
+                    if !is_subfield_code(*code) {
+                        warn(
+                            position,
+                            format!(
+                                "invalid subfield code '{}' of field \
+                                 {tag} accepted",
+                                code.escape_ascii()
+                            ),
+                        );
+                    }
+
+                    subfields.push(Subfield { code: *code, value });
+                }
+            }
+        }
+
+        let [indicator1, indicator2] = indicators;
+        fields.push(Field::Data(DataField {
+            tag,
+            indicator1,
+            indicator2,
+            subfields,
+        }));
+    }
+
+    Ok(ByteRecord {
+        leader,
+        fields,
+        raw_data: None,
+    })
+}
+
+/// Returns the field, which links the parts of a split record: a host
+/// item entry (773) with the number of the part (`$g`) and the control
+/// number of the record (`$w`), which is shared by all parts.
+fn link_field<'b>(cn: Option<&'b [u8]>, part: &'b [u8]) -> Field<'b> {
+    let mut subfields = vec![Subfield::new(b'g', part)];
+    if let Some(cn) = cn {
+        subfields.push(Subfield::new(b'w', cn));
+    }
+
+    Field::Data(DataField {
+        tag: Tag(b"773"),
+        indicator1: b' ',
+        indicator2: b' ',
+        subfields,
+    })
+}
+
+/// Returns the length of the serialized field including the field
+/// terminator.
+fn field_length(field: &Field, indicator_count: usize) -> usize {
+    match field {
+        Field::Control(cf) => cf.value().len() + 1,
+        Field::Data(df) => {
+            indicator_count
+                + df.subfields()
+                    .map(|subfield| subfield.value().len() + 2)
+                    .sum::<usize>()
+                + 1
+        }
+    }
+}
+
 #[cfg_attr(feature = "perf-inline", inline(always))]
 fn parse_indicator(i: &mut &[u8]) -> ModalResult<u8> {
-    one_of(|b: u8| {
-        b == b' ' || b.is_ascii_lowercase() || b.is_ascii_digit()
-    })
-    .parse_next(i)
+    one_of(is_indicator).parse_next(i)
+}
+
+/// Parses the given number of indicators (at most two). Indicators,
+/// which aren't defined by the record format, are blank.
+#[cfg_attr(feature = "perf-inline", inline(always))]
+fn parse_indicators(
+    count: u8,
+) -> impl FnMut(&mut &[u8]) -> ModalResult<(u8, u8)> {
+    move |i: &mut &[u8]| match count {
+        0 => Ok((b' ', b' ')),
+        1 => Ok((parse_indicator.parse_next(i)?, b' ')),
+        _ => (parse_indicator, parse_indicator).parse_next(i),
+    }
 }
 
 #[cfg_attr(feature = "perf-inline", inline(always))]
@@ -425,6 +1333,434 @@ mod tests {
         assert!(ByteRecord::from_bytes(bytes).is_ok());
     }
 
+    #[test]
+    fn test_parse_record_local_tags() -> TestResult {
+        let mut bytes = Vec::<u8>::new();
+        crate::RecordBuilder::default()
+            .leader(Leader::new(b"00000nam a2200000 c 4500")?)
+            .control_field("001", "123")
+            .data_field("CAT", b' ', b' ', [(b'a', "BATCH")])
+            .data_field("LKR", b' ', b' ', [(b'a', "UP")])
+            .build()?
+            .write_to(&mut bytes)?;
+
+        let record = ByteRecord::from_bytes(&bytes).expect("record");
+        let options = MatchOptions::default();
+        let matcher =
+            crate::matcher::RecordMatcher::new("CAT.a == 'BATCH'")?;
+        assert!(matcher.is_match(&record, &options));
+
+        let matcher =
+            crate::matcher::RecordMatcher::new("[A-Z][A-Z][A-Z]?")?;
+        assert!(matcher.is_match(&record, &options));
+
+        let tags: Vec<_> = record
+            .fields()
+            .map(|field| field.tag().to_string())
+            .collect();
+        assert_eq!(tags, vec!["001", "CAT", "LKR"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_record_control_field_rule() -> TestResult {
+        let bytes = b"00077nz  a2200061nc 4500\
+            001000400000FMT000300004100000800007\x1e\
+            123\x1eAU\x1e1 \x1faAda\x1e\x1d";
+
+        let rule = "00.,FMT".parse::<ControlFieldRule>()?;
+        let record =
+            ByteRecord::from_bytes_with(bytes, &rule).expect("record");
+        let kinds: Vec<_> = record
+            .fields()
+            .map(|field| {
+                (field.tag().to_string(), field.is_control_field())
+            })
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                ("001".into(), true),
+                ("FMT".into(), true),
+                ("100".into(), false)
+            ]
+        );
+
+        let mut warnings = vec![];
+        let lenient = parse_record_lenient(bytes, &rule, &mut warnings)
+            .expect("record");
+        assert_eq!(lenient.fields, record.fields);
+        assert!(warnings.is_empty());
+
+        // By default, `FMT` is a data field with invalid indicators.
+        assert_eq!(
+            ByteRecord::from_bytes(bytes).unwrap_err().kind(),
+            &ParseRecordErrorKind::InvalidIndicator("FMT".into())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_record_iso2709() -> TestResult {
+        let mut bytes = Vec::<u8>::new();
+        crate::RecordBuilder::default()
+            .leader(Leader::new(b"00000nM2.01200000   3410")?)
+            .control_field("001", "123")
+            .data_field("100", b'a', b' ', [(b'a', "Title")])
+            .build()?
+            .write_to(&mut bytes)?;
+
+        assert_eq!(
+            bytes,
+            b"00061nM2.01200047   3410\
+              0010040000010000900040\x1e\
+              123\x1ea\x1faTitle\x1e\x1d"
+        );
+
+        let record = ByteRecord::from_bytes(&bytes).expect("record");
+        let options = MatchOptions::default();
+        let matcher =
+            crate::matcher::RecordMatcher::new("100/a#.a == 'Title'")?;
+        assert!(matcher.is_match(&record, &options));
+
+        let mut warnings = vec![];
+        let lenient = parse_record_lenient(
+            &bytes,
+            &Default::default(),
+            &mut warnings,
+        )
+        .expect("record");
+        assert_eq!(lenient.fields, record.fields);
+        assert!(warnings.is_empty());
+
+        let bytes = b"00062nam0 0200049   450 \
+            001000400000200000800004\x1e123\x1e\x1faTitle\x1e\x1d";
+        let record = ByteRecord::from_bytes(bytes).expect("record");
+        assert_eq!(record.leader().indicator_count(), 0);
+        assert_eq!(
+            record.fields().nth(1).unwrap().to_string(),
+            "200 $a Title"
+        );
+
+        let record = ByteRecord::from_fields(
+            Leader::new(b"00000nM2.01200000   3410")?,
+            vec![Field::Data(DataField::new(
+                crate::Tag::from_bytes(b"100")?,
+                b'a',
+                b'1',
+                vec![],
+            ))],
+        );
+        assert!(record.write_to(&mut Vec::<u8>::new()).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_oversize_record() -> TestResult {
+        let value = vec![b'x'; 8000];
+        let mut fields = vec![Field::Control(ControlField {
+            tag: Tag::from_bytes(b"001")?,
+            value: b"123",
+        })];
+
+        for _ in 0..15 {
+            fields.push(Field::Data(DataField {
+                tag: Tag::from_bytes(b"856")?,
+                indicator1: b'4',
+                indicator2: b' ',
+                subfields: vec![Subfield::new(b'u', &value)],
+            }));
+        }
+
+        let ldr = Leader::new(b"00000nam a2200000 a 4500")?;
+        let record = ByteRecord::from_fields(ldr, fields);
+        assert!(record.write_to(&mut Vec::<u8>::new()).is_err());
+
+        // oversize form
+        let mut bytes = Vec::<u8>::new();
+        let dropped =
+            record.write_with(&mut bytes, OversizePolicy::Oversize)?;
+        assert!(dropped.is_empty());
+        assert!(bytes.len() > MAX_RECORD_LENGTH);
+        assert_eq!(&bytes[0..5], b"99999");
+
+        let result = ByteRecord::from_bytes(&bytes).expect("record");
+        assert_eq!(result.fields, record.fields);
+        assert_eq!(result.raw_data(), Some(&bytes[..]));
+
+        let mut out = Vec::<u8>::new();
+        result.write_to(&mut out)?;
+        assert_eq!(out, bytes);
+
+        let err = ByteRecord::from_bytes(&bytes[..bytes.len() - 1])
+            .unwrap_err();
+        assert_eq!(
+            err.kind(),
+            &ParseRecordErrorKind::MissingRecordTerminator
+        );
+
+        // split into linked records
+        let mut bytes = Vec::<u8>::new();
+        record.write_with(&mut bytes, OversizePolicy::Split)?;
+
+        let parts: Vec<_> = bytes
+            .split_inclusive(|b| *b == GROUP_SEPARATOR)
+            .map(|data| ByteRecord::from_bytes(data).expect("record"))
+            .collect();
+
+        assert_eq!(parts.len(), 2);
+        assert!(parts.iter().all(|part| {
+            part.raw_data().unwrap().len() <= MAX_RECORD_LENGTH
+                && part.control_number().unwrap() == "123"
+        }));
+        assert_eq!(
+            parts
+                .iter()
+                .map(|part| part.fields().count())
+                .sum::<usize>(),
+            17 + 2
+        );
+
+        let options = MatchOptions::default();
+        let value = |part: &ByteRecord, path: &str| {
+            let path = crate::Path::new(path).unwrap();
+            part.first(&path, &options)
+                .map(|value| value.to_str_lossy().to_string())
+        };
+
+        for (i, part) in parts.iter().enumerate() {
+            let g = format!("part {}/2", i + 1);
+            assert_eq!(value(part, "773.g"), Some(g));
+            assert_eq!(value(part, "773.w"), Some("123".into()));
+        }
+
+        // a record within the limit isn't split
+        let small = ByteRecord::from_fields(
+            record.leader.clone(),
+            record.fields[..3].to_vec(),
+        );
+        let mut bytes = Vec::<u8>::new();
+        small.write_with(&mut bytes, OversizePolicy::Split)?;
+        let result = ByteRecord::from_bytes(&bytes).expect("record");
+        assert_eq!(result.fields, small.fields);
+
+        // truncate
+        let mut bytes = Vec::<u8>::new();
+        let dropped =
+            record.write_with(&mut bytes, OversizePolicy::Truncate)?;
+        assert_eq!(dropped.len(), 3);
+        assert!(dropped.iter().all(|tag| tag == "856"));
+
+        let result = ByteRecord::from_bytes(&bytes).expect("record");
+        assert_eq!(result.fields().count(), 13);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_oversize_record_split_link() -> TestResult {
+        let value = vec![b'x'; 8000];
+        let data_field = |tag: &'static [u8], value| {
+            Field::Data(DataField {
+                tag: Tag(tag),
+                indicator1: b' ',
+                indicator2: b' ',
+                subfields: vec![Subfield::new(b'a', value)],
+            })
+        };
+
+        let mut fields = vec![
+            Field::Control(ControlField {
+                tag: Tag(b"001"),
+                value: b"123",
+            }),
+            data_field(b"100", b"Lovelace, Ada"),
+        ];
+        fields.extend((0..15).map(|_| data_field(b"856", &value)));
+        fields.push(data_field(b"900", b"foo"));
+
+        let ldr = Leader::new(b"00000nam a2200000 a 4500")?;
+        let record =
+            ByteRecord::from_fields(ldr.clone(), fields.clone());
+
+        let mut bytes = Vec::<u8>::new();
+        record.write_with(&mut bytes, OversizePolicy::Split)?;
+
+        // the link field is inserted in tag order
+        for data in bytes.split_inclusive(|b| *b == GROUP_SEPARATOR) {
+            let part = ByteRecord::from_bytes(data).expect("record");
+            let tags: Vec<_> =
+                part.fields().map(|field| field.tag().0).collect();
+            assert!(tags.is_sorted());
+            assert_eq!(
+                tags.iter().filter(|tag| **tag == b"773").count(),
+                1
+            );
+        }
+
+        // a record with a host item entry can't be split
+        fields.insert(2, data_field(b"773", b"foo"));
+        let record = ByteRecord::from_fields(ldr, fields);
+        let err = record
+            .write_with(&mut Vec::<u8>::new(), OversizePolicy::Split)
+            .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_oversize_record_truncate_skips_large_field() -> TestResult {
+        let value = vec![b'x'; MAX_RECORD_LENGTH];
+        let fields = vec![
+            Field::Control(ControlField {
+                tag: Tag(b"001"),
+                value: b"123",
+            }),
+            Field::Control(ControlField {
+                tag: Tag(b"009"),
+                value: &value,
+            }),
+            Field::Control(ControlField {
+                tag: Tag(b"009"),
+                value: b"foo",
+            }),
+        ];
+
+        let ldr = Leader::new(b"00000nam a2200000 a 4500")?;
+        let record = ByteRecord::from_fields(ldr, fields);
+
+        let mut bytes = Vec::<u8>::new();
+        let dropped =
+            record.write_with(&mut bytes, OversizePolicy::Truncate)?;
+        assert_eq!(dropped, vec![Tag(b"009")]);
+
+        // the small field following the large field is kept
+        let result = ByteRecord::from_bytes(&bytes).expect("record");
+        assert_eq!(
+            result.fields,
+            [record.fields[0].clone(), record.fields[2].clone()]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_to_without_raw_data() -> TestResult {
+        let bytes = include_bytes!("../../../tests/data/ada.mrc");
+        let record = ByteRecord::from_bytes(bytes)?;
+        let record = ByteRecord::from_fields(
+            record.leader().clone(),
+            record.fields().cloned().collect(),
+        );
+
+        let mut out = Vec::<u8>::new();
+        record.write_to(&mut out)?;
+        assert_eq!(out, bytes);
+
+        let ldr = Leader::new(b"99999nz  a2299999nc 4500")?;
+        let record = ByteRecord::from_fields(
+            ldr,
+            vec![Field::Control(ControlField {
+                tag: crate::Tag::from_bytes(b"001")?,
+                value: b"123",
+            })],
+        );
+
+        let mut out = Vec::<u8>::new();
+        record.write_to(&mut out)?;
+        assert_eq!(
+            out,
+            b"00042nz  a2200037nc 4500001000400000\x1e123\x1e\x1d"
+        );
+
+        assert!(ByteRecord::from_bytes(&out).is_ok());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_to_exceeds_limits() -> TestResult {
+        let value = vec![b'x'; 10_000];
+        let record = ByteRecord::from_fields(
+            Leader::new(b"00000nz  a2200000nc 4500")?,
+            vec![Field::Control(ControlField {
+                tag: crate::Tag::from_bytes(b"001")?,
+                value: &value,
+            })],
+        );
+
+        let mut out = Vec::<u8>::new();
+        assert!(record.write_to(&mut out).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_unsupported_leader() {
+        let data = include_bytes!("../../../tests/data/ada.mrc");
+
+        for (position, value) in [(10, b'3'), (11, b'3'), (11, b'1')] {
+            let mut data = data.to_vec();
+            data[position] = value;
+
+            let err = ByteRecord::from_bytes(&data).unwrap_err();
+            assert_eq!(
+                err.kind(),
+                &ParseRecordErrorKind::InvalidLeader
+            );
+        }
+    }
+
+    #[test]
+    fn test_write_to_unsupported_leader() -> TestResult {
+        for (leader, position, value) in [
+            (b"00000nz  a9200000nc 4500", 10, b'9'),
+            (b"00000nz  a2300000nc 4500", 11, b'3'),
+        ] {
+            let leader = Leader::new(leader)?;
+            let result = crate::RecordBuilder::default()
+                .leader(leader.clone())
+                .control_field("001", "123")
+                .data_field("100", b'1', b' ', [(b'a', "Ada")])
+                .build();
+            assert_eq!(
+                result.unwrap_err(),
+                crate::BuildRecordError::InvalidLeaderValue(
+                    position, value
+                )
+            );
+
+            let record = ByteRecord::from_fields(
+                leader,
+                vec![Field::Data(DataField {
+                    tag: crate::Tag::from_bytes(b"100")?,
+                    indicator1: b'1',
+                    indicator2: b' ',
+                    subfields: vec![Subfield::new(b'a', b"Ada")],
+                })],
+            );
+
+            for policy in [OversizePolicy::Error, OversizePolicy::Split]
+            {
+                let mut out = Vec::<u8>::new();
+                let err =
+                    record.write_with(&mut out, policy).unwrap_err();
+                assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+                assert!(out.is_empty());
+            }
+
+            let mut out = Vec::<u8>::new();
+            let err = record.write_to(&mut out).unwrap_err();
+            assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn test_string_record_try_from() -> TestResult {
         let bytes = include_bytes!("../../../tests/data/ada.mrc");
@@ -444,4 +1780,175 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_parse_record_lenient() -> TestResult {
+        let bytes = include_bytes!("../../../tests/data/ada.mrc");
+        let mut warnings = vec![];
+        let record = parse_record_lenient(
+            bytes,
+            &Default::default(),
+            &mut warnings,
+        )?;
+        let expected = ByteRecord::from_bytes(bytes)?;
+        assert_eq!(
+            record,
+            ByteRecord::from_fields(
+                expected.leader().clone(),
+                expected.fields().cloned().collect()
+            )
+        );
+        assert!(warnings.is_empty());
+
+        let bytes = b"00099nz  a2200099nc 4500\
+            001000100000100000000099\x1e123\x1e#A\x1faAda\x1e\x1d";
+        let mut warnings = vec![];
+        let record = parse_record_lenient(
+            bytes,
+            &Default::default(),
+            &mut warnings,
+        )?;
+        assert!(record.raw_data().is_none());
+
+        let mut out = vec![];
+        record.write_to(&mut out)?;
+        assert_eq!(
+            out,
+            b"00062nz  a2200049nc 4500\
+              001000400000100000800004\x1e123\x1e a\x1faAda\x1e\x1d"
+        );
+
+        let messages: Vec<_> =
+            warnings.iter().map(ParseWarning::message).collect();
+        assert_eq!(
+            messages,
+            vec![
+                "record length '00099' corrected to '00062'",
+                "base address of data '00099' corrected to '00049'",
+                "directory entry of field 001 corrected",
+                "directory entry of field 100 corrected",
+                "invalid indicator '#' of field 100 replaced by a blank",
+                "uppercase indicator 'A' of field 100 converted to \
+                 lowercase",
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_record_lenient_subfields() -> TestResult {
+        let bytes = b"00000nz  a2200000nc 4500\
+            245000000000\x1e\x1fa\x1fb\x1f\x1fcabc";
+        let mut warnings = vec![];
+        let record = parse_record_lenient(
+            bytes,
+            &Default::default(),
+            &mut warnings,
+        )?;
+        let Some(Field::Data(df)) = record.fields().next() else {
+            panic!("expected data field");
+        };
+
+        let codes: Vec<_> = df.subfields().map(|s| s.code).collect();
+        assert_eq!(codes, vec![b'a', b'b', b'c']);
+
+        let messages: Vec<_> =
+            warnings.iter().map(ParseWarning::message).collect();
+        assert!(messages.contains(&"missing record terminator"));
+        assert!(messages.contains(&"missing field terminator"));
+        assert!(messages.contains(
+            &"missing indicators of field 245 replaced by blanks"
+        ));
+        assert!(
+            messages.contains(&"empty subfield of field 245 ignored")
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_record_lenient_invalid() {
+        let mut warnings = vec![];
+        assert!(
+            parse_record_lenient(
+                b"00000nz",
+                &Default::default(),
+                &mut warnings
+            )
+            .is_err()
+        );
+
+        let bytes = b"00000nz  a2200000nc 4500\
+            001000100000\x1e123\x1e456\x1e\x1d";
+        assert!(
+            parse_record_lenient(
+                bytes,
+                &Default::default(),
+                &mut warnings
+            )
+            .is_err()
+        );
+
+        let bytes = b"00000nz  a2200000nc 4500001000100000";
+        assert!(
+            parse_record_lenient(
+                bytes,
+                &Default::default(),
+                &mut warnings
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_parse_record_error_kind() {
+        use ParseRecordErrorKind::*;
+
+        let valid = b"00064nz  a2200049nc 4500\
+            001000400000245001000004\x1e123\x1e10\x1faTitle\x1e\x1d";
+        assert!(ByteRecord::from_bytes(valid).is_ok());
+
+        let error = |range: std::ops::Range<usize>, value: &[u8]| {
+            let mut bytes = valid.to_vec();
+            bytes.splice(range, value.iter().copied());
+            let err = ByteRecord::from_bytes(&bytes).unwrap_err();
+            (err.kind().clone(), err.position())
+        };
+
+        assert_eq!(error(10..12, b"2#"), (InvalidLeader, 0));
+        assert_eq!(error(10..12, b"33"), (InvalidLeader, 10));
+        assert_eq!(
+            error(36..39, b"24-"),
+            (InvalidDirectoryEntry(1), 36)
+        );
+        assert_eq!(error(48..49, b""), (InvalidDirectoryEntry(2), 48));
+        assert_eq!(
+            error(40..64, b""),
+            (MissingDirectoryTerminator, 36)
+        );
+        assert_eq!(error(12..17, b"00050"), (BaseAddressMismatch, 12));
+        assert_eq!(error(0..5, b"00065"), (RecordLengthMismatch, 0));
+        assert_eq!(error(63..64, b"x"), (MissingRecordTerminator, 63));
+        assert_eq!(
+            error(27..31, b"0005"),
+            (FieldLengthMismatch("001".into()), 52)
+        );
+        assert_eq!(
+            error(52..53, b"x"),
+            (MissingFieldTerminator("001".into()), 52)
+        );
+        assert_eq!(
+            error(53..54, b"A"),
+            (InvalidIndicator("245".into()), 53)
+        );
+        assert_eq!(
+            error(56..57, b"!"),
+            (InvalidSubfield("245".into()), 55)
+        );
+        assert_eq!(
+            error(62..63, b"x"),
+            (MissingFieldTerminator("245".into()), 63)
+        );
+    }
 }
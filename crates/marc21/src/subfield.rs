@@ -1,4 +1,5 @@
 use std::fmt::{self, Display};
+use std::io::{self, Write};
 use std::iter;
 use std::str::Utf8Error;
 
@@ -8,6 +9,8 @@ use winnow::prelude::*;
 use winnow::stream::AsChar;
 use winnow::token::{one_of, take_till};
 
+#[cfg(feature = "serde")]
+use crate::Value;
 use crate::common::*;
 use crate::error::ParseRecordError;
 
@@ -18,6 +21,21 @@ pub struct Subfield<'a> {
 }
 
 impl<'a> Subfield<'a> {
+    /// Creates a new subfield from a code and a value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Subfield;
+    ///
+    /// let subfield = Subfield::new(b'a', b"abc");
+    /// assert_eq!(subfield.code(), &b'a');
+    /// assert_eq!(subfield.value(), b"abc");
+    /// ```
+    pub fn new(code: u8, value: &'a [u8]) -> Self {
+        Self { code, value }
+    }
+
     pub fn from_bytes<B: AsRef<[u8]>>(
         bytes: &'a B,
     ) -> Result<Self, ParseRecordError<'a>> {
@@ -46,6 +64,27 @@ impl<'a> Subfield<'a> {
         let _ = std::str::from_utf8(self.value)?;
         Ok(())
     }
+
+    /// Write the subfield (including the unit separator) into the given
+    /// writer.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Subfield;
+    ///
+    /// let subfield = Subfield::new(b'a', b"abc");
+    /// let mut out = Vec::<u8>::new();
+    /// subfield.write_to(&mut out)?;
+    ///
+    /// assert_eq!(out, b"\x1faabc");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn write_to<W: Write>(&self, out: &mut W) -> io::Result<()> {
+        out.write_all(&[UNIT_SEPARATOR, self.code])?;
+        out.write_all(self.value)
+    }
 }
 
 impl Display for Subfield<'_> {
@@ -58,6 +97,21 @@ impl Display for Subfield<'_> {
     }
 }
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for Subfield<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("Subfield", 2)?;
+        state.serialize_field("code", &char::from(self.code))?;
+        state.serialize_field("value", &Value::from(self.value))?;
+        state.end()
+    }
+}
+
 impl<'a> IntoIterator for &'a Subfield<'a> {
     type Item = &'a Subfield<'a>;
     type IntoIter = iter::Once<Self::Item>;
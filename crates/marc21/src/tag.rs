@@ -1,15 +1,21 @@
 use std::fmt::{self, Display};
 use std::ops::Index;
+use std::str::FromStr;
 
 use bstr::ByteSlice;
 use winnow::prelude::*;
 use winnow::token::take;
 
-use crate::error::ParseRecordError;
+use crate::error::{ParseRecordError, ParseRecordErrorKind};
+use crate::matcher::{ParseMatcherError, TagMatcher};
 
 /// A three character string to identify variable fields.
+///
+/// A tag consists of three ASCII digits or letters; besides the
+/// numeric MARC 21 tags, local tags like `CAT`, `SYS` or `LKR` are
+/// accepted.
 #[derive(Debug, Clone, PartialEq)]
-pub struct Tag<'a>(&'a [u8]);
+pub struct Tag<'a>(pub(crate) &'a [u8]);
 
 impl<'a> Tag<'a> {
     /// Create a new tag from a byte slice.
@@ -30,13 +36,18 @@ impl<'a> Tag<'a> {
     where
         B: AsRef<[u8]>,
     {
-        parse_tag
-            .parse(bytes.as_ref())
-            .map_err(ParseRecordError::from_parse)
+        parse_tag.parse(bytes.as_ref()).map_err(|e| {
+            ParseRecordError::from_parse(e)
+                .with_kind(ParseRecordErrorKind::InvalidTag)
+        })
     }
 
     /// Whether the tag is associated with a control field or not.
     ///
+    /// All tags starting with `00` are control fields, as defined by
+    /// MARC 21. Records with other control fields can be read and
+    /// built with a [`ControlFieldRule`].
+    ///
     /// # Example
     ///
     /// ```rust
@@ -48,8 +59,12 @@ impl<'a> Tag<'a> {
     /// let tag = Tag::from_bytes(b"123")?;
     /// assert!(!tag.is_control_field());
     ///
+    /// let tag = Tag::from_bytes(b"CAT")?;
+    /// assert!(!tag.is_control_field());
+    ///
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
+    #[inline(always)]
     pub fn is_control_field(&self) -> bool {
         self.0.starts_with(b"00")
     }
@@ -106,12 +121,101 @@ impl Display for Tag<'_> {
     }
 }
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for Tag<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.0.to_str_lossy())
+    }
+}
+
+/// The rule, which decides whether a tag is associated with a control
+/// field or not, when a record is parsed.
+///
+/// By default, all tags starting with `00` are control fields, as
+/// defined by MARC 21 (see [`Tag::is_control_field`]). A custom rule
+/// consists of a list of tag matchers and a tag belongs to a control
+/// field, if it matches any of them. Thus, local control fields (e.g.
+/// `FMT` or `SYS` of Aleph exports) can be added to the MARC 21 rule,
+/// which corresponds to the matcher `00.`.
+///
+/// The rule is a setting of the reader (see
+/// [`MarcReadOptions::control_fields`](crate::io::MarcReadOptions::control_fields))
+/// and of the owned record types (see
+/// [`ControlFieldBuf::new_with`](crate::ControlFieldBuf::new_with) and
+/// [`RecordBuilder::control_fields`](crate::RecordBuilder::control_fields)).
+///
+/// # Example
+///
+/// ```rust
+/// use marc21::ControlFieldRule;
+/// use marc21::prelude::*;
+///
+/// let rule: ControlFieldRule = "00., FMT".parse()?;
+/// assert!(rule.is_control_field(&Tag::from_bytes(b"001")?));
+/// assert!(rule.is_control_field(&Tag::from_bytes(b"FMT")?));
+/// assert!(!rule.is_control_field(&Tag::from_bytes(b"CAT")?));
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ControlFieldRule(Option<Vec<TagMatcher>>);
+
+impl ControlFieldRule {
+    /// Creates a rule, which treats every tag matching any of the
+    /// given tag matchers as a control field.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::ControlFieldRule;
+    /// use marc21::matcher::TagMatcher;
+    /// use marc21::prelude::*;
+    ///
+    /// let rule = ControlFieldRule::new([TagMatcher::new("SYS")?]);
+    /// assert!(rule.is_control_field(&Tag::from_bytes(b"SYS")?));
+    /// assert!(!rule.is_control_field(&Tag::from_bytes(b"001")?));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn new<I>(matchers: I) -> Self
+    where
+        I: IntoIterator<Item = TagMatcher>,
+    {
+        Self(Some(matchers.into_iter().collect()))
+    }
+
+    /// Whether the given tag is associated with a control field or
+    /// not.
+    #[cfg_attr(feature = "perf-inline", inline(always))]
+    pub fn is_control_field(&self, tag: &Tag) -> bool {
+        match self.0 {
+            None => tag.is_control_field(),
+            Some(ref matchers) => {
+                matchers.iter().any(|matcher| matcher.is_match(tag))
+            }
+        }
+    }
+}
+
+impl FromStr for ControlFieldRule {
+    type Err = ParseMatcherError;
+
+    /// Parses a rule from a comma-separated list of tag matchers.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.split(',')
+            .map(|matcher| TagMatcher::new(matcher.trim()))
+            .collect::<Result<Vec<_>, _>>()
+            .map(|matchers| Self(Some(matchers)))
+    }
+}
+
 pub(crate) fn parse_tag<'a>(i: &mut &'a [u8]) -> ModalResult<Tag<'a>> {
     take(3usize)
         .verify(|value: &[u8]| {
-            value[0].is_ascii_digit()
-                && value[1].is_ascii_digit()
-                && value[2].is_ascii_digit()
+            value.iter().all(u8::is_ascii_alphanumeric)
         })
         .map(|value: &[u8]| Tag(value))
         .parse_next(i)
@@ -126,8 +230,11 @@ mod tests {
     fn test_parse_tag_ref() -> TestResult {
         assert_eq!(parse_tag.parse(b"001").unwrap(), Tag(b"001"));
         assert_eq!(parse_tag.parse(b"123").unwrap(), Tag(b"123"));
+        assert_eq!(parse_tag.parse(b"CAT").unwrap(), Tag(b"CAT"));
+        assert_eq!(parse_tag.parse(b"a1b").unwrap(), Tag(b"a1b"));
         assert!(parse_tag.parse(b"1234").is_err());
-        assert!(parse_tag.parse(b"abc").is_err());
+        assert!(parse_tag.parse(b"00 ").is_err());
+        assert!(parse_tag.parse(b"0-1").is_err());
 
         Ok(())
     }
@@ -136,8 +243,9 @@ mod tests {
     fn test_tag_from_bytes() -> TestResult {
         assert_eq!(Tag::from_bytes(b"001")?, Tag(b"001"));
         assert_eq!(Tag::from_bytes(b"123")?, Tag(b"123"));
+        assert_eq!(Tag::from_bytes(b"LKR")?, Tag(b"LKR"));
         assert!(Tag::from_bytes(b"1234").is_err());
-        assert!(Tag::from_bytes(b"abc").is_err());
+        assert!(Tag::from_bytes(b"ab").is_err());
 
         Ok(())
     }
@@ -150,6 +258,34 @@ mod tests {
         let tag = Tag::from_bytes(b"123")?;
         assert!(!tag.is_control_field());
 
+        let tag = Tag::from_bytes(b"SYS")?;
+        assert!(!tag.is_control_field());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_control_field_rule() -> TestResult {
+        let rule = ControlFieldRule::default();
+        assert!(rule.is_control_field(&Tag(b"001")));
+        assert!(rule.is_control_field(&Tag(b"00A")));
+        assert!(!rule.is_control_field(&Tag(b"100")));
+        assert!(!rule.is_control_field(&Tag(b"FMT")));
+
+        let rule = "00.,FMT, SYS".parse::<ControlFieldRule>()?;
+        assert!(rule.is_control_field(&Tag(b"001")));
+        assert!(rule.is_control_field(&Tag(b"FMT")));
+        assert!(rule.is_control_field(&Tag(b"SYS")));
+        assert!(!rule.is_control_field(&Tag(b"CAT")));
+        assert!(!rule.is_control_field(&Tag(b"100")));
+
+        let rule = "0[01].".parse::<ControlFieldRule>()?;
+        assert!(rule.is_control_field(&Tag(b"010")));
+        assert!(!rule.is_control_field(&Tag(b"020")));
+
+        assert!("00.,".parse::<ControlFieldRule>().is_err());
+        assert!("0000".parse::<ControlFieldRule>().is_err());
+
         Ok(())
     }
 
@@ -56,6 +56,27 @@ impl<'a> From<&'a [u8; 0]> for Value<'a> {
     }
 }
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for Value<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        crate::common::serialize_value(&self.0, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Value<'_> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        crate::common::deserialize_value(deserializer)
+            .map(|value| Self(Cow::Owned(value)))
+    }
+}
+
 impl<T> PartialEq<T> for Value<'_>
 where
     T: AsRef<[u8]>,
@@ -3,9 +3,14 @@ mod query;
 
 pub(crate) mod prelude {
     pub(crate) type TestResult = Result<(), Box<dyn std::error::Error>>;
-    pub use marc21::matcher::{MatchOptions, RecordMatcher};
+    pub use marc21::matcher::{
+        MatchOptions, RecordMatcher, StrsimAlgorithm,
+    };
     pub use marc21::{ByteRecord, Query};
 
     pub(crate) static ADA_LOVELACE: &[u8] =
         include_bytes!("../../../../tests/data/ada.mrc");
+
+    pub(crate) static MINNA_VON_BARNHELM: &[u8] =
+        include_bytes!("../../../../tests/data/minna.mrc");
 }
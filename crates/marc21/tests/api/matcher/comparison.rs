@@ -108,3 +108,51 @@ fn compare_data_field() -> TestResult {
 
     Ok(())
 }
+
+#[test]
+fn compare_typed_values() -> TestResult {
+    let record = ByteRecord::from_bytes(&ADA_LOVELACE)?;
+    let options = MatchOptions::default();
+
+    let matcher = RecordMatcher::new("040.d >= 1841")?;
+    assert!(matcher.is_match(&record, &options));
+
+    let matcher = RecordMatcher::new("040.d > 1841")?;
+    assert!(!matcher.is_match(&record, &options));
+
+    let matcher = RecordMatcher::new("040{ int(d) < '1900' }")?;
+    assert!(matcher.is_match(&record, &options));
+
+    let matcher = RecordMatcher::new("375.a == 2.0")?;
+    assert!(matcher.is_match(&record, &options));
+
+    let matcher = RecordMatcher::new("001 > 119232021")?;
+    assert!(matcher.is_match(&record, &options));
+
+    let matcher = RecordMatcher::new("date(005[0:8]) >= '2024-01-01'")?;
+    assert!(matcher.is_match(&record, &options));
+
+    let matcher = RecordMatcher::new(
+        "timestamp(005) >= '2025-07-20T17:39:11' \
+            && timestamp(005) < 20250720173912",
+    )?;
+    assert!(matcher.is_match(&record, &options));
+
+    let matcher = RecordMatcher::new("int(008.date_entered) > 950000")?;
+    assert!(matcher.is_match(&record, &options));
+
+    // values, which can't be converted, never match
+    let matcher = RecordMatcher::new("100.d >= 1800")?;
+    assert!(!matcher.is_match(&record, &options));
+
+    let matcher = RecordMatcher::new("040.b != 1")?;
+    assert!(!matcher.is_match(&record, &options));
+
+    let matcher = RecordMatcher::new("int(005) != 0")?;
+    assert!(!matcher.is_match(&record, &options));
+
+    assert!(RecordMatcher::new("date(005) > '2024-02-30'").is_err());
+    assert!(RecordMatcher::new("040{ int(d) > 'abc' }").is_err());
+
+    Ok(())
+}
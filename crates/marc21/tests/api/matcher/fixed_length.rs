@@ -104,3 +104,36 @@ fn fixed_length_fields() -> TestResult {
 
     Ok(())
 }
+
+#[test]
+fn fixed_length_field_elements() -> TestResult {
+    let record = ByteRecord::from_bytes(&ADA_LOVELACE)?;
+    let options = MatchOptions::default();
+
+    let matcher = RecordMatcher::new("008.date_entered == '950316'")?;
+    assert!(matcher.is_match(&record, &options));
+
+    let matcher = RecordMatcher::new("008.kind_of_record == 'a'")?;
+    assert!(matcher.is_match(&record, &options));
+
+    let matcher = RecordMatcher::new(
+        "008.level_of_establishment in ['a', 'c'] && 001 == '119232022'",
+    )?;
+    assert!(matcher.is_match(&record, &options));
+
+    let matcher =
+        RecordMatcher::new("!(008.cataloging_source not in ['c'])")?;
+    assert!(matcher.is_match(&record, &options));
+
+    // element not defined for authority records
+    let matcher = RecordMatcher::new("008.lang == 'ger'")?;
+    assert!(!matcher.is_match(&record, &options));
+
+    let matcher = RecordMatcher::new("008.lang != 'ger'")?;
+    assert!(!matcher.is_match(&record, &options));
+
+    // unknown element
+    assert!(RecordMatcher::new("008.foo == 'a'").is_err());
+
+    Ok(())
+}
@@ -0,0 +1,88 @@
+use crate::prelude::*;
+
+#[test]
+fn folding_operator_flags() -> TestResult {
+    let record = ByteRecord::from_bytes(&MINNA_VON_BARNHELM)?;
+    let options = MatchOptions::default();
+
+    // The record is NFD normalized, the expressions are NFC normalized.
+    let matcher = RecordMatcher::new(
+        "400/1#.t == 'Minna von Barnhelm oder Das Soldatengl\u{fc}ck'",
+    )?;
+    assert!(!matcher.is_match(&record, &options));
+
+    let matcher = RecordMatcher::new(
+        "400/1#.t ==n 'Minna von Barnhelm oder Das Soldatengl\u{fc}ck'",
+    )?;
+    assert!(matcher.is_match(&record, &options));
+
+    let matcher = RecordMatcher::new(
+        "400/1#.t ==a 'Minna von Barnhelm oder Das Soldatengluck'",
+    )?;
+    assert!(matcher.is_match(&record, &options));
+
+    let matcher = RecordMatcher::new(
+        "400/1#.t ==ia 'MINNA VON BARNHELM ODER DAS SOLDATENGLUCK'",
+    )?;
+    assert!(matcher.is_match(&record, &options));
+
+    let matcher =
+        RecordMatcher::new("100/1#.t !=i 'MINNA VON BARNHELM'")?;
+    assert!(!matcher.is_match(&record, &options));
+
+    let matcher = RecordMatcher::new(
+        "100/1#.t ini ['minna von barnhelm', 'emilia galotti']",
+    )?;
+    assert!(matcher.is_match(&record, &options));
+
+    let matcher = RecordMatcher::new("100/1#.t =^i 'MINNA'")?;
+    assert!(matcher.is_match(&record, &options));
+
+    let matcher = RecordMatcher::new("100/1#.t =$i 'BARNHELM'")?;
+    assert!(matcher.is_match(&record, &options));
+
+    let matcher = RecordMatcher::new("400/1#.t =?ia 'GLUCK'")?;
+    assert!(matcher.is_match(&record, &options));
+
+    let matcher =
+        RecordMatcher::new("100/1#.t =*i 'MINNA VON BARNHELM'")?;
+    assert!(matcher.is_match(&record, &options));
+
+    let matcher = RecordMatcher::new("003 ==i 'de-101'")?;
+    assert!(matcher.is_match(&record, &options));
+
+    let matcher = RecordMatcher::new("003 ini ['de-101', 'de-588']")?;
+    assert!(matcher.is_match(&record, &options));
+
+    assert!(RecordMatcher::new("100/1#.t ==ii 'minna'").is_err());
+    assert!(RecordMatcher::new("100/1#.t ==x 'minna'").is_err());
+
+    Ok(())
+}
+
+#[test]
+fn folding_match_options() -> TestResult {
+    let record = ByteRecord::from_bytes(&MINNA_VON_BARNHELM)?;
+
+    let options = MatchOptions::new()
+        .case_insensitive(true)
+        .diacritic_insensitive(true);
+
+    let matcher = RecordMatcher::new(
+        "400/1#.t == 'minna von barnhelm oder das soldatengluck'",
+    )?;
+    assert!(matcher.is_match(&record, &options));
+    assert!(!matcher.is_match(&record, &MatchOptions::default()));
+
+    let matcher = RecordMatcher::new("400/1#.t =? 'GLUCK'")?;
+    assert!(matcher.is_match(&record, &options));
+    assert!(!matcher.is_match(&record, &MatchOptions::default()));
+
+    let options = MatchOptions::new().normalization_insensitive(true);
+    let matcher =
+        RecordMatcher::new("400/1#.t =$ 'Soldatengl\u{fc}ck'")?;
+    assert!(matcher.is_match(&record, &options));
+    assert!(!matcher.is_match(&record, &MatchOptions::default()));
+
+    Ok(())
+}
@@ -67,3 +67,64 @@ fn data_field_in() -> TestResult {
 
     Ok(())
 }
+
+#[test]
+fn control_field_in_file() -> TestResult {
+    let record = ByteRecord::from_bytes(&ADA_LOVELACE)?;
+    let options = MatchOptions::default();
+
+    let matcher = RecordMatcher::new("001 in @tests/data/ids.txt")?;
+    assert!(matcher.is_match(&record, &options));
+
+    let matcher =
+        RecordMatcher::new("001 in @'tests/data/ids.txt.gz'")?;
+    assert!(matcher.is_match(&record, &options));
+
+    let matcher = RecordMatcher::new("001 not in @tests/data/ids.txt")?;
+    assert!(!matcher.is_match(&record, &options));
+
+    let matcher = RecordMatcher::new("003 in @tests/data/ids.txt")?;
+    assert!(!matcher.is_match(&record, &options));
+
+    assert!(
+        RecordMatcher::new("001 in @tests/data/missing.txt").is_err()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn data_field_in_file() -> TestResult {
+    let record = ByteRecord::from_bytes(&ADA_LOVELACE)?;
+    let options = MatchOptions::default();
+
+    let matcher = RecordMatcher::new("035.a in @tests/data/ids.txt")?;
+    assert!(matcher.is_match(&record, &options));
+
+    let matcher =
+        RecordMatcher::new("035{ a in @tests/data/ids.txt.gz }")?;
+    assert!(matcher.is_match(&record, &options));
+
+    let matcher =
+        RecordMatcher::new("ALL 035.a in @tests/data/ids.txt")?;
+    assert!(!matcher.is_match(&record, &options));
+
+    let matcher =
+        RecordMatcher::new("035.a not in @tests/data/ids.txt")?;
+    assert!(matcher.is_match(&record, &options));
+
+    let matcher = RecordMatcher::new(
+        "035.a ini @tests/data/ids.txt && 001 in @tests/data/ids.txt",
+    )?;
+    assert!(matcher.is_match(&record, &options));
+
+    let matcher = RecordMatcher::new("035.a in @tests/data/ids.txt")?;
+    let options = MatchOptions::default().case_insensitive(true);
+    assert!(matcher.is_match(&record, &options));
+
+    let err = RecordMatcher::new("035.a in @tests/data/missing.txt")
+        .unwrap_err();
+    assert!(err.to_string().contains("tests/data/missing.txt"));
+
+    Ok(())
+}
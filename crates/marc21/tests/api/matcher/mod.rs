@@ -5,8 +5,10 @@ mod count;
 mod ends_with;
 mod exists;
 mod fixed_length;
+mod folding;
 mod group;
 mod r#in;
+mod prefilter;
 mod regex;
 mod starts_with;
 mod strsim;
@@ -0,0 +1,87 @@
+use crate::prelude::*;
+
+#[test]
+fn prefilter_rejects_only_non_matching_records() -> TestResult {
+    let options = MatchOptions::default();
+
+    for data in [ADA_LOVELACE, MINNA_VON_BARNHELM] {
+        let record = ByteRecord::from_bytes(data)?;
+
+        for matcher in [
+            "001 == '119232022'",
+            "001 in ['118540238', '119232022']",
+            "100/1#.a == 'Lovelace, Ada'",
+            "100/1#{ a =^ 'Lessing' && t =$ 'Barnhelm' }",
+            "075{ b == 'p' && 2 == 'gndgen' }",
+            "400.a =? ['Lovelace', 'Lessing']",
+            "548.4 in ['datl', 'datx']",
+            "100/1#.a == 'Lovelace, Ada' || 100/1#.t =? 'Minna'",
+            "042.a == 'gnd1' && 001 != '119232022'",
+        ] {
+            let matcher = RecordMatcher::new(matcher)?;
+            let prefilter =
+                matcher.prefilter(&options).expect("prefilter");
+
+            if !prefilter.is_match(data) {
+                assert!(!matcher.is_match(&record, &options));
+            }
+
+            if matcher.is_match(&record, &options) {
+                assert!(prefilter.is_match(data));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn prefilter_without_literals() -> TestResult {
+    let options = MatchOptions::default();
+
+    for matcher in [
+        "ldr.status == 'z'",
+        "001 != '119232022'",
+        "100/1#.a =~ '^Love'",
+        "100/1#.a =* 'Lovelace, A.'",
+        "100/1#.a ==i 'lovelace, ada'",
+        "100/1#{ ALL a == 'Lovelace, Ada' }",
+        "!(100/1#.a == 'Lovelace, Ada')",
+        "100/1#.a == 'Lovelace, Ada' || 100/1#.a =~ 'Lessing'",
+    ] {
+        let matcher = RecordMatcher::new(matcher)?;
+        assert!(matcher.prefilter(&options).is_none());
+    }
+
+    let matcher = RecordMatcher::new("100/1#.a == 'Lovelace, Ada'")?;
+    let options = MatchOptions::default().case_insensitive(true);
+    assert!(matcher.prefilter(&options).is_none());
+
+    Ok(())
+}
+
+#[test]
+fn prefilter_reader() -> TestResult {
+    use marc21::io::{ByteRecordsIter, MarcReader};
+    use marc21::prelude::*;
+
+    let options = MatchOptions::default();
+    let data = [ADA_LOVELACE, MINNA_VON_BARNHELM].concat();
+    let matcher = RecordMatcher::new("001 == '040992918'")?;
+    let prefilter = matcher.prefilter(&options).expect("prefilter");
+
+    let mut rdr = MarcReader::new(
+        &data[..],
+        MarcReadOptions::default().prefilter(prefilter),
+    );
+
+    let Some(Ok(record)) = rdr.next_byte_record() else {
+        panic!("expected record");
+    };
+
+    assert!(matcher.is_match(&record, &options));
+    assert_eq!(rdr.position(), ADA_LOVELACE.len());
+    assert!(rdr.next_byte_record().is_none());
+
+    Ok(())
+}
@@ -55,3 +55,75 @@ fn strsim_multiple_phrases() -> TestResult {
 
     Ok(())
 }
+
+#[test]
+fn strsim_algorithm() -> TestResult {
+    let record = ByteRecord::from_bytes(&ADA_LOVELACE)?;
+    let options = MatchOptions::default();
+
+    let matcher = RecordMatcher::new("100/1#.a =* 'Ada Lovelace'")?;
+    assert!(!matcher.is_match(&record, &options));
+
+    let options = MatchOptions::default()
+        .strsim_algorithm(StrsimAlgorithm::TokenSet);
+    assert!(matcher.is_match(&record, &options));
+
+    let options = MatchOptions::default()
+        .strsim_algorithm(StrsimAlgorithm::JaroWinkler);
+    let matcher = RecordMatcher::new("100/1#.a =* 'Lovelace, A.'")?;
+    assert!(matcher.is_match(&record, &options));
+
+    let options = options
+        .strsim_algorithm_threshold(StrsimAlgorithm::JaroWinkler, 0.99);
+    assert!(!matcher.is_match(&record, &options));
+
+    let options = MatchOptions::default()
+        .strsim_algorithm(StrsimAlgorithm::SorensenDice);
+    let matcher = RecordMatcher::new("100/1#.a =* 'Lovelace, Bda'")?;
+    assert!(matcher.is_match(&record, &options));
+
+    Ok(())
+}
+
+#[test]
+fn strsim_inline_algorithm() -> TestResult {
+    let record = ByteRecord::from_bytes(&ADA_LOVELACE)?;
+    let options = MatchOptions::default();
+
+    let matcher =
+        RecordMatcher::new("100/1#.a =*(token-set) 'Ada Lovelace'")?;
+    assert!(matcher.is_match(&record, &options));
+
+    let matcher =
+        RecordMatcher::new("100/1#.a !*(token-set) 'Ada Lovelace'")?;
+    assert!(!matcher.is_match(&record, &options));
+
+    let matcher =
+        RecordMatcher::new("100/1#.a =*i(token-set) 'ADA LOVELACE'")?;
+    assert!(matcher.is_match(&record, &options));
+
+    let matcher = RecordMatcher::new(
+        "100/1#.a =*(jaro-winkler, 99) 'Lovelace, A.'",
+    )?;
+    assert!(!matcher.is_match(&record, &options));
+
+    let matcher = RecordMatcher::new(
+        "100/1#.a =*(jaro-winkler, 90) 'Lovelace, A.'",
+    )?;
+    assert!(matcher.is_match(&record, &options));
+
+    // The inline threshold takes precedence over the options.
+    let options = MatchOptions::default().strsim_threshold(0.99);
+    let matcher = RecordMatcher::new(
+        "100/1#.a =*(levenshtein, 80) 'Lovelace, Bda'",
+    )?;
+    assert!(matcher.is_match(&record, &options));
+
+    assert!(RecordMatcher::new("100/1#.a =*(foo) 'Ada'").is_err());
+    assert!(
+        RecordMatcher::new("100/1#.a =*(token-set, 101) 'Ada'")
+            .is_err()
+    );
+
+    Ok(())
+}
@@ -30,6 +30,26 @@ fn path_leader_field() -> TestResult {
     let values = record.path(&path, &options);
     assert_eq!(values, vec!["589"]);
 
+    // encoding_level
+    let path = Path::new("ldr.encoding_level")?;
+    let values = record.path(&path, &options);
+    assert_eq!(values, vec!["n"]);
+
+    // punctuation_policy
+    let path = Path::new("ldr.punctuation_policy")?;
+    let values = record.path(&path, &options);
+    assert_eq!(values, vec!["c"]);
+
+    // indicator_count
+    let path = Path::new("ldr.indicator_count")?;
+    let values = record.path(&path, &options);
+    assert_eq!(values, vec!["2"]);
+
+    // bibliographic_level (not defined in authority records)
+    let path = Path::new("ldr.bibliographic_level")?;
+    let values = record.path(&path, &options);
+    assert!(values.is_empty());
+
     Ok(())
 }
 
@@ -83,6 +83,30 @@ fn query_control_field() -> TestResult {
     Ok(())
 }
 
+#[test]
+fn query_fixed_field() -> TestResult {
+    let record = ByteRecord::from_bytes(&ADA_LOVELACE)?;
+    let options = MatchOptions::default();
+
+    let query = Query::new("008.date_entered, 008.kind_of_record")?;
+    let values = record.query(&query, &options);
+    assert_eq!(values, vec![vec!["950316", "a"]]);
+
+    // element not defined for authority records
+    let query = Query::new("001, 008.lang")?;
+    let values = record.query(&query, &options);
+    assert_eq!(values, vec![vec!["119232022", ""]]);
+
+    // missing field
+    let query = Query::new("007.color")?;
+    let values = record.query(&query, &options);
+    assert!(values.is_empty());
+
+    assert!(Query::new("008.foo").is_err());
+
+    Ok(())
+}
+
 #[test]
 fn query_data_field() -> TestResult {
     let record = ByteRecord::from_bytes(&ADA_LOVELACE)?;
@@ -1,4 +1,5 @@
 use std::fmt;
+use std::str::Utf8Error;
 
 use marc21::ParseQueryError;
 use marc21::matcher::ParseMatcherError;
@@ -10,6 +11,7 @@ pub(crate) enum Error {
     Query(ParseQueryError),
     Matcher(ParseMatcherError),
     IO(std::io::Error),
+    Utf8(Utf8Error),
 }
 
 impl std::error::Error for Error {}
@@ -20,6 +22,7 @@ impl fmt::Display for Error {
             Self::Query(e) => write!(f, "{e}"),
             Self::Matcher(e) => write!(f, "{e}"),
             Self::IO(e) => write!(f, "{e}"),
+            Self::Utf8(e) => write!(f, "{e}"),
         }
     }
 }
@@ -42,6 +45,12 @@ impl From<std::io::Error> for Error {
     }
 }
 
+impl From<Utf8Error> for Error {
+    fn from(err: Utf8Error) -> Self {
+        Self::Utf8(err)
+    }
+}
+
 impl From<Error> for PyErr {
     fn from(err: Error) -> Self {
         PyOSError::new_err(err.to_string())
@@ -1,5 +1,7 @@
+use std::ops::ControlFlow;
 use std::path::PathBuf;
 use std::sync::Mutex;
+use std::thread;
 
 use marc21::matcher::{MatchOptions, RecordMatcher};
 use marc21::prelude::*;
@@ -52,54 +54,77 @@ impl LazyReader {
         slf
     }
 
-    fn __next__(mut slf: PyRefMut<'_, Self>) -> Option<Vec<String>> {
+    fn __next__(
+        mut slf: PyRefMut<'_, Self>,
+    ) -> Result<Option<Vec<String>>, Error> {
         let iter = slf.rows.get_mut().unwrap();
 
         match iter.next() {
-            Some(row) => Some(row),
+            Some(row) => Ok(Some(row)),
             None => match slf.sources.get_mut().unwrap().next() {
                 Some(path) => {
                     let mut rows: Vec<Vec<String>> = vec![];
-                    let mut rdr = MarcReadOptions::default()
-                        .try_into_reader_from_path(path)
-                        .unwrap(); // FIXME
-
-                    while let Some(result) = rdr.next_byte_record() {
-                        let Ok(record) = result else {
-                            continue;
-                        };
-
-                        if let Some(ref matcher) = slf.matcher
-                            && !matcher.is_match(&record, &slf.options)
-                        {
-                            continue;
-                        }
-
-                        let record =
-                            StringRecord::try_from(record).unwrap();
-
-                        let values: Vec<Vec<String>> = record
-                            .query(&slf.query, &slf.options)
-                            .iter()
-                            .map(|values| {
-                                values
+                    let mut error = None;
+                    let threads = thread::available_parallelism()
+                        .map(usize::from)
+                        .unwrap_or(1);
+
+                    let this = &*slf;
+                    MarcReadOptions::default()
+                        .try_into_chunk_reader_from_path(path)?
+                        .par_map(
+                            threads,
+                            |_, result| -> Result<Vec<Vec<String>>, Error> {
+                                let Ok(record) = result else {
+                                    return Ok(vec![]);
+                                };
+
+                                if let Some(ref matcher) = this.matcher
+                                    && !matcher.is_match(
+                                        &record,
+                                        &this.options,
+                                    )
+                                {
+                                    return Ok(vec![]);
+                                }
+
+                                let record = StringRecord::try_from(record)?;
+
+                                Ok(record
+                                    .query(&this.query, &this.options)
                                     .iter()
-                                    .map(|value| {
-                                        value
-                                            .to_str_unchecked()
-                                            .to_string()
+                                    .map(|values| {
+                                        values
+                                            .iter()
+                                            .map(|value| {
+                                                value
+                                                    .to_str_unchecked()
+                                                    .to_string()
+                                            })
+                                            .collect()
                                     })
-                                    .collect()
-                            })
-                            .collect();
-
-                        rows.extend(values);
+                                    .collect())
+                            },
+                            |values| match values {
+                                Ok(values) => {
+                                    rows.extend(values);
+                                    ControlFlow::Continue(())
+                                }
+                                Err(e) => {
+                                    error = Some(e);
+                                    ControlFlow::Break(())
+                                }
+                            },
+                        )?;
+
+                    if let Some(e) = error {
+                        return Err(e);
                     }
 
                     slf.rows = Mutex::new(Box::new(rows.into_iter()));
-                    slf.rows.lock().unwrap().next()
+                    Ok(slf.rows.lock().unwrap().next())
                 }
-                None => None,
+                None => Ok(None),
             },
         }
     }
@@ -70,6 +70,17 @@ def test_query_control_field(data_dir: Path) -> None:
     assert isinstance(actual, pl.DataFrame)
     assert actual.is_empty()
 
+    # named element of a fixed length control field
+    expected = pl.DataFrame({"column_1": ["950316"]})
+    actual = scan_marc21(path, "008.date_entered").collect()
+    assert isinstance(actual, pl.DataFrame)
+    assert_frame_equal(actual, expected)
+
+    # element not defined for authority records
+    actual = scan_marc21(path, "008.lang").collect()
+    assert isinstance(actual, pl.DataFrame)
+    assert actual.is_empty()
+
 
 def test_query_leader(data_dir: Path) -> None:
     path = data_dir.joinpath("ada.mrc")
@@ -86,6 +97,12 @@ def test_query_leader(data_dir: Path) -> None:
     assert isinstance(actual, pl.DataFrame)
     assert_frame_equal(actual, expected)
 
+    # encoding level
+    expected = pl.DataFrame({"column_1": ["n"]})
+    actual = scan_marc21(path, "ldr.encoding_level").collect()
+    assert isinstance(actual, pl.DataFrame)
+    assert_frame_equal(actual, expected)
+
     # length
     expected = pl.DataFrame({"column_1": ["3612"]})
     actual = scan_marc21(path, "ldr.length").collect()
@@ -200,3 +200,13 @@ shape: (2, 2)
     assert isinstance(lhs, pl.DataFrame)
     assert isinstance(rhs, pl.DataFrame)
     assert_frame_equal(lhs, rhs)
+
+
+def test_read_marc21_invalid_utf8(data_dir: Path, tmp_path: Path) -> None:
+    """Check that an invalid UTF-8 value raises instead of hanging."""
+    data = data_dir.joinpath("ada.mrc").read_bytes()
+    path = tmp_path.joinpath("invalid.mrc")
+    path.write_bytes(data.replace(b"Lovelace", b"Lovelac\xff", 1))
+
+    with pytest.raises(Exception, match="invalid utf-8"):
+        read_marc21(path, "001")